@@ -0,0 +1,443 @@
+#![cfg_attr(not(feature = "default"), allow(dead_code))]
+
+use crate::quote::ToTokens;
+use crate::syn;
+use crate::Trait;
+
+/// Collects errors so that a single build can report every mistake at once.
+#[derive(Default)]
+pub struct Errors {
+    error: Option<syn::Error>,
+}
+
+impl Errors {
+    #[inline]
+    pub fn new() -> Errors {
+        Errors::default()
+    }
+
+    #[inline]
+    pub fn push(&mut self, error: syn::Error) {
+        match self.error.as_mut() {
+            Some(e) => e.combine(error),
+            None => self.error = Some(error),
+        }
+    }
+
+    #[inline]
+    pub fn handle<T>(&mut self, result: Result<T, syn::Error>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(error) => {
+                self.push(error);
+
+                None
+            }
+        }
+    }
+
+    #[inline]
+    pub fn finish(self) -> Result<(), syn::Error> {
+        match self.error {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+}
+
+#[inline]
+pub fn unsupported_trait<T: ToTokens>(tokens: T, name: &str) -> syn::Error {
+    syn::Error::new_spanned(
+        tokens,
+        format!("Unsupported trait `{}`. Available traits are {:?}", name, Trait::variants()),
+    )
+}
+
+#[inline]
+pub fn reuse_a_trait<T: ToTokens>(tokens: T, t: Trait) -> syn::Error {
+    syn::Error::new_spanned(tokens, format!("The trait `{:?}` is repeatedly used.", t))
+}
+
+#[inline]
+pub fn trait_not_used<T: ToTokens>(tokens: T, t: Trait) -> syn::Error {
+    syn::Error::new_spanned(tokens, format!("The `{:?}` trait is not used.", t))
+}
+
+#[inline]
+pub fn trait_not_support_union<T: ToTokens>(tokens: T, t: Trait) -> syn::Error {
+    syn::Error::new_spanned(tokens, format!("The `{:?}` trait does not support to a union.", t))
+}
+
+#[inline]
+pub fn trait_only_support_enum<T: ToTokens>(tokens: T, t: Trait) -> syn::Error {
+    syn::Error::new_spanned(tokens, format!("The `{:?}` trait only supports to an enum.", t))
+}
+
+#[inline]
+pub fn trait_only_support_unit_variants<T: ToTokens>(tokens: T, t: Trait) -> syn::Error {
+    syn::Error::new_spanned(
+        tokens,
+        format!(
+            "The `{:?}` trait only supports to an enum whose variants are all unit variants.",
+            t
+        ),
+    )
+}
+
+#[inline]
+pub fn too_many_variants<T: ToTokens>(tokens: T, t: Trait, max: usize) -> syn::Error {
+    syn::Error::new_spanned(
+        tokens,
+        format!("The `{:?}` trait only supports to an enum with at most {} variants.", t, max),
+    )
+}
+
+#[inline]
+pub fn rank_without_ord<T: ToTokens>(tokens: T) -> syn::Error {
+    syn::Error::new_spanned(tokens, "The `rank` parameter needs the `Ord` trait or the `PartialOrd` trait implemented by Educe.")
+}
+
+#[inline]
+pub fn trait_only_support_named_fields<T: ToTokens>(tokens: T, t: Trait) -> syn::Error {
+    syn::Error::new_spanned(
+        tokens,
+        format!("The `{:?}` trait only supports to a struct with named fields.", t),
+    )
+}
+
+#[inline]
+pub fn attribute_incorrect_format<T: ToTokens>(
+    tokens: T,
+    attribute_name: &str,
+    correct_usage: &[&str],
+) -> syn::Error {
+    syn::Error::new_spanned(
+        tokens,
+        format!(
+            "You are using an incorrect format of the `{}` attribute.{}",
+            attribute_name,
+            concat_string_slice_array(correct_usage)
+        ),
+    )
+}
+
+#[inline]
+pub fn parameter_incorrect_format<T: ToTokens>(
+    tokens: T,
+    parameter_name: &str,
+    correct_usage: &[&str],
+) -> syn::Error {
+    syn::Error::new_spanned(
+        tokens,
+        format!(
+            "You are using an incorrect format of the `{}` parameter.{}",
+            parameter_name,
+            concat_string_slice_array(correct_usage)
+        ),
+    )
+}
+
+#[inline]
+pub fn derive_attribute_not_set_up_yet<T: ToTokens>(tokens: T, attribute_name: &str) -> syn::Error {
+    syn::Error::new_spanned(
+        tokens,
+        format!(
+            "You are using `{}` in the `derive` attribute, but it has not been set up yet.",
+            attribute_name
+        ),
+    )
+}
+
+#[inline]
+pub fn reset_parameter<T: ToTokens>(tokens: T, parameter_name: &str) -> syn::Error {
+    syn::Error::new_spanned(tokens, format!("Try to reset the `{}` parameter.", parameter_name))
+}
+
+#[inline]
+pub fn unknown_parameter<T: ToTokens>(
+    tokens: T,
+    attribute_name: &str,
+    parameter_name: &str,
+) -> syn::Error {
+    syn::Error::new_spanned(
+        tokens,
+        format!(
+            "Unknown parameter `{}` used in the `{}` attribute.",
+            parameter_name, attribute_name
+        ),
+    )
+}
+
+#[inline]
+pub fn set_value_expression<T: ToTokens>(tokens: T) -> syn::Error {
+    syn::Error::new_spanned(
+        tokens,
+        "The default value and the expression parameter can not be set at the same time.",
+    )
+}
+
+#[inline]
+pub fn set_ignore_with_others<T: ToTokens>(tokens: T) -> syn::Error {
+    syn::Error::new_spanned(
+        tokens,
+        "You don't need to set other parameters for an ignored variant or field.",
+    )
+}
+
+#[inline]
+pub fn set_const_with_into<T: ToTokens>(tokens: T) -> syn::Error {
+    syn::Error::new_spanned(
+        tokens,
+        "You cannot set the `const` parameter and the `into` parameter at the same time.",
+    )
+}
+
+#[inline]
+pub fn set_copy_with_deref<T: ToTokens>(tokens: T) -> syn::Error {
+    syn::Error::new_spanned(
+        tokens,
+        "You cannot set the `copy` parameter and the `deref` parameter at the same time.",
+    )
+}
+
+#[inline]
+pub fn set_expression_bound<T: ToTokens>(tokens: T) -> syn::Error {
+    syn::Error::new_spanned(
+        tokens,
+        "You don't need to set the expression and the bound at the same time.",
+    )
+}
+
+#[inline]
+pub fn no_default_field<T: ToTokens>(tokens: T) -> syn::Error {
+    syn::Error::new_spanned(tokens, "There is no field set as default.")
+}
+
+#[inline]
+pub fn multiple_default_fields<T: ToTokens>(tokens: T) -> syn::Error {
+    syn::Error::new_spanned(tokens, "Multiple default fields are set.")
+}
+
+#[inline]
+pub fn no_default_variant<T: ToTokens>(tokens: T) -> syn::Error {
+    syn::Error::new_spanned(tokens, "There is no variant set as default.")
+}
+
+#[inline]
+pub fn multiple_default_variants<T: ToTokens>(tokens: T) -> syn::Error {
+    syn::Error::new_spanned(tokens, "Multiple default variants are set.")
+}
+
+#[inline]
+pub fn no_deref_field<T: ToTokens>(tokens: T) -> syn::Error {
+    syn::Error::new_spanned(tokens, "There is no field which is assigned for `Deref`.")
+}
+
+#[inline]
+pub fn no_deref_field_of_variant<T: ToTokens>(tokens: T, variant_name: &str) -> syn::Error {
+    syn::Error::new_spanned(
+        tokens,
+        format!(
+            "There is no field for the `{variant_name}` variant which is assigned for `Deref`.",
+            variant_name = variant_name
+        ),
+    )
+}
+
+#[inline]
+pub fn multiple_deref_fields<T: ToTokens>(tokens: T) -> syn::Error {
+    syn::Error::new_spanned(tokens, "Multiple fields are set for `Deref`.")
+}
+
+#[inline]
+pub fn multiple_deref_fields_of_variant<T: ToTokens>(tokens: T, variant_name: &str) -> syn::Error {
+    syn::Error::new_spanned(
+        tokens,
+        format!(
+            "Multiple fields of the `{variant_name}` variant are set for deref.",
+            variant_name = variant_name
+        ),
+    )
+}
+
+#[inline]
+pub fn deref_cannot_support_unit_variant<T: ToTokens>(tokens: T) -> syn::Error {
+    syn::Error::new_spanned(
+        tokens,
+        "The `Deref` trait cannot be implemented for an enum which has unit variants.",
+    )
+}
+
+#[inline]
+pub fn no_deref_mut_field<T: ToTokens>(tokens: T) -> syn::Error {
+    syn::Error::new_spanned(tokens, "There is no field which is assigned for `DerefMut`.")
+}
+
+#[inline]
+pub fn no_deref_mut_field_of_variant<T: ToTokens>(tokens: T, variant_name: &str) -> syn::Error {
+    syn::Error::new_spanned(
+        tokens,
+        format!(
+            "There is no field for the `{variant_name}` variant which is assigned for `DerefMut`.",
+            variant_name = variant_name
+        ),
+    )
+}
+
+#[inline]
+pub fn multiple_deref_mut_fields<T: ToTokens>(tokens: T) -> syn::Error {
+    syn::Error::new_spanned(tokens, "Multiple fields are set for `DerefMut`.")
+}
+
+#[inline]
+pub fn multiple_deref_mut_fields_of_variant<T: ToTokens>(
+    tokens: T,
+    variant_name: &str,
+) -> syn::Error {
+    syn::Error::new_spanned(
+        tokens,
+        format!(
+            "Multiple fields of the `{variant_name}` variant are set for `DerefMut`.",
+            variant_name = variant_name
+        ),
+    )
+}
+
+#[inline]
+pub fn deref_mut_cannot_support_unit_variant<T: ToTokens>(tokens: T) -> syn::Error {
+    syn::Error::new_spanned(
+        tokens,
+        "The `DerefMut` trait cannot be implemented for an enum which has unit variants.",
+    )
+}
+
+#[inline]
+pub fn disable_named_field_name<T: ToTokens>(tokens: T) -> syn::Error {
+    syn::Error::new_spanned(tokens, "You can't disable the name of a named field.")
+}
+
+#[inline]
+pub fn empty_parameter<T: ToTokens>(tokens: T, parameter_name: &str) -> syn::Error {
+    syn::Error::new_spanned(
+        tokens,
+        format!("You can't set the `{}` parameter to empty.", parameter_name),
+    )
+}
+
+#[inline]
+pub fn unit_struct_need_name<T: ToTokens>(tokens: T) -> syn::Error {
+    syn::Error::new_spanned(tokens, "A unit struct needs to have a name.")
+}
+
+#[inline]
+pub fn unit_enum_need_name<T: ToTokens>(tokens: T) -> syn::Error {
+    syn::Error::new_spanned(tokens, "A unit enum needs to have a name.")
+}
+
+#[inline]
+pub fn unit_variant_need_name<T: ToTokens>(tokens: T) -> syn::Error {
+    syn::Error::new_spanned(
+        tokens,
+        "A unit variant which doesn't use an enum name needs to have a name.",
+    )
+}
+
+#[inline]
+pub fn ignore_ranked_field<T: ToTokens>(tokens: T) -> syn::Error {
+    syn::Error::new_spanned(tokens, "You can't ignore a ranked field.")
+}
+
+#[inline]
+pub fn reuse_a_rank<T: ToTokens>(tokens: T, rank: isize) -> syn::Error {
+    syn::Error::new_spanned(tokens, format!("The rank `{}` is repeatedly used.", rank))
+}
+
+#[inline]
+pub fn not_a_type_parameter<T: ToTokens>(tokens: T, name: &str) -> syn::Error {
+    syn::Error::new_spanned(tokens, format!("`{}` is not a type parameter of this type.", name))
+}
+
+#[inline]
+pub fn reuse_a_value<T: ToTokens>(tokens: T, value: isize) -> syn::Error {
+    syn::Error::new_spanned(tokens, format!("The value `{}` is repeatedly used.", value))
+}
+
+#[inline]
+pub fn reference_to_packed_field<T: ToTokens>(tokens: T, t: Trait) -> syn::Error {
+    syn::Error::new_spanned(
+        tokens,
+        format!(
+            "`{:?}` needs a reference to this field, which cannot be taken because the struct is \
+             `#[repr(packed)]`.",
+            t
+        ),
+    )
+}
+
+#[inline]
+pub fn unsupported_discriminant<T: ToTokens>(tokens: T, t: Trait) -> syn::Error {
+    syn::Error::new_spanned(
+        tokens,
+        format!(
+            "`{:?}` orders variants by their discriminants, which need to be integer literals.",
+            t
+        ),
+    )
+}
+
+#[inline]
+pub fn reserved_method_name<T: ToTokens>(tokens: T, name: &str, t: Trait) -> syn::Error {
+    syn::Error::new_spanned(
+        tokens,
+        format!(
+            "`{}` cannot be the name of the method created by `{:?}`. Set another name by \
+             `#[educe({:?}(name = \"method_name\"))]`.",
+            name, t, t
+        ),
+    )
+}
+
+// TODO patterns
+
+#[inline]
+pub fn educe_format_incorrect<T: ToTokens>(tokens: T) -> syn::Error {
+    attribute_incorrect_format(tokens, "educe", &[
+        stringify!(#[educe(Trait1, Trait2, ..., TraitN)]),
+    ])
+}
+
+fn concat_string_slice_array(array: &[&str]) -> String {
+    let len = array.len();
+
+    if len == 0 {
+        String::new()
+    } else {
+        let mut string = String::from(" It needs to be formed into ");
+
+        let mut iter = array.iter();
+
+        let first = iter.next().unwrap();
+
+        string.push('`');
+        string.push_str(&first.replace("\n", ""));
+        string.push('`');
+
+        if len > 2 {
+            for s in iter.take(len - 2) {
+                string.push_str(", `");
+                string.push_str(&s.replace("\n", ""));
+                string.push('`');
+            }
+        }
+
+        if len > 1 {
+            string.push_str(", or `");
+            string.push_str(&array[len - 1].replace("\n", ""));
+            string.push('`');
+        }
+
+        string.push('.');
+
+        string
+    }
+}
@@ -390,6 +390,22 @@ enum Enum<T, K> {
 }
 ```
 
+#### Comparing with Other Instantiations of a Generic Type
+
+The `#[educe(PartialEq(rhs = "generic_parameters"))]` attribute can be used to compare a generic type with another instantiation of itself, just like `Vec<A>` implements `PartialEq<Vec<B>>`. Each listed generic parameter gets an independent counterpart on the right-hand side, and the where predicates like `A: PartialEq<B>` are added automatically for the fields compared by `PartialEq` itself. A custom `bound` replaces these predicates. Use `#[educe(PartialEq(rhs))]` to list all generic type parameters.
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(PartialEq(rhs = "T"))]
+struct Wrapper<T> {
+    f1: T,
+}
+
+assert!(Wrapper { f1: String::from("Hi") } == Wrapper { f1: "Hi" });
+```
+
 ## Eq
 
 Use `#[derive(Educe)]` and `#[educe(Eq)]` to implement the `Eq` trait for a struct, an enum or a union.
@@ -633,6 +649,20 @@ enum Enum<T, K> {
 }
 ```
 
+#### Comparing with Other Instantiations of a Generic Type
+
+The `#[educe(PartialOrd(rhs = "generic_parameters"))]` attribute can be used to compare a generic type with another instantiation of itself, just like `PartialEq(rhs)`. Each listed generic parameter gets an independent counterpart on the right-hand side, and the where predicates like `A: PartialOrd<B>` are added automatically for the fields compared by `PartialOrd` itself. A custom `bound` replaces these predicates. Use `#[educe(PartialOrd(rhs))]` to list all generic type parameters.
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(PartialEq(rhs), PartialOrd(rhs))]
+struct Wrapper<T> {
+    f1: T,
+}
+```
+
 #### Ranking
 
 Each field can add a `#[educe(PartialOrd(rank = priority_value))]` attribute where `priority_value` is a positive integer value to determine their comparing precedence (lower `priority_value` leads to higher priority). The default `priority_value` for a field dependends on its ordinal (the lower the front) and is always lower than any custom `priority_value`.
@@ -1185,6 +1215,28 @@ struct Struct {
 }
 ```
 
+#### Const Default Values
+
+With the `#[educe(Default(const))]` attribute, your type will have an extra associated constant called `DEFAULT`, which the `default` method returns, and the `new` associated function will be a `const fn`. Every field must then have a default value or a default expression which can be evaluated in a const context, and a string literal is used as is instead of being converted by `Into`, so the compiler rejects anything else at the `DEFAULT` constant.
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(Default(const, new))]
+struct Config {
+    #[educe(Default = 8080)]
+    port: u16,
+    #[educe(Default = "localhost")]
+    host: &'static str,
+}
+
+static CONFIG: Config = Config::new();
+
+assert_eq!(8080, CONFIG.port);
+assert_eq!("localhost", Config::DEFAULT.host);
+```
+
 ## Clone
 
 Use `#[derive(Educe)]` and `#[educe(Clone)]` to implement the `Clone` trait for a struct, an enum, or a union. It supports to set a trait and/or a method to replace the `Clone` trait used by default.
@@ -1496,204 +1548,1318 @@ enum Enum {
 
 The mutable dereferencing fields don't need to be the same as the inmutable dereferencing fields. But their type must be the same.
 
-## TODO
+## IsVariant
 
-There is a lot of work to be done. Unimplemented traits are listed below:
+Use `#[derive(Educe)]` and `#[educe(IsVariant)]` to implement `is_<variant>` methods for an enum. The method names are the snake_case names of the variants.
 
-1. `From`
-1. `Into`
-1. `FromStr`
-1. `TryFrom`
-1. `TryInto`
+#### Basic Usage
 
-*/
+```rust
+#[macro_use] extern crate educe;
 
-#![recursion_limit = "128"]
+#[derive(Educe)]
+#[educe(IsVariant)]
+enum Enum {
+    V1,
+    V2 {
+        f1: u8,
+    },
+    V3(u8),
+}
 
-extern crate proc_macro;
-extern crate proc_macro2;
+assert!(Enum::V1.is_v1());
+assert!(Enum::V3(1).is_v3());
+```
 
-extern crate syn;
+#### Change the Name or Ignore Variants
 
-#[macro_use]
-extern crate quote;
+The `name` attribute can be used to change the part of a method name after `is_`, and the `ignore` attribute can be used to skip generating the method for a variant.
 
-#[macro_use]
-extern crate enum_ordinalize;
+```rust
+#[macro_use] extern crate educe;
 
-mod panic;
-mod support_traits;
-mod trait_handlers;
+#[derive(Educe)]
+#[educe(IsVariant)]
+enum Enum {
+    #[educe(IsVariant(name = "empty"))]
+    V1,
+    #[educe(IsVariant(ignore))]
+    V2 {
+        f1: u8,
+    },
+    V3(u8),
+}
 
-use std::collections::BTreeMap;
+assert!(Enum::V1.is_empty());
+```
 
-use proc_macro2::TokenStream;
-use quote::ToTokens;
-use syn::{DeriveInput, Meta, NestedMeta};
+## AsVariant
 
-use support_traits::Trait;
-use trait_handlers::TraitHandler;
+Use `#[derive(Educe)]` and `#[educe(AsVariant)]` to implement `as_<variant>` and `as_<variant>_mut` methods for an enum. They return `Option<&T>` and `Option<&mut T>` for variants with exactly one field, tuples of references for variants with more fields, and are not generated for unit variants.
 
-fn derive_input_handler(ast: DeriveInput) -> TokenStream {
-    let mut tokens = TokenStream::new();
-    let mut trait_meta_map: BTreeMap<Trait, Meta> = BTreeMap::new();
+#### Basic Usage
 
-    for attr in ast.attrs.iter() {
-        if let Some(attr_meta_name) = attr.path.get_ident() {
-            if attr_meta_name == "educe" {
-                let attr_meta = attr.parse_meta().unwrap();
+```rust
+#[macro_use] extern crate educe;
 
-                match attr_meta {
-                    Meta::List(list) => {
-                        for p in list.nested {
-                            match p {
-                                NestedMeta::Meta(meta) => {
-                                    let meta_name = meta.path().into_token_stream().to_string();
+#[derive(Educe)]
+#[educe(AsVariant)]
+enum Enum {
+    V1,
+    V2 {
+        f1: u8,
+        f2: u8,
+    },
+    V3(u8),
+}
 
-                                    let t = Trait::from_str(meta_name);
+let mut e = Enum::V3(1);
 
-                                    if trait_meta_map.contains_key(&t) {
-                                        panic::reuse_a_trait(t);
-                                    }
+*e.as_v3_mut().unwrap() += 1;
 
-                                    trait_meta_map.insert(t, meta);
-                                }
-                                NestedMeta::Lit(_) => {
-                                    panic::educe_format_incorrect();
-                                }
-                            }
-                        }
-                    }
-                    _ => {
-                        panic::educe_format_incorrect();
-                    }
-                }
-            }
-        }
-    }
+assert_eq!(Some(&2), e.as_v3());
+assert_eq!(None, e.as_v2());
+```
 
-    let traits: Vec<Trait> = trait_meta_map.keys().copied().collect();
+#### Change the Name or the Visibility, or Ignore Variants
 
-    #[cfg(feature = "Debug")]
-    {
-        if let Some(meta) = trait_meta_map.get(&Trait::Debug) {
-            trait_handlers::debug::DebugHandler::trait_meta_handler(
-                &ast,
-                &mut tokens,
-                &traits,
-                meta,
-            );
-        }
-    }
+The `name` attribute can be used to change the part of method names after `as_`, and the `ignore` attribute can be used to skip generating the methods for a variant. The `vis` attribute can be used on the enum or on a variant to change the visibility of the methods, which is `pub` by default.
 
-    #[cfg(feature = "PartialEq")]
-    {
-        if let Some(meta) = trait_meta_map.get(&Trait::PartialEq) {
-            trait_handlers::partial_eq::PartialEqHandler::trait_meta_handler(
-                &ast,
-                &mut tokens,
-                &traits,
-                meta,
-            );
-        }
-    }
+```rust
+#[macro_use] extern crate educe;
 
-    #[cfg(feature = "Eq")]
-    {
-        if let Some(meta) = trait_meta_map.get(&Trait::Eq) {
-            trait_handlers::eq::EqHandler::trait_meta_handler(&ast, &mut tokens, &traits, meta);
-        }
-    }
+#[derive(Educe)]
+#[educe(AsVariant(vis = "pub(crate)"))]
+enum Enum {
+    #[educe(AsVariant(ignore))]
+    V1 {
+        f1: u8,
+    },
+    #[educe(AsVariant(name = "value", vis = ""))]
+    V2(u8),
+}
 
-    #[cfg(feature = "PartialOrd")]
-    {
-        if let Some(meta) = trait_meta_map.get(&Trait::PartialOrd) {
-            trait_handlers::partial_ord::PartialOrdHandler::trait_meta_handler(
-                &ast,
-                &mut tokens,
-                &traits,
-                meta,
-            );
-        }
-    }
+assert_eq!(Some(&1), Enum::V2(1).as_value());
+```
 
-    #[cfg(feature = "Ord")]
-    {
-        if let Some(meta) = trait_meta_map.get(&Trait::Ord) {
-            trait_handlers::ord::OrdHandler::trait_meta_handler(&ast, &mut tokens, &traits, meta);
-        }
-    }
+## IntoVariant
 
-    #[cfg(feature = "Hash")]
-    {
-        if let Some(meta) = trait_meta_map.get(&Trait::Hash) {
-            trait_handlers::hash::HashHandler::trait_meta_handler(&ast, &mut tokens, &traits, meta);
-        }
-    }
+Use `#[derive(Educe)]` and `#[educe(IntoVariant)]` to implement `into_<variant>`, `unwrap_<variant>` and `expect_<variant>` methods for an enum. `into_<variant>` returns `Err(self)` if the value is another variant, while the other two methods panic with a message containing both the expected and the actual variant names. If the `Debug` trait is also implemented by Educe, the variant names in the message follow its `name` attributes.
 
-    #[cfg(feature = "Default")]
-    {
-        if let Some(meta) = trait_meta_map.get(&Trait::Default) {
-            trait_handlers::default::DefaultHandler::trait_meta_handler(
-                &ast,
-                &mut tokens,
-                &traits,
-                meta,
-            );
-        }
-    }
+#### Basic Usage
 
-    #[cfg(feature = "Clone")]
-    {
-        if let Some(meta) = trait_meta_map.get(&Trait::Clone) {
-            trait_handlers::clone::CloneHandler::trait_meta_handler(
-                &ast,
-                &mut tokens,
-                &traits,
-                meta,
-            );
-        }
-    }
+```rust
+#[macro_use] extern crate educe;
 
-    #[cfg(feature = "Copy")]
-    {
-        if let Some(meta) = trait_meta_map.get(&Trait::Copy) {
-            trait_handlers::copy::CopyHandler::trait_meta_handler(&ast, &mut tokens, &traits, meta);
-        }
-    }
+#[derive(Educe)]
+#[educe(IntoVariant)]
+enum Enum {
+    V1,
+    V2 {
+        f1: u8,
+        f2: u8,
+    },
+    V3(u8),
+}
 
-    #[cfg(feature = "Deref")]
-    {
-        if let Some(meta) = trait_meta_map.get(&Trait::Deref) {
-            trait_handlers::deref::DerefHandler::trait_meta_handler(
-                &ast,
-                &mut tokens,
-                &traits,
-                meta,
-            );
-        }
-    }
+assert_eq!(1, Enum::V3(1).unwrap_v3());
+assert_eq!((1, 2), Enum::V2 { f1: 1, f2: 2 }.expect_v2("not V2"));
+assert!(Enum::V1.into_v3().is_err());
+```
 
-    #[cfg(feature = "DerefMut")]
-    {
-        if let Some(meta) = trait_meta_map.get(&Trait::DerefMut) {
-            trait_handlers::deref_mut::DerefMutHandler::trait_meta_handler(
-                &ast,
-                &mut tokens,
-                &traits,
-                meta,
-            );
-        }
-    }
+#### Change the Name or the Visibility, or Ignore Variants
 
-    if tokens.is_empty() {
-        panic::derive_attribute_not_set_up_yet("Educe");
-    }
+The `name`, `vis` and `ignore` attributes can be used in the same way as `AsVariant`.
 
-    tokens
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(IntoVariant(vis = "pub(crate)"))]
+enum Enum {
+    #[educe(IntoVariant(ignore))]
+    V1 {
+        f1: u8,
+    },
+    #[educe(IntoVariant(name = "value"))]
+    V2(u8),
 }
 
-#[proc_macro_derive(Educe, attributes(educe))]
-pub fn educe_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    derive_input_handler(syn::parse(input).unwrap()).into()
+assert_eq!(1, Enum::V2(1).unwrap_value());
+```
+
+## Variants
+
+Use `#[derive(Educe)]` and `#[educe(Variants)]` to expose the metadata of the variants of an enum. The `VARIANTS` and `VARIANT_COUNT` associated constants contain the names and the number of the variants, and the `variant_name` and `variant_index` methods return the name and the index of the current variant. If the `Debug` trait is also implemented by Educe, the variant names follow its `name` attributes.
+
+#### Basic Usage
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(Variants)]
+enum Enum {
+    V1,
+    V2 {
+        f1: u8,
+    },
+    V3(u8),
+}
+
+assert_eq!(["V1", "V2", "V3"], Enum::VARIANTS);
+assert_eq!(3, Enum::VARIANT_COUNT);
+assert_eq!("V3", Enum::V3(1).variant_name());
+assert_eq!(2, Enum::V3(1).variant_index());
+```
+
+#### All Variants
+
+An enum whose variants are all unit variants additionally gets the `ALL` associated constant, which contains every variant in the declaration order.
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(Variants)]
+enum Enum {
+    V1,
+    V2,
+    V3,
+}
+
+assert_eq!(3, Enum::ALL.len());
+assert_eq!("V2", Enum::ALL[1].variant_name());
+```
+
+## Kind
+
+Use `#[derive(Educe)]` and `#[educe(Kind)]` to generate a fieldless companion enum which has a variant for every variant of an enum, and a `kind` method which returns the companion variant of the current variant. The companion enum is named `{Enum}Kind` and has the same visibility as the enum by default.
+
+#### Basic Usage
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(Kind)]
+enum Enum {
+    V1,
+    V2 {
+        f1: u8,
+    },
+    V3(u8),
+}
+
+assert!(matches!(Enum::V3(1).kind(), EnumKind::V3));
+```
+
+#### Change the Name or the Visibility
+
+The `name` and `vis` attributes can be used to change the name and the visibility of the companion enum.
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(Kind(name = "MessageKind", vis = "pub(crate)"))]
+enum Message {
+    Ping,
+    Text(u8),
+}
+
+assert!(matches!(Message::Text(1).kind(), MessageKind::Text));
+```
+
+#### Derive Traits for the Companion Enum
+
+The `derive` attribute can be used to let Educe implement traits for the companion enum. Any trait supported by Educe can be listed, with its own parameters.
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(Kind(derive(Debug, Clone, Copy, PartialEq, Eq, Hash)))]
+enum Message {
+    Ping,
+    Text(u8),
+}
+
+assert_eq!(MessageKind::Text, Message::Text(1).kind());
+```
+
+## Cycle
+
+Use `#[derive(Educe)]` and `#[educe(Cycle)]` to implement `next`, `prev`, `wrapping_next` and `wrapping_prev` methods for an enum whose variants are all unit variants. `next` and `prev` return `None` at the last and the first variant, while the wrapping methods go around to the other end.
+
+#### Basic Usage
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Debug, PartialEq, Educe)]
+#[educe(Cycle)]
+enum Enum {
+    V1,
+    V2,
+    V3,
+}
+
+assert_eq!(Some(Enum::V2), Enum::V1.next());
+assert_eq!(None, Enum::V3.next());
+assert_eq!(Enum::V1, Enum::V3.wrapping_next());
+assert_eq!(Enum::V3, Enum::V1.wrapping_prev());
+```
+
+#### Follow the Ranks
+
+The variants are visited in the declaration order by default. With the `rank` parameter, they are visited in the order of the `rank` attributes of the `Ord` trait, or of the `PartialOrd` trait if `Ord` is not implemented by Educe. If no variant has a `rank`, that order is the one of the discriminants, which then need to be integer literals.
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Debug, PartialEq, Educe)]
+#[educe(PartialOrd, Cycle(rank))]
+enum Enum {
+    #[educe(PartialOrd(rank = 2))]
+    V1,
+    #[educe(PartialOrd(rank = 0))]
+    V2,
+    #[educe(PartialOrd(rank = 1))]
+    V3,
+}
+
+assert_eq!(Some(Enum::V3), Enum::V2.next());
+assert_eq!(Some(Enum::V1), Enum::V3.next());
+assert_eq!(None, Enum::V1.next());
+```
+
+## EnumSet
+
+Use `#[derive(Educe)]` and `#[educe(EnumSet)]` to generate a bitset companion type for an enum whose variants are all unit variants. The set is named `{Enum}Set`, has the same visibility as the enum by default, and is backed by the smallest unsigned integer type that has a bit for every variant, so an enum can have at most 128 variants.
+
+The set has the `insert`, `remove`, `contains` and `iter` methods, the latter visiting the variants in the declaration order. It also implements the `|`, `&`, `^`, `-` and `!` operators, `FromIterator` and `Extend`.
+
+#### Basic Usage
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Debug, PartialEq, Educe)]
+#[educe(EnumSet)]
+enum Permission {
+    Read,
+    Write,
+    Execute,
+}
+
+let mut set = PermissionSet::new();
+
+set.insert(Permission::Execute);
+set.insert(Permission::Read);
+
+assert!(set.contains(Permission::Read));
+assert!(!set.contains(Permission::Write));
+assert_eq!(0b101, set.bits());
+assert_eq!(vec![Permission::Read, Permission::Execute], set.iter().collect::<Vec<_>>());
+assert_eq!(PermissionSet::all(), set | PermissionSet::from(Permission::Write));
+```
+
+#### Change the Name or the Visibility
+
+The `name` and `vis` attributes can be used to change the name and the visibility of the set.
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(EnumSet(name = "Permissions", vis = "pub(crate)"))]
+enum Permission {
+    Read,
+    Write,
+}
+
+let set: Permissions = vec![Permission::Write].into_iter().collect();
+
+assert_eq!(1, set.len());
+```
+
+## EnumMap
+
+Use `#[derive(Educe)]` and `#[educe(EnumMap)]` to generate an array-backed map companion type, which has a value for every variant of an enum whose variants are all unit variants. The map is named `{Enum}Map<V>` and has the same visibility as the enum by default.
+
+The map can be indexed by the variants, and its `iter` method visits the `(variant, &value)` pairs in the declaration order. It implements `Default`, `Clone` and `Debug` when `V` implements them.
+
+#### Basic Usage
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Debug, PartialEq, Educe)]
+#[educe(EnumMap)]
+enum Status {
+    Ok,
+    NotFound,
+    Error,
+}
+
+let mut counters = StatusMap::<u32>::default();
+
+counters[Status::Ok] += 2;
+counters[Status::Error] += 1;
+
+assert_eq!(2, counters[Status::Ok]);
+assert_eq!(vec![(Status::Ok, &2), (Status::NotFound, &0), (Status::Error, &1)], counters.iter().collect::<Vec<_>>());
+
+let names = StatusMap::from_array(["ok", "not found", "error"]);
+
+assert_eq!("not found", names[Status::NotFound]);
+```
+
+#### Change the Name or the Visibility
+
+The `name` and `vis` attributes can be used to change the name and the visibility of the map.
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(EnumMap(name = "Counters", vis = "pub(crate)"))]
+enum Status {
+    Ok,
+    Error,
+}
+
+let counters = Counters::from_fn(|status| match status {
+    Status::Ok => 1,
+    Status::Error => 0,
+});
+
+assert_eq!(1, counters[Status::Ok]);
+```
+
+## New
+
+Use `#[derive(Educe)]` and `#[educe(New)]` to implement a `new` constructor for a struct, which takes the fields as its parameters in the declaration order, or a constructor for every variant of an enum.
+
+#### Basic Usage
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(New)]
+struct Struct {
+    f1: u8,
+    f2: String,
+}
+
+let s = Struct::new(1, String::from("Hi"));
+
+assert_eq!(1, s.f1);
+assert_eq!("Hi", s.f2);
+```
+
+#### Default Values
+
+If the `Default` trait is also implemented by Educe, the fields which have a default value or a default expression are left out of the parameters and initialized with those defaults. A default can also be set by `#[educe(New(default))]`, `#[educe(New(default = literal))]` or `#[educe(New(default(expression = "expression")))]` on a field, which doesn't need the type to implement `Default`. `New(default)` uses the `Default` implementation of the field type.
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(Default, New)]
+struct Struct {
+    f1: u8,
+    #[educe(Default = "Hello")]
+    f2: String,
+    #[educe(Default(expression = "2 + 3"))]
+    f3: u8,
+}
+
+let s = Struct::new(1);
+
+assert_eq!(1, s.f1);
+assert_eq!("Hello", s.f2);
+assert_eq!(5, s.f3);
+```
+
+#### Change the Name or the Visibility, Make It Const, or Accept Into
+
+The `name` and `vis` attributes can be used to change the name and the visibility of the constructor. The `const` attribute makes it a `const fn`. The `into` attribute, on the type or on a field, makes the parameters accept `impl Into<T>`. A `const fn` cannot have `into` parameters.
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(New(name = "create", vis = "pub(crate)", const))]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Educe)]
+#[educe(New)]
+struct Message {
+    id: u64,
+    #[educe(New(into))]
+    text: String,
+}
+
+const ORIGIN: Point = Point::create(0, 0);
+
+assert_eq!(0, ORIGIN.x);
+assert_eq!("Hi", Message::new(1, "Hi").text);
+```
+
+#### Enums
+
+For an enum, a constructor is implemented for every variant and named after the variant in snake case. The `name`, `vis`, `const` and `into` attributes can be set on a variant, and the `vis`, `const` and `into` attributes can be set on the enum for all the constructors. Default values of fields are left out of the parameters in the same way as for a struct.
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Debug, PartialEq, Educe)]
+#[educe(New)]
+enum Shape {
+    Circle(f64),
+    Rect {
+        w: f64,
+        h: f64,
+    },
+    #[educe(New(name = "unit_square"))]
+    Square(f64),
+}
+
+assert_eq!(Shape::Circle(1.0), Shape::circle(1.0));
+assert_eq!(Shape::Rect { w: 1.0, h: 2.0 }, Shape::rect(1.0, 2.0));
+assert_eq!(Shape::Square(1.0), Shape::unit_square(1.0));
+```
+
+## Builder
+
+Use `#[derive(Educe)]` and `#[educe(Builder)]` to generate a builder for a struct with named fields. The builder is named `{Struct}Builder`, has the same visibility as the struct by default, and is created by the `builder` associated function. It has a chained setter for every field and a `build` method.
+
+If the `Default` trait is also implemented by Educe, the fields which have a default value or a default expression are optional, and the other fields are required. A default can also be set by `#[educe(Builder(default))]`, `#[educe(Builder(default = literal))]` or `#[educe(Builder(default(expression = "expression")))]` on a field, so the other fields don't need to implement `Default`. `build` returns an error, named `{Struct}BuilderError`, if a required field is not set.
+
+#### Basic Usage
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(Default, Builder)]
+struct Config {
+    host: String,
+    #[educe(Default = 8080)]
+    port: u16,
+}
+
+let config = Config::builder().host(String::from("localhost")).build().unwrap();
+
+assert_eq!("localhost", config.host);
+assert_eq!(8080, config.port);
+
+let error = Config::builder().port(80).build().err().unwrap();
+
+assert_eq!("host", error.field_name());
+```
+
+```rust
+#[macro_use] extern crate educe;
+
+use std::net::SocketAddr;
+
+#[derive(Educe)]
+#[educe(Builder)]
+struct Config {
+    addr: SocketAddr,
+    #[educe(Builder(default = 8))]
+    workers: u8,
+}
+
+let config = Config::builder().addr(([127, 0, 0, 1], 80).into()).build().unwrap();
+
+assert_eq!(8, config.workers);
+```
+
+#### Change the Name or the Visibility, or Accept Into
+
+The `name` and `vis` attributes can be used to change the name and the visibility of the builder. The `into` attribute, on the type or on a field, makes the setters accept `impl Into<T>`.
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(Builder(name = "ConfigOptions", vis = "pub(crate)"))]
+struct Config {
+    #[educe(Builder(into))]
+    host: String,
+    port: u16,
+}
+
+let options: ConfigOptions = Config::builder();
+
+let config = options.host("localhost").port(80).build().unwrap();
+
+assert_eq!("localhost", config.host);
+```
+
+#### Typestate
+
+With the `typestate` attribute, the builder tracks which required fields are set in its type, so `build` can only be called after all of them are set and returns the struct directly.
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(Default, Builder(typestate))]
+struct Config {
+    host: &'static str,
+    #[educe(Default = 8080)]
+    port: u16,
+}
+
+let config = Config::builder().host("localhost").build();
+
+assert_eq!(8080, config.port);
+```
+
+```rust,compile_fail
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(Default, Builder(typestate))]
+struct Config {
+    host: &'static str,
+    #[educe(Default = 8080)]
+    port: u16,
+}
+
+let config = Config::builder().port(80).build();
+```
+
+## Get
+
+Use `#[derive(Educe)]` and `#[educe(Get)]` to implement getters for the fields of a struct with named fields. A getter has the same name as its field and returns a reference to it.
+
+#### Basic Usage
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(Get)]
+struct User {
+    id: u64,
+    name: String,
+}
+
+let user = User { id: 1, name: String::from("Magic Len") };
+
+assert_eq!(1, *user.id());
+assert_eq!("Magic Len", user.name());
+```
+
+#### Copy and Deref
+
+The `copy` attribute, on the type or on a field, makes getters return the values instead of references. The `deref` attribute makes a getter return the dereferenced value, such as `&str` for a `String`, `&[T]` for a `Vec<T>`, `&T` for a `Box<T>`, or `Option<&str>` for an `Option<String>`.
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(Get)]
+struct User {
+    #[educe(Get(copy))]
+    id: u64,
+    #[educe(Get(deref))]
+    name: String,
+    #[educe(Get(deref))]
+    email: Option<String>,
+}
+
+let user = User { id: 1, name: String::from("Magic Len"), email: None };
+
+let id: u64 = user.id();
+let name: &str = user.name();
+let email: Option<&str> = user.email();
+```
+
+#### Change the Name or the Visibility, Ignore or Select Fields
+
+The `name` attribute can be used on a field to change the name of its getter. The `vis` attribute, on the type or on a field, can be used to change the visibility of getters, which is `pub` by default. The `ignore` attribute can be used to skip a field.
+
+If any field has the `#[educe(Get)]` attribute, only the fields which have a `Get` attribute get getters.
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(Get(vis = "pub(crate)"))]
+struct User {
+    #[educe(Get)]
+    id: u64,
+    #[educe(Get(name = "display_name"))]
+    name: String,
+    password: String,
+}
+
+let user = User { id: 1, name: String::from("Magic Len"), password: String::new() };
+
+assert_eq!("Magic Len", user.display_name());
+```
+
+## Set
+
+Use `#[derive(Educe)]` and `#[educe(Set)]` to implement a `set_<field>` setter and a consuming `with_<field>` method for the fields of a struct with named fields.
+
+#### Basic Usage
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(Set)]
+struct User {
+    id: u64,
+    name: String,
+}
+
+let mut user = User { id: 1, name: String::new() };
+
+user.set_name(String::from("Magic Len"));
+
+let user = user.with_id(2);
+
+assert_eq!(2, user.id);
+assert_eq!("Magic Len", user.name);
+```
+
+#### Accept Into, or Chain Setters
+
+The `into` attribute, on the type or on a field, makes the methods accept `impl Into<T>`. The `chain` attribute makes the setters return `&mut Self`.
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(Set(into, chain))]
+struct User {
+    id: u64,
+    name: String,
+}
+
+let mut user = User { id: 1, name: String::new() };
+
+user.set_id(2u8).set_name("Magic Len");
+
+assert_eq!(2, user.id);
+assert_eq!("Magic Len", user.name);
+```
+
+#### Change the Name or the Visibility, Ignore or Select Fields
+
+The `name` attribute can be used on a field to change the name in its methods. The `vis` attribute, on the type or on a field, can be used to change the visibility of the methods, which is `pub` by default. The `ignore` attribute can be used to skip a field.
+
+If any field has the `#[educe(Set)]` attribute, only the fields which have a `Set` attribute get the methods.
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(Set(vis = "pub(crate)"))]
+struct User {
+    #[educe(Set(name = "display_name"))]
+    name: String,
+    #[educe(Set(ignore))]
+    id: u64,
+}
+
+let user = User { name: String::new(), id: 1 }.with_display_name(String::from("Magic Len"));
+
+assert_eq!("Magic Len", user.name);
+```
+
+## Unquoted Attribute Values
+
+The values of the `trait`, `method`, `bound` and `expression` attributes can be written as they are in Rust, without being quoted into string literals. A single string literal keeps its old meaning, so `expression("\"Hi\"")` is still the expression `"Hi"`. Unquoted values keep the spans of their tokens, so an error points at the exact token, and IDEs can follow the paths in them.
+
+```rust
+#[macro_use] extern crate educe;
+
+use std::fmt::{self, Formatter};
+
+trait A {
+    fn format(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("Hi")
+    }
+}
+
+impl A for u8 {};
+
+#[derive(Educe)]
+#[educe(Debug(bound(T: std::fmt::Debug)), Default(bound(T: Default)))]
+struct Struct<T> {
+    #[educe(Debug(trait(A), method(format)), Default(expression(1 + 2)))]
+    f1: u8,
+    f2: T,
+}
+
+assert_eq!(3, Struct::<u8>::default().f1);
+```
+
+## Bounds Inferred from Field Types
+
+`bound(*)` (or `bound = "*"`) makes the `Debug`, `PartialEq`, `Eq`, `PartialOrd`, `Ord`, `Hash`, `Default`, `Clone` and `Copy` implementations require the trait for the types of the fields instead of for every generic parameter. Only the fields which mention a generic parameter and use the trait itself are bounded, so ignored fields, fields using a custom `method` (or a default value for `Default`) and `PhantomData` fields add nothing. Fields whose types mention the type itself or `Self`, like the `next` field of a linked list, add nothing either, since such a bound could never be proven.
+
+```rust
+#[macro_use] extern crate educe;
+
+use std::marker::PhantomData;
+
+struct NotDebug;
+
+#[derive(Educe)]
+#[educe(Debug(bound(*)))]
+struct Struct<T, K> {
+    f1: Vec<T>,
+    #[educe(Debug(ignore))]
+    f2: K,
+    f3: PhantomData<K>,
+}
+
+println!("{:?}", Struct { f1: vec![1], f2: NotDebug, f3: PhantomData });
+```
+
+## Bounds on Fields and Variants
+
+A `bound` can also be put on a field, or on a variant of an enum, for every trait which supports `bound`. The where predicates of a field are added to the ones of the type, so a bound which only one field needs, such as a bound on an associated type, doesn't force you to write the whole `bound` of the type by hand. A variant accepts the same forms as the type, and `bound(*)` on a variant only looks at the fields of that variant. `Default` only accepts it on fields.
+
+```rust
+#[macro_use] extern crate educe;
+
+trait Container {
+    type Item;
+}
+
+struct Bytes;
+
+impl Container for Bytes {
+    type Item = u8;
+}
+
+#[derive(Educe)]
+#[educe(Debug)]
+enum Enum<T: Container, U> {
+    Struct {
+        #[educe(Debug(bound = "T::Item: std::fmt::Debug"))]
+        f1: T::Item,
+    },
+    #[educe(Debug(bound(*)))]
+    Tuple(U),
+}
+
+println!("{:?}", Enum::<Bytes, u8>::Struct { f1: 1 });
+```
+
+## Path to the Crate
+
+When Educe is re-exported from another crate, `#[educe(crate = "path")]` (or `crate(path)`) tells the derive where Educe can be reached. The generated code only refers to `core` at present, so nothing needs `educe` to be a direct dependency. The path is checked, but it is not applied to the generated code yet, so a path which doesn't lead to Educe is accepted too.
+
+```rust
+mod prelude {
+    pub use educe::Educe;
+}
+
+#[derive(prelude::Educe)]
+#[educe(crate = "prelude", Debug)]
+struct Struct {
+    f1: u8,
+}
+```
+
+## Packed Structs
+
+`Debug`, `PartialEq`, `PartialOrd`, `Ord`, `Hash` and `Clone` can be derived for a `#[repr(packed)]` struct. A field of a packed struct may not be aligned, so it is copied out before being compared, hashed, cloned or formatted, and every field in use must be `Copy`. `Deref`, `DerefMut` and `Get` without `copy` hand out a reference to a field, so they are reported as errors on a packed struct.
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(Debug, PartialEq, Hash, Clone, Copy)]
+#[repr(C, packed)]
+struct Struct {
+    f1: u8,
+    f2: u64,
+}
+```
+
+## Ignoring a Field in Several Traits
+
+A field which shouldn't take part in comparisons, such as a cache or a timestamp, usually has to be ignored by `PartialEq`, `PartialOrd`, `Ord` and `Hash` together. `#[educe(skip)]` on a field ignores it in every one of those traits which is derived, and `#[educe(ignore(Trait, ...))]` ignores it in the listed traits, which can be any trait supporting `ignore` on fields. A trait which is ignored by a shorthand can't also be given on the same field.
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Struct {
+    f1: u8,
+    #[educe(skip)]
+    cache: u8,
+    #[educe(ignore(Debug, Hash))]
+    f2: u8,
+}
+```
+
+## TODO
+
+There is a lot of work to be done. Unimplemented traits are listed below:
+
+1. `From`
+1. `Into`
+1. `FromStr`
+1. `TryFrom`
+1. `TryInto`
+
+*/
+
+#![recursion_limit = "128"]
+
+extern crate proc_macro;
+extern crate proc_macro2;
+
+extern crate syn;
+
+#[macro_use]
+extern crate quote;
+
+#[macro_use]
+extern crate enum_ordinalize;
+
+mod error;
+mod support_traits;
+mod trait_handlers;
+
+use std::collections::BTreeMap;
+
+use proc_macro2::TokenStream;
+use syn::{DeriveInput, Lit, Meta, NestedMeta, Path};
+
+use error::Errors;
+use support_traits::Trait;
+use trait_handlers::TraitHandler;
+
+fn derive_input_handler(mut ast: DeriveInput) -> TokenStream {
+    let mut tokens = TokenStream::new();
+    let mut errors = Errors::new();
+    let mut trait_meta_map: BTreeMap<Trait, Meta> = BTreeMap::new();
+
+    // The override is accepted but not applied yet. The generated code only refers to `core`, so
+    // it never needs the path to Educe, which is only validated.
+    let mut crate_is_set = false;
+
+    for attr in ast.attrs.iter() {
+        if let Some(attr_meta_name) = attr.path.get_ident() {
+            if attr_meta_name == "educe" {
+                let attr_meta = match trait_handlers::parse_educe_meta(attr) {
+                    Ok(attr_meta) => attr_meta,
+                    Err(error) => {
+                        errors.push(error);
+
+                        continue;
+                    }
+                };
+
+                match attr_meta {
+                    Meta::List(list) => {
+                        for p in list.nested {
+                            match p {
+                                NestedMeta::Meta(meta) if meta.path().is_ident("crate") => {
+                                    if crate_is_set {
+                                        errors.push(error::reset_parameter(&meta, "crate"));
+                                    } else {
+                                        crate_is_set = true;
+
+                                        errors.handle(create_crate_path(&meta));
+                                    }
+                                }
+                                NestedMeta::Meta(meta) => {
+                                    let t = match Trait::from_path(meta.path()) {
+                                        Ok(t) => t,
+                                        Err(error) => {
+                                            errors.push(error);
+
+                                            continue;
+                                        }
+                                    };
+
+                                    if trait_meta_map.contains_key(&t) {
+                                        errors.push(error::reuse_a_trait(&meta, t));
+
+                                        continue;
+                                    }
+
+                                    trait_meta_map.insert(t, meta);
+                                }
+                                NestedMeta::Lit(lit) => {
+                                    errors.push(error::educe_format_incorrect(lit));
+                                }
+                            }
+                        }
+                    }
+                    _ => {
+                        errors.push(error::educe_format_incorrect(attr));
+                    }
+                }
+            }
+        }
+    }
+
+    let traits: Vec<Trait> = trait_meta_map.keys().copied().collect();
+
+    errors.handle(trait_handlers::expand_field_shorthands(&mut ast, &traits));
+
+    #[cfg(feature = "Debug")]
+    {
+        if let Some(meta) = trait_meta_map.get(&Trait::Debug) {
+            errors.handle(trait_handlers::debug::DebugHandler::trait_meta_handler(
+                &ast,
+                &mut tokens,
+                &traits,
+                meta,
+            ));
+        }
+    }
+
+    #[cfg(feature = "PartialEq")]
+    {
+        if let Some(meta) = trait_meta_map.get(&Trait::PartialEq) {
+            errors.handle(trait_handlers::partial_eq::PartialEqHandler::trait_meta_handler(
+                &ast,
+                &mut tokens,
+                &traits,
+                meta,
+            ));
+        }
+    }
+
+    #[cfg(feature = "Eq")]
+    {
+        if let Some(meta) = trait_meta_map.get(&Trait::Eq) {
+            errors.handle(trait_handlers::eq::EqHandler::trait_meta_handler(
+                &ast,
+                &mut tokens,
+                &traits,
+                meta,
+            ));
+        }
+    }
+
+    #[cfg(feature = "PartialOrd")]
+    {
+        if let Some(meta) = trait_meta_map.get(&Trait::PartialOrd) {
+            errors.handle(trait_handlers::partial_ord::PartialOrdHandler::trait_meta_handler(
+                &ast,
+                &mut tokens,
+                &traits,
+                meta,
+            ));
+        }
+    }
+
+    #[cfg(feature = "Ord")]
+    {
+        if let Some(meta) = trait_meta_map.get(&Trait::Ord) {
+            errors.handle(trait_handlers::ord::OrdHandler::trait_meta_handler(
+                &ast,
+                &mut tokens,
+                &traits,
+                meta,
+            ));
+        }
+    }
+
+    #[cfg(feature = "Hash")]
+    {
+        if let Some(meta) = trait_meta_map.get(&Trait::Hash) {
+            errors.handle(trait_handlers::hash::HashHandler::trait_meta_handler(
+                &ast,
+                &mut tokens,
+                &traits,
+                meta,
+            ));
+        }
+    }
+
+    #[cfg(feature = "Default")]
+    {
+        if let Some(meta) = trait_meta_map.get(&Trait::Default) {
+            errors.handle(trait_handlers::default::DefaultHandler::trait_meta_handler(
+                &ast,
+                &mut tokens,
+                &traits,
+                meta,
+            ));
+        }
+    }
+
+    #[cfg(feature = "Clone")]
+    {
+        if let Some(meta) = trait_meta_map.get(&Trait::Clone) {
+            errors.handle(trait_handlers::clone::CloneHandler::trait_meta_handler(
+                &ast,
+                &mut tokens,
+                &traits,
+                meta,
+            ));
+        }
+    }
+
+    #[cfg(feature = "Copy")]
+    {
+        if let Some(meta) = trait_meta_map.get(&Trait::Copy) {
+            errors.handle(trait_handlers::copy::CopyHandler::trait_meta_handler(
+                &ast,
+                &mut tokens,
+                &traits,
+                meta,
+            ));
+        }
+    }
+
+    #[cfg(feature = "Deref")]
+    {
+        if let Some(meta) = trait_meta_map.get(&Trait::Deref) {
+            errors.handle(trait_handlers::deref::DerefHandler::trait_meta_handler(
+                &ast,
+                &mut tokens,
+                &traits,
+                meta,
+            ));
+        }
+    }
+
+    #[cfg(feature = "DerefMut")]
+    {
+        if let Some(meta) = trait_meta_map.get(&Trait::DerefMut) {
+            errors.handle(trait_handlers::deref_mut::DerefMutHandler::trait_meta_handler(
+                &ast,
+                &mut tokens,
+                &traits,
+                meta,
+            ));
+        }
+    }
+
+    #[cfg(feature = "IsVariant")]
+    {
+        if let Some(meta) = trait_meta_map.get(&Trait::IsVariant) {
+            errors.handle(trait_handlers::is_variant::IsVariantHandler::trait_meta_handler(
+                &ast,
+                &mut tokens,
+                &traits,
+                meta,
+            ));
+        }
+    }
+
+    #[cfg(feature = "AsVariant")]
+    {
+        if let Some(meta) = trait_meta_map.get(&Trait::AsVariant) {
+            errors.handle(trait_handlers::as_variant::AsVariantHandler::trait_meta_handler(
+                &ast,
+                &mut tokens,
+                &traits,
+                meta,
+            ));
+        }
+    }
+
+    #[cfg(feature = "IntoVariant")]
+    {
+        if let Some(meta) = trait_meta_map.get(&Trait::IntoVariant) {
+            errors.handle(trait_handlers::into_variant::IntoVariantHandler::trait_meta_handler(
+                &ast,
+                &mut tokens,
+                &traits,
+                meta,
+            ));
+        }
+    }
+
+    #[cfg(feature = "Variants")]
+    {
+        if let Some(meta) = trait_meta_map.get(&Trait::Variants) {
+            errors.handle(trait_handlers::variants::VariantsHandler::trait_meta_handler(
+                &ast,
+                &mut tokens,
+                &traits,
+                meta,
+            ));
+        }
+    }
+
+    #[cfg(feature = "Kind")]
+    {
+        if let Some(meta) = trait_meta_map.get(&Trait::Kind) {
+            errors.handle(trait_handlers::kind::KindHandler::trait_meta_handler(
+                &ast,
+                &mut tokens,
+                &traits,
+                meta,
+            ));
+        }
+    }
+
+    #[cfg(feature = "Cycle")]
+    {
+        if let Some(meta) = trait_meta_map.get(&Trait::Cycle) {
+            errors.handle(trait_handlers::cycle::CycleHandler::trait_meta_handler(
+                &ast,
+                &mut tokens,
+                &traits,
+                meta,
+            ));
+        }
+    }
+
+    #[cfg(feature = "EnumSet")]
+    {
+        if let Some(meta) = trait_meta_map.get(&Trait::EnumSet) {
+            errors.handle(trait_handlers::enum_set::EnumSetHandler::trait_meta_handler(
+                &ast,
+                &mut tokens,
+                &traits,
+                meta,
+            ));
+        }
+    }
+
+    #[cfg(feature = "EnumMap")]
+    {
+        if let Some(meta) = trait_meta_map.get(&Trait::EnumMap) {
+            errors.handle(trait_handlers::enum_map::EnumMapHandler::trait_meta_handler(
+                &ast,
+                &mut tokens,
+                &traits,
+                meta,
+            ));
+        }
+    }
+
+    #[cfg(feature = "New")]
+    {
+        if let Some(meta) = trait_meta_map.get(&Trait::New) {
+            errors.handle(trait_handlers::new::NewHandler::trait_meta_handler(
+                &ast,
+                &mut tokens,
+                &traits,
+                meta,
+            ));
+        }
+    }
+
+    #[cfg(feature = "Builder")]
+    {
+        if let Some(meta) = trait_meta_map.get(&Trait::Builder) {
+            errors.handle(trait_handlers::builder::BuilderHandler::trait_meta_handler(
+                &ast,
+                &mut tokens,
+                &traits,
+                meta,
+            ));
+        }
+    }
+
+    #[cfg(feature = "Get")]
+    {
+        if let Some(meta) = trait_meta_map.get(&Trait::Get) {
+            errors.handle(trait_handlers::get::GetHandler::trait_meta_handler(
+                &ast,
+                &mut tokens,
+                &traits,
+                meta,
+            ));
+        }
+    }
+
+    #[cfg(feature = "Set")]
+    {
+        if let Some(meta) = trait_meta_map.get(&Trait::Set) {
+            errors.handle(trait_handlers::set::SetHandler::trait_meta_handler(
+                &ast,
+                &mut tokens,
+                &traits,
+                meta,
+            ));
+        }
+    }
+
+    if let Err(error) = errors.finish() {
+        tokens.extend(error.to_compile_error());
+    } else if tokens.is_empty() {
+        tokens
+            .extend(error::derive_attribute_not_set_up_yet(&ast.ident, "Educe").to_compile_error());
+    }
+
+    tokens
+}
+
+fn create_crate_path(meta: &Meta) -> Result<Path, syn::Error> {
+    let correct_usage_for_crate = [
+        stringify!(#[educe(crate = "path_to_educe")]),
+        stringify!(#[educe(crate("path_to_educe"))]),
+        stringify!(#[educe(crate(path_to_educe))]),
+    ];
+
+    let s = match meta {
+        Meta::NameValue(named_value) => {
+            match &named_value.lit {
+                Lit::Str(s) => s,
+                _ => {
+                    return Err(error::parameter_incorrect_format(
+                        meta,
+                        "crate",
+                        &correct_usage_for_crate,
+                    ))
+                }
+            }
+        }
+        Meta::List(list) if list.nested.len() == 1 => {
+            match &list.nested[0] {
+                NestedMeta::Lit(Lit::Str(s)) => s,
+                _ => {
+                    return Err(error::parameter_incorrect_format(
+                        meta,
+                        "crate",
+                        &correct_usage_for_crate,
+                    ))
+                }
+            }
+        }
+        _ => {
+            return Err(error::parameter_incorrect_format(meta, "crate", &correct_usage_for_crate))
+        }
+    };
+
+    match trait_handlers::create_path_from_lit_str(s)? {
+        Some(path) => Ok(path),
+        None => Err(error::empty_parameter(meta, "crate")),
+    }
+}
+
+#[proc_macro_derive(Educe, attributes(educe))]
+pub fn educe_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    match syn::parse(input) {
+        Ok(ast) => {
+            let tokens = derive_input_handler(ast);
+
+            trait_handlers::clear_typed_values();
+
+            tokens.into()
+        }
+        Err(error) => error.to_compile_error().into(),
+    }
 }
@@ -1,3 +1,7 @@
+use crate::error;
+use crate::quote::ToTokens;
+use crate::syn::{self, Path};
+
 #[cfg(not(any(
     feature = "Debug",
     feature = "PartialEq",
@@ -9,7 +13,19 @@
     feature = "Clone",
     feature = "Copy",
     feature = "Deref",
-    feature = "DerefMut"
+    feature = "DerefMut",
+    feature = "IsVariant",
+    feature = "AsVariant",
+    feature = "IntoVariant",
+    feature = "Variants",
+    feature = "Kind",
+    feature = "Cycle",
+    feature = "EnumSet",
+    feature = "EnumMap",
+    feature = "New",
+    feature = "Builder",
+    feature = "Get",
+    feature = "Set"
 )))]
 compile_error!("at least one of the trait features must be enabled");
 
@@ -38,14 +54,38 @@ pub enum Trait {
     Deref,
     #[cfg(feature = "DerefMut")]
     DerefMut,
+    #[cfg(feature = "IsVariant")]
+    IsVariant,
+    #[cfg(feature = "AsVariant")]
+    AsVariant,
+    #[cfg(feature = "IntoVariant")]
+    IntoVariant,
+    #[cfg(feature = "Variants")]
+    Variants,
+    #[cfg(feature = "Kind")]
+    Kind,
+    #[cfg(feature = "Cycle")]
+    Cycle,
+    #[cfg(feature = "EnumSet")]
+    EnumSet,
+    #[cfg(feature = "EnumMap")]
+    EnumMap,
+    #[cfg(feature = "New")]
+    New,
+    #[cfg(feature = "Builder")]
+    Builder,
+    #[cfg(feature = "Get")]
+    Get,
+    #[cfg(feature = "Set")]
+    Set,
 }
 
 impl Trait {
     #[inline]
-    pub fn from_str<S: AsRef<str>>(s: S) -> Trait {
-        let s = s.as_ref();
+    pub fn from_path(path: &Path) -> Result<Trait, syn::Error> {
+        let s = path.into_token_stream().to_string();
 
-        match s {
+        let t = match s.as_str() {
             #[cfg(feature = "Debug")]
             "Debug" => Trait::Debug,
             #[cfg(feature = "PartialEq")]
@@ -68,7 +108,33 @@ impl Trait {
             "Deref" => Trait::Deref,
             #[cfg(feature = "DerefMut")]
             "DerefMut" => Trait::DerefMut,
-            _ => panic!("Unsupported trait `{}`. Available traits are {:?}", s, Trait::variants()),
-        }
+            #[cfg(feature = "IsVariant")]
+            "IsVariant" => Trait::IsVariant,
+            #[cfg(feature = "AsVariant")]
+            "AsVariant" => Trait::AsVariant,
+            #[cfg(feature = "IntoVariant")]
+            "IntoVariant" => Trait::IntoVariant,
+            #[cfg(feature = "Variants")]
+            "Variants" => Trait::Variants,
+            #[cfg(feature = "Kind")]
+            "Kind" => Trait::Kind,
+            #[cfg(feature = "Cycle")]
+            "Cycle" => Trait::Cycle,
+            #[cfg(feature = "EnumSet")]
+            "EnumSet" => Trait::EnumSet,
+            #[cfg(feature = "EnumMap")]
+            "EnumMap" => Trait::EnumMap,
+            #[cfg(feature = "New")]
+            "New" => Trait::New,
+            #[cfg(feature = "Builder")]
+            "Builder" => Trait::Builder,
+            #[cfg(feature = "Get")]
+            "Get" => Trait::Get,
+            #[cfg(feature = "Set")]
+            "Set" => Trait::Set,
+            _ => return Err(error::unsupported_trait(path, &s)),
+        };
+
+        Ok(t)
     }
 }
@@ -0,0 +1,143 @@
+mod models;
+
+use super::{
+    create_field_binding, create_field_member, create_snake_case_string_from_ident, TraitHandler,
+};
+
+use crate::error::{self, Errors};
+use crate::proc_macro2::TokenStream;
+use crate::syn::{ext::IdentExt, Data, DeriveInput, Fields, Meta};
+use crate::Trait;
+
+use models::TypeAttributeBuilder;
+
+pub struct AsVariantHandler;
+
+impl TraitHandler for AsVariantHandler {
+    fn trait_meta_handler(
+        ast: &DeriveInput,
+        tokens: &mut TokenStream,
+        traits: &[Trait],
+        meta: &Meta,
+    ) -> Result<(), syn::Error> {
+        let mut errors = Errors::new();
+
+        let type_attribute = TypeAttributeBuilder {
+            enable_flag: true,
+            enable_name: false,
+            enable_ignore: false,
+            enable_vis: true,
+        }
+        .from_as_variant_meta(meta)?;
+
+        let vis = type_attribute.vis.unwrap_or_else(|| syn::parse2(quote!(pub)).unwrap());
+
+        let ident = &ast.ident;
+
+        let mut methods = TokenStream::new();
+
+        match &ast.data {
+            Data::Enum(data) => {
+                for variant in data.variants.iter() {
+                    let variant_attribute = match errors.handle(
+                        TypeAttributeBuilder {
+                            enable_flag: false,
+                            enable_name: true,
+                            enable_ignore: true,
+                            enable_vis: true,
+                        }
+                        .from_attributes(&variant.attrs, traits),
+                    ) {
+                        Some(variant_attribute) => variant_attribute,
+                        None => continue,
+                    };
+
+                    if variant_attribute.ignore {
+                        continue;
+                    }
+
+                    let variant_ident = &variant.ident;
+
+                    let (pattern, bindings, types) = match &variant.fields {
+                        Fields::Unit => continue,
+                        Fields::Named(_) | Fields::Unnamed(_) => {
+                            let mut members = Vec::new();
+                            let mut bindings = Vec::new();
+                            let mut types = Vec::new();
+
+                            for (index, field) in variant.fields.iter().enumerate() {
+                                members.push(create_field_member(index, field));
+                                bindings.push(create_field_binding(index));
+                                types.push(&field.ty);
+                            }
+
+                            (
+                                quote!(Self::#variant_ident { #(#members: #bindings),* }),
+                                bindings,
+                                types,
+                            )
+                        }
+                    };
+
+                    let name = match variant_attribute.name {
+                        Some(name) => name.unraw().to_string(),
+                        None => create_snake_case_string_from_ident(variant_ident),
+                    };
+
+                    let method_name = format_ident!("as_{}", name);
+                    let method_name_mut = format_ident!("as_{}_mut", name);
+
+                    let vis = variant_attribute.vis.as_ref().unwrap_or(&vis);
+
+                    let (return_type, return_type_mut, return_value) = if types.len() == 1 {
+                        let ty = types[0];
+                        let binding = &bindings[0];
+
+                        (quote!(&#ty), quote!(&mut #ty), quote!(#binding))
+                    } else {
+                        (
+                            quote!((#(&#types),*)),
+                            quote!((#(&mut #types),*)),
+                            quote!((#(#bindings),*)),
+                        )
+                    };
+
+                    methods.extend(quote! {
+                        #[inline]
+                        #[allow(unreachable_patterns)]
+                        #vis fn #method_name(&self) -> ::core::option::Option<#return_type> {
+                            match self {
+                                #pattern => ::core::option::Option::Some(#return_value),
+                                _ => ::core::option::Option::None,
+                            }
+                        }
+
+                        #[inline]
+                        #[allow(unreachable_patterns)]
+                        #vis fn #method_name_mut(&mut self) -> ::core::option::Option<#return_type_mut> {
+                            match self {
+                                #pattern => ::core::option::Option::Some(#return_value),
+                                _ => ::core::option::Option::None,
+                            }
+                        }
+                    });
+                }
+            }
+            _ => return Err(error::trait_only_support_enum(meta, Trait::AsVariant)),
+        }
+
+        let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+        let as_variant_impl = quote! {
+            impl #impl_generics #ident #ty_generics #where_clause {
+                #methods
+            }
+        };
+
+        errors.finish()?;
+
+        tokens.extend(as_variant_impl);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,392 @@
+use super::super::super::{
+    create_ident_from_lit_str, create_visibility_from_lit_str, parse_educe_meta,
+};
+
+use crate::error;
+use crate::quote::ToTokens;
+use crate::syn::{Attribute, Ident, Lit, Meta, NestedMeta, Visibility};
+use crate::Trait;
+
+#[derive(Clone)]
+pub struct TypeAttribute {
+    pub name: Option<Ident>,
+    pub ignore: bool,
+    pub vis: Option<Visibility>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TypeAttributeBuilder {
+    pub enable_flag: bool,
+    pub enable_name: bool,
+    pub enable_ignore: bool,
+    pub enable_vis: bool,
+}
+
+impl TypeAttributeBuilder {
+    #[allow(clippy::cognitive_complexity)]
+    pub fn from_as_variant_meta(&self, meta: &Meta) -> Result<TypeAttribute, syn::Error> {
+        let mut name = None;
+        let mut ignore = false;
+        let mut vis = None;
+
+        let correct_usage_for_as_variant_attribute = {
+            let mut usage = vec![];
+
+            if self.enable_flag {
+                usage.push(stringify!(#[educe(AsVariant)]));
+            }
+
+            if self.enable_ignore {
+                usage.push(stringify!(#[educe(AsVariant(ignore))]));
+            }
+
+            usage
+        };
+
+        let correct_usage_for_name = {
+            let usage = vec![
+                stringify!(#[educe(AsVariant(name = "new_name"))]),
+                stringify!(#[educe(AsVariant(name("new_name")))]),
+            ];
+
+            usage
+        };
+
+        let correct_usage_for_vis = {
+            let usage = vec![
+                stringify!(#[educe(AsVariant(vis = "visibility"))]),
+                stringify!(#[educe(AsVariant(vis("visibility")))]),
+            ];
+
+            usage
+        };
+
+        let correct_usage_for_ignore = {
+            let usage = vec![stringify!(#[educe(AsVariant(ignore))])];
+
+            usage
+        };
+
+        match meta {
+            Meta::List(list) => {
+                let mut ignore_is_set = false;
+
+                for p in list.nested.iter() {
+                    match p {
+                        NestedMeta::Meta(meta) => {
+                            let meta_name = meta.path().into_token_stream().to_string();
+
+                            match meta_name.as_str() {
+                                "name" => {
+                                    if !self.enable_name {
+                                        return Err(error::unknown_parameter(
+                                            meta,
+                                            "AsVariant",
+                                            meta_name.as_str(),
+                                        ));
+                                    }
+
+                                    match meta {
+                                        Meta::List(list) => {
+                                            for p in list.nested.iter() {
+                                                match p {
+                                                    NestedMeta::Lit(lit) => {
+                                                        match lit {
+                                                            Lit::Str(s) => {
+                                                                if name.is_some() {
+                                                                    return Err(
+                                                                        error::reset_parameter(
+                                                                            meta,
+                                                                            meta_name.as_str(),
+                                                                        ),
+                                                                    );
+                                                                }
+
+                                                                let s =
+                                                                    create_ident_from_lit_str(s)?;
+
+                                                                if let Some(s) = s {
+                                                                    name = Some(s);
+                                                                } else {
+                                                                    return Err(
+                                                                        error::empty_parameter(
+                                                                            meta,
+                                                                            meta_name.as_str(),
+                                                                        ),
+                                                                    );
+                                                                }
+                                                            }
+                                                            _ => return Err(
+                                                                error::parameter_incorrect_format(
+                                                                    meta,
+                                                                    meta_name.as_str(),
+                                                                    &correct_usage_for_name,
+                                                                ),
+                                                            ),
+                                                        }
+                                                    }
+                                                    _ => {
+                                                        return Err(
+                                                            error::parameter_incorrect_format(
+                                                                meta,
+                                                                meta_name.as_str(),
+                                                                &correct_usage_for_name,
+                                                            ),
+                                                        )
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        Meta::NameValue(named_value) => {
+                                            let lit = &named_value.lit;
+
+                                            match lit {
+                                                Lit::Str(s) => {
+                                                    if name.is_some() {
+                                                        return Err(error::reset_parameter(
+                                                            meta,
+                                                            meta_name.as_str(),
+                                                        ));
+                                                    }
+
+                                                    let s = create_ident_from_lit_str(s)?;
+
+                                                    if let Some(s) = s {
+                                                        name = Some(s);
+                                                    } else {
+                                                        return Err(error::empty_parameter(
+                                                            meta,
+                                                            meta_name.as_str(),
+                                                        ));
+                                                    }
+                                                }
+                                                _ => {
+                                                    return Err(error::parameter_incorrect_format(
+                                                        meta,
+                                                        meta_name.as_str(),
+                                                        &correct_usage_for_name,
+                                                    ))
+                                                }
+                                            }
+                                        }
+                                        _ => {
+                                            return Err(error::parameter_incorrect_format(
+                                                meta,
+                                                meta_name.as_str(),
+                                                &correct_usage_for_name,
+                                            ))
+                                        }
+                                    }
+                                }
+                                "vis" => {
+                                    if !self.enable_vis {
+                                        return Err(error::unknown_parameter(
+                                            meta,
+                                            "AsVariant",
+                                            meta_name.as_str(),
+                                        ));
+                                    }
+
+                                    match meta {
+                                        Meta::List(list) => {
+                                            for p in list.nested.iter() {
+                                                match p {
+                                                    NestedMeta::Lit(lit) => {
+                                                        match lit {
+                                                            Lit::Str(s) => {
+                                                                if vis.is_some() {
+                                                                    return Err(
+                                                                        error::reset_parameter(
+                                                                            meta,
+                                                                            meta_name.as_str(),
+                                                                        ),
+                                                                    );
+                                                                }
+
+                                                                vis = Some(
+                                                                    create_visibility_from_lit_str(
+                                                                        s,
+                                                                    )?,
+                                                                );
+                                                            }
+                                                            _ => return Err(
+                                                                error::parameter_incorrect_format(
+                                                                    meta,
+                                                                    meta_name.as_str(),
+                                                                    &correct_usage_for_vis,
+                                                                ),
+                                                            ),
+                                                        }
+                                                    }
+                                                    _ => {
+                                                        return Err(
+                                                            error::parameter_incorrect_format(
+                                                                meta,
+                                                                meta_name.as_str(),
+                                                                &correct_usage_for_vis,
+                                                            ),
+                                                        )
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        Meta::NameValue(named_value) => {
+                                            let lit = &named_value.lit;
+
+                                            match lit {
+                                                Lit::Str(s) => {
+                                                    if vis.is_some() {
+                                                        return Err(error::reset_parameter(
+                                                            meta,
+                                                            meta_name.as_str(),
+                                                        ));
+                                                    }
+
+                                                    vis = Some(create_visibility_from_lit_str(s)?);
+                                                }
+                                                _ => {
+                                                    return Err(error::parameter_incorrect_format(
+                                                        meta,
+                                                        meta_name.as_str(),
+                                                        &correct_usage_for_vis,
+                                                    ))
+                                                }
+                                            }
+                                        }
+                                        _ => {
+                                            return Err(error::parameter_incorrect_format(
+                                                meta,
+                                                meta_name.as_str(),
+                                                &correct_usage_for_vis,
+                                            ))
+                                        }
+                                    }
+                                }
+                                "ignore" => {
+                                    if !self.enable_ignore {
+                                        return Err(error::unknown_parameter(
+                                            meta,
+                                            "AsVariant",
+                                            meta_name.as_str(),
+                                        ));
+                                    }
+
+                                    match meta {
+                                        Meta::Path(_) => {
+                                            if ignore_is_set {
+                                                return Err(error::reset_parameter(
+                                                    meta,
+                                                    meta_name.as_str(),
+                                                ));
+                                            }
+
+                                            ignore_is_set = true;
+
+                                            ignore = true;
+                                        }
+                                        _ => {
+                                            return Err(error::parameter_incorrect_format(
+                                                meta,
+                                                meta_name.as_str(),
+                                                &correct_usage_for_ignore,
+                                            ))
+                                        }
+                                    }
+                                }
+                                _ => {
+                                    return Err(error::unknown_parameter(
+                                        meta,
+                                        "AsVariant",
+                                        meta_name.as_str(),
+                                    ))
+                                }
+                            }
+                        }
+                        _ => {
+                            return Err(error::attribute_incorrect_format(
+                                meta,
+                                "AsVariant",
+                                &correct_usage_for_as_variant_attribute,
+                            ))
+                        }
+                    }
+                }
+            }
+            Meta::NameValue(_) => {
+                return Err(error::attribute_incorrect_format(
+                    meta,
+                    "AsVariant",
+                    &correct_usage_for_as_variant_attribute,
+                ))
+            }
+            Meta::Path(_) => {
+                if !self.enable_flag {
+                    return Err(error::attribute_incorrect_format(
+                        meta,
+                        "AsVariant",
+                        &correct_usage_for_as_variant_attribute,
+                    ));
+                }
+            }
+        }
+
+        if (name.is_some() || vis.is_some()) && ignore {
+            return Err(error::set_ignore_with_others(meta));
+        }
+
+        Ok(TypeAttribute {
+            name,
+            ignore,
+            vis,
+        })
+    }
+
+    #[allow(clippy::wrong_self_convention)]
+    pub fn from_attributes(
+        self,
+        attributes: &[Attribute],
+        traits: &[Trait],
+    ) -> Result<TypeAttribute, syn::Error> {
+        let mut result = None;
+
+        for attribute in attributes.iter() {
+            if let Some(meta_name) = attribute.path.get_ident() {
+                if meta_name == "educe" {
+                    let meta = parse_educe_meta(attribute)?;
+
+                    match meta {
+                        Meta::List(list) => {
+                            for p in list.nested.iter() {
+                                match p {
+                                    NestedMeta::Meta(meta) => {
+                                        let t = Trait::from_path(meta.path())?;
+
+                                        if traits.binary_search(&t).is_err() {
+                                            return Err(error::trait_not_used(meta, t));
+                                        }
+
+                                        if t == Trait::AsVariant {
+                                            if result.is_some() {
+                                                return Err(error::reuse_a_trait(meta, t));
+                                            }
+
+                                            result = Some(self.from_as_variant_meta(&meta)?);
+                                        }
+                                    }
+                                    _ => return Err(error::educe_format_incorrect(attribute)),
+                                }
+                            }
+                        }
+                        _ => return Err(error::educe_format_incorrect(attribute)),
+                    }
+                }
+            }
+        }
+
+        Ok(result.unwrap_or(TypeAttribute {
+            name: None,
+            ignore: false,
+            vis: None,
+        }))
+    }
+}
@@ -0,0 +1,364 @@
+mod models;
+
+use super::{
+    create_field_default_tokens, create_hygienic_ident, create_unique_ident, TraitHandler,
+};
+
+use crate::error::{self, Errors};
+use crate::proc_macro2::TokenStream;
+use crate::syn::{ext::IdentExt, Data, DeriveInput, Fields, GenericParam, Generics, Meta};
+use crate::Trait;
+
+use models::{FieldAttributeBuilder, TypeAttributeBuilder};
+
+pub struct BuilderHandler;
+
+impl TraitHandler for BuilderHandler {
+    fn trait_meta_handler(
+        ast: &DeriveInput,
+        tokens: &mut TokenStream,
+        traits: &[Trait],
+        meta: &Meta,
+    ) -> Result<(), syn::Error> {
+        let mut errors = Errors::new();
+
+        let type_attribute = TypeAttributeBuilder {
+            enable_flag: true,
+            enable_name: true,
+            enable_vis: true,
+            enable_typestate: true,
+            enable_into: true,
+        }
+        .from_builder_meta(meta)?;
+
+        let ident = &ast.ident;
+
+        let builder_ident =
+            type_attribute.name.unwrap_or_else(|| format_ident!("{}Builder", ident));
+
+        let vis = type_attribute.vis.as_ref().unwrap_or(&ast.vis);
+
+        let mut field_idents = Vec::new();
+        let mut field_types = Vec::new();
+        let mut field_defaults = Vec::new();
+        let mut field_intos = Vec::new();
+
+        match &ast.data {
+            Data::Struct(data) => {
+                match &data.fields {
+                    Fields::Named(fields) => {
+                        for field in fields.named.iter() {
+                            let field_attribute = match errors.handle(
+                                FieldAttributeBuilder {
+                                    enable_into: true,
+                                    enable_default: true,
+                                }
+                                .from_attributes(&field.attrs, traits),
+                            ) {
+                                Some(field_attribute) => field_attribute,
+                                None => continue,
+                            };
+
+                            field_idents.push(field.ident.as_ref().unwrap());
+                            field_types.push(&field.ty);
+                            field_defaults.push(match field_attribute.default {
+                                Some(default) => Some(default.into_tokens(false)),
+                                None => create_field_default_tokens(field, traits, false),
+                            });
+                            field_intos.push(type_attribute.into || field_attribute.into);
+                        }
+                    }
+                    _ => return Err(error::trait_only_support_named_fields(meta, Trait::Builder)),
+                }
+            }
+            _ => return Err(error::trait_only_support_named_fields(meta, Trait::Builder)),
+        }
+
+        let ty_arguments: Vec<TokenStream> = ast
+            .generics
+            .params
+            .iter()
+            .map(|param| {
+                match param {
+                    GenericParam::Lifetime(lifetime) => {
+                        let lifetime = &lifetime.lifetime;
+
+                        quote!(#lifetime)
+                    }
+                    GenericParam::Type(ty) => {
+                        let ident = &ty.ident;
+
+                        quote!(#ident)
+                    }
+                    GenericParam::Const(constant) => {
+                        let ident = &constant.ident;
+
+                        quote!(#ident)
+                    }
+                }
+            })
+            .collect();
+
+        let mut setters = TokenStream::new();
+
+        let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+        errors.finish()?;
+
+        let inner = create_hygienic_ident("value");
+
+        if type_attribute.typestate {
+            let mut markers = Vec::new();
+
+            for (index, default) in field_defaults.iter().enumerate() {
+                if default.is_none() {
+                    markers.push(Some(create_unique_ident(ast, &format!("__Field{}", index))));
+                } else {
+                    markers.push(None);
+                }
+            }
+
+            let phantom = create_unique_ident(ast, "__phantom");
+
+            let marker_idents: Vec<_> = markers.iter().flatten().collect();
+
+            let unset_markers = marker_idents.iter().map(|_| quote!(()));
+
+            let mut builder_generics: Generics = ast.generics.clone();
+
+            // The markers follow the parameters of the struct, so a default can't be kept.
+            for param in builder_generics.params.iter_mut() {
+                match param {
+                    GenericParam::Type(typ) => {
+                        typ.eq_token = None;
+                        typ.default = None;
+                    }
+                    GenericParam::Const(cst) => {
+                        cst.eq_token = None;
+                        cst.default = None;
+                    }
+                    GenericParam::Lifetime(_) => (),
+                }
+            }
+
+            for marker in marker_idents.iter() {
+                builder_generics.params.push(syn::parse2(quote!(#marker)).unwrap());
+            }
+
+            let (builder_impl_generics, builder_ty_generics, _) = builder_generics.split_for_impl();
+
+            let mut builder_fields = TokenStream::new();
+            let mut empty_values = TokenStream::new();
+            let mut built_types = Vec::new();
+            let mut built_values = TokenStream::new();
+
+            for (index, field_ident) in field_idents.iter().enumerate() {
+                let ty = field_types[index];
+
+                match &markers[index] {
+                    Some(marker) => {
+                        builder_fields.extend(quote!(#field_ident: #marker,));
+                        empty_values.extend(quote!(#field_ident: (),));
+                        built_types.push(quote!(#ty));
+                        built_values.extend(quote!(#field_ident: self.#field_ident,));
+                    }
+                    None => {
+                        let default = field_defaults[index].as_ref().unwrap();
+
+                        builder_fields.extend(quote!(#field_ident: ::core::option::Option<#ty>,));
+                        empty_values.extend(quote!(#field_ident: ::core::option::Option::None,));
+                        built_values.extend(quote! {
+                            #field_ident: match self.#field_ident {
+                                ::core::option::Option::Some(#inner) => #inner,
+                                ::core::option::Option::None => #default,
+                            },
+                        });
+                    }
+                }
+            }
+
+            for (index, field_ident) in field_idents.iter().enumerate() {
+                let ty = field_types[index];
+
+                let (parameter_type, value) = if field_intos[index] {
+                    (
+                        quote!(impl ::core::convert::Into<#ty>),
+                        quote!(::core::convert::Into::into(#field_ident)),
+                    )
+                } else {
+                    (quote!(#ty), quote!(#field_ident))
+                };
+
+                match &markers[index] {
+                    Some(_) => {
+                        let mut return_markers = Vec::new();
+                        let mut moved_values = TokenStream::new();
+
+                        for (i, other_ident) in field_idents.iter().enumerate() {
+                            if let Some(marker) = &markers[i] {
+                                if i == index {
+                                    return_markers.push(quote!(#ty));
+                                } else {
+                                    return_markers.push(quote!(#marker));
+                                }
+                            }
+
+                            if i == index {
+                                moved_values.extend(quote!(#other_ident: #value,));
+                            } else {
+                                moved_values.extend(quote!(#other_ident: self.#other_ident,));
+                            }
+                        }
+
+                        setters.extend(quote! {
+                            #[inline]
+                            pub fn #field_ident(self, #field_ident: #parameter_type) -> #builder_ident<#(#ty_arguments,)* #(#return_markers),*> {
+                                #builder_ident {
+                                    #moved_values
+                                    #phantom: ::core::marker::PhantomData,
+                                }
+                            }
+                        });
+                    }
+                    None => {
+                        setters.extend(quote! {
+                            #[inline]
+                            pub fn #field_ident(mut self, #field_ident: #parameter_type) -> Self {
+                                self.#field_ident = ::core::option::Option::Some(#value);
+
+                                self
+                            }
+                        });
+                    }
+                }
+            }
+
+            let builder_impl = quote! {
+                #vis struct #builder_ident #builder_generics #where_clause {
+                    #builder_fields
+                    #phantom: ::core::marker::PhantomData<fn() -> #ident #ty_generics>,
+                }
+
+                impl #impl_generics #ident #ty_generics #where_clause {
+                    #[inline]
+                    #vis fn builder() -> #builder_ident<#(#ty_arguments,)* #(#unset_markers),*> {
+                        #builder_ident {
+                            #empty_values
+                            #phantom: ::core::marker::PhantomData,
+                        }
+                    }
+                }
+
+                impl #builder_impl_generics #builder_ident #builder_ty_generics #where_clause {
+                    #setters
+                }
+
+                impl #impl_generics #builder_ident<#(#ty_arguments,)* #(#built_types),*> #where_clause {
+                    #[inline]
+                    pub fn build(self) -> #ident #ty_generics {
+                        #ident {
+                            #built_values
+                        }
+                    }
+                }
+            };
+
+            tokens.extend(builder_impl);
+        } else {
+            let error_ident = format_ident!("{}Error", builder_ident);
+
+            let mut built_values = TokenStream::new();
+
+            for (index, field_ident) in field_idents.iter().enumerate() {
+                let ty = field_types[index];
+
+                let (parameter_type, value) = if field_intos[index] {
+                    (
+                        quote!(impl ::core::convert::Into<#ty>),
+                        quote!(::core::convert::Into::into(#field_ident)),
+                    )
+                } else {
+                    (quote!(#ty), quote!(#field_ident))
+                };
+
+                setters.extend(quote! {
+                    #[inline]
+                    pub fn #field_ident(mut self, #field_ident: #parameter_type) -> Self {
+                        self.#field_ident = ::core::option::Option::Some(#value);
+
+                        self
+                    }
+                });
+
+                let field_name = field_ident.unraw().to_string();
+
+                let missing = match &field_defaults[index] {
+                    Some(default) => quote!(#default),
+                    None => {
+                        quote! {
+                            return ::core::result::Result::Err(#error_ident {
+                                field_name: #field_name,
+                            })
+                        }
+                    }
+                };
+
+                built_values.extend(quote! {
+                    #field_ident: match self.#field_ident {
+                        ::core::option::Option::Some(#inner) => #inner,
+                        ::core::option::Option::None => #missing,
+                    },
+                });
+            }
+
+            let builder_impl = quote! {
+                #vis struct #builder_ident #impl_generics #where_clause {
+                    #(#field_idents: ::core::option::Option<#field_types>,)*
+                }
+
+                #[derive(::core::fmt::Debug, ::core::clone::Clone, ::core::marker::Copy, ::core::cmp::PartialEq, ::core::cmp::Eq)]
+                #vis struct #error_ident {
+                    field_name: &'static ::core::primitive::str,
+                }
+
+                impl #error_ident {
+                    #[inline]
+                    pub fn field_name(&self) -> &'static ::core::primitive::str {
+                        self.field_name
+                    }
+                }
+
+                impl ::core::fmt::Display for #error_ident {
+                    #[inline]
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        f.write_fmt(::core::format_args!("the `{}` field is not set", self.field_name))
+                    }
+                }
+
+                impl #impl_generics #ident #ty_generics #where_clause {
+                    #[inline]
+                    #vis fn builder() -> #builder_ident #ty_generics {
+                        #builder_ident {
+                            #(#field_idents: ::core::option::Option::None,)*
+                        }
+                    }
+                }
+
+                impl #impl_generics #builder_ident #ty_generics #where_clause {
+                    #setters
+
+                    #[inline]
+                    pub fn build(self) -> ::core::result::Result<#ident #ty_generics, #error_ident> {
+                        ::core::result::Result::Ok(#ident {
+                            #built_values
+                        })
+                    }
+                }
+            };
+
+            tokens.extend(builder_impl);
+        }
+
+        Ok(())
+    }
+}
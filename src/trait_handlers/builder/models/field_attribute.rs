@@ -0,0 +1,201 @@
+use super::super::super::{parse_educe_meta, FieldDefault};
+
+use crate::error;
+use crate::quote::ToTokens;
+use crate::syn::{Attribute, Meta, NestedMeta};
+use crate::Trait;
+
+#[derive(Clone)]
+pub struct FieldAttribute {
+    pub into: bool,
+    pub default: Option<FieldDefault>,
+}
+
+#[derive(Debug, Clone)]
+pub struct FieldAttributeBuilder {
+    pub enable_into: bool,
+    pub enable_default: bool,
+}
+
+impl FieldAttributeBuilder {
+    pub fn from_builder_meta(&self, meta: &Meta) -> Result<FieldAttribute, syn::Error> {
+        let mut into = false;
+        let mut default = None;
+
+        let correct_usage_for_builder_attribute = {
+            let mut usage = vec![];
+
+            if self.enable_into {
+                usage.push(stringify!(#[educe(Builder(into))]));
+            }
+
+            if self.enable_default {
+                usage.push(stringify!(#[educe(Builder(default))]));
+                usage.push(stringify!(#[educe(Builder(default = literal))]));
+                usage.push(stringify!(#[educe(Builder(default(expression = "expression")))]));
+            }
+
+            usage
+        };
+
+        let correct_usage_for_into = {
+            let usage = vec![stringify!(#[educe(Builder(into))])];
+
+            usage
+        };
+
+        let correct_usage_for_default = {
+            let usage = vec![
+                stringify!(#[educe(Builder(default))]),
+                stringify!(#[educe(Builder(default = literal))]),
+                stringify!(#[educe(Builder(default(expression = "expression")))]),
+                stringify!(#[educe(Builder(default(expression(expression))))]),
+            ];
+
+            usage
+        };
+
+        match meta {
+            Meta::List(list) => {
+                let mut into_is_set = false;
+
+                for p in list.nested.iter() {
+                    match p {
+                        NestedMeta::Meta(meta) => {
+                            let meta_name = meta.path().into_token_stream().to_string();
+
+                            match meta_name.as_str() {
+                                "into" => {
+                                    if !self.enable_into {
+                                        return Err(error::unknown_parameter(
+                                            meta,
+                                            "Builder",
+                                            meta_name.as_str(),
+                                        ));
+                                    }
+
+                                    match meta {
+                                        Meta::Path(_) => {
+                                            if into_is_set {
+                                                return Err(error::reset_parameter(
+                                                    meta,
+                                                    meta_name.as_str(),
+                                                ));
+                                            }
+
+                                            into_is_set = true;
+
+                                            into = true;
+                                        }
+                                        _ => {
+                                            return Err(error::parameter_incorrect_format(
+                                                meta,
+                                                meta_name.as_str(),
+                                                &correct_usage_for_into,
+                                            ))
+                                        }
+                                    }
+                                }
+                                "default" => {
+                                    if !self.enable_default {
+                                        return Err(error::unknown_parameter(
+                                            meta,
+                                            "Builder",
+                                            meta_name.as_str(),
+                                        ));
+                                    }
+
+                                    if default.is_some() {
+                                        return Err(error::reset_parameter(
+                                            meta,
+                                            meta_name.as_str(),
+                                        ));
+                                    }
+
+                                    default = Some(FieldDefault::from_meta(
+                                        meta,
+                                        &correct_usage_for_default,
+                                    )?);
+                                }
+                                _ => {
+                                    return Err(error::unknown_parameter(
+                                        meta,
+                                        "Builder",
+                                        meta_name.as_str(),
+                                    ))
+                                }
+                            }
+                        }
+                        _ => {
+                            return Err(error::attribute_incorrect_format(
+                                meta,
+                                "Builder",
+                                &correct_usage_for_builder_attribute,
+                            ))
+                        }
+                    }
+                }
+            }
+            _ => {
+                return Err(error::attribute_incorrect_format(
+                    meta,
+                    "Builder",
+                    &correct_usage_for_builder_attribute,
+                ))
+            }
+        }
+
+        Ok(FieldAttribute {
+            into,
+            default,
+        })
+    }
+
+    #[allow(clippy::wrong_self_convention)]
+    pub fn from_attributes(
+        self,
+        attributes: &[Attribute],
+        traits: &[Trait],
+    ) -> Result<FieldAttribute, syn::Error> {
+        let mut result = None;
+
+        for attribute in attributes.iter() {
+            if let Some(meta_name) = attribute.path.get_ident() {
+                if meta_name == "educe" {
+                    let meta = parse_educe_meta(attribute)?;
+
+                    match meta {
+                        Meta::List(list) => {
+                            for p in list.nested.iter() {
+                                match p {
+                                    NestedMeta::Meta(meta) => {
+                                        let t = Trait::from_path(meta.path())?;
+
+                                        if traits.binary_search(&t).is_err() {
+                                            return Err(error::trait_not_used(meta, t));
+                                        }
+
+                                        if t == Trait::Builder {
+                                            if result.is_some() {
+                                                return Err(error::reuse_a_trait(meta, t));
+                                            }
+
+                                            result = Some(self.from_builder_meta(&meta)?);
+                                        }
+                                    }
+                                    _ => return Err(error::educe_format_incorrect(attribute)),
+                                }
+                            }
+                        }
+                        _ => return Err(error::educe_format_incorrect(attribute)),
+                    }
+                }
+            }
+        }
+
+        Ok(result.unwrap_or(FieldAttribute {
+            into: false,
+            default: None,
+        }))
+    }
+}
@@ -0,0 +1,382 @@
+use super::super::super::{create_ident_from_lit_str, create_visibility_from_lit_str};
+
+use crate::error;
+use crate::quote::ToTokens;
+use crate::syn::{Ident, Lit, Meta, NestedMeta, Visibility};
+
+#[derive(Clone)]
+pub struct TypeAttribute {
+    pub name: Option<Ident>,
+    pub typestate: bool,
+    pub into: bool,
+    pub vis: Option<Visibility>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TypeAttributeBuilder {
+    pub enable_flag: bool,
+    pub enable_name: bool,
+    pub enable_typestate: bool,
+    pub enable_into: bool,
+    pub enable_vis: bool,
+}
+
+impl TypeAttributeBuilder {
+    #[allow(clippy::cognitive_complexity)]
+    pub fn from_builder_meta(&self, meta: &Meta) -> Result<TypeAttribute, syn::Error> {
+        let mut name = None;
+        let mut typestate = false;
+        let mut into = false;
+        let mut vis = None;
+
+        let correct_usage_for_builder_attribute = {
+            let mut usage = vec![];
+
+            if self.enable_flag {
+                usage.push(stringify!(#[educe(Builder)]));
+            }
+
+            if self.enable_typestate {
+                usage.push(stringify!(#[educe(Builder(typestate))]));
+            }
+
+            if self.enable_into {
+                usage.push(stringify!(#[educe(Builder(into))]));
+            }
+
+            usage
+        };
+
+        let correct_usage_for_name = {
+            let usage = vec![
+                stringify!(#[educe(Builder(name = "new_name"))]),
+                stringify!(#[educe(Builder(name("new_name")))]),
+            ];
+
+            usage
+        };
+
+        let correct_usage_for_typestate = {
+            let usage = vec![stringify!(#[educe(Builder(typestate))])];
+
+            usage
+        };
+
+        let correct_usage_for_into = {
+            let usage = vec![stringify!(#[educe(Builder(into))])];
+
+            usage
+        };
+
+        let correct_usage_for_vis = {
+            let usage = vec![
+                stringify!(#[educe(Builder(vis = "visibility"))]),
+                stringify!(#[educe(Builder(vis("visibility")))]),
+            ];
+
+            usage
+        };
+
+        match meta {
+            Meta::List(list) => {
+                let mut typestate_is_set = false;
+                let mut into_is_set = false;
+
+                for p in list.nested.iter() {
+                    match p {
+                        NestedMeta::Meta(meta) => {
+                            let meta_name = meta.path().into_token_stream().to_string();
+
+                            match meta_name.as_str() {
+                                "name" => {
+                                    if !self.enable_name {
+                                        return Err(error::unknown_parameter(
+                                            meta,
+                                            "Builder",
+                                            meta_name.as_str(),
+                                        ));
+                                    }
+
+                                    match meta {
+                                        Meta::List(list) => {
+                                            for p in list.nested.iter() {
+                                                match p {
+                                                    NestedMeta::Lit(lit) => {
+                                                        match lit {
+                                                            Lit::Str(s) => {
+                                                                if name.is_some() {
+                                                                    return Err(
+                                                                        error::reset_parameter(
+                                                                            meta,
+                                                                            meta_name.as_str(),
+                                                                        ),
+                                                                    );
+                                                                }
+
+                                                                let s =
+                                                                    create_ident_from_lit_str(s)?;
+
+                                                                if let Some(s) = s {
+                                                                    name = Some(s);
+                                                                } else {
+                                                                    return Err(
+                                                                        error::empty_parameter(
+                                                                            meta,
+                                                                            meta_name.as_str(),
+                                                                        ),
+                                                                    );
+                                                                }
+                                                            }
+                                                            _ => return Err(
+                                                                error::parameter_incorrect_format(
+                                                                    meta,
+                                                                    meta_name.as_str(),
+                                                                    &correct_usage_for_name,
+                                                                ),
+                                                            ),
+                                                        }
+                                                    }
+                                                    _ => {
+                                                        return Err(
+                                                            error::parameter_incorrect_format(
+                                                                meta,
+                                                                meta_name.as_str(),
+                                                                &correct_usage_for_name,
+                                                            ),
+                                                        )
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        Meta::NameValue(named_value) => {
+                                            let lit = &named_value.lit;
+
+                                            match lit {
+                                                Lit::Str(s) => {
+                                                    if name.is_some() {
+                                                        return Err(error::reset_parameter(
+                                                            meta,
+                                                            meta_name.as_str(),
+                                                        ));
+                                                    }
+
+                                                    let s = create_ident_from_lit_str(s)?;
+
+                                                    if let Some(s) = s {
+                                                        name = Some(s);
+                                                    } else {
+                                                        return Err(error::empty_parameter(
+                                                            meta,
+                                                            meta_name.as_str(),
+                                                        ));
+                                                    }
+                                                }
+                                                _ => {
+                                                    return Err(error::parameter_incorrect_format(
+                                                        meta,
+                                                        meta_name.as_str(),
+                                                        &correct_usage_for_name,
+                                                    ))
+                                                }
+                                            }
+                                        }
+                                        _ => {
+                                            return Err(error::parameter_incorrect_format(
+                                                meta,
+                                                meta_name.as_str(),
+                                                &correct_usage_for_name,
+                                            ))
+                                        }
+                                    }
+                                }
+                                "vis" => {
+                                    if !self.enable_vis {
+                                        return Err(error::unknown_parameter(
+                                            meta,
+                                            "Builder",
+                                            meta_name.as_str(),
+                                        ));
+                                    }
+
+                                    match meta {
+                                        Meta::List(list) => {
+                                            for p in list.nested.iter() {
+                                                match p {
+                                                    NestedMeta::Lit(lit) => {
+                                                        match lit {
+                                                            Lit::Str(s) => {
+                                                                if vis.is_some() {
+                                                                    return Err(
+                                                                        error::reset_parameter(
+                                                                            meta,
+                                                                            meta_name.as_str(),
+                                                                        ),
+                                                                    );
+                                                                }
+
+                                                                vis = Some(
+                                                                    create_visibility_from_lit_str(
+                                                                        s,
+                                                                    )?,
+                                                                );
+                                                            }
+                                                            _ => return Err(
+                                                                error::parameter_incorrect_format(
+                                                                    meta,
+                                                                    meta_name.as_str(),
+                                                                    &correct_usage_for_vis,
+                                                                ),
+                                                            ),
+                                                        }
+                                                    }
+                                                    _ => {
+                                                        return Err(
+                                                            error::parameter_incorrect_format(
+                                                                meta,
+                                                                meta_name.as_str(),
+                                                                &correct_usage_for_vis,
+                                                            ),
+                                                        )
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        Meta::NameValue(named_value) => {
+                                            let lit = &named_value.lit;
+
+                                            match lit {
+                                                Lit::Str(s) => {
+                                                    if vis.is_some() {
+                                                        return Err(error::reset_parameter(
+                                                            meta,
+                                                            meta_name.as_str(),
+                                                        ));
+                                                    }
+
+                                                    vis = Some(create_visibility_from_lit_str(s)?);
+                                                }
+                                                _ => {
+                                                    return Err(error::parameter_incorrect_format(
+                                                        meta,
+                                                        meta_name.as_str(),
+                                                        &correct_usage_for_vis,
+                                                    ))
+                                                }
+                                            }
+                                        }
+                                        _ => {
+                                            return Err(error::parameter_incorrect_format(
+                                                meta,
+                                                meta_name.as_str(),
+                                                &correct_usage_for_vis,
+                                            ))
+                                        }
+                                    }
+                                }
+                                "typestate" => {
+                                    if !self.enable_typestate {
+                                        return Err(error::unknown_parameter(
+                                            meta,
+                                            "Builder",
+                                            meta_name.as_str(),
+                                        ));
+                                    }
+
+                                    match meta {
+                                        Meta::Path(_) => {
+                                            if typestate_is_set {
+                                                return Err(error::reset_parameter(
+                                                    meta,
+                                                    meta_name.as_str(),
+                                                ));
+                                            }
+
+                                            typestate_is_set = true;
+
+                                            typestate = true;
+                                        }
+                                        _ => {
+                                            return Err(error::parameter_incorrect_format(
+                                                meta,
+                                                meta_name.as_str(),
+                                                &correct_usage_for_typestate,
+                                            ))
+                                        }
+                                    }
+                                }
+                                "into" => {
+                                    if !self.enable_into {
+                                        return Err(error::unknown_parameter(
+                                            meta,
+                                            "Builder",
+                                            meta_name.as_str(),
+                                        ));
+                                    }
+
+                                    match meta {
+                                        Meta::Path(_) => {
+                                            if into_is_set {
+                                                return Err(error::reset_parameter(
+                                                    meta,
+                                                    meta_name.as_str(),
+                                                ));
+                                            }
+
+                                            into_is_set = true;
+
+                                            into = true;
+                                        }
+                                        _ => {
+                                            return Err(error::parameter_incorrect_format(
+                                                meta,
+                                                meta_name.as_str(),
+                                                &correct_usage_for_into,
+                                            ))
+                                        }
+                                    }
+                                }
+                                _ => {
+                                    return Err(error::unknown_parameter(
+                                        meta,
+                                        "Builder",
+                                        meta_name.as_str(),
+                                    ))
+                                }
+                            }
+                        }
+                        _ => {
+                            return Err(error::attribute_incorrect_format(
+                                meta,
+                                "Builder",
+                                &correct_usage_for_builder_attribute,
+                            ))
+                        }
+                    }
+                }
+            }
+            Meta::NameValue(_) => {
+                return Err(error::attribute_incorrect_format(
+                    meta,
+                    "Builder",
+                    &correct_usage_for_builder_attribute,
+                ))
+            }
+            Meta::Path(_) => {
+                if !self.enable_flag {
+                    return Err(error::attribute_incorrect_format(
+                        meta,
+                        "Builder",
+                        &correct_usage_for_builder_attribute,
+                    ));
+                }
+            }
+        }
+
+        Ok(TypeAttribute {
+            name,
+            vis,
+            typestate,
+            into,
+        })
+    }
+}
@@ -1,9 +1,10 @@
-use std::fmt::Write;
-use std::str::FromStr;
-
-use super::super::TraitHandler;
+use super::super::{
+    create_field_member, create_field_reference, create_hygienic_ident,
+    create_packed_field_where_predicate, is_packed, TraitHandler,
+};
 use super::models::{FieldAttributeBuilder, TypeAttributeBuilder};
 
+use crate::error::Errors;
 use crate::proc_macro2::TokenStream;
 use crate::syn::{punctuated::Punctuated, Data, DeriveInput, Fields, Generics, Meta};
 use crate::Trait;
@@ -16,44 +17,62 @@ impl TraitHandler for CloneStructHandler {
         tokens: &mut TokenStream,
         traits: &[Trait],
         meta: &Meta,
-    ) {
+    ) -> Result<(), syn::Error> {
+        let mut errors = Errors::new();
+
         let type_attribute = TypeAttributeBuilder {
             enable_flag: true,
             enable_bound: true,
         }
-        .from_clone_meta(meta);
+        .from_clone_meta(meta)?;
 
         let mut bound = Punctuated::new();
+        let mut extra_bound = Vec::new();
+
+        let source = create_hygienic_ident("_source");
+
+        let packed = is_packed(ast);
 
         let mut clone_tokens = TokenStream::new();
         let mut clone_from_tokens = TokenStream::new();
 
         if let Data::Struct(data) = &ast.data {
             let mut field_attributes = Vec::new();
-            let mut field_names = Vec::new();
+            let mut field_members = Vec::new();
+            let mut field_types = Vec::new();
 
             #[cfg(feature = "Copy")]
             let mut has_custom_clone_method = false;
 
             for (index, field) in data.fields.iter().enumerate() {
-                let field_attribute = FieldAttributeBuilder {
-                    enable_impl: true,
-                }
-                .from_attributes(&field.attrs, traits);
-
-                let field_name = if let Some(ident) = field.ident.as_ref() {
-                    ident.to_string()
-                } else {
-                    format!("{}", index)
+                let field_attribute = match errors.handle(
+                    FieldAttributeBuilder {
+                        enable_impl: true,
+                        enable_bound: true,
+                    }
+                    .from_attributes(&field.attrs, traits),
+                ) {
+                    Some(field_attribute) => field_attribute,
+                    None => continue,
                 };
 
+                extra_bound.extend(field_attribute.bound.iter().cloned());
+
                 #[cfg(feature = "Copy")]
                 if field_attribute.clone_method.is_some() {
                     has_custom_clone_method = true;
                 }
 
+                if field_attribute.clone_method.is_none() {
+                    field_types.push(&field.ty);
+                }
+
+                if packed {
+                    extra_bound.push(create_packed_field_where_predicate(&field.ty));
+                }
+
                 field_attributes.push(field_attribute);
-                field_names.push(field_name);
+                field_members.push(create_field_member(index, field));
             }
 
             #[cfg(feature = "Copy")]
@@ -66,22 +85,31 @@ impl TraitHandler for CloneStructHandler {
                 bound = type_attribute
                     .bound
                     .into_punctuated_where_predicates_by_generic_parameters_with_copy(
+                        &ast.ident,
                         &ast.generics.params,
+                        &field_types,
                     );
 
                 clone_tokens.extend(quote!(*self));
 
-                let mut clone_from = String::new();
-
-                for field_name in field_names {
-                    clone_from.write_fmt(format_args!("core::clone::Clone::clone_from(&mut self.{field_name}, &_source.{field_name});", field_name = field_name)).unwrap();
+                for field_member in field_members {
+                    if packed {
+                        clone_from_tokens.extend(quote! {
+                            self.#field_member = #source.#field_member;
+                        });
+                    } else {
+                        clone_from_tokens.extend(quote! {
+                            ::core::clone::Clone::clone_from(&mut self.#field_member, &#source.#field_member);
+                        });
+                    }
                 }
-
-                clone_from_tokens.extend(TokenStream::from_str(&clone_from).unwrap());
             } else {
-                bound = type_attribute
-                    .bound
-                    .into_punctuated_where_predicates_by_generic_parameters(&ast.generics.params);
+                bound =
+                    type_attribute.bound.into_punctuated_where_predicates_by_generic_parameters(
+                        &ast.ident,
+                        &ast.generics.params,
+                        &field_types,
+                    );
 
                 match &data.fields {
                     Fields::Unit => {
@@ -89,96 +117,57 @@ impl TraitHandler for CloneStructHandler {
 
                         clone_tokens.extend(quote!(#ident));
                     }
-                    Fields::Unnamed(_) => {
-                        let mut clone = ast.ident.to_string();
-                        let mut clone_from = String::new();
-
-                        clone.push('(');
-
-                        for (index, field_attribute) in field_attributes.into_iter().enumerate() {
-                            let field_name = &field_names[index];
-
-                            let clone_trait = field_attribute.clone_trait;
-                            let clone_method = field_attribute.clone_method;
-
-                            match clone_trait {
+                    Fields::Named(_) | Fields::Unnamed(_) => {
+                        let mut fields_tokens = TokenStream::new();
+
+                        for (field_attribute, field_member) in
+                            field_attributes.into_iter().zip(field_members)
+                        {
+                            let self_field =
+                                create_field_reference(quote!(self), &field_member, packed);
+                            let source_field =
+                                create_field_reference(&source, &field_member, packed);
+
+                            let (clone, clone_from) = match field_attribute.clone_trait {
                                 Some(clone_trait) => {
-                                    let clone_method = clone_method.unwrap();
-
-                                    clone
-                                        .write_fmt(format_args!(
-                                            "{clone_trait}::{clone_method}(&self.{field_name}),",
-                                            clone_trait = clone_trait,
-                                            clone_method = clone_method,
-                                            field_name = field_name
-                                        ))
-                                        .unwrap();
-                                    clone_from.write_fmt(format_args!("self.{field_name} = {clone_trait}::{clone_method}(&_source.{field_name});", clone_trait = clone_trait, clone_method = clone_method, field_name = field_name)).unwrap();
-                                }
-                                None => {
-                                    match clone_method {
-                                        Some(clone_method) => {
-                                            clone
-                                                .write_fmt(format_args!(
-                                                    "{clone_method}(&self.{field_name}),",
-                                                    clone_method = clone_method,
-                                                    field_name = field_name
-                                                ))
-                                                .unwrap();
-                                            clone_from.write_fmt(format_args!("self.{field_name} = {clone_method}(&_source.{field_name});", clone_method = clone_method, field_name = field_name)).unwrap();
-                                        }
-                                        None => {
-                                            clone.write_fmt(format_args!("core::clone::Clone::clone(&self.{field_name}),", field_name = field_name)).unwrap();
-                                            clone_from.write_fmt(format_args!("core::clone::Clone::clone_from(&mut self.{field_name}, &_source.{field_name});", field_name = field_name)).unwrap();
-                                        }
-                                    }
-                                }
-                            }
-                        }
-
-                        clone.push(')');
-
-                        clone_tokens.extend(TokenStream::from_str(&clone).unwrap());
-                        clone_from_tokens.extend(TokenStream::from_str(&clone_from).unwrap());
-                    }
-                    Fields::Named(_) => {
-                        let mut clone = ast.ident.to_string();
-                        let mut clone_from = String::new();
-
-                        clone.push('{');
-
-                        for (index, field_attribute) in field_attributes.into_iter().enumerate() {
-                            let field_name = &field_names[index];
-
-                            let clone_trait = field_attribute.clone_trait;
-                            let clone_method = field_attribute.clone_method;
-
-                            match clone_trait {
-                                Some(clone_trait) => {
-                                    let clone_method = clone_method.unwrap();
+                                    let clone_method = field_attribute.clone_method.unwrap();
 
-                                    clone.write_fmt(format_args!("{field_name}: {clone_trait}::{clone_method}(&self.{field_name}),", clone_trait = clone_trait, clone_method = clone_method, field_name = field_name)).unwrap();
-                                    clone_from.write_fmt(format_args!("self.{field_name} = {clone_trait}::{clone_method}(&_source.{field_name});", clone_trait = clone_trait, clone_method = clone_method, field_name = field_name)).unwrap();
+                                    (
+                                        quote!(#clone_trait::#clone_method(#self_field)),
+                                        quote!(self.#field_member = #clone_trait::#clone_method(#source_field);),
+                                    )
                                 }
                                 None => {
-                                    match clone_method {
+                                    match field_attribute.clone_method {
                                         Some(clone_method) => {
-                                            clone.write_fmt(format_args!("{field_name}: {clone_method}(&self.{field_name}),", clone_method = clone_method, field_name = field_name)).unwrap();
-                                            clone_from.write_fmt(format_args!("self.{field_name} = {clone_method}(&_source.{field_name});", clone_method = clone_method, field_name = field_name)).unwrap();
+                                            (
+                                                quote!(#clone_method(#self_field)),
+                                                quote!(self.#field_member = #clone_method(#source_field);),
+                                            )
+                                        }
+                                        None if packed => {
+                                            (
+                                                quote!(::core::clone::Clone::clone(#self_field)),
+                                                quote!(self.#field_member = ::core::clone::Clone::clone(#source_field);),
+                                            )
                                         }
                                         None => {
-                                            clone.write_fmt(format_args!("{field_name}: core::clone::Clone::clone(&self.{field_name}),", field_name = field_name)).unwrap();
-                                            clone_from.write_fmt(format_args!("core::clone::Clone::clone_from(&mut self.{field_name}, &_source.{field_name});", field_name = field_name)).unwrap();
+                                            (
+                                                quote!(::core::clone::Clone::clone(#self_field)),
+                                                quote!(::core::clone::Clone::clone_from(&mut self.#field_member, &#source.#field_member);),
+                                            )
                                         }
                                     }
                                 }
-                            }
+                            };
+
+                            fields_tokens.extend(quote!(#field_member: #clone,));
+                            clone_from_tokens.extend(clone_from);
                         }
 
-                        clone.push('}');
+                        let ident = &ast.ident;
 
-                        clone_tokens.extend(TokenStream::from_str(&clone).unwrap());
-                        clone_from_tokens.extend(TokenStream::from_str(&clone_from).unwrap());
+                        clone_tokens.extend(quote!(#ident { #fields_tokens }));
                     }
                 }
             }
@@ -190,26 +179,30 @@ impl TraitHandler for CloneStructHandler {
 
         let where_clause = generics_cloned.make_where_clause();
 
-        for where_predicate in bound {
+        for where_predicate in bound.into_iter().chain(extra_bound) {
             where_clause.predicates.push(where_predicate);
         }
 
         let (impl_generics, ty_generics, where_clause) = generics_cloned.split_for_impl();
 
         let compare_impl = quote! {
-            impl #impl_generics core::clone::Clone for #ident #ty_generics #where_clause {
+            impl #impl_generics ::core::clone::Clone for #ident #ty_generics #where_clause {
                 #[inline]
                 fn clone(&self) -> Self {
                     #clone_tokens
                 }
 
                 #[inline]
-                fn clone_from(&mut self, _source: &Self) {
+                fn clone_from(&mut self, #source: &Self) {
                     #clone_from_tokens
                 }
             }
         };
 
+        errors.finish()?;
+
         tokens.extend(compare_impl);
+
+        Ok(())
     }
 }
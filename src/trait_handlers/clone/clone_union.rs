@@ -1,6 +1,7 @@
 use super::super::TraitHandler;
 use super::models::{FieldAttributeBuilder, TypeAttributeBuilder};
 
+use crate::error::Errors;
 use crate::proc_macro2::TokenStream;
 use crate::syn::{Data, DeriveInput, Meta};
 use crate::Trait;
@@ -13,19 +14,24 @@ impl TraitHandler for CloneUnionHandler {
         tokens: &mut TokenStream,
         traits: &[Trait],
         meta: &Meta,
-    ) {
+    ) -> Result<(), syn::Error> {
+        let mut errors = Errors::new();
+
         let _ = TypeAttributeBuilder {
             enable_flag: true,
             enable_bound: false,
         }
-        .from_clone_meta(meta);
+        .from_clone_meta(meta)?;
 
         if let Data::Union(data) = &ast.data {
             for field in data.fields.named.iter() {
-                let _ = FieldAttributeBuilder {
-                    enable_impl: false,
-                }
-                .from_attributes(&field.attrs, traits);
+                errors.handle(
+                    FieldAttributeBuilder {
+                        enable_impl: false,
+                        enable_bound: false,
+                    }
+                    .from_attributes(&field.attrs, traits),
+                );
             }
         }
 
@@ -34,7 +40,7 @@ impl TraitHandler for CloneUnionHandler {
         let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
 
         let compare_impl = quote! {
-            impl #impl_generics core::clone::Clone for #ident #ty_generics #where_clause {
+            impl #impl_generics ::core::clone::Clone for #ident #ty_generics #where_clause {
                 #[inline]
                 fn clone(&self) -> Self {
                     *self
@@ -42,6 +48,10 @@ impl TraitHandler for CloneUnionHandler {
             }
         };
 
+        errors.finish()?;
+
         tokens.extend(compare_impl);
+
+        Ok(())
     }
 }
@@ -22,17 +22,19 @@ impl TraitHandler for CloneHandler {
         tokens: &mut TokenStream,
         traits: &[Trait],
         meta: &Meta,
-    ) {
+    ) -> Result<(), syn::Error> {
         match ast.data {
             Data::Struct(_) => {
-                CloneStructHandler::trait_meta_handler(ast, tokens, traits, meta);
+                CloneStructHandler::trait_meta_handler(ast, tokens, traits, meta)?;
             }
             Data::Enum(_) => {
-                CloneEnumHandler::trait_meta_handler(ast, tokens, traits, meta);
+                CloneEnumHandler::trait_meta_handler(ast, tokens, traits, meta)?;
             }
             Data::Union(_) => {
-                CloneUnionHandler::trait_meta_handler(ast, tokens, traits, meta);
+                CloneUnionHandler::trait_meta_handler(ast, tokens, traits, meta)?;
             }
         }
+
+        Ok(())
     }
 }
@@ -1,12 +1,13 @@
 use super::super::super::{
-    create_where_predicates_from_generic_parameters, create_where_predicates_from_lit_str,
+    create_where_predicates_from_field_types, create_where_predicates_from_generic_parameters,
+    create_where_predicates_from_lit_str, parse_educe_meta,
 };
 
-use crate::panic;
+use crate::error;
 use crate::quote::ToTokens;
 use crate::syn::{
-    punctuated::Punctuated, token::Comma, Attribute, GenericParam, Lit, Meta, NestedMeta,
-    WherePredicate,
+    punctuated::Punctuated, token::Comma, Attribute, GenericParam, Ident, Lit, Meta, NestedMeta,
+    Type, WherePredicate,
 };
 use crate::Trait;
 
@@ -14,20 +15,31 @@ use crate::Trait;
 pub enum TypeAttributeBound {
     None,
     Auto,
+    Infer,
     Custom(Punctuated<WherePredicate, Comma>),
 }
 
 impl TypeAttributeBound {
     pub fn into_punctuated_where_predicates_by_generic_parameters(
         self,
+        ident: &Ident,
         params: &Punctuated<GenericParam, Comma>,
+        field_types: &[&Type],
     ) -> Punctuated<WherePredicate, Comma> {
         match self {
             TypeAttributeBound::None => Punctuated::new(),
             TypeAttributeBound::Auto => {
                 create_where_predicates_from_generic_parameters(
                     params,
-                    &syn::parse2(quote!(core::clone::Clone)).unwrap(),
+                    &syn::parse2(quote!(::core::clone::Clone)).unwrap(),
+                )
+            }
+            TypeAttributeBound::Infer => {
+                create_where_predicates_from_field_types(
+                    ident,
+                    params,
+                    field_types,
+                    &syn::parse2(quote!(::core::clone::Clone)).unwrap(),
                 )
             }
             TypeAttributeBound::Custom(where_predicates) => where_predicates,
@@ -36,14 +48,24 @@ impl TypeAttributeBound {
 
     pub fn into_punctuated_where_predicates_by_generic_parameters_with_copy(
         self,
+        ident: &Ident,
         params: &Punctuated<GenericParam, Comma>,
+        field_types: &[&Type],
     ) -> Punctuated<WherePredicate, Comma> {
         match self {
             TypeAttributeBound::None => Punctuated::new(),
             TypeAttributeBound::Auto => {
                 create_where_predicates_from_generic_parameters(
                     params,
-                    &syn::parse2(quote!(core::marker::Copy)).unwrap(),
+                    &syn::parse2(quote!(::core::marker::Copy)).unwrap(),
+                )
+            }
+            TypeAttributeBound::Infer => {
+                create_where_predicates_from_field_types(
+                    ident,
+                    params,
+                    field_types,
+                    &syn::parse2(quote!(::core::marker::Copy)).unwrap(),
                 )
             }
             TypeAttributeBound::Custom(where_predicates) => where_predicates,
@@ -64,7 +86,7 @@ pub struct TypeAttributeBuilder {
 }
 
 impl TypeAttributeBuilder {
-    pub fn from_clone_meta(&self, meta: &Meta) -> TypeAttribute {
+    pub fn from_clone_meta(&self, meta: &Meta) -> Result<TypeAttribute, syn::Error> {
         let mut flag = false;
         let mut bound = TypeAttributeBound::None;
 
@@ -81,8 +103,10 @@ impl TypeAttributeBuilder {
         let correct_usage_for_bound = {
             let usage = vec![
                 stringify!(#[educe(Clone(bound))]),
+                stringify!(#[educe(Clone(bound(*)))]),
                 stringify!(#[educe(Clone(bound = "where_predicates"))]),
                 stringify!(#[educe(Clone(bound("where_predicates")))]),
+                stringify!(#[educe(Clone(bound(where_predicates)))]),
             ];
 
             usage
@@ -100,7 +124,11 @@ impl TypeAttributeBuilder {
                             match meta_name.as_str() {
                                 "bound" => {
                                     if !self.enable_bound {
-                                        panic::unknown_parameter("Clone", meta_name.as_str());
+                                        return Err(error::unknown_parameter(
+                                            meta,
+                                            "Clone",
+                                            meta_name.as_str(),
+                                        ));
                                     }
 
                                     match meta {
@@ -111,40 +139,54 @@ impl TypeAttributeBuilder {
                                                         match lit {
                                                             Lit::Str(s) => {
                                                                 if bound_is_set {
-                                                                    panic::reset_parameter(
-                                                                        meta_name.as_str(),
+                                                                    return Err(
+                                                                        error::reset_parameter(
+                                                                            meta,
+                                                                            meta_name.as_str(),
+                                                                        ),
                                                                     );
                                                                 }
 
                                                                 bound_is_set = true;
 
-                                                                let where_predicates = create_where_predicates_from_lit_str(s);
+                                                                bound = if s.value().trim() == "*" {
+                                                                    TypeAttributeBound::Infer
+                                                                } else {
+                                                                    let where_predicates = create_where_predicates_from_lit_str(s)?;
 
-                                                                bound = match where_predicates {
+                                                                    match where_predicates {
                                                                     Some(where_predicates) => {
                                                                         TypeAttributeBound::Custom(
                                                                             where_predicates,
                                                                         )
                                                                     }
                                                                     None => {
-                                                                        panic::empty_parameter(
-                                                                            meta_name.as_str(),
+                                                                        return Err(
+                                                                            error::empty_parameter(
+                                                                                meta,
+                                                                                meta_name.as_str(),
+                                                                            ),
                                                                         )
                                                                     }
+                                                                }
                                                                 };
                                                             }
-                                                            _ => {
-                                                                panic::parameter_incorrect_format(
+                                                            _ => return Err(
+                                                                error::parameter_incorrect_format(
+                                                                    meta,
                                                                     meta_name.as_str(),
                                                                     &correct_usage_for_bound,
-                                                                )
-                                                            }
+                                                                ),
+                                                            ),
                                                         }
                                                     }
                                                     _ => {
-                                                        panic::parameter_incorrect_format(
-                                                            meta_name.as_str(),
-                                                            &correct_usage_for_bound,
+                                                        return Err(
+                                                            error::parameter_incorrect_format(
+                                                                meta,
+                                                                meta_name.as_str(),
+                                                                &correct_usage_for_bound,
+                                                            ),
                                                         )
                                                     }
                                                 }
@@ -156,38 +198,52 @@ impl TypeAttributeBuilder {
                                             match lit {
                                                 Lit::Str(s) => {
                                                     if bound_is_set {
-                                                        panic::reset_parameter(meta_name.as_str());
+                                                        return Err(error::reset_parameter(
+                                                            meta,
+                                                            meta_name.as_str(),
+                                                        ));
                                                     }
 
                                                     bound_is_set = true;
 
-                                                    let where_predicates =
-                                                        create_where_predicates_from_lit_str(s);
-
-                                                    bound = match where_predicates {
-                                                        Some(where_predicates) => {
-                                                            TypeAttributeBound::Custom(
-                                                                where_predicates,
-                                                            )
-                                                        }
-                                                        None => {
-                                                            panic::empty_parameter(
-                                                                meta_name.as_str(),
-                                                            )
+                                                    bound = if s.value().trim() == "*" {
+                                                        TypeAttributeBound::Infer
+                                                    } else {
+                                                        let where_predicates =
+                                                            create_where_predicates_from_lit_str(
+                                                                s,
+                                                            )?;
+
+                                                        match where_predicates {
+                                                            Some(where_predicates) => {
+                                                                TypeAttributeBound::Custom(
+                                                                    where_predicates,
+                                                                )
+                                                            }
+                                                            None => {
+                                                                return Err(error::empty_parameter(
+                                                                    meta,
+                                                                    meta_name.as_str(),
+                                                                ))
+                                                            }
                                                         }
                                                     };
                                                 }
                                                 _ => {
-                                                    panic::parameter_incorrect_format(
+                                                    return Err(error::parameter_incorrect_format(
+                                                        meta,
                                                         meta_name.as_str(),
                                                         &correct_usage_for_bound,
-                                                    )
+                                                    ))
                                                 }
                                             }
                                         }
                                         Meta::Path(_) => {
                                             if bound_is_set {
-                                                panic::reset_parameter(meta_name.as_str());
+                                                return Err(error::reset_parameter(
+                                                    meta,
+                                                    meta_name.as_str(),
+                                                ));
                                             }
 
                                             bound_is_set = true;
@@ -196,79 +252,96 @@ impl TypeAttributeBuilder {
                                         }
                                     }
                                 }
-                                _ => panic::unknown_parameter("Clone", meta_name.as_str()),
+                                _ => {
+                                    return Err(error::unknown_parameter(
+                                        meta,
+                                        "Clone",
+                                        meta_name.as_str(),
+                                    ))
+                                }
                             }
                         }
                         _ => {
-                            panic::attribute_incorrect_format(
+                            return Err(error::attribute_incorrect_format(
+                                meta,
                                 "Clone",
                                 &correct_usage_for_clone_attribute,
-                            )
+                            ))
                         }
                     }
                 }
             }
             Meta::NameValue(_) => {
-                panic::attribute_incorrect_format("Clone", &correct_usage_for_clone_attribute)
+                return Err(error::attribute_incorrect_format(
+                    meta,
+                    "Clone",
+                    &correct_usage_for_clone_attribute,
+                ))
             }
             Meta::Path(_) => {
                 if !self.enable_flag {
-                    panic::attribute_incorrect_format("Clone", &correct_usage_for_clone_attribute);
+                    return Err(error::attribute_incorrect_format(
+                        meta,
+                        "Clone",
+                        &correct_usage_for_clone_attribute,
+                    ));
                 }
 
                 flag = true;
             }
         }
 
-        TypeAttribute {
+        Ok(TypeAttribute {
             flag,
             bound,
-        }
+        })
     }
 
     #[allow(clippy::wrong_self_convention)]
-    pub fn from_attributes(self, attributes: &[Attribute], traits: &[Trait]) -> TypeAttribute {
+    pub fn from_attributes(
+        self,
+        attributes: &[Attribute],
+        traits: &[Trait],
+    ) -> Result<TypeAttribute, syn::Error> {
         let mut result = None;
 
         for attribute in attributes.iter() {
             if let Some(meta_name) = attribute.path.get_ident() {
                 if meta_name == "educe" {
-                    let meta = attribute.parse_meta().unwrap();
+                    let meta = parse_educe_meta(attribute)?;
 
                     match meta {
                         Meta::List(list) => {
                             for p in list.nested.iter() {
                                 match p {
                                     NestedMeta::Meta(meta) => {
-                                        let meta_name = meta.path().into_token_stream().to_string();
-
-                                        let t = Trait::from_str(meta_name);
+                                        let t = Trait::from_path(meta.path())?;
 
                                         if traits.binary_search(&t).is_err() {
-                                            panic::trait_not_used(t);
+                                            return Err(error::trait_not_used(meta, t));
                                         }
 
                                         if t == Trait::Clone {
                                             if result.is_some() {
-                                                panic::reuse_a_trait(t);
+                                                return Err(error::reuse_a_trait(meta, t));
                                             }
 
-                                            result = Some(self.from_clone_meta(&meta));
+                                            result = Some(self.from_clone_meta(&meta)?);
                                         }
                                     }
-                                    _ => panic::educe_format_incorrect(),
+                                    _ => return Err(error::educe_format_incorrect(attribute)),
                                 }
                             }
                         }
-                        _ => panic::educe_format_incorrect(),
+                        _ => return Err(error::educe_format_incorrect(attribute)),
                     }
                 }
             }
         }
 
-        result.unwrap_or(TypeAttribute {
+        Ok(result.unwrap_or(TypeAttribute {
             flag: false,
             bound: TypeAttributeBound::None,
-        })
+        }))
     }
 }
@@ -0,0 +1,215 @@
+use super::super::super::{create_where_predicates_from_lit_str, parse_educe_meta};
+
+use crate::error;
+use crate::quote::ToTokens;
+use crate::syn::{
+    punctuated::Punctuated, token::Comma, Attribute, Lit, Meta, NestedMeta, WherePredicate,
+};
+use crate::Trait;
+
+#[derive(Clone)]
+pub struct FieldAttribute {
+    pub bound: Punctuated<WherePredicate, Comma>,
+}
+
+#[derive(Debug, Clone)]
+pub struct FieldAttributeBuilder {
+    pub enable_bound: bool,
+}
+
+impl FieldAttributeBuilder {
+    pub fn from_copy_meta(&self, meta: &Meta) -> Result<FieldAttribute, syn::Error> {
+        let mut bound = Punctuated::new();
+
+        let correct_usage_for_copy_attribute = {
+            let usage = vec![stringify!(#[educe(Copy(bound = "where_predicates"))])];
+
+            usage
+        };
+
+        let correct_usage_for_bound = {
+            let usage = vec![
+                stringify!(#[educe(Copy(bound = "where_predicates"))]),
+                stringify!(#[educe(Copy(bound("where_predicates")))]),
+                stringify!(#[educe(Copy(bound(where_predicates)))]),
+            ];
+
+            usage
+        };
+
+        match meta {
+            Meta::List(list) => {
+                for p in list.nested.iter() {
+                    match p {
+                        NestedMeta::Meta(meta) => {
+                            let meta_name = meta.path().into_token_stream().to_string();
+
+                            match meta_name.as_str() {
+                                "bound" => {
+                                    if !self.enable_bound {
+                                        return Err(error::unknown_parameter(
+                                            meta,
+                                            "Copy",
+                                            meta_name.as_str(),
+                                        ));
+                                    }
+
+                                    match meta {
+                                        Meta::List(list) => {
+                                            for p in list.nested.iter() {
+                                                match p {
+                                                    NestedMeta::Lit(Lit::Str(s)) => {
+                                                        if !bound.is_empty() {
+                                                            return Err(error::reset_parameter(
+                                                                meta,
+                                                                meta_name.as_str(),
+                                                            ));
+                                                        }
+
+                                                        bound = match create_where_predicates_from_lit_str(s)? {
+                                                            Some(where_predicates) => where_predicates,
+                                                            None => {
+                                                                return Err(error::empty_parameter(
+                                                                    meta,
+                                                                    meta_name.as_str(),
+                                                                ))
+                                                            }
+                                                        };
+                                                    }
+                                                    _ => {
+                                                        return Err(
+                                                            error::parameter_incorrect_format(
+                                                                meta,
+                                                                meta_name.as_str(),
+                                                                &correct_usage_for_bound,
+                                                            ),
+                                                        )
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        Meta::NameValue(named_value) => {
+                                            let lit = &named_value.lit;
+
+                                            match lit {
+                                                Lit::Str(s) => {
+                                                    if !bound.is_empty() {
+                                                        return Err(error::reset_parameter(
+                                                            meta,
+                                                            meta_name.as_str(),
+                                                        ));
+                                                    }
+
+                                                    bound =
+                                                        match create_where_predicates_from_lit_str(
+                                                            s,
+                                                        )? {
+                                                            Some(where_predicates) => {
+                                                                where_predicates
+                                                            }
+                                                            None => {
+                                                                return Err(error::empty_parameter(
+                                                                    meta,
+                                                                    meta_name.as_str(),
+                                                                ))
+                                                            }
+                                                        };
+                                                }
+                                                _ => {
+                                                    return Err(error::parameter_incorrect_format(
+                                                        meta,
+                                                        meta_name.as_str(),
+                                                        &correct_usage_for_bound,
+                                                    ))
+                                                }
+                                            }
+                                        }
+                                        _ => {
+                                            return Err(error::parameter_incorrect_format(
+                                                meta,
+                                                meta_name.as_str(),
+                                                &correct_usage_for_bound,
+                                            ))
+                                        }
+                                    }
+                                }
+                                _ => {
+                                    return Err(error::unknown_parameter(
+                                        meta,
+                                        "Copy",
+                                        meta_name.as_str(),
+                                    ))
+                                }
+                            }
+                        }
+                        _ => {
+                            return Err(error::attribute_incorrect_format(
+                                meta,
+                                "Copy",
+                                &correct_usage_for_copy_attribute,
+                            ))
+                        }
+                    }
+                }
+            }
+            _ => {
+                return Err(error::attribute_incorrect_format(
+                    meta,
+                    "Copy",
+                    &correct_usage_for_copy_attribute,
+                ))
+            }
+        }
+
+        Ok(FieldAttribute {
+            bound,
+        })
+    }
+
+    #[allow(clippy::wrong_self_convention)]
+    pub fn from_attributes(
+        self,
+        attributes: &[Attribute],
+        traits: &[Trait],
+    ) -> Result<FieldAttribute, syn::Error> {
+        let mut result = None;
+
+        for attribute in attributes.iter() {
+            let meta = parse_educe_meta(attribute)?;
+
+            let meta_name = meta.path().into_token_stream().to_string();
+
+            if meta_name.as_str() == "educe" {
+                match meta {
+                    Meta::List(list) => {
+                        for p in list.nested.iter() {
+                            match p {
+                                NestedMeta::Meta(meta) => {
+                                    let t = Trait::from_path(meta.path())?;
+
+                                    if traits.binary_search(&t).is_err() {
+                                        return Err(error::trait_not_used(meta, t));
+                                    }
+
+                                    if t == Trait::Copy {
+                                        if result.is_some() {
+                                            return Err(error::reuse_a_trait(meta, t));
+                                        }
+
+                                        result = Some(self.from_copy_meta(&meta)?);
+                                    }
+                                }
+                                _ => return Err(error::educe_format_incorrect(attribute)),
+                            }
+                        }
+                    }
+                    _ => return Err(error::educe_format_incorrect(attribute)),
+                }
+            }
+        }
+
+        Ok(result.unwrap_or(FieldAttribute {
+            bound: Punctuated::new(),
+        }))
+    }
+}
@@ -1,3 +1,5 @@
+mod field_attribute;
 mod type_attribute;
 
+pub use field_attribute::*;
 pub use type_attribute::*;
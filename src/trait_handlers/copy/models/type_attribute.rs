@@ -1,31 +1,45 @@
 use super::super::super::{
-    create_where_predicates_from_generic_parameters, create_where_predicates_from_lit_str,
+    create_where_predicates_from_field_types, create_where_predicates_from_generic_parameters,
+    create_where_predicates_from_lit_str, parse_educe_meta,
 };
 
-use crate::panic;
+use crate::error;
 use crate::quote::ToTokens;
 use crate::syn::{
-    punctuated::Punctuated, token::Comma, GenericParam, Lit, Meta, NestedMeta, WherePredicate,
+    punctuated::Punctuated, token::Comma, Attribute, GenericParam, Ident, Lit, Meta, NestedMeta,
+    Type, WherePredicate,
 };
+use crate::Trait;
 
 #[derive(Clone)]
 pub enum TypeAttributeBound {
     None,
     Auto,
+    Infer,
     Custom(Punctuated<WherePredicate, Comma>),
 }
 
 impl TypeAttributeBound {
     pub fn into_punctuated_where_predicates_by_generic_parameters(
         self,
+        ident: &Ident,
         params: &Punctuated<GenericParam, Comma>,
+        field_types: &[&Type],
     ) -> Punctuated<WherePredicate, Comma> {
         match self {
             TypeAttributeBound::None => Punctuated::new(),
             TypeAttributeBound::Auto => {
                 create_where_predicates_from_generic_parameters(
                     params,
-                    &syn::parse2(quote!(core::marker::Copy)).unwrap(),
+                    &syn::parse2(quote!(::core::marker::Copy)).unwrap(),
+                )
+            }
+            TypeAttributeBound::Infer => {
+                create_where_predicates_from_field_types(
+                    ident,
+                    params,
+                    field_types,
+                    &syn::parse2(quote!(::core::marker::Copy)).unwrap(),
                 )
             }
             TypeAttributeBound::Custom(where_predicates) => where_predicates,
@@ -44,7 +58,7 @@ pub struct TypeAttributeBuilder {
 }
 
 impl TypeAttributeBuilder {
-    pub fn from_copy_meta(&self, meta: &Meta) -> TypeAttribute {
+    pub fn from_copy_meta(&self, meta: &Meta) -> Result<TypeAttribute, syn::Error> {
         let mut bound = TypeAttributeBound::None;
 
         let correct_usage_for_copy_attribute = {
@@ -56,6 +70,7 @@ impl TypeAttributeBuilder {
         let correct_usage_for_bound = {
             let usage = vec![
                 stringify!(#[educe(Copy(bound))]),
+                stringify!(#[educe(Copy(bound(*)))]),
                 stringify!(#[educe(Copy(bound = "where_predicates"))]),
                 stringify!(#[educe(Copy(bound("where_predicates")))]),
             ];
@@ -75,7 +90,11 @@ impl TypeAttributeBuilder {
                             match meta_name.as_str() {
                                 "bound" => {
                                     if !self.enable_bound {
-                                        panic::unknown_parameter("Copy", meta_name.as_str());
+                                        return Err(error::unknown_parameter(
+                                            meta,
+                                            "Copy",
+                                            meta_name.as_str(),
+                                        ));
                                     }
 
                                     match meta {
@@ -86,40 +105,54 @@ impl TypeAttributeBuilder {
                                                         match lit {
                                                             Lit::Str(s) => {
                                                                 if bound_is_set {
-                                                                    panic::reset_parameter(
-                                                                        meta_name.as_str(),
+                                                                    return Err(
+                                                                        error::reset_parameter(
+                                                                            meta,
+                                                                            meta_name.as_str(),
+                                                                        ),
                                                                     );
                                                                 }
 
                                                                 bound_is_set = true;
 
-                                                                let where_predicates = create_where_predicates_from_lit_str(s);
+                                                                bound = if s.value().trim() == "*" {
+                                                                    TypeAttributeBound::Infer
+                                                                } else {
+                                                                    let where_predicates = create_where_predicates_from_lit_str(s)?;
 
-                                                                bound = match where_predicates {
+                                                                    match where_predicates {
                                                                     Some(where_predicates) => {
                                                                         TypeAttributeBound::Custom(
                                                                             where_predicates,
                                                                         )
                                                                     }
                                                                     None => {
-                                                                        panic::empty_parameter(
-                                                                            meta_name.as_str(),
+                                                                        return Err(
+                                                                            error::empty_parameter(
+                                                                                meta,
+                                                                                meta_name.as_str(),
+                                                                            ),
                                                                         )
                                                                     }
+                                                                }
                                                                 };
                                                             }
-                                                            _ => {
-                                                                panic::parameter_incorrect_format(
+                                                            _ => return Err(
+                                                                error::parameter_incorrect_format(
+                                                                    meta,
                                                                     meta_name.as_str(),
                                                                     &correct_usage_for_bound,
-                                                                )
-                                                            }
+                                                                ),
+                                                            ),
                                                         }
                                                     }
                                                     _ => {
-                                                        panic::parameter_incorrect_format(
-                                                            meta_name.as_str(),
-                                                            &correct_usage_for_bound,
+                                                        return Err(
+                                                            error::parameter_incorrect_format(
+                                                                meta,
+                                                                meta_name.as_str(),
+                                                                &correct_usage_for_bound,
+                                                            ),
                                                         )
                                                     }
                                                 }
@@ -131,38 +164,52 @@ impl TypeAttributeBuilder {
                                             match lit {
                                                 Lit::Str(s) => {
                                                     if bound_is_set {
-                                                        panic::reset_parameter(meta_name.as_str());
+                                                        return Err(error::reset_parameter(
+                                                            meta,
+                                                            meta_name.as_str(),
+                                                        ));
                                                     }
 
                                                     bound_is_set = true;
 
-                                                    let where_predicates =
-                                                        create_where_predicates_from_lit_str(s);
+                                                    bound = if s.value().trim() == "*" {
+                                                        TypeAttributeBound::Infer
+                                                    } else {
+                                                        let where_predicates =
+                                                            create_where_predicates_from_lit_str(
+                                                                s,
+                                                            )?;
 
-                                                    bound = match where_predicates {
-                                                        Some(where_predicates) => {
-                                                            TypeAttributeBound::Custom(
-                                                                where_predicates,
-                                                            )
-                                                        }
-                                                        None => {
-                                                            panic::empty_parameter(
-                                                                meta_name.as_str(),
-                                                            )
+                                                        match where_predicates {
+                                                            Some(where_predicates) => {
+                                                                TypeAttributeBound::Custom(
+                                                                    where_predicates,
+                                                                )
+                                                            }
+                                                            None => {
+                                                                return Err(error::empty_parameter(
+                                                                    meta,
+                                                                    meta_name.as_str(),
+                                                                ))
+                                                            }
                                                         }
                                                     };
                                                 }
                                                 _ => {
-                                                    panic::parameter_incorrect_format(
+                                                    return Err(error::parameter_incorrect_format(
+                                                        meta,
                                                         meta_name.as_str(),
                                                         &correct_usage_for_bound,
-                                                    )
+                                                    ))
                                                 }
                                             }
                                         }
                                         Meta::Path(_) => {
                                             if bound_is_set {
-                                                panic::reset_parameter(meta_name.as_str());
+                                                return Err(error::reset_parameter(
+                                                    meta,
+                                                    meta_name.as_str(),
+                                                ));
                                             }
 
                                             bound_is_set = true;
@@ -171,26 +218,84 @@ impl TypeAttributeBuilder {
                                         }
                                     }
                                 }
-                                _ => panic::unknown_parameter("Copy", meta_name.as_str()),
+                                _ => {
+                                    return Err(error::unknown_parameter(
+                                        meta,
+                                        "Copy",
+                                        meta_name.as_str(),
+                                    ))
+                                }
                             }
                         }
                         _ => {
-                            panic::attribute_incorrect_format(
+                            return Err(error::attribute_incorrect_format(
+                                meta,
                                 "Copy",
                                 &correct_usage_for_copy_attribute,
-                            )
+                            ))
                         }
                     }
                 }
             }
             Meta::NameValue(_) => {
-                panic::attribute_incorrect_format("Copy", &correct_usage_for_copy_attribute)
+                return Err(error::attribute_incorrect_format(
+                    meta,
+                    "Copy",
+                    &correct_usage_for_copy_attribute,
+                ))
             }
             Meta::Path(_) => (),
         }
 
-        TypeAttribute {
+        Ok(TypeAttribute {
             bound,
+        })
+    }
+
+    #[allow(clippy::wrong_self_convention)]
+    pub fn from_attributes(
+        self,
+        attributes: &[Attribute],
+        traits: &[Trait],
+    ) -> Result<TypeAttribute, syn::Error> {
+        let mut result = None;
+
+        for attribute in attributes.iter() {
+            if let Some(meta_name) = attribute.path.get_ident() {
+                if meta_name == "educe" {
+                    let meta = parse_educe_meta(attribute)?;
+
+                    match meta {
+                        Meta::List(list) => {
+                            for p in list.nested.iter() {
+                                match p {
+                                    NestedMeta::Meta(meta) => {
+                                        let t = Trait::from_path(meta.path())?;
+
+                                        if traits.binary_search(&t).is_err() {
+                                            return Err(error::trait_not_used(meta, t));
+                                        }
+
+                                        if t == Trait::Copy {
+                                            if result.is_some() {
+                                                return Err(error::reuse_a_trait(meta, t));
+                                            }
+
+                                            result = Some(self.from_copy_meta(&meta)?);
+                                        }
+                                    }
+                                    _ => return Err(error::educe_format_incorrect(attribute)),
+                                }
+                            }
+                        }
+                        _ => return Err(error::educe_format_incorrect(attribute)),
+                    }
+                }
+            }
         }
+
+        Ok(result.unwrap_or(TypeAttribute {
+            bound: TypeAttributeBound::None,
+        }))
     }
 }
@@ -0,0 +1,177 @@
+mod models;
+
+use super::{create_variant_rank, TraitHandler};
+
+use crate::error::{self, Errors};
+use crate::proc_macro2::TokenStream;
+use crate::syn::{Data, DeriveInput, Expr, Fields, Lit, Meta, UnOp};
+use crate::Trait;
+
+use models::TypeAttributeBuilder;
+
+pub struct CycleHandler;
+
+impl TraitHandler for CycleHandler {
+    fn trait_meta_handler(
+        ast: &DeriveInput,
+        tokens: &mut TokenStream,
+        traits: &[Trait],
+        meta: &Meta,
+    ) -> Result<(), syn::Error> {
+        let mut errors = Errors::new();
+
+        let type_attribute = TypeAttributeBuilder {
+            enable_flag: true,
+            enable_rank: true,
+        }
+        .from_cycle_meta(meta)?;
+
+        let ident = &ast.ident;
+
+        let mut variant_idents = Vec::new();
+
+        match &ast.data {
+            Data::Enum(data) => {
+                let mut ranked_variants = Vec::new();
+                let mut next_discriminant = Some(0);
+
+                for (index, variant) in data.variants.iter().enumerate() {
+                    if !matches!(variant.fields, Fields::Unit) {
+                        errors.push(error::trait_only_support_unit_variants(variant, Trait::Cycle));
+
+                        continue;
+                    }
+
+                    let rank = if type_attribute.rank {
+                        match create_variant_rank(index, variant, traits) {
+                            Some(rank) => rank,
+                            None => return Err(error::rank_without_ord(meta)),
+                        }
+                    } else {
+                        index as isize
+                    };
+
+                    let discriminant = match &variant.discriminant {
+                        Some((_, value)) => create_discriminant(value),
+                        None => next_discriminant,
+                    };
+
+                    next_discriminant = discriminant.map(|discriminant| discriminant + 1);
+
+                    ranked_variants.push((rank, discriminant, variant));
+                }
+
+                // Without a rank on any variant, `Ord` and `PartialOrd` compare the
+                // discriminants of unit variants, so follow the same order.
+                if type_attribute.rank && ranked_variants.iter().all(|(rank, ..)| *rank < 0) {
+                    for (rank, discriminant, variant) in ranked_variants.iter_mut() {
+                        match discriminant {
+                            Some(discriminant) => *rank = *discriminant as isize,
+                            None => {
+                                if let Some((_, value)) = &variant.discriminant {
+                                    errors
+                                        .push(error::unsupported_discriminant(value, Trait::Cycle));
+                                }
+                            }
+                        }
+                    }
+                }
+
+                ranked_variants.sort_by_key(|(rank, ..)| *rank);
+
+                for (_, _, variant) in ranked_variants {
+                    variant_idents.push(&variant.ident);
+                }
+            }
+            _ => return Err(error::trait_only_support_enum(meta, Trait::Cycle)),
+        }
+
+        errors.finish()?;
+
+        let mut next_arms = TokenStream::new();
+        let mut prev_arms = TokenStream::new();
+        let mut wrapping_next_arms = TokenStream::new();
+        let mut wrapping_prev_arms = TokenStream::new();
+
+        let variant_count = variant_idents.len();
+
+        for (index, variant_ident) in variant_idents.iter().enumerate() {
+            let next_ident = variant_idents[(index + 1) % variant_count];
+            let prev_ident = variant_idents[(index + variant_count - 1) % variant_count];
+
+            if index + 1 < variant_count {
+                next_arms.extend(quote!(Self::#variant_ident => ::core::option::Option::Some(Self::#next_ident),));
+            } else {
+                next_arms.extend(quote!(Self::#variant_ident => ::core::option::Option::None,));
+            }
+
+            if index > 0 {
+                prev_arms.extend(quote!(Self::#variant_ident => ::core::option::Option::Some(Self::#prev_ident),));
+            } else {
+                prev_arms.extend(quote!(Self::#variant_ident => ::core::option::Option::None,));
+            }
+
+            wrapping_next_arms.extend(quote!(Self::#variant_ident => Self::#next_ident,));
+            wrapping_prev_arms.extend(quote!(Self::#variant_ident => Self::#prev_ident,));
+        }
+
+        let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+        let cycle_impl = quote! {
+            impl #impl_generics #ident #ty_generics #where_clause {
+                #[inline]
+                pub fn next(self) -> ::core::option::Option<Self> {
+                    match self {
+                        #next_arms
+                    }
+                }
+
+                #[inline]
+                pub fn prev(self) -> ::core::option::Option<Self> {
+                    match self {
+                        #prev_arms
+                    }
+                }
+
+                #[inline]
+                pub fn wrapping_next(self) -> Self {
+                    match self {
+                        #wrapping_next_arms
+                    }
+                }
+
+                #[inline]
+                pub fn wrapping_prev(self) -> Self {
+                    match self {
+                        #wrapping_prev_arms
+                    }
+                }
+            }
+        };
+
+        tokens.extend(cycle_impl);
+
+        Ok(())
+    }
+}
+
+/// The value of an explicit discriminant written as an integer literal, like `5` or `-1`.
+fn create_discriminant(expr: &Expr) -> Option<i128> {
+    match expr {
+        Expr::Lit(lit) => {
+            match &lit.lit {
+                Lit::Int(i) => i.base10_parse().ok(),
+                _ => None,
+            }
+        }
+        Expr::Unary(unary) => {
+            match unary.op {
+                UnOp::Neg(_) => create_discriminant(&unary.expr).map(|value| -value),
+                _ => None,
+            }
+        }
+        Expr::Paren(paren) => create_discriminant(&paren.expr),
+        Expr::Group(group) => create_discriminant(&group.expr),
+        _ => None,
+    }
+}
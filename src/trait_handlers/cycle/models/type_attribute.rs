@@ -0,0 +1,122 @@
+use crate::error;
+use crate::quote::ToTokens;
+use crate::syn::{Meta, NestedMeta};
+
+#[derive(Clone)]
+pub struct TypeAttribute {
+    pub rank: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct TypeAttributeBuilder {
+    pub enable_flag: bool,
+    pub enable_rank: bool,
+}
+
+impl TypeAttributeBuilder {
+    pub fn from_cycle_meta(&self, meta: &Meta) -> Result<TypeAttribute, syn::Error> {
+        let mut rank = false;
+
+        let correct_usage_for_cycle_attribute = {
+            let mut usage = vec![];
+
+            if self.enable_flag {
+                usage.push(stringify!(#[educe(Cycle)]));
+            }
+
+            if self.enable_rank {
+                usage.push(stringify!(#[educe(Cycle(rank))]));
+            }
+
+            usage
+        };
+
+        let correct_usage_for_rank = {
+            let usage = vec![stringify!(#[educe(Cycle(rank))])];
+
+            usage
+        };
+
+        match meta {
+            Meta::List(list) => {
+                let mut rank_is_set = false;
+
+                for p in list.nested.iter() {
+                    match p {
+                        NestedMeta::Meta(meta) => {
+                            let meta_name = meta.path().into_token_stream().to_string();
+
+                            match meta_name.as_str() {
+                                "rank" => {
+                                    if !self.enable_rank {
+                                        return Err(error::unknown_parameter(
+                                            meta,
+                                            "Cycle",
+                                            meta_name.as_str(),
+                                        ));
+                                    }
+
+                                    match meta {
+                                        Meta::Path(_) => {
+                                            if rank_is_set {
+                                                return Err(error::reset_parameter(
+                                                    meta,
+                                                    meta_name.as_str(),
+                                                ));
+                                            }
+
+                                            rank_is_set = true;
+
+                                            rank = true;
+                                        }
+                                        _ => {
+                                            return Err(error::parameter_incorrect_format(
+                                                meta,
+                                                meta_name.as_str(),
+                                                &correct_usage_for_rank,
+                                            ))
+                                        }
+                                    }
+                                }
+                                _ => {
+                                    return Err(error::unknown_parameter(
+                                        meta,
+                                        "Cycle",
+                                        meta_name.as_str(),
+                                    ))
+                                }
+                            }
+                        }
+                        _ => {
+                            return Err(error::attribute_incorrect_format(
+                                meta,
+                                "Cycle",
+                                &correct_usage_for_cycle_attribute,
+                            ))
+                        }
+                    }
+                }
+            }
+            Meta::NameValue(_) => {
+                return Err(error::attribute_incorrect_format(
+                    meta,
+                    "Cycle",
+                    &correct_usage_for_cycle_attribute,
+                ))
+            }
+            Meta::Path(_) => {
+                if !self.enable_flag {
+                    return Err(error::attribute_incorrect_format(
+                        meta,
+                        "Cycle",
+                        &correct_usage_for_cycle_attribute,
+                    ));
+                }
+            }
+        }
+
+        Ok(TypeAttribute {
+            rank,
+        })
+    }
+}
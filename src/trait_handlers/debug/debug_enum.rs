@@ -1,15 +1,14 @@
-use std::fmt::Write;
-use std::str::FromStr;
-
-use super::super::TraitHandler;
+use super::super::{
+    create_field_binding, create_field_member, create_hygienic_ident, TraitHandler,
+};
+use super::create_format_arg;
 use super::models::{
     FieldAttributeBuilder, FieldAttributeName, TypeAttributeBuilder, TypeAttributeName,
 };
 
-use crate::panic;
+use crate::error::{self, Errors};
 use crate::proc_macro2::TokenStream;
-use crate::quote::ToTokens;
-use crate::syn::{Data, DeriveInput, Fields, Generics, Meta};
+use crate::syn::{ext::IdentExt, Data, DeriveInput, Fields, Generics, Meta};
 use crate::Trait;
 
 pub struct DebugEnumHandler;
@@ -21,7 +20,9 @@ impl TraitHandler for DebugEnumHandler {
         tokens: &mut TokenStream,
         traits: &[Trait],
         meta: &Meta,
-    ) {
+    ) -> Result<(), syn::Error> {
+        let mut errors = Errors::new();
+
         let type_attribute = TypeAttributeBuilder {
             enable_flag: true,
             name: TypeAttributeName::Disable,
@@ -30,600 +31,217 @@ impl TraitHandler for DebugEnumHandler {
             enable_named_field: false,
             enable_bound: true,
         }
-        .from_debug_meta(meta);
-
-        let enum_name = ast.ident.to_string();
+        .from_debug_meta(meta)?;
 
         let name = type_attribute.name.into_string_by_ident(&ast.ident);
 
-        let bound = type_attribute
-            .bound
-            .into_punctuated_where_predicates_by_generic_parameters(&ast.generics.params);
+        let bound = type_attribute.bound;
 
-        let mut builder_tokens = TokenStream::new();
-        let mut has_variants = false;
+        let ident = &ast.ident;
 
-        let mut match_tokens = String::from("match self {");
+        let formatter = create_hygienic_ident("formatter");
+        let builder = create_hygienic_ident("builder");
+
+        let mut arms = TokenStream::new();
+        let mut has_variants = false;
+        let mut field_types = Vec::new();
+        let mut extra_bound = Vec::new();
 
         if let Data::Enum(data) = &ast.data {
             for variant in data.variants.iter() {
-                let type_attribute = TypeAttributeBuilder {
-                    enable_flag: false,
-                    name: TypeAttributeName::Default,
-                    enable_name: true,
-                    named_field: if let Fields::Named(_) = &variant.fields {
-                        true
-                    } else {
-                        false
-                    },
-                    enable_named_field: true,
-                    enable_bound: false,
-                }
-                .from_attributes(&variant.attrs, traits);
+                let type_attribute = match errors.handle(
+                    TypeAttributeBuilder {
+                        enable_flag: false,
+                        name: TypeAttributeName::Default,
+                        enable_name: true,
+                        named_field: matches!(&variant.fields, Fields::Named(_)),
+                        enable_named_field: true,
+                        enable_bound: true,
+                    }
+                    .from_attributes(&variant.attrs, traits),
+                ) {
+                    Some(type_attribute) => type_attribute,
+                    None => continue,
+                };
 
                 let variant_name = type_attribute.name.into_string_by_ident(&variant.ident);
 
                 let named_field = type_attribute.named_field;
 
-                let variant_ident = variant.ident.to_string();
+                let variant_ident = &variant.ident;
 
                 let name = combine_names(&name, variant_name);
 
-                match &variant.fields {
-                    Fields::Unit => {
-                        // TODO Unit
-                        if name.is_empty() {
-                            panic::unit_variant_need_name();
-                        }
+                if let Fields::Unit = &variant.fields {
+                    if name.is_empty() {
+                        errors.push(error::unit_variant_need_name(meta));
+                    }
 
-                        match_tokens.write_fmt(format_args!("{enum_name}::{variant_ident} => {{ formatter.write_str({name:?}) }}", enum_name = enum_name, variant_ident = variant_ident, name = name)).unwrap();
+                    extra_bound.extend(
+                        type_attribute
+                            .bound
+                            .into_punctuated_where_predicates_by_generic_parameters(
+                                &ast.ident,
+                                &ast.generics.params,
+                                &[],
+                            ),
+                    );
 
-                        has_variants = true;
-                    }
-                    Fields::Named(fields) => {
-                        // TODO Struct
-                        let mut has_fields = false;
-
-                        let mut pattern_tokens = String::new();
-                        let mut block_tokens = String::new();
-
-                        if named_field {
-                            if name.is_empty() {
-                                block_tokens.push_str("
-                                    struct RawString(&'static str);
-
-                                    impl core::fmt::Debug for RawString {{
-                                        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {{
-                                            f.write_str(self.0)
-                                        }}
-                                    }}
-                                ");
-
-                                block_tokens.push_str("let mut builder = formatter.debug_map();");
-                            } else {
-                                block_tokens
-                                    .write_fmt(format_args!(
-                                        "let mut builder = formatter.debug_struct({name:?});",
-                                        name = name
-                                    ))
-                                    .unwrap();
-                            }
+                    arms.extend(quote!(Self::#variant_ident => #formatter.write_str(#name),));
 
-                            for field in fields.named.iter() {
-                                let field_attribute = FieldAttributeBuilder {
-                                    name: FieldAttributeName::Default,
-                                    enable_name: true,
-                                    enable_ignore: true,
-                                    enable_impl: true,
-                                }
-                                .from_attributes(&field.attrs, traits);
-
-                                let field_name = field.ident.as_ref().unwrap().to_string();
-
-                                if field_attribute.ignore {
-                                    pattern_tokens
-                                        .write_fmt(format_args!(
-                                            "{field_name}: _,",
-                                            field_name = field_name
-                                        ))
-                                        .unwrap();
-                                    continue;
-                                }
+                    has_variants = true;
 
-                                let rename = field_attribute.name.into_option_string();
-
-                                let format_trait = field_attribute.format_trait;
-                                let format_method = field_attribute.format_method;
-
-                                let key = rename.unwrap_or_else(|| field_name.clone());
-
-                                pattern_tokens
-                                    .write_fmt(format_args!(
-                                        "{field_name},",
-                                        field_name = field_name
-                                    ))
-                                    .unwrap();
-
-                                match format_trait {
-                                    Some(format_trait) => {
-                                        let format_method = format_method.unwrap();
-
-                                        block_tokens.write_fmt(format_args!("
-                                            let arg = {{
-                                                struct MyDebug<'a, T: {format_trait}>(&'a T);
-
-                                                impl<'a, T: {format_trait}> core::fmt::Debug for MyDebug<'a, T> {{
-                                                    fn fmt(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {{
-                                                        {format_trait}::{format_method}(self.0, formatter)
-                                                    }}
-                                                }}
-
-                                                MyDebug({field_name})
-                                            }};
-                                        ", format_trait = format_trait, format_method = format_method, field_name = field_name)).unwrap();
-
-                                        let statement = if name.is_empty() {
-                                            format!(
-                                                "builder.entry(&RawString({key:?}), &arg);",
-                                                key = key
-                                            )
-                                        } else {
-                                            format!("builder.field({key:?}, &arg);", key = key)
-                                        };
-
-                                        block_tokens.push_str(&statement);
-                                    }
-                                    None => {
-                                        match format_method {
-                                            Some(format_method) => {
-                                                let ty = field
-                                                    .ty
-                                                    .clone()
-                                                    .into_token_stream()
-                                                    .to_string();
-
-                                                block_tokens.write_fmt(format_args!("
-                                                    let arg = {{
-                                                        struct MyDebug<'a>(&'a {ty});
-
-                                                        impl<'a> core::fmt::Debug for MyDebug<'a> {{
-                                                            fn fmt(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {{
-                                                                {format_method}(self.0, formatter)
-                                                            }}
-                                                        }}
-
-                                                        MyDebug({field_name})
-                                                    }};
-                                                ", ty = ty, format_method = format_method, field_name = field_name)).unwrap();
-
-                                                let statement = if name.is_empty() {
-                                                    format!(
-                                                        "builder.entry(&RawString({key:?}), &arg);",
-                                                        key = key
-                                                    )
-                                                } else {
-                                                    format!(
-                                                        "builder.field({key:?}, &arg);",
-                                                        key = key
-                                                    )
-                                                };
-
-                                                block_tokens.push_str(&statement);
-                                            }
-                                            None => {
-                                                let statement = if name.is_empty() {
-                                                    format!("builder.entry(&RawString({key:?}), {field_name});", key = key, field_name = field_name)
-                                                } else {
-                                                    format!(
-                                                        "builder.field({key:?}, {field_name});",
-                                                        key = key,
-                                                        field_name = field_name
-                                                    )
-                                                };
-
-                                                block_tokens.push_str(&statement);
-                                            }
-                                        }
-                                    }
-                                }
+                    continue;
+                }
 
-                                has_fields = true;
-                            }
-                        } else {
-                            block_tokens
-                                .write_fmt(format_args!(
-                                    "let mut builder = formatter.debug_tuple({name:?});",
-                                    name = name
-                                ))
-                                .unwrap();
-
-                            for field in fields.named.iter() {
-                                let field_attribute = FieldAttributeBuilder {
-                                    name: FieldAttributeName::Default,
-                                    enable_name: false,
-                                    enable_ignore: true,
-                                    enable_impl: true,
-                                }
-                                .from_attributes(&field.attrs, traits);
-
-                                let field_name = field.ident.as_ref().unwrap().to_string();
-
-                                if field_attribute.ignore {
-                                    pattern_tokens
-                                        .write_fmt(format_args!(
-                                            "{field_name}: _,",
-                                            field_name = field_name
-                                        ))
-                                        .unwrap();
-                                    continue;
-                                }
+                let mut has_fields = false;
 
-                                let format_trait = field_attribute.format_trait;
-                                let format_method = field_attribute.format_method;
-
-                                pattern_tokens
-                                    .write_fmt(format_args!(
-                                        "{field_name},",
-                                        field_name = field_name
-                                    ))
-                                    .unwrap();
-
-                                match format_trait {
-                                    Some(format_trait) => {
-                                        let format_method = format_method.unwrap();
-
-                                        block_tokens.write_fmt(format_args!("
-                                            let arg = {{
-                                                struct MyDebug<'a, T: {format_trait}>(&'a T);
-
-                                                impl<'a, T: {format_trait}> core::fmt::Debug for MyDebug<'a, T> {{
-                                                    fn fmt(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {{
-                                                        {format_trait}::{format_method}(self.0, formatter)
-                                                    }}
-                                                }}
-
-                                                MyDebug({field_name})
-                                            }};
-                                        ", format_trait = format_trait, format_method = format_method, field_name = field_name)).unwrap();
-
-                                        block_tokens.push_str("builder.field(&arg);");
-                                    }
-                                    None => {
-                                        match format_method {
-                                            Some(format_method) => {
-                                                let ty = field
-                                                    .ty
-                                                    .clone()
-                                                    .into_token_stream()
-                                                    .to_string();
-
-                                                block_tokens.write_fmt(format_args!("
-                                                    let arg = {{
-                                                        struct MyDebug<'a>(&'a {ty});
-
-                                                        impl<'a> core::fmt::Debug for MyDebug<'a> {{
-                                                            fn fmt(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {{
-                                                                {format_method}(self.0, formatter)
-                                                            }}
-                                                        }}
-
-                                                        MyDebug({field_name})
-                                                    }};
-                                                ", ty = ty, format_method = format_method, field_name = field_name)).unwrap();
-
-                                                block_tokens.push_str("builder.field(&arg);");
-                                            }
-                                            None => {
-                                                let statement = format!(
-                                                    "builder.field({field_name});",
-                                                    field_name = field_name
-                                                );
-
-                                                block_tokens.push_str(&statement);
-                                            }
-                                        }
-                                    }
-                                }
+                let variant_field_types_start = field_types.len();
 
-                                has_fields = true;
-                            }
-                        }
+                let mut pattern_tokens = TokenStream::new();
+                let mut block_tokens = TokenStream::new();
+
+                if named_field {
+                    if name.is_empty() {
+                        block_tokens.extend(quote!(let mut #builder = #formatter.debug_map();));
+                    } else {
+                        block_tokens
+                            .extend(quote!(let mut #builder = #formatter.debug_struct(#name);));
+                    }
+                } else {
+                    block_tokens.extend(quote!(let mut #builder = #formatter.debug_tuple(#name);));
+                }
 
-                        if name.is_empty() && !has_fields {
-                            panic::unit_struct_need_name();
+                for (index, field) in variant.fields.iter().enumerate() {
+                    let field_attribute = match errors.handle(
+                        FieldAttributeBuilder {
+                            name: FieldAttributeName::Default,
+                            enable_name: named_field,
+                            enable_ignore: true,
+                            enable_impl: true,
+                            enable_bound: true,
                         }
+                        .from_attributes(&field.attrs, traits),
+                    ) {
+                        Some(field_attribute) => field_attribute,
+                        None => continue,
+                    };
+
+                    extra_bound.extend(field_attribute.bound.iter().cloned());
 
-                        block_tokens.push_str("return builder.finish();");
+                    let field_member = create_field_member(index, field);
 
-                        match_tokens.write_fmt(format_args!("{enum_name}::{variant_ident}{{ {pattern_tokens} }} => {{ {block_tokens} }}", enum_name = enum_name, variant_ident = variant_ident, pattern_tokens = pattern_tokens, block_tokens = block_tokens)).unwrap();
+                    if field_attribute.ignore {
+                        pattern_tokens.extend(quote!(#field_member: _,));
 
-                        has_variants = true;
+                        continue;
                     }
-                    Fields::Unnamed(fields) => {
-                        // TODO Tuple
-                        let mut has_fields = false;
-
-                        let mut pattern_tokens = String::new();
-                        let mut block_tokens = String::new();
-
-                        if named_field {
-                            if name.is_empty() {
-                                block_tokens.push_str("
-                                    struct RawString(&'static str);
-
-                                    impl core::fmt::Debug for RawString {{
-                                        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {{
-                                            f.write_str(self.0)
-                                        }}
-                                    }}
-                                ");
-
-                                block_tokens.push_str("let mut builder = formatter.debug_map();");
-                            } else {
-                                block_tokens
-                                    .write_fmt(format_args!(
-                                        "let mut builder = formatter.debug_struct({name:?});",
-                                        name = name
-                                    ))
-                                    .unwrap();
-                            }
 
-                            for (index, field) in fields.unnamed.iter().enumerate() {
-                                let field_attribute = FieldAttributeBuilder {
-                                    name: FieldAttributeName::Default,
-                                    enable_name: true,
-                                    enable_ignore: true,
-                                    enable_impl: true,
-                                }
-                                .from_attributes(&field.attrs, traits);
+                    if field_attribute.format_method.is_none() {
+                        field_types.push(&field.ty);
+                    }
 
-                                if field_attribute.ignore {
-                                    pattern_tokens.push_str("_,");
-                                    continue;
-                                }
+                    let field_binding = create_field_binding(index);
 
-                                let rename = field_attribute.name.into_option_string();
-
-                                let format_trait = field_attribute.format_trait;
-                                let format_method = field_attribute.format_method;
-
-                                let (key, field_name) = match rename {
-                                    Some(rename) => (rename, format!("{}", index)),
-                                    None => (format!("_{}", index), format!("{}", index)),
-                                };
-
-                                pattern_tokens
-                                    .write_fmt(format_args!(
-                                        "_{field_name},",
-                                        field_name = field_name
-                                    ))
-                                    .unwrap();
-
-                                match format_trait {
-                                    Some(format_trait) => {
-                                        let format_method = format_method.unwrap();
-
-                                        block_tokens.write_fmt(format_args!("
-                                            let arg = {{
-                                                struct MyDebug<'a, T: {format_trait}>(&'a T);
-
-                                                impl<'a, T: {format_trait}> core::fmt::Debug for MyDebug<'a, T> {{
-                                                    fn fmt(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {{
-                                                        {format_trait}::{format_method}(self.0, formatter)
-                                                    }}
-                                                }}
-
-                                                MyDebug(_{field_name})
-                                            }};
-                                        ", format_trait = format_trait, format_method = format_method, field_name = field_name)).unwrap();
-
-                                        let statement = if name.is_empty() {
-                                            format!(
-                                                "builder.entry(&RawString({key:?}), &arg);",
-                                                key = key
-                                            )
-                                        } else {
-                                            format!("builder.field({key:?}, &arg);", key = key)
-                                        };
-
-                                        block_tokens.push_str(&statement);
-                                    }
-                                    None => {
-                                        match format_method {
-                                            Some(format_method) => {
-                                                let ty = field
-                                                    .ty
-                                                    .clone()
-                                                    .into_token_stream()
-                                                    .to_string();
-
-                                                block_tokens.write_fmt(format_args!("
-                                                    let arg = {{
-                                                        struct MyDebug<'a>(&'a {ty});
-
-                                                        impl<'a> core::fmt::Debug for MyDebug<'a> {{
-                                                            fn fmt(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {{
-                                                                {format_method}(self.0, formatter)
-                                                            }}
-                                                        }}
-
-                                                        MyDebug(_{field_name})
-                                                    }};
-                                                ", ty = ty, format_method = format_method, field_name = field_name)).unwrap();
-
-                                                let statement = if name.is_empty() {
-                                                    format!(
-                                                        "builder.entry(&RawString({key:?}), &arg);",
-                                                        key = key
-                                                    )
-                                                } else {
-                                                    format!(
-                                                        "builder.field({key:?}, &arg);",
-                                                        key = key
-                                                    )
-                                                };
-
-                                                block_tokens.push_str(&statement);
-                                            }
-                                            None => {
-                                                let statement = if name.is_empty() {
-                                                    format!("builder.entry(&RawString({key:?}), {field_name});", key = key, field_name = field_name)
-                                                } else {
-                                                    format!(
-                                                        "builder.field({key:?}, _{field_name});",
-                                                        key = key,
-                                                        field_name = field_name
-                                                    )
-                                                };
-
-                                                block_tokens.push_str(&statement);
-                                            }
-                                        }
-                                    }
-                                }
+                    pattern_tokens.extend(quote!(#field_member: #field_binding,));
 
-                                has_fields = true;
-                            }
-                        } else {
-                            block_tokens
-                                .write_fmt(format_args!(
-                                    "let mut builder = formatter.debug_tuple({name:?});",
-                                    name = name
-                                ))
-                                .unwrap();
-
-                            for (index, field) in fields.unnamed.iter().enumerate() {
-                                let field_attribute = FieldAttributeBuilder {
-                                    name: FieldAttributeName::Default,
-                                    enable_name: false,
-                                    enable_ignore: true,
-                                    enable_impl: true,
-                                }
-                                .from_attributes(&field.attrs, traits);
-
-                                if field_attribute.ignore {
-                                    pattern_tokens.push_str("_,");
-                                    continue;
-                                }
+                    let arg = create_format_arg(
+                        field_attribute.format_trait,
+                        field_attribute.format_method,
+                        &field.ty,
+                        quote!(#field_binding),
+                    );
 
-                                let format_trait = field_attribute.format_trait;
-                                let format_method = field_attribute.format_method;
-
-                                let field_name = format!("{}", index);
-
-                                pattern_tokens
-                                    .write_fmt(format_args!(
-                                        "_{field_name},",
-                                        field_name = field_name
-                                    ))
-                                    .unwrap();
-
-                                match format_trait {
-                                    Some(format_trait) => {
-                                        let format_method = format_method.unwrap();
-
-                                        block_tokens.write_fmt(format_args!("
-                                            let arg = {{
-                                                struct MyDebug<'a, T: {format_trait}>(&'a T);
-
-                                                impl<'a, T: {format_trait}> core::fmt::Debug for MyDebug<'a, T> {{
-                                                    fn fmt(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {{
-                                                        {format_trait}::{format_method}(self.0, formatter)
-                                                    }}
-                                                }}
-
-                                                MyDebug(_{field_name})
-                                            }};
-                                        ", format_trait = format_trait, format_method = format_method, field_name = field_name)).unwrap();
-
-                                        block_tokens.push_str("builder.field(&arg);");
-                                    }
-                                    None => {
-                                        match format_method {
-                                            Some(format_method) => {
-                                                let ty = field
-                                                    .ty
-                                                    .clone()
-                                                    .into_token_stream()
-                                                    .to_string();
-
-                                                block_tokens.write_fmt(format_args!("
-                                                    let arg = {{
-                                                        struct MyDebug<'a>(&'a {ty});
-
-                                                        impl<'a> core::fmt::Debug for MyDebug<'a> {{
-                                                            fn fmt(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {{
-                                                                {format_method}(self.0, formatter)
-                                                            }}
-                                                        }}
-
-                                                        MyDebug(_{field_name})
-                                                    }};
-                                                ", ty = ty, format_method = format_method, field_name = field_name)).unwrap();
-
-                                                block_tokens.push_str("builder.field(&arg);");
-                                            }
-                                            None => {
-                                                let statement = format!(
-                                                    "builder.field(_{field_name});",
-                                                    field_name = field_name
-                                                );
-
-                                                block_tokens.push_str(&statement);
-                                            }
-                                        }
-                                    }
+                    if named_field {
+                        let key = match field_attribute.name.into_option_string() {
+                            Some(rename) => rename,
+                            None => {
+                                match field.ident.as_ref() {
+                                    Some(ident) => ident.unraw().to_string(),
+                                    None => format!("_{}", index),
                                 }
-
-                                has_fields = true;
                             }
-                        }
+                        };
 
-                        if name.is_empty() && !has_fields {
-                            panic::unit_struct_need_name();
+                        if name.is_empty() {
+                            block_tokens.extend(
+                                quote!(#builder.entry(&::core::format_args!("{}", #key), #arg);),
+                            );
+                        } else {
+                            block_tokens.extend(quote!(#builder.field(#key, #arg);));
                         }
+                    } else {
+                        block_tokens.extend(quote!(#builder.field(#arg);));
+                    }
+
+                    has_fields = true;
+                }
+
+                extra_bound.extend(
+                    type_attribute.bound.into_punctuated_where_predicates_by_generic_parameters(
+                        &ast.ident,
+                        &ast.generics.params,
+                        &field_types[variant_field_types_start..],
+                    ),
+                );
 
-                        block_tokens.push_str("return builder.finish();");
+                if name.is_empty() && !has_fields {
+                    errors.push(error::unit_struct_need_name(meta));
+                }
 
-                        match_tokens.write_fmt(format_args!("{enum_name}::{variant_ident}( {pattern_tokens} ) => {{ {block_tokens} }}", enum_name = enum_name, variant_ident = variant_ident, pattern_tokens = pattern_tokens, block_tokens = block_tokens)).unwrap();
+                arms.extend(quote! {
+                    Self::#variant_ident { #pattern_tokens } => {
+                        #block_tokens
 
-                        has_variants = true;
+                        #builder.finish()
                     }
-                }
+                });
+
+                has_variants = true;
             }
         }
 
-        match_tokens.push('}');
-
-        builder_tokens.extend(TokenStream::from_str(&match_tokens).unwrap());
+        let builder_tokens = quote! {
+            match self {
+                #arms
+            }
+        };
 
         if name.is_empty() && !has_variants {
-            panic::unit_enum_need_name();
+            errors.push(error::unit_enum_need_name(meta));
         }
 
-        let ident = &ast.ident;
+        let bound = bound.into_punctuated_where_predicates_by_generic_parameters(
+            &ast.ident,
+            &ast.generics.params,
+            &field_types,
+        );
 
         let mut generics_cloned: Generics = ast.generics.clone();
 
         let where_clause = generics_cloned.make_where_clause();
 
-        for where_predicate in bound {
+        for where_predicate in bound.into_iter().chain(extra_bound) {
             where_clause.predicates.push(where_predicate);
         }
 
         let (impl_generics, ty_generics, where_clause) = generics_cloned.split_for_impl();
 
         let debug_impl = quote! {
-            impl #impl_generics core::fmt::Debug for #ident #ty_generics #where_clause {
+            impl #impl_generics ::core::fmt::Debug for #ident #ty_generics #where_clause {
                 #[inline]
                 #[allow(clippy::unneeded_field_pattern)]
-                fn fmt(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+                fn fmt(&self, #formatter: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
                     #builder_tokens
                 }
             }
         };
 
+        errors.finish()?;
+
         tokens.extend(debug_impl);
+
+        Ok(())
     }
 }
 
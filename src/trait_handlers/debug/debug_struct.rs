@@ -1,14 +1,15 @@
-use std::str::FromStr;
-
-use super::super::TraitHandler;
+use super::super::{
+    create_field_binding, create_field_member, create_hygienic_ident,
+    create_packed_field_where_predicate, is_packed, TraitHandler,
+};
+use super::create_format_arg;
 use super::models::{
     FieldAttributeBuilder, FieldAttributeName, TypeAttributeBuilder, TypeAttributeName,
 };
 
-use crate::panic;
+use crate::error::{self, Errors};
 use crate::proc_macro2::TokenStream;
-use crate::quote::ToTokens;
-use crate::syn::{Data, DeriveInput, Fields, Generics, Meta};
+use crate::syn::{ext::IdentExt, Data, DeriveInput, Fields, Generics, Meta};
 use crate::Trait;
 
 pub struct DebugStructHandler;
@@ -19,7 +20,9 @@ impl TraitHandler for DebugStructHandler {
         tokens: &mut TokenStream,
         traits: &[Trait],
         meta: &Meta,
-    ) {
+    ) -> Result<(), syn::Error> {
+        let mut errors = Errors::new();
+
         let is_tuple = {
             if let Data::Struct(data) = &ast.data {
                 if let Fields::Unnamed(_) = data.fields {
@@ -40,235 +43,156 @@ impl TraitHandler for DebugStructHandler {
             enable_named_field: true,
             enable_bound: true,
         }
-        .from_debug_meta(meta);
+        .from_debug_meta(meta)?;
 
         let name = type_attribute.name.into_string_by_ident(&ast.ident);
 
         let named_field = type_attribute.named_field;
 
-        let bound = type_attribute
-            .bound
-            .into_punctuated_where_predicates_by_generic_parameters(&ast.generics.params);
+        let packed = is_packed(ast);
+
+        let formatter = create_hygienic_ident("formatter");
+        let builder = create_hygienic_ident("builder");
 
         let mut builder_tokens = TokenStream::new();
         let mut has_fields = false;
+        let mut field_types = Vec::new();
+        let mut extra_bound = Vec::new();
 
         if named_field {
             if name.is_empty() {
-                builder_tokens.extend(quote!(
-                    struct RawString(&'static str);
-
-                    impl core::fmt::Debug for RawString {
-                        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                            f.write_str(self.0)
-                        }
-                    }
-                ));
-                builder_tokens.extend(quote!(let mut builder = formatter.debug_map();));
+                builder_tokens.extend(quote!(let mut #builder = #formatter.debug_map();));
             } else {
-                builder_tokens.extend(quote!(let mut builder = formatter.debug_struct(#name);));
+                builder_tokens.extend(quote!(let mut #builder = #formatter.debug_struct(#name);));
             }
 
             if let Data::Struct(data) = &ast.data {
                 for (index, field) in data.fields.iter().enumerate() {
-                    let field_attribute = FieldAttributeBuilder {
-                        name: FieldAttributeName::Default,
-                        enable_name: true,
-                        enable_ignore: true,
-                        enable_impl: true,
-                    }
-                    .from_attributes(&field.attrs, traits);
+                    let field_attribute = match errors.handle(
+                        FieldAttributeBuilder {
+                            name: FieldAttributeName::Default,
+                            enable_name: true,
+                            enable_ignore: true,
+                            enable_impl: true,
+                            enable_bound: true,
+                        }
+                        .from_attributes(&field.attrs, traits),
+                    ) {
+                        Some(field_attribute) => field_attribute,
+                        None => continue,
+                    };
+
+                    extra_bound.extend(field_attribute.bound.iter().cloned());
 
                     if field_attribute.ignore {
                         continue;
                     }
 
-                    let rename = field_attribute.name.into_option_string();
-
-                    let format_trait = field_attribute.format_trait;
-                    let format_method = field_attribute.format_method;
-
-                    let (key, field_name) = match rename {
-                        Some(rename) => {
-                            if let Some(ident) = field.ident.as_ref() {
-                                (rename, ident.to_string())
-                            } else {
-                                (rename, format!("{}", index))
-                            }
-                        }
+                    let key = match field_attribute.name.into_option_string() {
+                        Some(rename) => rename,
                         None => {
-                            if let Some(ident) = field.ident.as_ref() {
-                                (ident.to_string(), ident.to_string())
-                            } else {
-                                (format!("_{}", index), format!("{}", index))
+                            match field.ident.as_ref() {
+                                Some(ident) => ident.unraw().to_string(),
+                                None => format!("_{}", index),
                             }
                         }
                     };
 
-                    match format_trait {
-                        Some(format_trait) => {
-                            let format_method = format_method.unwrap();
+                    if field_attribute.format_method.is_none() {
+                        field_types.push(&field.ty);
+                    }
 
-                            builder_tokens.extend(TokenStream::from_str(&format!("
-                                let arg = {{
-                                    struct MyDebug<'a, T: {format_trait}>(&'a T);
+                    if packed {
+                        extra_bound.push(create_packed_field_where_predicate(&field.ty));
+                    }
 
-                                    impl<'a, T: {format_trait}> core::fmt::Debug for MyDebug<'a, T> {{
-                                        fn fmt(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {{
-                                            {format_trait}::{format_method}(self.0, formatter)
-                                        }}
-                                    }}
+                    let field_member = create_field_member(index, field);
 
-                                    MyDebug(&self.{field_name})
-                                }};
-                            ", format_trait = format_trait, format_method = format_method, field_name = field_name)).unwrap());
+                    let value = if packed {
+                        // Copy the field out of the packed struct so that a reference to it lives
+                        // as long as the builder.
+                        let field = create_field_binding(index);
 
-                            let statement = if name.is_empty() {
-                                format!("builder.entry(&RawString({key:?}), &arg);", key = key)
-                            } else {
-                                format!("builder.field({key:?}, &arg);", key = key)
-                            };
+                        builder_tokens.extend(quote!(let #field = self.#field_member;));
 
-                            builder_tokens.extend(TokenStream::from_str(&statement).unwrap());
-                        }
-                        None => {
-                            match format_method {
-                                Some(format_method) => {
-                                    let ty = field.ty.clone().into_token_stream().to_string();
-
-                                    builder_tokens.extend(TokenStream::from_str(&format!("
-                                        let arg = {{
-                                            struct MyDebug<'a>(&'a {ty});
-
-                                            impl<'a> core::fmt::Debug for MyDebug<'a> {{
-                                                fn fmt(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {{
-                                                    {format_method}(self.0, formatter)
-                                                }}
-                                            }}
-
-                                            MyDebug(&self.{field_name})
-                                        }};
-                                    ", ty = ty, format_method = format_method, field_name = field_name)).unwrap());
-
-                                    let statement = if name.is_empty() {
-                                        format!(
-                                            "builder.entry(&RawString({key:?}), &arg);",
-                                            key = key
-                                        )
-                                    } else {
-                                        format!("builder.field({key:?}, &arg);", key = key)
-                                    };
-
-                                    builder_tokens
-                                        .extend(TokenStream::from_str(&statement).unwrap());
-                                }
-                                None => {
-                                    let statement = if name.is_empty() {
-                                        format!(
-                                        "builder.entry(&RawString({key:?}), &self.{field_name});",
-                                        key = key,
-                                        field_name = field_name
-                                    )
-                                    } else {
-                                        format!(
-                                            "builder.field({key:?}, &self.{field_name});",
-                                            key = key,
-                                            field_name = field_name
-                                        )
-                                    };
-
-                                    builder_tokens
-                                        .extend(TokenStream::from_str(&statement).unwrap());
-                                }
-                            }
-                        }
+                        quote!(&#field)
+                    } else {
+                        quote!(&self.#field_member)
+                    };
+
+                    let arg = create_format_arg(
+                        field_attribute.format_trait,
+                        field_attribute.format_method,
+                        &field.ty,
+                        value,
+                    );
+
+                    if name.is_empty() {
+                        builder_tokens.extend(
+                            quote!(#builder.entry(&::core::format_args!("{}", #key), #arg);),
+                        );
+                    } else {
+                        builder_tokens.extend(quote!(#builder.field(#key, #arg);));
                     }
 
                     has_fields = true;
                 }
             }
         } else {
-            builder_tokens.extend(quote!(let mut builder = formatter.debug_tuple(#name);));
+            builder_tokens.extend(quote!(let mut #builder = #formatter.debug_tuple(#name);));
 
             if let Data::Struct(data) = &ast.data {
                 for (index, field) in data.fields.iter().enumerate() {
-                    let field_attribute = FieldAttributeBuilder {
-                        name: FieldAttributeName::Default,
-                        enable_name: false,
-                        enable_ignore: true,
-                        enable_impl: true,
-                    }
-                    .from_attributes(&field.attrs, traits);
+                    let field_attribute = match errors.handle(
+                        FieldAttributeBuilder {
+                            name: FieldAttributeName::Default,
+                            enable_name: false,
+                            enable_ignore: true,
+                            enable_impl: true,
+                            enable_bound: true,
+                        }
+                        .from_attributes(&field.attrs, traits),
+                    ) {
+                        Some(field_attribute) => field_attribute,
+                        None => continue,
+                    };
+
+                    extra_bound.extend(field_attribute.bound.iter().cloned());
 
                     if field_attribute.ignore {
                         continue;
                     }
 
-                    let format_trait = field_attribute.format_trait;
-                    let format_method = field_attribute.format_method;
+                    if field_attribute.format_method.is_none() {
+                        field_types.push(&field.ty);
+                    }
 
-                    let field_name = if let Some(ident) = field.ident.as_ref() {
-                        ident.to_string()
-                    } else {
-                        format!("{}", index)
-                    };
+                    if packed {
+                        extra_bound.push(create_packed_field_where_predicate(&field.ty));
+                    }
 
-                    match format_trait {
-                        Some(format_trait) => {
-                            let format_method = format_method.unwrap();
+                    let field_member = create_field_member(index, field);
 
-                            builder_tokens.extend(TokenStream::from_str(&format!("
-                                let arg = {{
-                                    struct MyDebug<'a, T: {format_trait}>(&'a T);
+                    let value = if packed {
+                        // Copy the field out of the packed struct so that a reference to it lives
+                        // as long as the builder.
+                        let field = create_field_binding(index);
 
-                                    impl<'a, T: {format_trait}> core::fmt::Debug for MyDebug<'a, T> {{
-                                        fn fmt(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {{
-                                            {format_trait}::{format_method}(self.0, formatter)
-                                        }}
-                                    }}
+                        builder_tokens.extend(quote!(let #field = self.#field_member;));
 
-                                    MyDebug(&self.{field_name})
-                                }};
-                            ", format_trait = format_trait, format_method = format_method, field_name = field_name)).unwrap());
+                        quote!(&#field)
+                    } else {
+                        quote!(&self.#field_member)
+                    };
 
-                            builder_tokens
-                                .extend(TokenStream::from_str("builder.field(&arg);").unwrap());
-                        }
-                        None => {
-                            match format_method {
-                                Some(format_method) => {
-                                    let ty = field.ty.clone().into_token_stream().to_string();
-
-                                    builder_tokens.extend(TokenStream::from_str(&format!("
-                                        let arg = {{
-                                            struct MyDebug<'a>(&'a {ty});
-
-                                            impl<'a> core::fmt::Debug for MyDebug<'a> {{
-                                                fn fmt(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {{
-                                                    {format_method}(self.0, formatter)
-                                                }}
-                                            }}
-
-                                            MyDebug(&self.{field_name})
-                                        }};
-                                    ", ty = ty, format_method = format_method, field_name = field_name)).unwrap());
-
-                                    builder_tokens.extend(
-                                        TokenStream::from_str("builder.field(&arg);").unwrap(),
-                                    );
-                                }
-                                None => {
-                                    let statement = format!(
-                                        "builder.field(&self.{field_name});",
-                                        field_name = field_name
-                                    );
-
-                                    builder_tokens
-                                        .extend(TokenStream::from_str(&statement).unwrap());
-                                }
-                            }
-                        }
-                    }
+                    let arg = create_format_arg(
+                        field_attribute.format_trait,
+                        field_attribute.format_method,
+                        &field.ty,
+                        value,
+                    );
+
+                    builder_tokens.extend(quote!(#builder.field(#arg);));
 
                     has_fields = true;
                 }
@@ -276,31 +200,41 @@ impl TraitHandler for DebugStructHandler {
         }
 
         if name.is_empty() && !has_fields {
-            panic::unit_struct_need_name();
+            errors.push(error::unit_struct_need_name(meta));
         }
 
+        let bound = type_attribute.bound.into_punctuated_where_predicates_by_generic_parameters(
+            &ast.ident,
+            &ast.generics.params,
+            &field_types,
+        );
+
         let ident = &ast.ident;
 
         let mut generics_cloned: Generics = ast.generics.clone();
 
         let where_clause = generics_cloned.make_where_clause();
 
-        for where_predicate in bound {
+        for where_predicate in bound.into_iter().chain(extra_bound) {
             where_clause.predicates.push(where_predicate);
         }
 
         let (impl_generics, ty_generics, where_clause) = generics_cloned.split_for_impl();
 
         let debug_impl = quote! {
-            impl #impl_generics core::fmt::Debug for #ident #ty_generics #where_clause {
+            impl #impl_generics ::core::fmt::Debug for #ident #ty_generics #where_clause {
                 #[inline]
-                fn fmt(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+                fn fmt(&self, #formatter: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
                     #builder_tokens
-                    builder.finish()
+                    #builder.finish()
                 }
             }
         };
 
+        errors.finish()?;
+
         tokens.extend(debug_impl);
+
+        Ok(())
     }
 }
@@ -1,8 +1,9 @@
-use super::super::TraitHandler;
+use super::super::{create_hygienic_ident, TraitHandler};
 use super::models::{
     FieldAttributeBuilder, FieldAttributeName, TypeAttributeBuilder, TypeAttributeName,
 };
 
+use crate::error::Errors;
 use crate::proc_macro2::TokenStream;
 use crate::syn::{Data, DeriveInput, Generics, Meta};
 use crate::Trait;
@@ -15,7 +16,9 @@ impl TraitHandler for DebugUnionHandler {
         tokens: &mut TokenStream,
         traits: &[Trait],
         meta: &Meta,
-    ) {
+    ) -> Result<(), syn::Error> {
+        let mut errors = Errors::new();
+
         let type_attribute = TypeAttributeBuilder {
             enable_flag: true,
             name: TypeAttributeName::Default,
@@ -24,45 +27,55 @@ impl TraitHandler for DebugUnionHandler {
             enable_named_field: false,
             enable_bound: true,
         }
-        .from_debug_meta(meta);
+        .from_debug_meta(meta)?;
 
         let name = type_attribute.name.into_string_by_ident(&ast.ident);
 
-        let bound = type_attribute
-            .bound
-            .into_punctuated_where_predicates_by_generic_parameters(&ast.generics.params);
+        let bound = type_attribute.bound.into_punctuated_where_predicates_by_generic_parameters(
+            &ast.ident,
+            &ast.generics.params,
+            &[],
+        );
+
+        let formatter = create_hygienic_ident("formatter");
+        let builder = create_hygienic_ident("builder");
+        let size = create_hygienic_ident("size");
+        let bytes = create_hygienic_ident("bytes");
 
         let mut builder_tokens = TokenStream::new();
 
         if let Data::Union(data) = &ast.data {
             for field in data.fields.named.iter() {
-                let _ = FieldAttributeBuilder {
-                    name: FieldAttributeName::Default,
-                    enable_name: false,
-                    enable_ignore: false,
-                    enable_impl: false,
-                }
-                .from_attributes(&field.attrs, traits);
+                errors.handle(
+                    FieldAttributeBuilder {
+                        name: FieldAttributeName::Default,
+                        enable_name: false,
+                        enable_ignore: false,
+                        enable_impl: false,
+                        enable_bound: false,
+                    }
+                    .from_attributes(&field.attrs, traits),
+                );
             }
 
             if name.is_empty() {
                 builder_tokens.extend(quote!(
-                    let size = core::mem::size_of::<Self>();
-                    let data = unsafe {{ core::slice::from_raw_parts(self as *const Self as *const u8, size) }};
+                    let #size = ::core::mem::size_of::<Self>();
+                    let #bytes = unsafe { ::core::slice::from_raw_parts(self as *const Self as *const ::core::primitive::u8, #size) };
 
-                    core::fmt::Debug::fmt(data, formatter)
+                    ::core::fmt::Debug::fmt(#bytes, #formatter)
                 ));
             } else {
                 builder_tokens.extend(quote!(
-                    let mut builder = formatter.debug_tuple(#name);
+                    let mut #builder = #formatter.debug_tuple(#name);
 
-                    let size = core::mem::size_of::<Self>();
+                    let #size = ::core::mem::size_of::<Self>();
 
-                    let data = unsafe {{ core::slice::from_raw_parts(self as *const Self as *const u8, size) }};
+                    let #bytes = unsafe { ::core::slice::from_raw_parts(self as *const Self as *const ::core::primitive::u8, #size) };
 
-                    builder.field(&data);
+                    #builder.field(&#bytes);
 
-                    builder.finish()
+                    #builder.finish()
                 ));
             }
         }
@@ -78,14 +91,18 @@ impl TraitHandler for DebugUnionHandler {
         let (impl_generics, ty_generics, where_clause) = generics_cloned.split_for_impl();
 
         let debug_impl = quote! {
-            impl #impl_generics core::fmt::Debug for #ident #ty_generics #where_clause {
+            impl #impl_generics ::core::fmt::Debug for #ident #ty_generics #where_clause {
                 #[inline]
-                fn fmt(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+                fn fmt(&self, #formatter: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
                     #builder_tokens
                 }
             }
         };
 
+        errors.finish()?;
+
         tokens.extend(debug_impl);
+
+        Ok(())
     }
 }
@@ -4,15 +4,16 @@ mod debug_enum;
 mod debug_struct;
 mod debug_union;
 
-use super::TraitHandler;
+use super::{create_hygienic_ident, TraitHandler};
 
 use crate::proc_macro2::TokenStream;
-use crate::syn::{Data, DeriveInput, Meta};
+use crate::syn::{ext::IdentExt, Data, DeriveInput, Fields, Meta, Path, Type, Variant};
 use crate::Trait;
 
 use debug_enum::DebugEnumHandler;
 use debug_struct::DebugStructHandler;
 use debug_union::DebugUnionHandler;
+use models::{TypeAttributeBuilder, TypeAttributeName};
 
 pub struct DebugHandler;
 
@@ -22,16 +23,88 @@ impl TraitHandler for DebugHandler {
         tokens: &mut TokenStream,
         traits: &[Trait],
         meta: &Meta,
-    ) {
+    ) -> Result<(), syn::Error> {
         match ast.data {
             Data::Struct(_) => {
-                DebugStructHandler::trait_meta_handler(ast, tokens, traits, meta);
+                DebugStructHandler::trait_meta_handler(ast, tokens, traits, meta)?;
             }
             Data::Enum(_) => {
-                DebugEnumHandler::trait_meta_handler(ast, tokens, traits, meta);
+                DebugEnumHandler::trait_meta_handler(ast, tokens, traits, meta)?;
             }
             Data::Union(_) => {
-                DebugUnionHandler::trait_meta_handler(ast, tokens, traits, meta);
+                DebugUnionHandler::trait_meta_handler(ast, tokens, traits, meta)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub fn create_variant_name_string(variant: &Variant, traits: &[Trait]) -> String {
+    let type_attribute = TypeAttributeBuilder {
+        enable_flag: false,
+        name: TypeAttributeName::Default,
+        enable_name: true,
+        named_field: matches!(&variant.fields, Fields::Named(_)),
+        enable_named_field: true,
+        enable_bound: false,
+    }
+    .from_attributes(&variant.attrs, traits);
+
+    // An invalid attribute is reported by the `Debug` handler itself.
+    match type_attribute.map(|type_attribute| type_attribute.name) {
+        Ok(TypeAttributeName::Custom(s)) => s,
+        _ => variant.ident.unraw().to_string(),
+    }
+}
+
+/// Creates the argument passed to a builder for `value`, a reference to a field. A custom format
+/// method is called through a wrapper type implementing `Debug`, which is declared in a block of
+/// its own so that it cannot shadow the user types and paths around it.
+fn create_format_arg(
+    format_trait: Option<Path>,
+    format_method: Option<Path>,
+    ty: &Type,
+    value: TokenStream,
+) -> TokenStream {
+    let format_method = match format_trait {
+        Some(format_trait) => {
+            let format_method = format_method.unwrap();
+
+            quote!(#format_trait::#format_method)
+        }
+        None => {
+            match format_method {
+                Some(format_method) => quote!(#format_method),
+                None => return value,
+            }
+        }
+    };
+
+    let field = create_hygienic_ident("field");
+    let method = create_hygienic_ident("method");
+    let formatter = create_hygienic_ident("formatter");
+
+    quote! {
+        &{
+            let #field = #value;
+
+            let #method = |#field: &#ty, #formatter: &mut ::core::fmt::Formatter| -> ::core::fmt::Result {
+                #format_method(#field, #formatter)
+            };
+
+            {
+                struct MyDebug<'a, T, F>(&'a T, F);
+
+                impl<'a, T, F> ::core::fmt::Debug for MyDebug<'a, T, F>
+                where
+                    F: ::core::ops::Fn(&T, &mut ::core::fmt::Formatter) -> ::core::fmt::Result, {
+                    fn fmt(&self, formatter: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        (self.1)(self.0, formatter)
+                    }
+                }
+
+                MyDebug(#field, #method)
             }
         }
     }
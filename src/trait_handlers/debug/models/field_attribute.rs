@@ -1,8 +1,14 @@
-use super::super::super::create_path_string_from_lit_str;
+use super::super::super::{
+    create_path_from_lit_str, create_path_string_from_lit_str,
+    create_where_predicates_from_lit_str, parse_educe_meta,
+};
 
-use crate::panic;
+use crate::error;
 use crate::quote::ToTokens;
-use crate::syn::{Attribute, Lit, Meta, NestedMeta};
+use crate::syn::spanned::Spanned;
+use crate::syn::{
+    punctuated::Punctuated, token::Comma, Attribute, Lit, Meta, NestedMeta, Path, WherePredicate,
+};
 use crate::Trait;
 
 #[derive(Debug, Clone)]
@@ -20,12 +26,13 @@ impl FieldAttributeName {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct FieldAttribute {
     pub name: FieldAttributeName,
     pub ignore: bool,
-    pub format_method: Option<String>,
-    pub format_trait: Option<String>,
+    pub format_method: Option<Path>,
+    pub format_trait: Option<Path>,
+    pub bound: Punctuated<WherePredicate, Comma>,
 }
 
 #[derive(Debug, Clone)]
@@ -34,11 +41,13 @@ pub struct FieldAttributeBuilder {
     pub enable_name: bool,
     pub enable_ignore: bool,
     pub enable_impl: bool,
+    pub enable_bound: bool,
 }
 
 impl FieldAttributeBuilder {
     #[allow(clippy::cognitive_complexity)]
-    pub fn from_debug_meta(&self, meta: &Meta) -> FieldAttribute {
+    pub fn from_debug_meta(&self, meta: &Meta) -> Result<FieldAttribute, syn::Error> {
+        let mut bound = Punctuated::new();
         let mut name = self.name.clone();
 
         let mut ignore = false;
@@ -83,8 +92,21 @@ impl FieldAttributeBuilder {
                 stringify!(#[educe(Debug(trait = "path_to_trait"))]),
                 stringify!(#[educe(Debug(trait = "path_to_trait", method = "path_to_method_in_trait"))]),
                 stringify!(#[educe(Debug(method("path_to_method")))]),
+                stringify!(#[educe(Debug(method(path_to_method)))]),
                 stringify!(#[educe(Debug(trait("path_to_trait")))]),
+                stringify!(#[educe(Debug(trait(path_to_trait)))]),
                 stringify!(#[educe(Debug(trait("path_to_trait"), method("path_to_method_in_trait")))]),
+                stringify!(#[educe(Debug(trait(path_to_trait), method(path_to_method_in_trait)))]),
+            ];
+
+            usage
+        };
+
+        let correct_usage_for_bound = {
+            let usage = vec![
+                stringify!(#[educe(Debug(bound = "where_predicates"))]),
+                stringify!(#[educe(Debug(bound("where_predicates")))]),
+                stringify!(#[educe(Debug(bound(where_predicates)))]),
             ];
 
             usage
@@ -103,7 +125,11 @@ impl FieldAttributeBuilder {
                             match meta_name.as_str() {
                                 "name" | "rename" => {
                                     if !self.enable_name {
-                                        panic::unknown_parameter("Debug", meta_name.as_str());
+                                        return Err(error::unknown_parameter(
+                                            meta,
+                                            "Debug",
+                                            meta_name.as_str(),
+                                        ));
                                     }
 
                                     match meta {
@@ -114,8 +140,11 @@ impl FieldAttributeBuilder {
                                                         match lit {
                                                             Lit::Str(s) => {
                                                                 if name_is_set {
-                                                                    panic::reset_parameter(
-                                                                        meta_name.as_str(),
+                                                                    return Err(
+                                                                        error::reset_parameter(
+                                                                            meta,
+                                                                            meta_name.as_str(),
+                                                                        ),
                                                                     );
                                                                 }
 
@@ -124,22 +153,24 @@ impl FieldAttributeBuilder {
                                                                 let s =
                                                                     create_path_string_from_lit_str(
                                                                         s,
-                                                                    );
+                                                                    )?;
 
                                                                 name = match s {
                                                                 Some(s) => {
                                                                     FieldAttributeName::Custom(s)
                                                                 }
                                                                 None => {
-                                                                    panic::disable_named_field_name(
-                                                                    )
+                                                                    return Err(error::disable_named_field_name(meta))
                                                                 }
                                                             };
                                                             }
                                                             Lit::Bool(s) => {
                                                                 if name_is_set {
-                                                                    panic::reset_parameter(
-                                                                        meta_name.as_str(),
+                                                                    return Err(
+                                                                        error::reset_parameter(
+                                                                            meta,
+                                                                            meta_name.as_str(),
+                                                                        ),
                                                                     );
                                                                 }
 
@@ -149,22 +180,25 @@ impl FieldAttributeBuilder {
                                                                     name =
                                                                         FieldAttributeName::Default;
                                                                 } else {
-                                                                    panic::disable_named_field_name(
-                                                                    );
+                                                                    return Err(error::disable_named_field_name(meta));
                                                                 }
                                                             }
-                                                            _ => {
-                                                                panic::parameter_incorrect_format(
+                                                            _ => return Err(
+                                                                error::parameter_incorrect_format(
+                                                                    meta,
                                                                     meta_name.as_str(),
                                                                     &correct_usage_for_name,
-                                                                )
-                                                            }
+                                                                ),
+                                                            ),
                                                         }
                                                     }
                                                     _ => {
-                                                        panic::parameter_incorrect_format(
-                                                            meta_name.as_str(),
-                                                            &correct_usage_for_name,
+                                                        return Err(
+                                                            error::parameter_incorrect_format(
+                                                                meta,
+                                                                meta_name.as_str(),
+                                                                &correct_usage_for_name,
+                                                            ),
                                                         )
                                                     }
                                                 }
@@ -176,21 +210,33 @@ impl FieldAttributeBuilder {
                                             match lit {
                                                 Lit::Str(s) => {
                                                     if name_is_set {
-                                                        panic::reset_parameter(meta_name.as_str());
+                                                        return Err(error::reset_parameter(
+                                                            meta,
+                                                            meta_name.as_str(),
+                                                        ));
                                                     }
 
                                                     name_is_set = true;
 
-                                                    let s = create_path_string_from_lit_str(s);
+                                                    let s = create_path_string_from_lit_str(s)?;
 
                                                     name = match s {
                                                         Some(s) => FieldAttributeName::Custom(s),
-                                                        None => panic::disable_named_field_name(),
+                                                        None => {
+                                                            return Err(
+                                                                error::disable_named_field_name(
+                                                                    meta,
+                                                                ),
+                                                            )
+                                                        }
                                                     };
                                                 }
                                                 Lit::Bool(s) => {
                                                     if name_is_set {
-                                                        panic::reset_parameter(meta_name.as_str());
+                                                        return Err(error::reset_parameter(
+                                                            meta,
+                                                            meta_name.as_str(),
+                                                        ));
                                                     }
 
                                                     name_is_set = true;
@@ -198,34 +244,45 @@ impl FieldAttributeBuilder {
                                                     if s.value {
                                                         name = FieldAttributeName::Default;
                                                     } else {
-                                                        panic::disable_named_field_name();
+                                                        return Err(
+                                                            error::disable_named_field_name(meta),
+                                                        );
                                                     }
                                                 }
                                                 _ => {
-                                                    panic::parameter_incorrect_format(
+                                                    return Err(error::parameter_incorrect_format(
+                                                        meta,
                                                         meta_name.as_str(),
                                                         &correct_usage_for_name,
-                                                    )
+                                                    ))
                                                 }
                                             }
                                         }
                                         _ => {
-                                            panic::parameter_incorrect_format(
+                                            return Err(error::parameter_incorrect_format(
+                                                meta,
                                                 meta_name.as_str(),
                                                 &correct_usage_for_name,
-                                            )
+                                            ))
                                         }
                                     }
                                 }
                                 "ignore" => {
                                     if !self.enable_ignore {
-                                        panic::unknown_parameter("Debug", meta_name.as_str());
+                                        return Err(error::unknown_parameter(
+                                            meta,
+                                            "Debug",
+                                            meta_name.as_str(),
+                                        ));
                                     }
 
                                     match meta {
                                         Meta::Path(_) => {
                                             if ignore_is_set {
-                                                panic::reset_parameter(meta_name.as_str());
+                                                return Err(error::reset_parameter(
+                                                    meta,
+                                                    meta_name.as_str(),
+                                                ));
                                             }
 
                                             ignore_is_set = true;
@@ -233,16 +290,21 @@ impl FieldAttributeBuilder {
                                             ignore = true;
                                         }
                                         _ => {
-                                            panic::parameter_incorrect_format(
+                                            return Err(error::parameter_incorrect_format(
+                                                meta,
                                                 meta_name.as_str(),
                                                 &correct_usage_for_ignore,
-                                            )
+                                            ))
                                         }
                                     }
                                 }
                                 "method" => {
                                     if !self.enable_impl {
-                                        panic::unknown_parameter("Debug", meta_name.as_str());
+                                        return Err(error::unknown_parameter(
+                                            meta,
+                                            "Debug",
+                                            meta_name.as_str(),
+                                        ));
                                     }
 
                                     match meta {
@@ -253,36 +315,44 @@ impl FieldAttributeBuilder {
                                                         match lit {
                                                             Lit::Str(s) => {
                                                                 if format_method.is_some() {
-                                                                    panic::reset_parameter(
-                                                                        meta_name.as_str(),
+                                                                    return Err(
+                                                                        error::reset_parameter(
+                                                                            meta,
+                                                                            meta_name.as_str(),
+                                                                        ),
                                                                     );
                                                                 }
 
                                                                 let s =
-                                                                    create_path_string_from_lit_str(
-                                                                        s,
-                                                                    );
+                                                                    create_path_from_lit_str(s)?;
 
                                                                 if let Some(s) = s {
                                                                     format_method = Some(s);
                                                                 } else {
-                                                                    panic::empty_parameter(
-                                                                        meta_name.as_str(),
+                                                                    return Err(
+                                                                        error::empty_parameter(
+                                                                            meta,
+                                                                            meta_name.as_str(),
+                                                                        ),
                                                                     );
                                                                 }
                                                             }
-                                                            _ => {
-                                                                panic::parameter_incorrect_format(
+                                                            _ => return Err(
+                                                                error::parameter_incorrect_format(
+                                                                    meta,
                                                                     meta_name.as_str(),
                                                                     &correct_usage_for_impl,
-                                                                )
-                                                            }
+                                                                ),
+                                                            ),
                                                         }
                                                     }
                                                     _ => {
-                                                        panic::parameter_incorrect_format(
-                                                            meta_name.as_str(),
-                                                            &correct_usage_for_impl,
+                                                        return Err(
+                                                            error::parameter_incorrect_format(
+                                                                meta,
+                                                                meta_name.as_str(),
+                                                                &correct_usage_for_impl,
+                                                            ),
                                                         )
                                                     }
                                                 }
@@ -294,36 +364,48 @@ impl FieldAttributeBuilder {
                                             match lit {
                                                 Lit::Str(s) => {
                                                     if format_method.is_some() {
-                                                        panic::reset_parameter(meta_name.as_str());
+                                                        return Err(error::reset_parameter(
+                                                            meta,
+                                                            meta_name.as_str(),
+                                                        ));
                                                     }
 
-                                                    let s = create_path_string_from_lit_str(s);
+                                                    let s = create_path_from_lit_str(s)?;
 
                                                     if let Some(s) = s {
                                                         format_method = Some(s);
                                                     } else {
-                                                        panic::empty_parameter(meta_name.as_str());
+                                                        return Err(error::empty_parameter(
+                                                            meta,
+                                                            meta_name.as_str(),
+                                                        ));
                                                     }
                                                 }
                                                 _ => {
-                                                    panic::parameter_incorrect_format(
+                                                    return Err(error::parameter_incorrect_format(
+                                                        meta,
                                                         meta_name.as_str(),
                                                         &correct_usage_for_impl,
-                                                    )
+                                                    ))
                                                 }
                                             }
                                         }
                                         _ => {
-                                            panic::parameter_incorrect_format(
+                                            return Err(error::parameter_incorrect_format(
+                                                meta,
                                                 meta_name.as_str(),
                                                 &correct_usage_for_impl,
-                                            )
+                                            ))
                                         }
                                     }
                                 }
                                 "trait" => {
                                     if !self.enable_impl {
-                                        panic::unknown_parameter("Debug", meta_name.as_str());
+                                        return Err(error::unknown_parameter(
+                                            meta,
+                                            "Debug",
+                                            meta_name.as_str(),
+                                        ));
                                     }
 
                                     match meta {
@@ -334,36 +416,44 @@ impl FieldAttributeBuilder {
                                                         match lit {
                                                             Lit::Str(s) => {
                                                                 if format_trait.is_some() {
-                                                                    panic::reset_parameter(
-                                                                        meta_name.as_str(),
+                                                                    return Err(
+                                                                        error::reset_parameter(
+                                                                            meta,
+                                                                            meta_name.as_str(),
+                                                                        ),
                                                                     );
                                                                 }
 
                                                                 let s =
-                                                                    create_path_string_from_lit_str(
-                                                                        s,
-                                                                    );
+                                                                    create_path_from_lit_str(s)?;
 
                                                                 if let Some(s) = s {
                                                                     format_trait = Some(s);
                                                                 } else {
-                                                                    panic::empty_parameter(
-                                                                        meta_name.as_str(),
+                                                                    return Err(
+                                                                        error::empty_parameter(
+                                                                            meta,
+                                                                            meta_name.as_str(),
+                                                                        ),
                                                                     );
                                                                 }
                                                             }
-                                                            _ => {
-                                                                panic::parameter_incorrect_format(
+                                                            _ => return Err(
+                                                                error::parameter_incorrect_format(
+                                                                    meta,
                                                                     meta_name.as_str(),
                                                                     &correct_usage_for_impl,
-                                                                )
-                                                            }
+                                                                ),
+                                                            ),
                                                         }
                                                     }
                                                     _ => {
-                                                        panic::parameter_incorrect_format(
-                                                            meta_name.as_str(),
-                                                            &correct_usage_for_impl,
+                                                        return Err(
+                                                            error::parameter_incorrect_format(
+                                                                meta,
+                                                                meta_name.as_str(),
+                                                                &correct_usage_for_impl,
+                                                            ),
                                                         )
                                                     }
                                                 }
@@ -375,69 +465,173 @@ impl FieldAttributeBuilder {
                                             match lit {
                                                 Lit::Str(s) => {
                                                     if format_trait.is_some() {
-                                                        panic::reset_parameter(meta_name.as_str());
+                                                        return Err(error::reset_parameter(
+                                                            meta,
+                                                            meta_name.as_str(),
+                                                        ));
                                                     }
 
-                                                    let s = create_path_string_from_lit_str(s);
+                                                    let s = create_path_from_lit_str(s)?;
 
                                                     if let Some(s) = s {
                                                         format_trait = Some(s);
                                                     } else {
-                                                        panic::empty_parameter(meta_name.as_str());
+                                                        return Err(error::empty_parameter(
+                                                            meta,
+                                                            meta_name.as_str(),
+                                                        ));
                                                     }
                                                 }
                                                 _ => {
-                                                    panic::parameter_incorrect_format(
+                                                    return Err(error::parameter_incorrect_format(
+                                                        meta,
                                                         meta_name.as_str(),
                                                         &correct_usage_for_impl,
-                                                    )
+                                                    ))
                                                 }
                                             }
                                         }
                                         _ => {
-                                            panic::parameter_incorrect_format(
+                                            return Err(error::parameter_incorrect_format(
+                                                meta,
                                                 meta_name.as_str(),
                                                 &correct_usage_for_impl,
-                                            )
+                                            ))
+                                        }
+                                    }
+                                }
+                                "bound" => {
+                                    if !self.enable_bound {
+                                        return Err(error::unknown_parameter(
+                                            meta,
+                                            "Debug",
+                                            meta_name.as_str(),
+                                        ));
+                                    }
+
+                                    match meta {
+                                        Meta::List(list) => {
+                                            for p in list.nested.iter() {
+                                                match p {
+                                                    NestedMeta::Lit(Lit::Str(s)) => {
+                                                        if !bound.is_empty() {
+                                                            return Err(error::reset_parameter(
+                                                                meta,
+                                                                meta_name.as_str(),
+                                                            ));
+                                                        }
+
+                                                        bound = match create_where_predicates_from_lit_str(s)? {
+                                                            Some(where_predicates) => where_predicates,
+                                                            None => {
+                                                                return Err(error::empty_parameter(
+                                                                    meta,
+                                                                    meta_name.as_str(),
+                                                                ))
+                                                            }
+                                                        };
+                                                    }
+                                                    _ => {
+                                                        return Err(
+                                                            error::parameter_incorrect_format(
+                                                                meta,
+                                                                meta_name.as_str(),
+                                                                &correct_usage_for_bound,
+                                                            ),
+                                                        )
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        Meta::NameValue(named_value) => {
+                                            let lit = &named_value.lit;
+
+                                            match lit {
+                                                Lit::Str(s) => {
+                                                    if !bound.is_empty() {
+                                                        return Err(error::reset_parameter(
+                                                            meta,
+                                                            meta_name.as_str(),
+                                                        ));
+                                                    }
+
+                                                    bound =
+                                                        match create_where_predicates_from_lit_str(
+                                                            s,
+                                                        )? {
+                                                            Some(where_predicates) => {
+                                                                where_predicates
+                                                            }
+                                                            None => {
+                                                                return Err(error::empty_parameter(
+                                                                    meta,
+                                                                    meta_name.as_str(),
+                                                                ))
+                                                            }
+                                                        };
+                                                }
+                                                _ => {
+                                                    return Err(error::parameter_incorrect_format(
+                                                        meta,
+                                                        meta_name.as_str(),
+                                                        &correct_usage_for_bound,
+                                                    ))
+                                                }
+                                            }
+                                        }
+                                        _ => {
+                                            return Err(error::parameter_incorrect_format(
+                                                meta,
+                                                meta_name.as_str(),
+                                                &correct_usage_for_bound,
+                                            ))
                                         }
                                     }
                                 }
-                                _ => panic::unknown_parameter("Debug", meta_name.as_str()),
+                                _ => {
+                                    return Err(error::unknown_parameter(
+                                        meta,
+                                        "Debug",
+                                        meta_name.as_str(),
+                                    ))
+                                }
                             }
                         }
                         NestedMeta::Lit(lit) => {
                             match lit {
                                 Lit::Str(s) => {
                                     if !self.enable_name {
-                                        panic::attribute_incorrect_format(
+                                        return Err(error::attribute_incorrect_format(
+                                            meta,
                                             "Debug",
                                             &correct_usage_for_debug_attribute,
-                                        )
+                                        ));
                                     }
 
                                     if name_is_set {
-                                        panic::reset_parameter("name");
+                                        return Err(error::reset_parameter(meta, "name"));
                                     }
 
                                     name_is_set = true;
 
-                                    let s = create_path_string_from_lit_str(s);
+                                    let s = create_path_string_from_lit_str(s)?;
 
                                     name = match s {
                                         Some(s) => FieldAttributeName::Custom(s),
-                                        None => panic::disable_named_field_name(),
+                                        None => return Err(error::disable_named_field_name(meta)),
                                     };
                                 }
                                 Lit::Bool(b) => {
                                     if !self.enable_ignore {
-                                        panic::attribute_incorrect_format(
+                                        return Err(error::attribute_incorrect_format(
+                                            meta,
                                             "Debug",
                                             &correct_usage_for_debug_attribute,
-                                        )
+                                        ));
                                     }
 
                                     if ignore_is_set {
-                                        panic::reset_parameter("ignore");
+                                        return Err(error::reset_parameter(meta, "ignore"));
                                     }
 
                                     ignore_is_set = true;
@@ -445,10 +639,11 @@ impl FieldAttributeBuilder {
                                     ignore = !b.value;
                                 }
                                 _ => {
-                                    panic::attribute_incorrect_format(
+                                    return Err(error::attribute_incorrect_format(
+                                        meta,
                                         "Debug",
                                         &correct_usage_for_debug_attribute,
-                                    )
+                                    ))
                                 }
                             }
                         }
@@ -461,58 +656,72 @@ impl FieldAttributeBuilder {
                 match lit {
                     Lit::Str(s) => {
                         if !self.enable_name {
-                            panic::attribute_incorrect_format(
+                            return Err(error::attribute_incorrect_format(
+                                meta,
                                 "Debug",
                                 &correct_usage_for_debug_attribute,
-                            )
+                            ));
                         }
 
-                        let s = create_path_string_from_lit_str(s);
+                        let s = create_path_string_from_lit_str(s)?;
 
                         name = match s {
                             Some(s) => FieldAttributeName::Custom(s),
-                            None => panic::disable_named_field_name(),
+                            None => return Err(error::disable_named_field_name(meta)),
                         };
                     }
                     Lit::Bool(b) => {
                         if !self.enable_ignore {
-                            panic::attribute_incorrect_format(
+                            return Err(error::attribute_incorrect_format(
+                                meta,
                                 "Debug",
                                 &correct_usage_for_debug_attribute,
-                            )
+                            ));
                         }
 
                         ignore = !b.value;
                     }
                     _ => {
-                        panic::attribute_incorrect_format(
+                        return Err(error::attribute_incorrect_format(
+                            meta,
                             "Debug",
                             &correct_usage_for_debug_attribute,
-                        )
+                        ))
                     }
                 }
             }
-            _ => panic::attribute_incorrect_format("Debug", &correct_usage_for_debug_attribute),
+            _ => {
+                return Err(error::attribute_incorrect_format(
+                    meta,
+                    "Debug",
+                    &correct_usage_for_debug_attribute,
+                ))
+            }
         }
 
-        if format_trait.is_some() && format_method.is_none() {
-            format_method = Some("fmt".to_string());
+        if let (Some(format_trait), None) = (&format_trait, &format_method) {
+            format_method = Some(Path::from(format_ident!("fmt", span = format_trait.span())));
         }
 
-        FieldAttribute {
+        Ok(FieldAttribute {
             name,
             ignore,
             format_method,
             format_trait,
-        }
+            bound,
+        })
     }
 
     #[allow(clippy::wrong_self_convention)]
-    pub fn from_attributes(self, attributes: &[Attribute], traits: &[Trait]) -> FieldAttribute {
+    pub fn from_attributes(
+        self,
+        attributes: &[Attribute],
+        traits: &[Trait],
+    ) -> Result<FieldAttribute, syn::Error> {
         let mut result = None;
 
         for attribute in attributes.iter() {
-            let meta = attribute.parse_meta().unwrap();
+            let meta = parse_educe_meta(attribute)?;
 
             let meta_name = meta.path().into_token_stream().to_string();
 
@@ -522,36 +731,35 @@ impl FieldAttributeBuilder {
                         for p in list.nested.iter() {
                             match p {
                                 NestedMeta::Meta(meta) => {
-                                    let meta_name = meta.path().into_token_stream().to_string();
-
-                                    let t = Trait::from_str(meta_name);
+                                    let t = Trait::from_path(meta.path())?;
 
                                     if traits.binary_search(&t).is_err() {
-                                        panic::trait_not_used(t);
+                                        return Err(error::trait_not_used(meta, t));
                                     }
 
                                     if t == Trait::Debug {
                                         if result.is_some() {
-                                            panic::reuse_a_trait(t);
+                                            return Err(error::reuse_a_trait(meta, t));
                                         }
 
-                                        result = Some(self.from_debug_meta(&meta));
+                                        result = Some(self.from_debug_meta(&meta)?);
                                     }
                                 }
-                                _ => panic::educe_format_incorrect(),
+                                _ => return Err(error::educe_format_incorrect(attribute)),
                             }
                         }
                     }
-                    _ => panic::educe_format_incorrect(),
+                    _ => return Err(error::educe_format_incorrect(attribute)),
                 }
             }
         }
 
-        result.unwrap_or(FieldAttribute {
+        Ok(result.unwrap_or(FieldAttribute {
             name: self.name,
             ignore: false,
             format_method: None,
             format_trait: None,
-        })
+            bound: Punctuated::new(),
+        }))
     }
 }
@@ -1,13 +1,14 @@
 use super::super::super::{
-    create_path_string_from_lit_str, create_where_predicates_from_generic_parameters,
-    create_where_predicates_from_lit_str,
+    create_path_string_from_lit_str, create_where_predicates_from_field_types,
+    create_where_predicates_from_generic_parameters, create_where_predicates_from_lit_str,
+    parse_educe_meta,
 };
 
-use crate::panic;
+use crate::error;
 use crate::quote::ToTokens;
 use crate::syn::{
-    punctuated::Punctuated, token::Comma, Attribute, GenericParam, Ident, Lit, Meta, NestedMeta,
-    WherePredicate,
+    ext::IdentExt, punctuated::Punctuated, token::Comma, Attribute, GenericParam, Ident, Lit, Meta,
+    NestedMeta, Type, WherePredicate,
 };
 use crate::Trait;
 
@@ -22,7 +23,7 @@ impl TypeAttributeName {
     pub fn into_string_by_ident(self, ident: &Ident) -> String {
         match self {
             TypeAttributeName::Disable => String::new(),
-            TypeAttributeName::Default => ident.to_string(),
+            TypeAttributeName::Default => ident.unraw().to_string(),
             TypeAttributeName::Custom(s) => s,
         }
     }
@@ -32,20 +33,31 @@ impl TypeAttributeName {
 pub enum TypeAttributeBound {
     None,
     Auto,
+    Infer,
     Custom(Punctuated<WherePredicate, Comma>),
 }
 
 impl TypeAttributeBound {
     pub fn into_punctuated_where_predicates_by_generic_parameters(
         self,
+        ident: &Ident,
         params: &Punctuated<GenericParam, Comma>,
+        field_types: &[&Type],
     ) -> Punctuated<WherePredicate, Comma> {
         match self {
             TypeAttributeBound::None => Punctuated::new(),
             TypeAttributeBound::Auto => {
                 create_where_predicates_from_generic_parameters(
                     params,
-                    &syn::parse2(quote!(core::fmt::Debug)).unwrap(),
+                    &syn::parse2(quote!(::core::fmt::Debug)).unwrap(),
+                )
+            }
+            TypeAttributeBound::Infer => {
+                create_where_predicates_from_field_types(
+                    ident,
+                    params,
+                    field_types,
+                    &syn::parse2(quote!(::core::fmt::Debug)).unwrap(),
                 )
             }
             TypeAttributeBound::Custom(where_predicates) => where_predicates,
@@ -73,7 +85,7 @@ pub struct TypeAttributeBuilder {
 
 impl TypeAttributeBuilder {
     #[allow(clippy::cognitive_complexity)]
-    pub fn from_debug_meta(&self, meta: &Meta) -> TypeAttribute {
+    pub fn from_debug_meta(&self, meta: &Meta) -> Result<TypeAttribute, syn::Error> {
         let mut flag = false;
         let mut name = self.name.clone();
         let mut named_field = self.named_field;
@@ -132,8 +144,10 @@ impl TypeAttributeBuilder {
         let correct_usage_for_bound = {
             let usage = vec![
                 stringify!(#[educe(Debug(bound))]),
+                stringify!(#[educe(Debug(bound(*)))]),
                 stringify!(#[educe(Debug(bound = "where_predicates"))]),
                 stringify!(#[educe(Debug(bound("where_predicates")))]),
+                stringify!(#[educe(Debug(bound(where_predicates)))]),
             ];
 
             usage
@@ -153,7 +167,11 @@ impl TypeAttributeBuilder {
                             match meta_name.as_str() {
                                 "name" | "rename" => {
                                     if !self.enable_name {
-                                        panic::unknown_parameter("Debug", meta_name.as_str());
+                                        return Err(error::unknown_parameter(
+                                            meta,
+                                            "Debug",
+                                            meta_name.as_str(),
+                                        ));
                                     }
 
                                     match meta {
@@ -164,17 +182,18 @@ impl TypeAttributeBuilder {
                                                         match lit {
                                                             Lit::Str(s) => {
                                                                 if name_is_set {
-                                                                    panic::reset_parameter(
-                                                                        meta_name.as_str(),
+                                                                    return Err(
+                                                                        error::reset_parameter(
+                                                                            meta,
+                                                                            meta_name.as_str(),
+                                                                        ),
                                                                     );
                                                                 }
 
                                                                 name_is_set = true;
 
                                                                 let s =
-                                                                    create_path_string_from_lit_str(
-                                                                        s,
-                                                                    );
+                                                                create_path_string_from_lit_str(s)?;
 
                                                                 name = match s {
                                                                     Some(s) => {
@@ -187,8 +206,11 @@ impl TypeAttributeBuilder {
                                                             }
                                                             Lit::Bool(s) => {
                                                                 if name_is_set {
-                                                                    panic::reset_parameter(
-                                                                        meta_name.as_str(),
+                                                                    return Err(
+                                                                        error::reset_parameter(
+                                                                            meta,
+                                                                            meta_name.as_str(),
+                                                                        ),
                                                                     );
                                                                 }
 
@@ -202,18 +224,22 @@ impl TypeAttributeBuilder {
                                                                         TypeAttributeName::Disable;
                                                                 }
                                                             }
-                                                            _ => {
-                                                                panic::parameter_incorrect_format(
+                                                            _ => return Err(
+                                                                error::parameter_incorrect_format(
+                                                                    meta,
                                                                     meta_name.as_str(),
                                                                     &correct_usage_for_name,
-                                                                )
-                                                            }
+                                                                ),
+                                                            ),
                                                         }
                                                     }
                                                     _ => {
-                                                        panic::parameter_incorrect_format(
-                                                            meta_name.as_str(),
-                                                            &correct_usage_for_name,
+                                                        return Err(
+                                                            error::parameter_incorrect_format(
+                                                                meta,
+                                                                meta_name.as_str(),
+                                                                &correct_usage_for_name,
+                                                            ),
                                                         )
                                                     }
                                                 }
@@ -225,12 +251,15 @@ impl TypeAttributeBuilder {
                                             match lit {
                                                 Lit::Str(s) => {
                                                     if name_is_set {
-                                                        panic::reset_parameter(meta_name.as_str());
+                                                        return Err(error::reset_parameter(
+                                                            meta,
+                                                            meta_name.as_str(),
+                                                        ));
                                                     }
 
                                                     name_is_set = true;
 
-                                                    let s = create_path_string_from_lit_str(s);
+                                                    let s = create_path_string_from_lit_str(s)?;
 
                                                     name = match s {
                                                         Some(s) => TypeAttributeName::Custom(s),
@@ -239,7 +268,10 @@ impl TypeAttributeBuilder {
                                                 }
                                                 Lit::Bool(s) => {
                                                     if name_is_set {
-                                                        panic::reset_parameter(meta_name.as_str());
+                                                        return Err(error::reset_parameter(
+                                                            meta,
+                                                            meta_name.as_str(),
+                                                        ));
                                                     }
 
                                                     name_is_set = true;
@@ -251,24 +283,30 @@ impl TypeAttributeBuilder {
                                                     }
                                                 }
                                                 _ => {
-                                                    panic::parameter_incorrect_format(
+                                                    return Err(error::parameter_incorrect_format(
+                                                        meta,
                                                         meta_name.as_str(),
                                                         &correct_usage_for_name,
-                                                    )
+                                                    ))
                                                 }
                                             }
                                         }
                                         _ => {
-                                            panic::parameter_incorrect_format(
+                                            return Err(error::parameter_incorrect_format(
+                                                meta,
                                                 meta_name.as_str(),
                                                 &correct_usage_for_name,
-                                            )
+                                            ))
                                         }
                                     }
                                 }
                                 "named_field" => {
                                     if !self.enable_named_field {
-                                        panic::unknown_parameter("Debug", meta_name.as_str());
+                                        return Err(error::unknown_parameter(
+                                            meta,
+                                            "Debug",
+                                            meta_name.as_str(),
+                                        ));
                                     }
 
                                     match meta {
@@ -279,8 +317,11 @@ impl TypeAttributeBuilder {
                                                         match lit {
                                                             Lit::Bool(s) => {
                                                                 if named_field_is_set {
-                                                                    panic::reset_parameter(
-                                                                        meta_name.as_str(),
+                                                                    return Err(
+                                                                        error::reset_parameter(
+                                                                            meta,
+                                                                            meta_name.as_str(),
+                                                                        ),
                                                                     );
                                                                 }
 
@@ -288,18 +329,22 @@ impl TypeAttributeBuilder {
 
                                                                 named_field = s.value;
                                                             }
-                                                            _ => {
-                                                                panic::parameter_incorrect_format(
+                                                            _ => return Err(
+                                                                error::parameter_incorrect_format(
+                                                                    meta,
                                                                     meta_name.as_str(),
                                                                     &correct_usage_for_named_field,
-                                                                )
-                                                            }
+                                                                ),
+                                                            ),
                                                         }
                                                     }
                                                     _ => {
-                                                        panic::parameter_incorrect_format(
-                                                            meta_name.as_str(),
-                                                            &correct_usage_for_named_field,
+                                                        return Err(
+                                                            error::parameter_incorrect_format(
+                                                                meta,
+                                                                meta_name.as_str(),
+                                                                &correct_usage_for_named_field,
+                                                            ),
                                                         )
                                                     }
                                                 }
@@ -311,7 +356,10 @@ impl TypeAttributeBuilder {
                                             match lit {
                                                 Lit::Bool(s) => {
                                                     if named_field_is_set {
-                                                        panic::reset_parameter(meta_name.as_str());
+                                                        return Err(error::reset_parameter(
+                                                            meta,
+                                                            meta_name.as_str(),
+                                                        ));
                                                     }
 
                                                     named_field_is_set = true;
@@ -319,24 +367,30 @@ impl TypeAttributeBuilder {
                                                     named_field = s.value;
                                                 }
                                                 _ => {
-                                                    panic::parameter_incorrect_format(
+                                                    return Err(error::parameter_incorrect_format(
+                                                        meta,
                                                         meta_name.as_str(),
                                                         &correct_usage_for_named_field,
-                                                    )
+                                                    ))
                                                 }
                                             }
                                         }
                                         _ => {
-                                            panic::parameter_incorrect_format(
+                                            return Err(error::parameter_incorrect_format(
+                                                meta,
                                                 meta_name.as_str(),
                                                 &correct_usage_for_named_field,
-                                            )
+                                            ))
                                         }
                                     }
                                 }
                                 "bound" => {
                                     if !self.enable_bound {
-                                        panic::unknown_parameter("Debug", meta_name.as_str());
+                                        return Err(error::unknown_parameter(
+                                            meta,
+                                            "Debug",
+                                            meta_name.as_str(),
+                                        ));
                                     }
 
                                     match meta {
@@ -347,40 +401,54 @@ impl TypeAttributeBuilder {
                                                         match lit {
                                                             Lit::Str(s) => {
                                                                 if bound_is_set {
-                                                                    panic::reset_parameter(
-                                                                        meta_name.as_str(),
+                                                                    return Err(
+                                                                        error::reset_parameter(
+                                                                            meta,
+                                                                            meta_name.as_str(),
+                                                                        ),
                                                                     );
                                                                 }
 
                                                                 bound_is_set = true;
 
-                                                                let where_predicates = create_where_predicates_from_lit_str(s);
+                                                                bound = if s.value().trim() == "*" {
+                                                                    TypeAttributeBound::Infer
+                                                                } else {
+                                                                    let where_predicates = create_where_predicates_from_lit_str(s)?;
 
-                                                                bound = match where_predicates {
+                                                                    match where_predicates {
                                                                     Some(where_predicates) => {
                                                                         TypeAttributeBound::Custom(
                                                                             where_predicates,
                                                                         )
                                                                     }
                                                                     None => {
-                                                                        panic::empty_parameter(
-                                                                            meta_name.as_str(),
+                                                                        return Err(
+                                                                            error::empty_parameter(
+                                                                                meta,
+                                                                                meta_name.as_str(),
+                                                                            ),
                                                                         )
                                                                     }
+                                                                }
                                                                 };
                                                             }
-                                                            _ => {
-                                                                panic::parameter_incorrect_format(
+                                                            _ => return Err(
+                                                                error::parameter_incorrect_format(
+                                                                    meta,
                                                                     meta_name.as_str(),
                                                                     &correct_usage_for_bound,
-                                                                )
-                                                            }
+                                                                ),
+                                                            ),
                                                         }
                                                     }
                                                     _ => {
-                                                        panic::parameter_incorrect_format(
-                                                            meta_name.as_str(),
-                                                            &correct_usage_for_bound,
+                                                        return Err(
+                                                            error::parameter_incorrect_format(
+                                                                meta,
+                                                                meta_name.as_str(),
+                                                                &correct_usage_for_bound,
+                                                            ),
                                                         )
                                                     }
                                                 }
@@ -392,38 +460,52 @@ impl TypeAttributeBuilder {
                                             match lit {
                                                 Lit::Str(s) => {
                                                     if bound_is_set {
-                                                        panic::reset_parameter(meta_name.as_str());
+                                                        return Err(error::reset_parameter(
+                                                            meta,
+                                                            meta_name.as_str(),
+                                                        ));
                                                     }
 
                                                     bound_is_set = true;
 
-                                                    let where_predicates =
-                                                        create_where_predicates_from_lit_str(s);
-
-                                                    bound = match where_predicates {
-                                                        Some(where_predicates) => {
-                                                            TypeAttributeBound::Custom(
-                                                                where_predicates,
-                                                            )
-                                                        }
-                                                        None => {
-                                                            panic::empty_parameter(
-                                                                meta_name.as_str(),
-                                                            )
+                                                    bound = if s.value().trim() == "*" {
+                                                        TypeAttributeBound::Infer
+                                                    } else {
+                                                        let where_predicates =
+                                                            create_where_predicates_from_lit_str(
+                                                                s,
+                                                            )?;
+
+                                                        match where_predicates {
+                                                            Some(where_predicates) => {
+                                                                TypeAttributeBound::Custom(
+                                                                    where_predicates,
+                                                                )
+                                                            }
+                                                            None => {
+                                                                return Err(error::empty_parameter(
+                                                                    meta,
+                                                                    meta_name.as_str(),
+                                                                ))
+                                                            }
                                                         }
                                                     };
                                                 }
                                                 _ => {
-                                                    panic::parameter_incorrect_format(
+                                                    return Err(error::parameter_incorrect_format(
+                                                        meta,
                                                         meta_name.as_str(),
                                                         &correct_usage_for_bound,
-                                                    )
+                                                    ))
                                                 }
                                             }
                                         }
                                         Meta::Path(_) => {
                                             if bound_is_set {
-                                                panic::reset_parameter(meta_name.as_str());
+                                                return Err(error::reset_parameter(
+                                                    meta,
+                                                    meta_name.as_str(),
+                                                ));
                                             }
 
                                             bound_is_set = true;
@@ -432,26 +514,33 @@ impl TypeAttributeBuilder {
                                         }
                                     }
                                 }
-                                _ => panic::unknown_parameter("Debug", meta_name.as_str()),
+                                _ => {
+                                    return Err(error::unknown_parameter(
+                                        meta,
+                                        "Debug",
+                                        meta_name.as_str(),
+                                    ))
+                                }
                             }
                         }
                         NestedMeta::Lit(lit) => {
                             match lit {
                                 Lit::Str(s) => {
                                     if !self.enable_name {
-                                        panic::attribute_incorrect_format(
+                                        return Err(error::attribute_incorrect_format(
+                                            meta,
                                             "Debug",
                                             &correct_usage_for_debug_attribute,
-                                        )
+                                        ));
                                     }
 
                                     if name_is_set {
-                                        panic::reset_parameter("name");
+                                        return Err(error::reset_parameter(meta, "name"));
                                     }
 
                                     name_is_set = true;
 
-                                    let s = create_path_string_from_lit_str(s);
+                                    let s = create_path_string_from_lit_str(s)?;
 
                                     name = match s {
                                         Some(s) => TypeAttributeName::Custom(s),
@@ -459,10 +548,11 @@ impl TypeAttributeBuilder {
                                     };
                                 }
                                 _ => {
-                                    panic::attribute_incorrect_format(
+                                    return Err(error::attribute_incorrect_format(
+                                        meta,
                                         "Debug",
                                         &correct_usage_for_debug_attribute,
-                                    )
+                                    ))
                                 }
                             }
                         }
@@ -475,13 +565,14 @@ impl TypeAttributeBuilder {
                 match lit {
                     Lit::Str(s) => {
                         if !self.enable_name {
-                            panic::attribute_incorrect_format(
+                            return Err(error::attribute_incorrect_format(
+                                meta,
                                 "Debug",
                                 &correct_usage_for_debug_attribute,
-                            )
+                            ));
                         }
 
-                        let s = create_path_string_from_lit_str(s);
+                        let s = create_path_string_from_lit_str(s)?;
 
                         name = match s {
                             Some(s) => TypeAttributeName::Custom(s),
@@ -489,75 +580,82 @@ impl TypeAttributeBuilder {
                         };
                     }
                     _ => {
-                        panic::attribute_incorrect_format(
+                        return Err(error::attribute_incorrect_format(
+                            meta,
                             "Debug",
                             &correct_usage_for_debug_attribute,
-                        )
+                        ))
                     }
                 }
             }
             Meta::Path(_) => {
                 if !self.enable_flag {
-                    panic::attribute_incorrect_format("Debug", &correct_usage_for_debug_attribute);
+                    return Err(error::attribute_incorrect_format(
+                        meta,
+                        "Debug",
+                        &correct_usage_for_debug_attribute,
+                    ));
                 }
 
                 flag = true;
             }
         }
 
-        TypeAttribute {
+        Ok(TypeAttribute {
             flag,
             name,
             named_field,
             bound,
-        }
+        })
     }
 
     #[allow(clippy::wrong_self_convention)]
-    pub fn from_attributes(self, attributes: &[Attribute], traits: &[Trait]) -> TypeAttribute {
+    pub fn from_attributes(
+        self,
+        attributes: &[Attribute],
+        traits: &[Trait],
+    ) -> Result<TypeAttribute, syn::Error> {
         let mut result = None;
 
         for attribute in attributes.iter() {
             if let Some(meta_name) = attribute.path.get_ident() {
                 if meta_name == "educe" {
-                    let meta = attribute.parse_meta().unwrap();
+                    let meta = parse_educe_meta(attribute)?;
 
                     match meta {
                         Meta::List(list) => {
                             for p in list.nested.iter() {
                                 match p {
                                     NestedMeta::Meta(meta) => {
-                                        let meta_name = meta.path().into_token_stream().to_string();
-
-                                        let t = Trait::from_str(meta_name);
+                                        let t = Trait::from_path(meta.path())?;
 
                                         if traits.binary_search(&t).is_err() {
-                                            panic::trait_not_used(t);
+                                            return Err(error::trait_not_used(meta, t));
                                         }
 
                                         if t == Trait::Debug {
                                             if result.is_some() {
-                                                panic::reuse_a_trait(t);
+                                                return Err(error::reuse_a_trait(meta, t));
                                             }
 
-                                            result = Some(self.from_debug_meta(&meta));
+                                            result = Some(self.from_debug_meta(&meta)?);
                                         }
                                     }
-                                    _ => panic::educe_format_incorrect(),
+                                    _ => return Err(error::educe_format_incorrect(attribute)),
                                 }
                             }
                         }
-                        _ => panic::educe_format_incorrect(),
+                        _ => return Err(error::educe_format_incorrect(attribute)),
                     }
                 }
             }
         }
 
-        result.unwrap_or(TypeAttribute {
+        Ok(result.unwrap_or(TypeAttribute {
             flag: false,
             name: self.name,
             named_field: self.named_field,
             bound: TypeAttributeBound::None,
-        })
+        }))
     }
 }
@@ -1,12 +1,8 @@
-use std::fmt::Write;
-use std::str::FromStr;
-
-use super::super::TraitHandler;
+use super::super::{create_field_member, TraitHandler};
 use super::models::{FieldAttributeBuilder, TypeAttributeBuilder};
 
-use crate::panic;
+use crate::error::{self, Errors};
 use crate::proc_macro2::TokenStream;
-use crate::quote::ToTokens;
 use crate::syn::{Data, DeriveInput, Fields, Generics, Lit, Meta};
 use crate::Trait;
 
@@ -18,34 +14,38 @@ impl TraitHandler for DefaultEnumHandler {
         tokens: &mut TokenStream,
         traits: &[Trait],
         meta: &Meta,
-    ) {
+    ) -> Result<(), syn::Error> {
+        let mut errors = Errors::new();
+
         let type_attribute = TypeAttributeBuilder {
             enable_flag: true,
             enable_new: true,
+            enable_const: true,
             enable_expression: true,
             enable_bound: true,
         }
-        .from_default_meta(meta);
-
-        let bound = type_attribute
-            .bound
-            .into_punctuated_where_predicates_by_generic_parameters(&ast.generics.params);
+        .from_default_meta(meta)?;
 
         let mut builder_tokens = TokenStream::new();
+        let mut field_types = Vec::new();
+        let mut extra_bound = Vec::new();
 
         if let Data::Enum(data) = &ast.data {
             match type_attribute.expression {
                 Some(expression) => {
                     for variant in data.variants.iter() {
-                        let _ = TypeAttributeBuilder {
-                            enable_flag: false,
-                            enable_new: false,
-                            enable_expression: false,
-                            enable_bound: false,
-                        }
-                        .from_attributes(&variant.attrs, traits);
+                        errors.handle(
+                            TypeAttributeBuilder {
+                                enable_flag: false,
+                                enable_new: false,
+                                enable_const: false,
+                                enable_expression: false,
+                                enable_bound: false,
+                            }
+                            .from_attributes(&variant.attrs, traits),
+                        );
 
-                        ensure_fields_no_attribute(&variant.fields, traits);
+                        ensure_fields_no_attribute(&variant.fields, traits, &mut errors);
                     }
 
                     builder_tokens.extend(quote!(#expression));
@@ -57,13 +57,16 @@ impl TraitHandler for DefaultEnumHandler {
                         if variants.len() == 1 {
                             let variant = &variants[0];
 
-                            let _ = TypeAttributeBuilder {
-                                enable_flag: true,
-                                enable_new: false,
-                                enable_expression: false,
-                                enable_bound: false,
-                            }
-                            .from_attributes(&variant.attrs, traits);
+                            errors.handle(
+                                TypeAttributeBuilder {
+                                    enable_flag: true,
+                                    enable_new: false,
+                                    enable_const: false,
+                                    enable_expression: false,
+                                    enable_bound: false,
+                                }
+                                .from_attributes(&variant.attrs, traits),
+                            );
 
                             variant
                         } else {
@@ -74,13 +77,19 @@ impl TraitHandler for DefaultEnumHandler {
 
                                 match variant {
                                     Some(variant) => {
-                                        let variant_attribute = TypeAttributeBuilder {
-                                            enable_flag: true,
-                                            enable_new: false,
-                                            enable_expression: false,
-                                            enable_bound: false,
-                                        }
-                                        .from_attributes(&variant.attrs, traits);
+                                        let variant_attribute = match errors.handle(
+                                            TypeAttributeBuilder {
+                                                enable_flag: true,
+                                                enable_new: false,
+                                                enable_const: false,
+                                                enable_expression: false,
+                                                enable_bound: false,
+                                            }
+                                            .from_attributes(&variant.attrs, traits),
+                                        ) {
+                                            Some(variant_attribute) => variant_attribute,
+                                            None => continue,
+                                        };
 
                                         if variant_attribute.flag {
                                             loop {
@@ -88,19 +97,37 @@ impl TraitHandler for DefaultEnumHandler {
 
                                                 match variant {
                                                     Some(variant) => {
-                                                        let variant_attribute = TypeAttributeBuilder {
-                                                            enable_flag: true,
-                                                            enable_new: false,
-                                                            enable_expression: false,
-                                                            enable_bound: false,
-                                                        }.from_attributes(&variant.attrs, traits);
+                                                        let variant_attribute =
+                                                            match errors.handle(
+                                                                TypeAttributeBuilder {
+                                                                    enable_flag: true,
+                                                                    enable_new: false,
+                                                                    enable_const: false,
+                                                                    enable_expression: false,
+                                                                    enable_bound: false,
+                                                                }
+                                                                .from_attributes(
+                                                                    &variant.attrs,
+                                                                    traits,
+                                                                ),
+                                                            ) {
+                                                                Some(variant_attribute) => {
+                                                                    variant_attribute
+                                                                }
+                                                                None => continue,
+                                                            };
 
                                                         if variant_attribute.flag {
-                                                            panic::multiple_default_variants();
+                                                            errors.push(
+                                                                error::multiple_default_variants(
+                                                                    variant,
+                                                                ),
+                                                            );
                                                         } else {
                                                             ensure_fields_no_attribute(
                                                                 &variant.fields,
                                                                 traits,
+                                                                &mut errors,
                                                             );
                                                         }
                                                     }
@@ -110,208 +137,193 @@ impl TraitHandler for DefaultEnumHandler {
 
                                             break variant;
                                         } else {
-                                            ensure_fields_no_attribute(&variant.fields, traits);
+                                            ensure_fields_no_attribute(
+                                                &variant.fields,
+                                                traits,
+                                                &mut errors,
+                                            );
                                         }
                                     }
-                                    None => panic::no_default_variant(),
+                                    None => {
+                                        errors.finish()?;
+
+                                        return Err(error::no_default_variant(meta));
+                                    }
                                 }
                             }
                         }
                     };
 
-                    let enum_name = ast.ident.to_string();
-                    let variant_name = variant.ident.to_string();
-
-                    let mut enum_tokens = format!(
-                        "{enum_name}::{variant_name}",
-                        enum_name = enum_name,
-                        variant_name = variant_name
-                    );
+                    let variant_ident = &variant.ident;
 
                     match &variant.fields {
-                        Fields::Unit => (), // TODO Unit
-                        Fields::Named(fields) => {
-                            // TODO Struct
-                            enum_tokens.push('{');
-
-                            for field in fields.named.iter() {
-                                let field_attribute = FieldAttributeBuilder {
-                                    enable_flag: false,
-                                    enable_literal: true,
-                                    enable_expression: true,
-                                }
-                                .from_attributes(&field.attrs, traits);
-
-                                let field_name = field.ident.as_ref().unwrap().to_string();
-
-                                enum_tokens
-                                    .write_fmt(format_args!(
-                                        "{field_name}: ",
-                                        field_name = field_name
-                                    ))
-                                    .unwrap();
-
-                                match field_attribute.literal {
-                                    Some(value) => {
-                                        match &value {
-                                            Lit::Str(s) => {
-                                                enum_tokens
-                                                    .write_fmt(format_args!(
-                                                        "core::convert::Into::into({s})",
-                                                        s = s.into_token_stream().to_string()
-                                                    ))
-                                                    .unwrap();
-                                            }
-                                            _ => {
-                                                enum_tokens.push_str(
-                                                    &value.into_token_stream().to_string(),
-                                                );
-                                            }
-                                        }
+                        Fields::Unit => {
+                            builder_tokens.extend(quote!(Self::#variant_ident));
+                        }
+                        Fields::Named(_) | Fields::Unnamed(_) => {
+                            let mut fields_tokens = TokenStream::new();
+
+                            for (index, field) in variant.fields.iter().enumerate() {
+                                let field_attribute = match errors.handle(
+                                    FieldAttributeBuilder {
+                                        enable_flag: false,
+                                        enable_literal: true,
+                                        enable_expression: true,
+                                        enable_bound: true,
                                     }
-                                    None => {
-                                        match field_attribute.expression {
-                                            Some(expression) => {
-                                                enum_tokens.push_str(&expression);
-                                            }
-                                            None => {
-                                                let typ = field
-                                                    .ty
-                                                    .clone()
-                                                    .into_token_stream()
-                                                    .to_string();
+                                    .from_attributes(&field.attrs, traits),
+                                ) {
+                                    Some(field_attribute) => field_attribute,
+                                    None => continue,
+                                };
 
-                                                enum_tokens.write_fmt(format_args!("<{typ} as core::default::Default>::default()", typ = typ)).unwrap();
-                                            }
-                                        }
-                                    }
-                                }
+                                extra_bound.extend(field_attribute.bound.iter().cloned());
 
-                                enum_tokens.push(',');
-                            }
+                                let field_member = create_field_member(index, field);
 
-                            enum_tokens.push('}');
-                        }
-                        Fields::Unnamed(fields) => {
-                            // TODO Tuple
-                            enum_tokens.push('(');
-
-                            for field in fields.unnamed.iter() {
-                                let field_attribute = FieldAttributeBuilder {
-                                    enable_flag: false,
-                                    enable_literal: true,
-                                    enable_expression: true,
-                                }
-                                .from_attributes(&field.attrs, traits);
-
-                                match field_attribute.literal {
-                                    Some(value) => {
-                                        match &value {
-                                            Lit::Str(s) => {
-                                                enum_tokens
-                                                    .write_fmt(format_args!(
-                                                        "core::convert::Into::into({s})",
-                                                        s = s.into_token_stream().to_string()
-                                                    ))
-                                                    .unwrap();
-                                            }
-                                            _ => {
-                                                enum_tokens.push_str(
-                                                    &value.into_token_stream().to_string(),
-                                                );
-                                            }
-                                        }
+                                let value = match field_attribute.literal {
+                                    Some(Lit::Str(s)) if !type_attribute.constant => {
+                                        quote!(::core::convert::Into::into(#s))
                                     }
+                                    Some(value) => quote!(#value),
                                     None => {
                                         match field_attribute.expression {
-                                            Some(expression) => {
-                                                enum_tokens.push_str(&expression);
-                                            }
+                                            Some(expression) => quote!(#expression),
                                             None => {
-                                                let typ = field
-                                                    .ty
-                                                    .clone()
-                                                    .into_token_stream()
-                                                    .to_string();
+                                                let ty = &field.ty;
 
-                                                enum_tokens.write_fmt(format_args!("<{typ} as core::default::Default>::default()", typ = typ)).unwrap();
+                                                field_types.push(ty);
+
+                                                quote!(<#ty as ::core::default::Default>::default())
                                             }
                                         }
                                     }
-                                }
+                                };
 
-                                enum_tokens.push(',');
+                                fields_tokens.extend(quote!(#field_member: #value,));
                             }
 
-                            enum_tokens.push(')');
+                            builder_tokens.extend(quote!(Self::#variant_ident { #fields_tokens }));
                         }
                     }
-
-                    builder_tokens.extend(TokenStream::from_str(&enum_tokens).unwrap());
                 }
             }
         }
 
+        let bound = type_attribute.bound.into_punctuated_where_predicates_by_generic_parameters(
+            &ast.ident,
+            &ast.generics.params,
+            &field_types,
+        );
+
         let ident = &ast.ident;
 
         let mut generics_cloned: Generics = ast.generics.clone();
 
         let where_clause = generics_cloned.make_where_clause();
 
-        for where_predicate in bound {
+        for where_predicate in bound.into_iter().chain(extra_bound) {
             where_clause.predicates.push(where_predicate);
         }
 
         let (impl_generics, ty_generics, where_clause) = generics_cloned.split_for_impl();
 
-        let default_impl = quote! {
-            impl #impl_generics core::default::Default for #ident #ty_generics #where_clause {
-                #[inline]
-                fn default() -> Self {
-                    #builder_tokens
+        errors.finish()?;
+
+        if type_attribute.constant {
+            let default_impl = quote! {
+                impl #impl_generics #ident #ty_generics #where_clause {
+                    /// The "default value" for a type.
+                    pub const DEFAULT: Self = #builder_tokens;
                 }
-            }
-        };
 
-        tokens.extend(default_impl);
+                impl #impl_generics ::core::default::Default for #ident #ty_generics #where_clause {
+                    #[inline]
+                    fn default() -> Self {
+                        Self::DEFAULT
+                    }
+                }
+            };
 
-        if type_attribute.new {
-            let new_impl = quote! {
-                impl #impl_generics #ident #ty_generics #where_clause {
-                    /// Returns the "default value" for a type.
+            tokens.extend(default_impl);
+
+            if type_attribute.new {
+                let new_impl = quote! {
+                    impl #impl_generics #ident #ty_generics #where_clause {
+                        /// Returns the "default value" for a type.
+                        #[inline]
+                        pub const fn new() -> Self {
+                            Self::DEFAULT
+                        }
+                    }
+                };
+
+                tokens.extend(new_impl);
+            }
+        } else {
+            let default_impl = quote! {
+                impl #impl_generics ::core::default::Default for #ident #ty_generics #where_clause {
                     #[inline]
-                    pub fn new() -> Self {
-                        <Self as core::default::Default>::default()
+                    fn default() -> Self {
+                        #builder_tokens
                     }
                 }
             };
 
-            tokens.extend(new_impl);
+            tokens.extend(default_impl);
+
+            if type_attribute.new {
+                let new_impl = quote! {
+                    impl #impl_generics #ident #ty_generics #where_clause {
+                        /// Returns the "default value" for a type.
+                        #[inline]
+                        pub fn new() -> Self {
+                            <Self as ::core::default::Default>::default()
+                        }
+                    }
+                };
+
+                tokens.extend(new_impl);
+            }
         }
+
+        Ok(())
     }
 }
 
-fn ensure_fields_no_attribute(fields: &Fields, traits: &[Trait]) {
+fn ensure_fields_no_attribute(fields: &Fields, traits: &[Trait], errors: &mut Errors) {
+    #[cfg(feature = "New")]
+    let enable_value = traits.binary_search(&Trait::New).is_ok();
+
+    #[cfg(not(feature = "New"))]
+    let enable_value = false;
+
     match fields {
         Fields::Unit => (),
         Fields::Named(fields) => {
             for field in fields.named.iter() {
-                let _ = FieldAttributeBuilder {
-                    enable_flag: false,
-                    enable_literal: false,
-                    enable_expression: false,
-                }
-                .from_attributes(&field.attrs, traits);
+                errors.handle(
+                    FieldAttributeBuilder {
+                        enable_flag: false,
+                        enable_literal: enable_value,
+                        enable_expression: enable_value,
+                        enable_bound: false,
+                    }
+                    .from_attributes(&field.attrs, traits),
+                );
             }
         }
         Fields::Unnamed(fields) => {
             for field in fields.unnamed.iter() {
-                let _ = FieldAttributeBuilder {
-                    enable_flag: false,
-                    enable_literal: false,
-                    enable_expression: false,
-                }
-                .from_attributes(&field.attrs, traits);
+                errors.handle(
+                    FieldAttributeBuilder {
+                        enable_flag: false,
+                        enable_literal: enable_value,
+                        enable_expression: enable_value,
+                        enable_bound: false,
+                    }
+                    .from_attributes(&field.attrs, traits),
+                );
             }
         }
     }
@@ -1,12 +1,8 @@
-use std::fmt::Write;
-use std::str::FromStr;
-
 use super::super::TraitHandler;
 use super::models::{FieldAttributeBuilder, TypeAttributeBuilder};
 
-use crate::panic;
+use crate::error::{self, Errors};
 use crate::proc_macro2::TokenStream;
-use crate::quote::ToTokens;
 use crate::syn::{Data, DeriveInput, Generics, Lit, Meta};
 use crate::Trait;
 
@@ -18,39 +14,41 @@ impl TraitHandler for DefaultUnionHandler {
         tokens: &mut TokenStream,
         traits: &[Trait],
         meta: &Meta,
-    ) {
+    ) -> Result<(), syn::Error> {
+        let mut errors = Errors::new();
+
         let type_attribute = TypeAttributeBuilder {
             enable_flag: true,
             enable_new: true,
+            enable_const: true,
             enable_expression: true,
             enable_bound: true,
         }
-        .from_default_meta(meta);
-
-        let bound = type_attribute
-            .bound
-            .into_punctuated_where_predicates_by_generic_parameters(&ast.generics.params);
+        .from_default_meta(meta)?;
 
         let mut builder_tokens = TokenStream::new();
+        let mut field_types = Vec::new();
+        let mut extra_bound = Vec::new();
 
         if let Data::Union(data) = &ast.data {
             match type_attribute.expression {
                 Some(expression) => {
                     for field in data.fields.named.iter() {
-                        let _ = FieldAttributeBuilder {
-                            enable_flag: false,
-                            enable_literal: false,
-                            enable_expression: false,
-                        }
-                        .from_attributes(&field.attrs, traits);
+                        errors.handle(
+                            FieldAttributeBuilder {
+                                enable_flag: false,
+                                enable_literal: false,
+                                enable_expression: false,
+                                enable_bound: false,
+                            }
+                            .from_attributes(&field.attrs, traits),
+                        );
                     }
 
                     builder_tokens.extend(quote!(#expression));
                 }
                 None => {
-                    let ident = ast.ident.to_string();
-
-                    let (field_name, field_attribute, typ) = {
+                    let (field_name, field_attribute, ty) = {
                         let fields = &data.fields.named;
 
                         if fields.len() == 1 {
@@ -60,16 +58,13 @@ impl TraitHandler for DefaultUnionHandler {
                                 enable_flag: true,
                                 enable_literal: true,
                                 enable_expression: true,
+                                enable_bound: true,
                             }
-                            .from_attributes(&field.attrs, traits);
+                            .from_attributes(&field.attrs, traits)?;
 
-                            let field_name = field.ident.as_ref().unwrap().to_string();
+                            let field_name = field.ident.as_ref().unwrap();
 
-                            (
-                                field_name,
-                                field_attribute,
-                                field.ty.clone().into_token_stream().to_string(),
-                            )
+                            (field_name, field_attribute, &field.ty)
                         } else {
                             let mut fields_iter = fields.iter();
 
@@ -78,138 +73,176 @@ impl TraitHandler for DefaultUnionHandler {
 
                                 match field {
                                     Some(field) => {
-                                        let field_attribute = FieldAttributeBuilder {
-                                            enable_flag: true,
-                                            enable_literal: true,
-                                            enable_expression: true,
-                                        }
-                                        .from_attributes(&field.attrs, traits);
+                                        let field_attribute = match errors.handle(
+                                            FieldAttributeBuilder {
+                                                enable_flag: true,
+                                                enable_literal: true,
+                                                enable_expression: true,
+                                                enable_bound: true,
+                                            }
+                                            .from_attributes(&field.attrs, traits),
+                                        ) {
+                                            Some(field_attribute) => field_attribute,
+                                            None => continue,
+                                        };
 
                                         if field_attribute.flag
                                             || field_attribute.literal.is_some()
                                             || field_attribute.expression.is_some()
                                         {
-                                            let field_name =
-                                                field.ident.as_ref().unwrap().to_string();
+                                            let field_name = field.ident.as_ref().unwrap();
 
                                             loop {
                                                 let field = fields_iter.next();
 
                                                 match field {
                                                     Some(field) => {
-                                                        let field_attribute =
+                                                        let field_attribute = match errors.handle(
                                                             FieldAttributeBuilder {
                                                                 enable_flag: true,
                                                                 enable_literal: true,
                                                                 enable_expression: true,
+                                                                enable_bound: true,
                                                             }
-                                                            .from_attributes(&field.attrs, traits);
+                                                            .from_attributes(&field.attrs, traits),
+                                                        ) {
+                                                            Some(field_attribute) => {
+                                                                field_attribute
+                                                            }
+                                                            None => continue,
+                                                        };
 
                                                         if field_attribute.flag
                                                             || field_attribute.literal.is_some()
                                                             || field_attribute.expression.is_some()
                                                         {
-                                                            panic::multiple_default_fields();
+                                                            errors.push(
+                                                                error::multiple_default_fields(
+                                                                    field,
+                                                                ),
+                                                            );
                                                         }
                                                     }
                                                     None => break,
                                                 }
                                             }
 
-                                            break (
-                                                field_name,
-                                                field_attribute,
-                                                field.ty.clone().into_token_stream().to_string(),
-                                            );
+                                            break (field_name, field_attribute, &field.ty);
                                         }
                                     }
-                                    None => panic::no_default_field(),
+                                    None => {
+                                        errors.finish()?;
+
+                                        return Err(error::no_default_field(meta));
+                                    }
                                 }
                             }
                         }
                     };
 
-                    let mut union_tokens = format!(
-                        "{ident} {{ {field_name}: ",
-                        ident = ident,
-                        field_name = field_name
-                    );
-
-                    match field_attribute.literal {
-                        Some(value) => {
-                            match &value {
-                                Lit::Str(s) => {
-                                    union_tokens
-                                        .write_fmt(format_args!(
-                                            "core::convert::Into::into({s})",
-                                            s = s.into_token_stream().to_string()
-                                        ))
-                                        .unwrap();
-                                }
-                                _ => {
-                                    union_tokens.push_str(&value.into_token_stream().to_string());
-                                }
-                            }
+                    extra_bound.extend(field_attribute.bound.iter().cloned());
+
+                    let value = match field_attribute.literal {
+                        Some(Lit::Str(s)) if !type_attribute.constant => {
+                            quote!(::core::convert::Into::into(#s))
                         }
+                        Some(value) => quote!(#value),
                         None => {
                             match field_attribute.expression {
-                                Some(expression) => {
-                                    union_tokens.push_str(&expression);
-                                }
+                                Some(expression) => quote!(#expression),
                                 None => {
-                                    union_tokens
-                                        .write_fmt(format_args!(
-                                            "<{typ} as core::default::Default>::default()",
-                                            typ = typ
-                                        ))
-                                        .unwrap();
+                                    field_types.push(ty);
+
+                                    quote!(<#ty as ::core::default::Default>::default())
                                 }
                             }
                         }
-                    }
+                    };
 
-                    union_tokens.push('}');
+                    let ident = &ast.ident;
 
-                    builder_tokens.extend(TokenStream::from_str(&union_tokens).unwrap());
+                    builder_tokens.extend(quote!(#ident { #field_name: #value }));
                 }
             }
         }
 
+        let bound = type_attribute.bound.into_punctuated_where_predicates_by_generic_parameters(
+            &ast.ident,
+            &ast.generics.params,
+            &field_types,
+        );
+
         let ident = &ast.ident;
 
         let mut generics_cloned: Generics = ast.generics.clone();
 
         let where_clause = generics_cloned.make_where_clause();
 
-        for where_predicate in bound {
+        for where_predicate in bound.into_iter().chain(extra_bound) {
             where_clause.predicates.push(where_predicate);
         }
 
         let (impl_generics, ty_generics, where_clause) = generics_cloned.split_for_impl();
 
-        let default_impl = quote! {
-            impl #impl_generics core::default::Default for #ident #ty_generics #where_clause {
-                #[inline]
-                fn default() -> Self {
-                    #builder_tokens
+        errors.finish()?;
+
+        if type_attribute.constant {
+            let default_impl = quote! {
+                impl #impl_generics #ident #ty_generics #where_clause {
+                    /// The "default value" for a type.
+                    pub const DEFAULT: Self = #builder_tokens;
                 }
-            }
-        };
 
-        tokens.extend(default_impl);
+                impl #impl_generics ::core::default::Default for #ident #ty_generics #where_clause {
+                    #[inline]
+                    fn default() -> Self {
+                        Self::DEFAULT
+                    }
+                }
+            };
+
+            tokens.extend(default_impl);
 
-        if type_attribute.new {
-            let new_impl = quote! {
-                impl #impl_generics #ident #ty_generics #where_clause {
-                    /// Returns the "default value" for a type.
+            if type_attribute.new {
+                let new_impl = quote! {
+                    impl #impl_generics #ident #ty_generics #where_clause {
+                        /// Returns the "default value" for a type.
+                        #[inline]
+                        pub const fn new() -> Self {
+                            Self::DEFAULT
+                        }
+                    }
+                };
+
+                tokens.extend(new_impl);
+            }
+        } else {
+            let default_impl = quote! {
+                impl #impl_generics ::core::default::Default for #ident #ty_generics #where_clause {
                     #[inline]
-                    pub fn new() -> Self {
-                        <Self as core::default::Default>::default()
+                    fn default() -> Self {
+                        #builder_tokens
                     }
                 }
             };
 
-            tokens.extend(new_impl);
+            tokens.extend(default_impl);
+
+            if type_attribute.new {
+                let new_impl = quote! {
+                    impl #impl_generics #ident #ty_generics #where_clause {
+                        /// Returns the "default value" for a type.
+                        #[inline]
+                        pub fn new() -> Self {
+                            <Self as ::core::default::Default>::default()
+                        }
+                    }
+                };
+
+                tokens.extend(new_impl);
+            }
         }
+
+        Ok(())
     }
 }
@@ -7,12 +7,13 @@ mod default_union;
 use super::TraitHandler;
 
 use crate::proc_macro2::TokenStream;
-use crate::syn::{Data, DeriveInput, Meta};
+use crate::syn::{Data, DeriveInput, Field, Lit, Meta};
 use crate::Trait;
 
 use default_enum::DefaultEnumHandler;
 use default_struct::DefaultStructHandler;
 use default_union::DefaultUnionHandler;
+use models::FieldAttributeBuilder;
 
 pub struct DefaultHandler;
 
@@ -22,17 +23,43 @@ impl TraitHandler for DefaultHandler {
         tokens: &mut TokenStream,
         traits: &[Trait],
         meta: &Meta,
-    ) {
+    ) -> Result<(), syn::Error> {
         match ast.data {
             Data::Struct(_) => {
-                DefaultStructHandler::trait_meta_handler(ast, tokens, traits, meta);
+                DefaultStructHandler::trait_meta_handler(ast, tokens, traits, meta)?;
             }
             Data::Enum(_) => {
-                DefaultEnumHandler::trait_meta_handler(ast, tokens, traits, meta);
+                DefaultEnumHandler::trait_meta_handler(ast, tokens, traits, meta)?;
             }
             Data::Union(_) => {
-                DefaultUnionHandler::trait_meta_handler(ast, tokens, traits, meta);
+                DefaultUnionHandler::trait_meta_handler(ast, tokens, traits, meta)?;
             }
         }
+
+        Ok(())
+    }
+}
+
+/// Creates the default value of a field for another trait, like `New`. A string literal is kept as
+/// it is in a `const fn`, where `Into::into` cannot be called.
+pub fn create_field_default_tokens(
+    field: &Field,
+    traits: &[Trait],
+    constant: bool,
+) -> Option<TokenStream> {
+    // An invalid attribute is reported by the `Default` handler itself.
+    let field_attribute = FieldAttributeBuilder {
+        enable_flag: false,
+        enable_literal: true,
+        enable_expression: true,
+        enable_bound: true,
+    }
+    .from_attributes(&field.attrs, traits)
+    .ok()?;
+
+    match field_attribute.literal {
+        Some(Lit::Str(s)) if !constant => Some(quote!(::core::convert::Into::into(#s))),
+        Some(value) => Some(quote!(#value)),
+        None => field_attribute.expression.map(|expression| quote!(#expression)),
     }
 }
@@ -1,15 +1,20 @@
-use super::super::super::create_expr_string_from_lit_str;
+use super::super::super::{
+    create_expr_from_lit_str, create_where_predicates_from_lit_str, parse_educe_meta,
+};
 
-use crate::panic;
+use crate::error;
 use crate::quote::ToTokens;
-use crate::syn::{Attribute, Lit, Meta, NestedMeta};
+use crate::syn::{
+    punctuated::Punctuated, token::Comma, Attribute, Expr, Lit, Meta, NestedMeta, WherePredicate,
+};
 use crate::Trait;
 
 #[derive(Clone)]
 pub struct FieldAttribute {
     pub flag: bool,
     pub literal: Option<Lit>,
-    pub expression: Option<String>,
+    pub expression: Option<Expr>,
+    pub bound: Punctuated<WherePredicate, Comma>,
 }
 
 #[derive(Debug, Clone)]
@@ -17,13 +22,15 @@ pub struct FieldAttributeBuilder {
     pub enable_flag: bool,
     pub enable_literal: bool,
     pub enable_expression: bool,
+    pub enable_bound: bool,
 }
 
 impl FieldAttributeBuilder {
-    pub fn from_default_meta(&self, meta: &Meta) -> FieldAttribute {
+    pub fn from_default_meta(&self, meta: &Meta) -> Result<FieldAttribute, syn::Error> {
+        let mut bound = Punctuated::new();
         let mut flag = false;
         let mut value: Option<Lit> = None;
-        let mut expression: Option<String> = None;
+        let mut expression: Option<Expr> = None;
 
         let correct_usage_for_default_attribute = {
             let mut usage = vec![];
@@ -44,6 +51,17 @@ impl FieldAttributeBuilder {
             let usage = vec![
                 stringify!(#[educe(Default(expression = "expression"))]),
                 stringify!(#[educe(Default(expression("expression")))]),
+                stringify!(#[educe(Default(expression(expression)))]),
+            ];
+
+            usage
+        };
+
+        let correct_usage_for_bound = {
+            let usage = vec![
+                stringify!(#[educe(Default(bound = "where_predicates"))]),
+                stringify!(#[educe(Default(bound("where_predicates")))]),
+                stringify!(#[educe(Default(bound(where_predicates)))]),
             ];
 
             usage
@@ -59,7 +77,11 @@ impl FieldAttributeBuilder {
                             match meta_name.as_str() {
                                 "expression" | "expr" => {
                                     if !self.enable_expression {
-                                        panic::unknown_parameter("Default", meta_name.as_str());
+                                        return Err(error::unknown_parameter(
+                                            meta,
+                                            "Default",
+                                            meta_name.as_str(),
+                                        ));
                                     }
 
                                     match meta {
@@ -70,36 +92,44 @@ impl FieldAttributeBuilder {
                                                         match lit {
                                                             Lit::Str(s) => {
                                                                 if expression.is_some() {
-                                                                    panic::reset_parameter(
-                                                                        meta_name.as_str(),
+                                                                    return Err(
+                                                                        error::reset_parameter(
+                                                                            meta,
+                                                                            meta_name.as_str(),
+                                                                        ),
                                                                     );
                                                                 }
 
                                                                 let s =
-                                                                    create_expr_string_from_lit_str(
-                                                                        s,
-                                                                    );
+                                                                    create_expr_from_lit_str(s)?;
 
                                                                 if s.is_some() {
                                                                     expression = s;
                                                                 } else {
-                                                                    panic::empty_parameter(
-                                                                        meta_name.as_str(),
-                                                                    )
+                                                                    return Err(
+                                                                        error::empty_parameter(
+                                                                            meta,
+                                                                            meta_name.as_str(),
+                                                                        ),
+                                                                    );
                                                                 }
                                                             }
-                                                            _ => {
-                                                                panic::parameter_incorrect_format(
+                                                            _ => return Err(
+                                                                error::parameter_incorrect_format(
+                                                                    meta,
                                                                     meta_name.as_str(),
                                                                     &correct_usage_for_expression,
-                                                                )
-                                                            }
+                                                                ),
+                                                            ),
                                                         }
                                                     }
                                                     _ => {
-                                                        panic::parameter_incorrect_format(
-                                                            meta_name.as_str(),
-                                                            &correct_usage_for_expression,
+                                                        return Err(
+                                                            error::parameter_incorrect_format(
+                                                                meta,
+                                                                meta_name.as_str(),
+                                                                &correct_usage_for_expression,
+                                                            ),
                                                         )
                                                     }
                                                 }
@@ -111,46 +141,149 @@ impl FieldAttributeBuilder {
                                             match lit {
                                                 Lit::Str(s) => {
                                                     if expression.is_some() {
-                                                        panic::reset_parameter(meta_name.as_str());
+                                                        return Err(error::reset_parameter(
+                                                            meta,
+                                                            meta_name.as_str(),
+                                                        ));
                                                     }
 
-                                                    let s = create_expr_string_from_lit_str(s);
+                                                    let s = create_expr_from_lit_str(s)?;
 
                                                     if s.is_some() {
                                                         expression = s;
                                                     } else {
-                                                        panic::empty_parameter(meta_name.as_str())
+                                                        return Err(error::empty_parameter(
+                                                            meta,
+                                                            meta_name.as_str(),
+                                                        ));
                                                     }
                                                 }
                                                 _ => {
-                                                    panic::parameter_incorrect_format(
+                                                    return Err(error::parameter_incorrect_format(
+                                                        meta,
                                                         meta_name.as_str(),
                                                         &correct_usage_for_expression,
-                                                    )
+                                                    ))
                                                 }
                                             }
                                         }
                                         _ => {
-                                            panic::parameter_incorrect_format(
+                                            return Err(error::parameter_incorrect_format(
+                                                meta,
                                                 meta_name.as_str(),
                                                 &correct_usage_for_expression,
-                                            )
+                                            ))
+                                        }
+                                    }
+                                }
+                                "bound" => {
+                                    if !self.enable_bound {
+                                        return Err(error::unknown_parameter(
+                                            meta,
+                                            "Default",
+                                            meta_name.as_str(),
+                                        ));
+                                    }
+
+                                    match meta {
+                                        Meta::List(list) => {
+                                            for p in list.nested.iter() {
+                                                match p {
+                                                    NestedMeta::Lit(Lit::Str(s)) => {
+                                                        if !bound.is_empty() {
+                                                            return Err(error::reset_parameter(
+                                                                meta,
+                                                                meta_name.as_str(),
+                                                            ));
+                                                        }
+
+                                                        bound = match create_where_predicates_from_lit_str(s)? {
+                                                            Some(where_predicates) => where_predicates,
+                                                            None => {
+                                                                return Err(error::empty_parameter(
+                                                                    meta,
+                                                                    meta_name.as_str(),
+                                                                ))
+                                                            }
+                                                        };
+                                                    }
+                                                    _ => {
+                                                        return Err(
+                                                            error::parameter_incorrect_format(
+                                                                meta,
+                                                                meta_name.as_str(),
+                                                                &correct_usage_for_bound,
+                                                            ),
+                                                        )
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        Meta::NameValue(named_value) => {
+                                            let lit = &named_value.lit;
+
+                                            match lit {
+                                                Lit::Str(s) => {
+                                                    if !bound.is_empty() {
+                                                        return Err(error::reset_parameter(
+                                                            meta,
+                                                            meta_name.as_str(),
+                                                        ));
+                                                    }
+
+                                                    bound =
+                                                        match create_where_predicates_from_lit_str(
+                                                            s,
+                                                        )? {
+                                                            Some(where_predicates) => {
+                                                                where_predicates
+                                                            }
+                                                            None => {
+                                                                return Err(error::empty_parameter(
+                                                                    meta,
+                                                                    meta_name.as_str(),
+                                                                ))
+                                                            }
+                                                        };
+                                                }
+                                                _ => {
+                                                    return Err(error::parameter_incorrect_format(
+                                                        meta,
+                                                        meta_name.as_str(),
+                                                        &correct_usage_for_bound,
+                                                    ))
+                                                }
+                                            }
+                                        }
+                                        _ => {
+                                            return Err(error::parameter_incorrect_format(
+                                                meta,
+                                                meta_name.as_str(),
+                                                &correct_usage_for_bound,
+                                            ))
                                         }
                                     }
                                 }
-                                _ => panic::unknown_parameter("Default", meta_name.as_str()),
+                                _ => {
+                                    return Err(error::unknown_parameter(
+                                        meta,
+                                        "Default",
+                                        meta_name.as_str(),
+                                    ))
+                                }
                             }
                         }
                         NestedMeta::Lit(lit) => {
                             if !self.enable_literal {
-                                panic::attribute_incorrect_format(
+                                return Err(error::attribute_incorrect_format(
+                                    meta,
                                     "Default",
                                     &correct_usage_for_default_attribute,
-                                )
+                                ));
                             }
 
                             if value.is_some() {
-                                panic::reset_parameter("value");
+                                return Err(error::reset_parameter(meta, "value"));
                             }
 
                             value = Some(lit.clone());
@@ -160,10 +293,11 @@ impl FieldAttributeBuilder {
             }
             Meta::NameValue(named_value) => {
                 if !self.enable_literal {
-                    panic::attribute_incorrect_format(
+                    return Err(error::attribute_incorrect_format(
+                        meta,
                         "Default",
                         &correct_usage_for_default_attribute,
-                    )
+                    ));
                 }
 
                 let lit = &named_value.lit;
@@ -172,10 +306,11 @@ impl FieldAttributeBuilder {
             }
             Meta::Path(_) => {
                 if !self.enable_flag {
-                    panic::attribute_incorrect_format(
+                    return Err(error::attribute_incorrect_format(
+                        meta,
                         "Default",
                         &correct_usage_for_default_attribute,
-                    );
+                    ));
                 }
 
                 flag = true;
@@ -183,22 +318,27 @@ impl FieldAttributeBuilder {
         }
 
         if value.is_some() && expression.is_some() {
-            panic::set_value_expression();
+            return Err(error::set_value_expression(meta));
         }
 
-        FieldAttribute {
+        Ok(FieldAttribute {
             flag,
             literal: value,
             expression,
-        }
+            bound,
+        })
     }
 
     #[allow(clippy::wrong_self_convention)]
-    pub fn from_attributes(self, attributes: &[Attribute], traits: &[Trait]) -> FieldAttribute {
+    pub fn from_attributes(
+        self,
+        attributes: &[Attribute],
+        traits: &[Trait],
+    ) -> Result<FieldAttribute, syn::Error> {
         let mut result = None;
 
         for attribute in attributes.iter() {
-            let meta = attribute.parse_meta().unwrap();
+            let meta = parse_educe_meta(attribute)?;
 
             let meta_name = meta.path().into_token_stream().to_string();
 
@@ -208,35 +348,34 @@ impl FieldAttributeBuilder {
                         for p in list.nested.iter() {
                             match p {
                                 NestedMeta::Meta(meta) => {
-                                    let meta_name = meta.path().into_token_stream().to_string();
-
-                                    let t = Trait::from_str(meta_name);
+                                    let t = Trait::from_path(meta.path())?;
 
                                     if traits.binary_search(&t).is_err() {
-                                        panic::trait_not_used(t);
+                                        return Err(error::trait_not_used(meta, t));
                                     }
 
                                     if t == Trait::Default {
                                         if result.is_some() {
-                                            panic::reuse_a_trait(t);
+                                            return Err(error::reuse_a_trait(meta, t));
                                         }
 
-                                        result = Some(self.from_default_meta(&meta));
+                                        result = Some(self.from_default_meta(&meta)?);
                                     }
                                 }
-                                _ => panic::educe_format_incorrect(),
+                                _ => return Err(error::educe_format_incorrect(attribute)),
                             }
                         }
                     }
-                    _ => panic::educe_format_incorrect(),
+                    _ => return Err(error::educe_format_incorrect(attribute)),
                 }
             }
         }
 
-        result.unwrap_or(FieldAttribute {
+        Ok(result.unwrap_or(FieldAttribute {
             flag: false,
             literal: None,
             expression: None,
-        })
+            bound: Punctuated::new(),
+        }))
     }
 }
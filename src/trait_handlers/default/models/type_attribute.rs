@@ -1,13 +1,14 @@
 use super::super::super::{
-    create_expr_from_lit_str, create_where_predicates_from_generic_parameters,
-    create_where_predicates_from_lit_str,
+    create_expr_from_lit_str, create_where_predicates_from_field_types,
+    create_where_predicates_from_generic_parameters, create_where_predicates_from_lit_str,
+    parse_educe_meta,
 };
 
-use crate::panic;
+use crate::error;
 use crate::quote::ToTokens;
 use crate::syn::{
-    punctuated::Punctuated, token::Comma, Attribute, Expr, GenericParam, Lit, Meta, NestedMeta,
-    WherePredicate,
+    punctuated::Punctuated, token::Comma, Attribute, Expr, GenericParam, Ident, Lit, Meta,
+    NestedMeta, Type, WherePredicate,
 };
 use crate::Trait;
 
@@ -15,20 +16,31 @@ use crate::Trait;
 pub enum TypeAttributeBound {
     None,
     Auto,
+    Infer,
     Custom(Punctuated<WherePredicate, Comma>),
 }
 
 impl TypeAttributeBound {
     pub fn into_punctuated_where_predicates_by_generic_parameters(
         self,
+        ident: &Ident,
         params: &Punctuated<GenericParam, Comma>,
+        field_types: &[&Type],
     ) -> Punctuated<WherePredicate, Comma> {
         match self {
             TypeAttributeBound::None => Punctuated::new(),
             TypeAttributeBound::Auto => {
                 create_where_predicates_from_generic_parameters(
                     params,
-                    &syn::parse2(quote!(core::default::Default)).unwrap(),
+                    &syn::parse2(quote!(::core::default::Default)).unwrap(),
+                )
+            }
+            TypeAttributeBound::Infer => {
+                create_where_predicates_from_field_types(
+                    ident,
+                    params,
+                    field_types,
+                    &syn::parse2(quote!(::core::default::Default)).unwrap(),
                 )
             }
             TypeAttributeBound::Custom(where_predicates) => where_predicates,
@@ -40,6 +52,7 @@ impl TypeAttributeBound {
 pub struct TypeAttribute {
     pub flag: bool,
     pub new: bool,
+    pub constant: bool,
     pub expression: Option<Expr>,
     pub bound: TypeAttributeBound,
 }
@@ -48,15 +61,17 @@ pub struct TypeAttribute {
 pub struct TypeAttributeBuilder {
     pub enable_flag: bool,
     pub enable_new: bool,
+    pub enable_const: bool,
     pub enable_expression: bool,
     pub enable_bound: bool,
 }
 
 impl TypeAttributeBuilder {
     #[allow(clippy::cognitive_complexity)]
-    pub fn from_default_meta(&self, meta: &Meta) -> TypeAttribute {
+    pub fn from_default_meta(&self, meta: &Meta) -> Result<TypeAttribute, syn::Error> {
         let mut flag = false;
         let mut new = false;
+        let mut constant = false;
         let mut expression: Option<Expr> = None;
         let mut bound = TypeAttributeBound::None;
 
@@ -71,6 +86,10 @@ impl TypeAttributeBuilder {
                 usage.push(stringify!(#[educe(Default(new))]));
             }
 
+            if self.enable_const {
+                usage.push(stringify!(#[educe(Default(const))]));
+            }
+
             usage
         };
 
@@ -80,10 +99,17 @@ impl TypeAttributeBuilder {
             usage
         };
 
+        let correct_usage_for_const = {
+            let usage = vec![stringify!(#[educe(Default(const))])];
+
+            usage
+        };
+
         let correct_usage_for_expression = {
             let usage = vec![
                 stringify!(#[educe(Default(expression = "expression"))]),
                 stringify!(#[educe(Default(expression("expression")))]),
+                stringify!(#[educe(Default(expression(expression)))]),
             ];
 
             usage
@@ -92,8 +118,10 @@ impl TypeAttributeBuilder {
         let correct_usage_for_bound = {
             let usage = vec![
                 stringify!(#[educe(Default(bound))]),
+                stringify!(#[educe(Default(bound(*)))]),
                 stringify!(#[educe(Default(bound = "where_predicates"))]),
                 stringify!(#[educe(Default(bound("where_predicates")))]),
+                stringify!(#[educe(Default(bound(where_predicates)))]),
             ];
 
             usage
@@ -102,6 +130,7 @@ impl TypeAttributeBuilder {
         match meta {
             Meta::List(list) => {
                 let mut new_is_set = false;
+                let mut constant_is_set = false;
                 let mut bound_is_set = false;
 
                 for p in list.nested.iter() {
@@ -112,7 +141,11 @@ impl TypeAttributeBuilder {
                             match meta_name.as_str() {
                                 "expression" | "expr" => {
                                     if !self.enable_expression {
-                                        panic::unknown_parameter("Default", meta_name.as_str());
+                                        return Err(error::unknown_parameter(
+                                            meta,
+                                            "Default",
+                                            meta_name.as_str(),
+                                        ));
                                     }
 
                                     match meta {
@@ -123,33 +156,44 @@ impl TypeAttributeBuilder {
                                                         match lit {
                                                             Lit::Str(s) => {
                                                                 if expression.is_some() {
-                                                                    panic::reset_parameter(
-                                                                        meta_name.as_str(),
+                                                                    return Err(
+                                                                        error::reset_parameter(
+                                                                            meta,
+                                                                            meta_name.as_str(),
+                                                                        ),
                                                                     );
                                                                 }
 
-                                                                let s = create_expr_from_lit_str(s);
+                                                                let s =
+                                                                    create_expr_from_lit_str(s)?;
 
                                                                 if s.is_some() {
                                                                     expression = s;
                                                                 } else {
-                                                                    panic::empty_parameter(
-                                                                        meta_name.as_str(),
-                                                                    )
+                                                                    return Err(
+                                                                        error::empty_parameter(
+                                                                            meta,
+                                                                            meta_name.as_str(),
+                                                                        ),
+                                                                    );
                                                                 }
                                                             }
-                                                            _ => {
-                                                                panic::parameter_incorrect_format(
+                                                            _ => return Err(
+                                                                error::parameter_incorrect_format(
+                                                                    meta,
                                                                     meta_name.as_str(),
                                                                     &correct_usage_for_expression,
-                                                                )
-                                                            }
+                                                                ),
+                                                            ),
                                                         }
                                                     }
                                                     _ => {
-                                                        panic::parameter_incorrect_format(
-                                                            meta_name.as_str(),
-                                                            &correct_usage_for_expression,
+                                                        return Err(
+                                                            error::parameter_incorrect_format(
+                                                                meta,
+                                                                meta_name.as_str(),
+                                                                &correct_usage_for_expression,
+                                                            ),
                                                         )
                                                     }
                                                 }
@@ -161,36 +205,48 @@ impl TypeAttributeBuilder {
                                             match lit {
                                                 Lit::Str(s) => {
                                                     if expression.is_some() {
-                                                        panic::reset_parameter(meta_name.as_str());
+                                                        return Err(error::reset_parameter(
+                                                            meta,
+                                                            meta_name.as_str(),
+                                                        ));
                                                     }
 
-                                                    let s = create_expr_from_lit_str(s);
+                                                    let s = create_expr_from_lit_str(s)?;
 
                                                     if s.is_some() {
                                                         expression = s;
                                                     } else {
-                                                        panic::empty_parameter(meta_name.as_str())
+                                                        return Err(error::empty_parameter(
+                                                            meta,
+                                                            meta_name.as_str(),
+                                                        ));
                                                     }
                                                 }
                                                 _ => {
-                                                    panic::parameter_incorrect_format(
+                                                    return Err(error::parameter_incorrect_format(
+                                                        meta,
                                                         meta_name.as_str(),
                                                         &correct_usage_for_expression,
-                                                    )
+                                                    ))
                                                 }
                                             }
                                         }
                                         _ => {
-                                            panic::parameter_incorrect_format(
+                                            return Err(error::parameter_incorrect_format(
+                                                meta,
                                                 meta_name.as_str(),
                                                 &correct_usage_for_expression,
-                                            )
+                                            ))
                                         }
                                     }
                                 }
                                 "bound" => {
                                     if !self.enable_bound {
-                                        panic::unknown_parameter("Default", meta_name.as_str());
+                                        return Err(error::unknown_parameter(
+                                            meta,
+                                            "Default",
+                                            meta_name.as_str(),
+                                        ));
                                     }
 
                                     match meta {
@@ -201,40 +257,54 @@ impl TypeAttributeBuilder {
                                                         match lit {
                                                             Lit::Str(s) => {
                                                                 if bound_is_set {
-                                                                    panic::reset_parameter(
-                                                                        meta_name.as_str(),
+                                                                    return Err(
+                                                                        error::reset_parameter(
+                                                                            meta,
+                                                                            meta_name.as_str(),
+                                                                        ),
                                                                     );
                                                                 }
 
                                                                 bound_is_set = true;
 
-                                                                let where_predicates = create_where_predicates_from_lit_str(s);
+                                                                bound = if s.value().trim() == "*" {
+                                                                    TypeAttributeBound::Infer
+                                                                } else {
+                                                                    let where_predicates = create_where_predicates_from_lit_str(s)?;
 
-                                                                bound = match where_predicates {
+                                                                    match where_predicates {
                                                                     Some(where_predicates) => {
                                                                         TypeAttributeBound::Custom(
                                                                             where_predicates,
                                                                         )
                                                                     }
                                                                     None => {
-                                                                        panic::empty_parameter(
-                                                                            meta_name.as_str(),
+                                                                        return Err(
+                                                                            error::empty_parameter(
+                                                                                meta,
+                                                                                meta_name.as_str(),
+                                                                            ),
                                                                         )
                                                                     }
+                                                                }
                                                                 };
                                                             }
-                                                            _ => {
-                                                                panic::parameter_incorrect_format(
+                                                            _ => return Err(
+                                                                error::parameter_incorrect_format(
+                                                                    meta,
                                                                     meta_name.as_str(),
                                                                     &correct_usage_for_bound,
-                                                                )
-                                                            }
+                                                                ),
+                                                            ),
                                                         }
                                                     }
                                                     _ => {
-                                                        panic::parameter_incorrect_format(
-                                                            meta_name.as_str(),
-                                                            &correct_usage_for_bound,
+                                                        return Err(
+                                                            error::parameter_incorrect_format(
+                                                                meta,
+                                                                meta_name.as_str(),
+                                                                &correct_usage_for_bound,
+                                                            ),
                                                         )
                                                     }
                                                 }
@@ -246,38 +316,52 @@ impl TypeAttributeBuilder {
                                             match lit {
                                                 Lit::Str(s) => {
                                                     if bound_is_set {
-                                                        panic::reset_parameter(meta_name.as_str());
+                                                        return Err(error::reset_parameter(
+                                                            meta,
+                                                            meta_name.as_str(),
+                                                        ));
                                                     }
 
                                                     bound_is_set = true;
 
-                                                    let where_predicates =
-                                                        create_where_predicates_from_lit_str(s);
-
-                                                    bound = match where_predicates {
-                                                        Some(where_predicates) => {
-                                                            TypeAttributeBound::Custom(
-                                                                where_predicates,
-                                                            )
-                                                        }
-                                                        None => {
-                                                            panic::empty_parameter(
-                                                                meta_name.as_str(),
-                                                            )
+                                                    bound = if s.value().trim() == "*" {
+                                                        TypeAttributeBound::Infer
+                                                    } else {
+                                                        let where_predicates =
+                                                            create_where_predicates_from_lit_str(
+                                                                s,
+                                                            )?;
+
+                                                        match where_predicates {
+                                                            Some(where_predicates) => {
+                                                                TypeAttributeBound::Custom(
+                                                                    where_predicates,
+                                                                )
+                                                            }
+                                                            None => {
+                                                                return Err(error::empty_parameter(
+                                                                    meta,
+                                                                    meta_name.as_str(),
+                                                                ))
+                                                            }
                                                         }
                                                     };
                                                 }
                                                 _ => {
-                                                    panic::parameter_incorrect_format(
+                                                    return Err(error::parameter_incorrect_format(
+                                                        meta,
                                                         meta_name.as_str(),
                                                         &correct_usage_for_bound,
-                                                    )
+                                                    ))
                                                 }
                                             }
                                         }
                                         Meta::Path(_) => {
                                             if bound_is_set {
-                                                panic::reset_parameter(meta_name.as_str());
+                                                return Err(error::reset_parameter(
+                                                    meta,
+                                                    meta_name.as_str(),
+                                                ));
                                             }
 
                                             bound_is_set = true;
@@ -288,13 +372,20 @@ impl TypeAttributeBuilder {
                                 }
                                 "new" => {
                                     if !self.enable_new {
-                                        panic::unknown_parameter("Default", meta_name.as_str());
+                                        return Err(error::unknown_parameter(
+                                            meta,
+                                            "Default",
+                                            meta_name.as_str(),
+                                        ));
                                     }
 
                                     match meta {
                                         Meta::Path(_) => {
                                             if new_is_set {
-                                                panic::reset_parameter(meta_name.as_str());
+                                                return Err(error::reset_parameter(
+                                                    meta,
+                                                    meta_name.as_str(),
+                                                ));
                                             }
 
                                             new_is_set = true;
@@ -302,34 +393,78 @@ impl TypeAttributeBuilder {
                                             new = true;
                                         }
                                         _ => {
-                                            panic::parameter_incorrect_format(
+                                            return Err(error::parameter_incorrect_format(
+                                                meta,
                                                 meta_name.as_str(),
                                                 &correct_usage_for_new,
-                                            )
+                                            ))
                                         }
                                     }
                                 }
-                                _ => panic::unknown_parameter("Default", meta_name.as_str()),
+                                "const" => {
+                                    if !self.enable_const {
+                                        return Err(error::unknown_parameter(
+                                            meta,
+                                            "Default",
+                                            meta_name.as_str(),
+                                        ));
+                                    }
+
+                                    match meta {
+                                        Meta::Path(_) => {
+                                            if constant_is_set {
+                                                return Err(error::reset_parameter(
+                                                    meta,
+                                                    meta_name.as_str(),
+                                                ));
+                                            }
+
+                                            constant_is_set = true;
+
+                                            constant = true;
+                                        }
+                                        _ => {
+                                            return Err(error::parameter_incorrect_format(
+                                                meta,
+                                                meta_name.as_str(),
+                                                &correct_usage_for_const,
+                                            ))
+                                        }
+                                    }
+                                }
+                                _ => {
+                                    return Err(error::unknown_parameter(
+                                        meta,
+                                        "Default",
+                                        meta_name.as_str(),
+                                    ))
+                                }
                             }
                         }
                         _ => {
-                            panic::attribute_incorrect_format(
+                            return Err(error::attribute_incorrect_format(
+                                meta,
                                 "Default",
                                 &correct_usage_for_default_attribute,
-                            )
+                            ))
                         }
                     }
                 }
             }
             Meta::NameValue(_) => {
-                panic::attribute_incorrect_format("Default", &correct_usage_for_default_attribute)
+                return Err(error::attribute_incorrect_format(
+                    meta,
+                    "Default",
+                    &correct_usage_for_default_attribute,
+                ))
             }
             Meta::Path(_) => {
                 if !self.enable_flag {
-                    panic::attribute_incorrect_format(
+                    return Err(error::attribute_incorrect_format(
+                        meta,
                         "Default",
                         &correct_usage_for_default_attribute,
-                    );
+                    ));
                 }
 
                 flag = true;
@@ -339,63 +474,67 @@ impl TypeAttributeBuilder {
         if expression.is_some() {
             if let TypeAttributeBound::None = &bound {
             } else {
-                panic::set_expression_bound();
+                return Err(error::set_expression_bound(meta));
             }
         }
 
-        TypeAttribute {
+        Ok(TypeAttribute {
             flag,
             new,
+            constant,
             expression,
             bound,
-        }
+        })
     }
 
     #[allow(clippy::wrong_self_convention)]
-    pub fn from_attributes(self, attributes: &[Attribute], traits: &[Trait]) -> TypeAttribute {
+    pub fn from_attributes(
+        self,
+        attributes: &[Attribute],
+        traits: &[Trait],
+    ) -> Result<TypeAttribute, syn::Error> {
         let mut result = None;
 
         for attribute in attributes.iter() {
             if let Some(meta_name) = attribute.path.get_ident() {
                 if meta_name == "educe" {
-                    let meta = attribute.parse_meta().unwrap();
+                    let meta = parse_educe_meta(attribute)?;
 
                     match meta {
                         Meta::List(list) => {
                             for p in list.nested.iter() {
                                 match p {
                                     NestedMeta::Meta(meta) => {
-                                        let meta_name = meta.path().into_token_stream().to_string();
-
-                                        let t = Trait::from_str(meta_name);
+                                        let t = Trait::from_path(meta.path())?;
 
                                         if traits.binary_search(&t).is_err() {
-                                            panic::trait_not_used(t);
+                                            return Err(error::trait_not_used(meta, t));
                                         }
 
                                         if t == Trait::Default {
                                             if result.is_some() {
-                                                panic::reuse_a_trait(t);
+                                                return Err(error::reuse_a_trait(meta, t));
                                             }
 
-                                            result = Some(self.from_default_meta(&meta));
+                                            result = Some(self.from_default_meta(&meta)?);
                                         }
                                     }
-                                    _ => panic::educe_format_incorrect(),
+                                    _ => return Err(error::educe_format_incorrect(attribute)),
                                 }
                             }
                         }
-                        _ => panic::educe_format_incorrect(),
+                        _ => return Err(error::educe_format_incorrect(attribute)),
                     }
                 }
             }
         }
 
-        result.unwrap_or(TypeAttribute {
+        Ok(result.unwrap_or(TypeAttribute {
             flag: false,
             new: false,
+            constant: false,
             expression: None,
             bound: TypeAttributeBound::None,
-        })
+        }))
     }
 }
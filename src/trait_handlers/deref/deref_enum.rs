@@ -1,10 +1,7 @@
-use std::fmt::Write;
-use std::str::FromStr;
-
-use super::super::TraitHandler;
+use super::super::{create_field_binding, create_field_member, TraitHandler};
 use super::models::{FieldAttributeBuilder, TypeAttributeBuilder};
 
-use crate::panic;
+use crate::error::{self, Errors};
 use crate::proc_macro2::TokenStream;
 use crate::quote::ToTokens;
 use crate::syn::{Data, DeriveInput, Fields, Meta};
@@ -18,203 +15,122 @@ impl TraitHandler for DerefEnumHandler {
         tokens: &mut TokenStream,
         traits: &[Trait],
         meta: &Meta,
-    ) {
+    ) -> Result<(), syn::Error> {
+        let mut errors = Errors::new();
+
         let _ = TypeAttributeBuilder {
             enable_flag: true,
         }
-        .from_deref_meta(meta);
+        .from_deref_meta(meta)?;
 
-        let enum_name = ast.ident.to_string();
+        let ident = &ast.ident;
 
         let mut ty_all = TokenStream::new();
         let mut deref_tokens = TokenStream::new();
 
-        let mut match_tokens = String::from("match self {");
+        let mut arms = TokenStream::new();
 
         if let Data::Enum(data) = &ast.data {
             for variant in data.variants.iter() {
-                let _ = TypeAttributeBuilder {
-                    enable_flag: false,
+                errors.handle(
+                    TypeAttributeBuilder {
+                        enable_flag: false,
+                    }
+                    .from_attributes(&variant.attrs, traits),
+                );
+
+                let variant_ident = &variant.ident;
+
+                if let Fields::Unit = &variant.fields {
+                    // TODO Unit
+                    errors.push(error::deref_cannot_support_unit_variant(variant));
+
+                    continue;
                 }
-                .from_attributes(&variant.attrs, traits);
 
-                let variant_ident = variant.ident.to_string();
+                let mut pattern_tokens = TokenStream::new();
+                let mut block_tokens = TokenStream::new();
 
-                match &variant.fields {
-                    Fields::Unit => {
-                        // TODO Unit
-                        panic::deref_cannot_support_unit_variant();
-                    }
-                    Fields::Named(fields) => {
-                        // TODO Struct
-                        let mut pattern_tokens = String::new();
-                        let mut block_tokens = String::new();
-
-                        let mut ty = TokenStream::new();
-
-                        let mut counter = 0;
-
-                        for field in fields.named.iter() {
-                            let field_attribute = FieldAttributeBuilder {
-                                enable_flag: true,
-                            }
-                            .from_attributes(&field.attrs, traits);
-
-                            if field_attribute.flag {
-                                if !ty.is_empty() {
-                                    panic::multiple_deref_fields_of_variant(&variant_ident);
-                                }
-
-                                let field_name = field.ident.as_ref().unwrap().to_string();
-
-                                ty.extend(field.ty.clone().into_token_stream());
-
-                                if ty_all.is_empty() {
-                                    ty_all.extend(field.ty.clone().into_token_stream());
-                                }
-
-                                block_tokens
-                                    .write_fmt(format_args!(
-                                        "return {field_name};",
-                                        field_name = field_name
-                                    ))
-                                    .unwrap();
-                                pattern_tokens
-                                    .write_fmt(format_args!(
-                                        "{field_name}, ..",
-                                        field_name = field_name
-                                    ))
-                                    .unwrap();
-                            }
-
-                            counter += 1;
+                let mut ty = TokenStream::new();
+
+                let mut counter = 0;
+
+                for (index, field) in variant.fields.iter().enumerate() {
+                    let field_attribute = match errors.handle(
+                        FieldAttributeBuilder {
+                            enable_flag: true,
+                        }
+                        .from_attributes(&field.attrs, traits),
+                    ) {
+                        Some(field_attribute) => field_attribute,
+                        None => continue,
+                    };
+
+                    if field_attribute.flag {
+                        if !ty.is_empty() {
+                            errors.push(error::multiple_deref_fields_of_variant(
+                                field,
+                                &variant_ident.to_string(),
+                            ));
                         }
 
-                        if ty.is_empty() {
-                            if counter == 1 {
-                                let field = fields.named.iter().next().unwrap();
-
-                                let field_name = field.ident.as_ref().unwrap().to_string();
-
-                                ty.extend(field.ty.clone().into_token_stream());
-
-                                if ty_all.is_empty() {
-                                    ty_all.extend(field.ty.clone().into_token_stream());
-                                }
-
-                                block_tokens
-                                    .write_fmt(format_args!(
-                                        "return {field_name};",
-                                        field_name = field_name
-                                    ))
-                                    .unwrap();
-                                pattern_tokens
-                                    .write_fmt(format_args!(
-                                        "{field_name}, ..",
-                                        field_name = field_name
-                                    ))
-                                    .unwrap();
-                            } else {
-                                panic::no_deref_field_of_variant(&variant_ident);
-                            }
+                        let field_member = create_field_member(index, field);
+                        let field_binding = create_field_binding(index);
+
+                        ty.extend(field.ty.clone().into_token_stream());
+
+                        if ty_all.is_empty() {
+                            ty_all.extend(field.ty.clone().into_token_stream());
                         }
 
-                        match_tokens.write_fmt(format_args!("{enum_name}::{variant_ident} {{ {pattern_tokens} }} => {{ {block_tokens} }}", enum_name = enum_name, variant_ident = variant_ident, pattern_tokens = pattern_tokens, block_tokens = block_tokens)).unwrap();
+                        block_tokens.extend(quote!(return #field_binding;));
+                        pattern_tokens.extend(quote!(#field_member: #field_binding,));
                     }
-                    Fields::Unnamed(fields) => {
-                        // TODO Tuple
-                        let mut pattern_tokens = String::new();
-                        let mut block_tokens = String::new();
-
-                        let mut ty = TokenStream::new();
-
-                        let mut counter = 0;
-
-                        for (index, field) in fields.unnamed.iter().enumerate() {
-                            let field_attribute = FieldAttributeBuilder {
-                                enable_flag: true,
-                            }
-                            .from_attributes(&field.attrs, traits);
-
-                            if field_attribute.flag {
-                                if !ty.is_empty() {
-                                    panic::multiple_deref_fields_of_variant(&variant_ident);
-                                }
-
-                                let field_name = format!("{}", index);
-
-                                ty.extend(field.ty.clone().into_token_stream());
-
-                                if ty_all.is_empty() {
-                                    ty_all.extend(field.ty.clone().into_token_stream());
-                                }
-
-                                block_tokens
-                                    .write_fmt(format_args!(
-                                        "return _{field_name};",
-                                        field_name = field_name
-                                    ))
-                                    .unwrap();
-                                pattern_tokens
-                                    .write_fmt(format_args!(
-                                        "_{field_name},",
-                                        field_name = field_name
-                                    ))
-                                    .unwrap();
-                            } else {
-                                pattern_tokens.push_str("_,");
-                            }
-
-                            counter += 1;
-                        }
 
-                        if ty.is_empty() {
-                            if counter == 1 {
-                                let field = fields.unnamed.iter().next().unwrap();
-
-                                let field_name = String::from("0");
-
-                                ty.extend(field.ty.clone().into_token_stream());
-
-                                if ty_all.is_empty() {
-                                    ty_all.extend(field.ty.clone().into_token_stream());
-                                }
-
-                                block_tokens
-                                    .write_fmt(format_args!(
-                                        "return _{field_name};",
-                                        field_name = field_name
-                                    ))
-                                    .unwrap();
-
-                                pattern_tokens.clear();
-                                pattern_tokens
-                                    .write_fmt(format_args!(
-                                        "_{field_name}",
-                                        field_name = field_name
-                                    ))
-                                    .unwrap();
-                            } else {
-                                panic::no_deref_field_of_variant(&variant_ident);
-                            }
+                    counter += 1;
+                }
+
+                if ty.is_empty() {
+                    if counter == 1 {
+                        let field = variant.fields.iter().next().unwrap();
+
+                        let field_member = create_field_member(0, field);
+                        let field_binding = create_field_binding(0);
+
+                        ty.extend(field.ty.clone().into_token_stream());
+
+                        if ty_all.is_empty() {
+                            ty_all.extend(field.ty.clone().into_token_stream());
                         }
 
-                        match_tokens.write_fmt(format_args!("{enum_name}::{variant_ident}( {pattern_tokens} ) => {{ {block_tokens} }}", enum_name = enum_name, variant_ident = variant_ident, pattern_tokens = pattern_tokens, block_tokens = block_tokens)).unwrap();
+                        block_tokens.extend(quote!(return #field_binding;));
+                        pattern_tokens.extend(quote!(#field_member: #field_binding,));
+                    } else {
+                        errors.push(error::no_deref_field_of_variant(
+                            variant,
+                            &variant_ident.to_string(),
+                        ));
                     }
                 }
+
+                arms.extend(quote! {
+                    Self::#variant_ident { #pattern_tokens .. } => {
+                        #block_tokens
+                    }
+                });
             }
         }
 
-        match_tokens.push('}');
-
-        deref_tokens.extend(TokenStream::from_str(&match_tokens).unwrap());
-
-        let ident = &ast.ident;
+        deref_tokens.extend(quote! {
+            match self {
+                #arms
+            }
+        });
 
         let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
 
         let deref_impl = quote! {
-            impl #impl_generics core::ops::Deref for #ident #ty_generics #where_clause {
+            impl #impl_generics ::core::ops::Deref for #ident #ty_generics #where_clause {
                 type Target = #ty_all;
 
                 #[inline]
@@ -224,6 +140,10 @@ impl TraitHandler for DerefEnumHandler {
             }
         };
 
+        errors.finish()?;
+
         tokens.extend(deref_impl);
+
+        Ok(())
     }
 }
@@ -1,9 +1,7 @@
-use std::str::FromStr;
-
-use super::super::TraitHandler;
+use super::super::{create_field_member, is_packed, TraitHandler};
 use super::models::{FieldAttributeBuilder, TypeAttributeBuilder};
 
-use crate::panic;
+use crate::error::{self, Errors};
 use crate::proc_macro2::TokenStream;
 use crate::quote::ToTokens;
 use crate::syn::{Data, DeriveInput, Meta};
@@ -17,43 +15,46 @@ impl TraitHandler for DerefStructHandler {
         tokens: &mut TokenStream,
         traits: &[Trait],
         meta: &Meta,
-    ) {
+    ) -> Result<(), syn::Error> {
+        let mut errors = Errors::new();
+
         let _ = TypeAttributeBuilder {
             enable_flag: true,
         }
-        .from_deref_meta(meta);
+        .from_deref_meta(meta)?;
 
         let mut ty = TokenStream::new();
         let mut deref_tokens = TokenStream::new();
 
+        let packed = is_packed(ast);
+
         if let Data::Struct(data) = &ast.data {
             let mut counter = 0;
 
             for (index, field) in data.fields.iter().enumerate() {
-                let field_attribute = FieldAttributeBuilder {
-                    enable_flag: true,
-                }
-                .from_attributes(&field.attrs, traits);
+                let field_attribute = match errors.handle(
+                    FieldAttributeBuilder {
+                        enable_flag: true,
+                    }
+                    .from_attributes(&field.attrs, traits),
+                ) {
+                    Some(field_attribute) => field_attribute,
+                    None => continue,
+                };
 
                 if field_attribute.flag {
                     if !ty.is_empty() {
-                        panic::multiple_deref_fields();
+                        errors.push(error::multiple_deref_fields(field));
                     }
 
-                    let field_name = if let Some(ident) = field.ident.as_ref() {
-                        ident.to_string()
-                    } else {
-                        format!("{}", index)
-                    };
+                    if packed {
+                        errors.push(error::reference_to_packed_field(field, Trait::Deref));
+                    }
+
+                    let field_member = create_field_member(index, field);
 
                     ty.extend(field.ty.clone().into_token_stream());
-                    deref_tokens.extend(
-                        TokenStream::from_str(&format!(
-                            "&self.{field_name}",
-                            field_name = field_name
-                        ))
-                        .unwrap(),
-                    );
+                    deref_tokens.extend(quote!(&self.#field_member));
                 }
 
                 counter += 1;
@@ -63,22 +64,16 @@ impl TraitHandler for DerefStructHandler {
                 if counter == 1 {
                     let field = data.fields.iter().next().unwrap();
 
-                    let field_name = if let Some(ident) = field.ident.as_ref() {
-                        ident.to_string()
-                    } else {
-                        String::from("0")
-                    };
+                    if packed {
+                        errors.push(error::reference_to_packed_field(field, Trait::Deref));
+                    }
+
+                    let field_member = create_field_member(0, field);
 
                     ty.extend(field.ty.clone().into_token_stream());
-                    deref_tokens.extend(
-                        TokenStream::from_str(&format!(
-                            "&self.{field_name}",
-                            field_name = field_name
-                        ))
-                        .unwrap(),
-                    );
+                    deref_tokens.extend(quote!(&self.#field_member));
                 } else {
-                    panic::no_deref_field();
+                    errors.push(error::no_deref_field(meta));
                 }
             }
         }
@@ -88,7 +83,7 @@ impl TraitHandler for DerefStructHandler {
         let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
 
         let deref_impl = quote! {
-            impl #impl_generics core::ops::Deref for #ident #ty_generics #where_clause {
+            impl #impl_generics ::core::ops::Deref for #ident #ty_generics #where_clause {
                 type Target = #ty;
 
                 #[inline]
@@ -98,6 +93,10 @@ impl TraitHandler for DerefStructHandler {
             }
         };
 
+        errors.finish()?;
+
         tokens.extend(deref_impl);
+
+        Ok(())
     }
 }
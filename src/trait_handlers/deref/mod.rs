@@ -5,7 +5,7 @@ mod deref_struct;
 
 use super::TraitHandler;
 
-use crate::panic;
+use crate::error;
 use crate::proc_macro2::TokenStream;
 use crate::syn::{Data, DeriveInput, Meta};
 use crate::Trait;
@@ -21,15 +21,17 @@ impl TraitHandler for DerefHandler {
         tokens: &mut TokenStream,
         traits: &[Trait],
         meta: &Meta,
-    ) {
+    ) -> Result<(), syn::Error> {
         match ast.data {
             Data::Struct(_) => {
-                DerefStructHandler::trait_meta_handler(ast, tokens, traits, meta);
+                DerefStructHandler::trait_meta_handler(ast, tokens, traits, meta)?;
             }
             Data::Enum(_) => {
-                DerefEnumHandler::trait_meta_handler(ast, tokens, traits, meta);
+                DerefEnumHandler::trait_meta_handler(ast, tokens, traits, meta)?;
             }
-            Data::Union(_) => panic::trait_not_support_union(Trait::Deref),
+            Data::Union(_) => return Err(error::trait_not_support_union(meta, Trait::Deref)),
         }
+
+        Ok(())
     }
 }
@@ -1,4 +1,6 @@
-use crate::panic;
+use super::super::super::parse_educe_meta;
+
+use crate::error;
 use crate::quote::ToTokens;
 use crate::syn::{Attribute, Meta, NestedMeta};
 use crate::Trait;
@@ -14,7 +16,7 @@ pub struct FieldAttributeBuilder {
 }
 
 impl FieldAttributeBuilder {
-    pub fn from_deref_meta(&self, meta: &Meta) -> FieldAttribute {
+    pub fn from_deref_meta(&self, meta: &Meta) -> Result<FieldAttribute, syn::Error> {
         let flag;
 
         let correct_usage_for_deref_attribute = {
@@ -29,31 +31,47 @@ impl FieldAttributeBuilder {
 
         match meta {
             Meta::List(_) => {
-                panic::attribute_incorrect_format("Deref", &correct_usage_for_deref_attribute)
+                return Err(error::attribute_incorrect_format(
+                    meta,
+                    "Deref",
+                    &correct_usage_for_deref_attribute,
+                ))
             }
             Meta::NameValue(_) => {
-                panic::attribute_incorrect_format("Deref", &correct_usage_for_deref_attribute)
+                return Err(error::attribute_incorrect_format(
+                    meta,
+                    "Deref",
+                    &correct_usage_for_deref_attribute,
+                ))
             }
             Meta::Path(_) => {
                 if !self.enable_flag {
-                    panic::attribute_incorrect_format("Deref", &correct_usage_for_deref_attribute);
+                    return Err(error::attribute_incorrect_format(
+                        meta,
+                        "Deref",
+                        &correct_usage_for_deref_attribute,
+                    ));
                 }
 
                 flag = true;
             }
         }
 
-        FieldAttribute {
+        Ok(FieldAttribute {
             flag,
-        }
+        })
     }
 
     #[allow(clippy::wrong_self_convention)]
-    pub fn from_attributes(self, attributes: &[Attribute], traits: &[Trait]) -> FieldAttribute {
+    pub fn from_attributes(
+        self,
+        attributes: &[Attribute],
+        traits: &[Trait],
+    ) -> Result<FieldAttribute, syn::Error> {
         let mut result = None;
 
         for attribute in attributes.iter() {
-            let meta = attribute.parse_meta().unwrap();
+            let meta = parse_educe_meta(attribute)?;
 
             let meta_name = meta.path().into_token_stream().to_string();
 
@@ -63,33 +81,31 @@ impl FieldAttributeBuilder {
                         for p in list.nested.iter() {
                             match p {
                                 NestedMeta::Meta(meta) => {
-                                    let meta_name = meta.path().into_token_stream().to_string();
-
-                                    let t = Trait::from_str(meta_name);
+                                    let t = Trait::from_path(meta.path())?;
 
                                     if traits.binary_search(&t).is_err() {
-                                        panic::trait_not_used(t);
+                                        return Err(error::trait_not_used(meta, t));
                                     }
 
                                     if t == Trait::Deref {
                                         if result.is_some() {
-                                            panic::reuse_a_trait(t);
+                                            return Err(error::reuse_a_trait(meta, t));
                                         }
 
-                                        result = Some(self.from_deref_meta(&meta));
+                                        result = Some(self.from_deref_meta(&meta)?);
                                     }
                                 }
-                                _ => panic::educe_format_incorrect(),
+                                _ => return Err(error::educe_format_incorrect(attribute)),
                             }
                         }
                     }
-                    _ => panic::educe_format_incorrect(),
+                    _ => return Err(error::educe_format_incorrect(attribute)),
                 }
             }
         }
 
-        result.unwrap_or(FieldAttribute {
+        Ok(result.unwrap_or(FieldAttribute {
             flag: false,
-        })
+        }))
     }
 }
@@ -1,5 +1,6 @@
-use crate::panic;
-use crate::quote::ToTokens;
+use super::super::super::parse_educe_meta;
+
+use crate::error;
 use crate::syn::{Attribute, Meta, NestedMeta};
 use crate::Trait;
 
@@ -14,7 +15,7 @@ pub struct TypeAttributeBuilder {
 }
 
 impl TypeAttributeBuilder {
-    pub fn from_deref_meta(&self, meta: &Meta) -> TypeAttribute {
+    pub fn from_deref_meta(&self, meta: &Meta) -> Result<TypeAttribute, syn::Error> {
         let flag;
 
         let correct_usage_for_deref_attribute = {
@@ -29,67 +30,81 @@ impl TypeAttributeBuilder {
 
         match meta {
             Meta::List(_) => {
-                panic::attribute_incorrect_format("Deref", &correct_usage_for_deref_attribute)
+                return Err(error::attribute_incorrect_format(
+                    meta,
+                    "Deref",
+                    &correct_usage_for_deref_attribute,
+                ))
             }
             Meta::NameValue(_) => {
-                panic::attribute_incorrect_format("Deref", &correct_usage_for_deref_attribute)
+                return Err(error::attribute_incorrect_format(
+                    meta,
+                    "Deref",
+                    &correct_usage_for_deref_attribute,
+                ))
             }
             Meta::Path(_) => {
                 if !self.enable_flag {
-                    panic::attribute_incorrect_format("Deref", &correct_usage_for_deref_attribute);
+                    return Err(error::attribute_incorrect_format(
+                        meta,
+                        "Deref",
+                        &correct_usage_for_deref_attribute,
+                    ));
                 }
 
                 flag = true;
             }
         }
 
-        TypeAttribute {
+        Ok(TypeAttribute {
             flag,
-        }
+        })
     }
 
     #[allow(clippy::wrong_self_convention)]
-    pub fn from_attributes(self, attributes: &[Attribute], traits: &[Trait]) -> TypeAttribute {
+    pub fn from_attributes(
+        self,
+        attributes: &[Attribute],
+        traits: &[Trait],
+    ) -> Result<TypeAttribute, syn::Error> {
         let mut result = None;
 
         for attribute in attributes.iter() {
             if let Some(meta_name) = attribute.path.get_ident() {
                 if meta_name == "educe" {
-                    let meta = attribute.parse_meta().unwrap();
+                    let meta = parse_educe_meta(attribute)?;
 
                     match meta {
                         Meta::List(list) => {
                             for p in list.nested.iter() {
                                 match p {
                                     NestedMeta::Meta(meta) => {
-                                        let meta_name = meta.path().into_token_stream().to_string();
-
-                                        let t = Trait::from_str(meta_name);
+                                        let t = Trait::from_path(meta.path())?;
 
                                         if traits.binary_search(&t).is_err() {
-                                            panic::trait_not_used(t);
+                                            return Err(error::trait_not_used(meta, t));
                                         }
 
                                         if t == Trait::Deref {
                                             if result.is_some() {
-                                                panic::reuse_a_trait(t);
+                                                return Err(error::reuse_a_trait(meta, t));
                                             }
 
-                                            result = Some(self.from_deref_meta(&meta));
+                                            result = Some(self.from_deref_meta(&meta)?);
                                         }
                                     }
-                                    _ => panic::educe_format_incorrect(),
+                                    _ => return Err(error::educe_format_incorrect(attribute)),
                                 }
                             }
                         }
-                        _ => panic::educe_format_incorrect(),
+                        _ => return Err(error::educe_format_incorrect(attribute)),
                     }
                 }
             }
         }
 
-        result.unwrap_or(TypeAttribute {
+        Ok(result.unwrap_or(TypeAttribute {
             flag: false,
-        })
+        }))
     }
 }
@@ -1,9 +1,7 @@
-use std::str::FromStr;
-
-use super::super::TraitHandler;
+use super::super::{create_field_member, is_packed, TraitHandler};
 use super::models::{FieldAttributeBuilder, TypeAttributeBuilder};
 
-use crate::panic;
+use crate::error::{self, Errors};
 use crate::proc_macro2::TokenStream;
 use crate::syn::{Data, DeriveInput, Meta};
 use crate::Trait;
@@ -16,41 +14,44 @@ impl TraitHandler for DerefMutStructHandler {
         tokens: &mut TokenStream,
         traits: &[Trait],
         meta: &Meta,
-    ) {
+    ) -> Result<(), syn::Error> {
+        let mut errors = Errors::new();
+
         let _ = TypeAttributeBuilder {
             enable_flag: true,
         }
-        .from_deref_mut_meta(meta);
+        .from_deref_mut_meta(meta)?;
 
         let mut deref_mut_tokens = TokenStream::new();
 
+        let packed = is_packed(ast);
+
         if let Data::Struct(data) = &ast.data {
             let mut counter = 0;
 
             for (index, field) in data.fields.iter().enumerate() {
-                let field_attribute = FieldAttributeBuilder {
-                    enable_flag: true,
-                }
-                .from_attributes(&field.attrs, traits);
+                let field_attribute = match errors.handle(
+                    FieldAttributeBuilder {
+                        enable_flag: true,
+                    }
+                    .from_attributes(&field.attrs, traits),
+                ) {
+                    Some(field_attribute) => field_attribute,
+                    None => continue,
+                };
 
                 if field_attribute.flag {
                     if !deref_mut_tokens.is_empty() {
-                        panic::multiple_deref_mut_fields();
+                        errors.push(error::multiple_deref_mut_fields(field));
+                    }
+
+                    if packed {
+                        errors.push(error::reference_to_packed_field(field, Trait::DerefMut));
                     }
 
-                    let field_name = if let Some(ident) = field.ident.as_ref() {
-                        ident.to_string()
-                    } else {
-                        format!("{}", index)
-                    };
-
-                    deref_mut_tokens.extend(
-                        TokenStream::from_str(&format!(
-                            "&mut self.{field_name}",
-                            field_name = field_name
-                        ))
-                        .unwrap(),
-                    );
+                    let field_member = create_field_member(index, field);
+
+                    deref_mut_tokens.extend(quote!(&mut self.#field_member));
                 }
 
                 counter += 1;
@@ -60,21 +61,15 @@ impl TraitHandler for DerefMutStructHandler {
                 if counter == 1 {
                     let field = data.fields.iter().next().unwrap();
 
-                    let field_name = if let Some(ident) = field.ident.as_ref() {
-                        ident.to_string()
-                    } else {
-                        String::from("0")
-                    };
-
-                    deref_mut_tokens.extend(
-                        TokenStream::from_str(&format!(
-                            "&mut self.{field_name}",
-                            field_name = field_name
-                        ))
-                        .unwrap(),
-                    );
+                    if packed {
+                        errors.push(error::reference_to_packed_field(field, Trait::DerefMut));
+                    }
+
+                    let field_member = create_field_member(0, field);
+
+                    deref_mut_tokens.extend(quote!(&mut self.#field_member));
                 } else {
-                    panic::no_deref_mut_field();
+                    errors.push(error::no_deref_mut_field(meta));
                 }
             }
         }
@@ -84,7 +79,7 @@ impl TraitHandler for DerefMutStructHandler {
         let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
 
         let deref_mut_impl = quote! {
-            impl #impl_generics core::ops::DerefMut for #ident #ty_generics #where_clause {
+            impl #impl_generics ::core::ops::DerefMut for #ident #ty_generics #where_clause {
                 #[inline]
                 fn deref_mut(&mut self) -> &mut Self::Target {
                     #deref_mut_tokens
@@ -92,6 +87,10 @@ impl TraitHandler for DerefMutStructHandler {
             }
         };
 
+        errors.finish()?;
+
         tokens.extend(deref_mut_impl);
+
+        Ok(())
     }
 }
@@ -0,0 +1,208 @@
+mod models;
+
+use super::{create_unique_ident, create_variant_name_string, TraitHandler};
+
+use crate::error::{self, Errors};
+use crate::proc_macro2::TokenStream;
+use crate::syn::{Data, DeriveInput, Fields, Meta};
+use crate::Trait;
+
+use models::TypeAttributeBuilder;
+
+pub struct EnumMapHandler;
+
+impl TraitHandler for EnumMapHandler {
+    fn trait_meta_handler(
+        ast: &DeriveInput,
+        tokens: &mut TokenStream,
+        traits: &[Trait],
+        meta: &Meta,
+    ) -> Result<(), syn::Error> {
+        let mut errors = Errors::new();
+
+        let type_attribute = TypeAttributeBuilder {
+            enable_flag: true,
+            enable_name: true,
+            enable_vis: true,
+        }
+        .from_enum_map_meta(meta)?;
+
+        let ident = &ast.ident;
+
+        let map_ident = type_attribute.name.unwrap_or_else(|| format_ident!("{}Map", ident));
+
+        let vis = type_attribute.vis.as_ref().unwrap_or(&ast.vis);
+
+        let mut variant_idents = Vec::new();
+        let mut variant_names = Vec::new();
+
+        match &ast.data {
+            Data::Enum(data) => {
+                for variant in data.variants.iter() {
+                    if !matches!(variant.fields, Fields::Unit) {
+                        errors
+                            .push(error::trait_only_support_unit_variants(variant, Trait::EnumMap));
+
+                        continue;
+                    }
+
+                    variant_idents.push(&variant.ident);
+                    variant_names.push(create_variant_name_string(variant, traits));
+                }
+            }
+            _ => return Err(error::trait_only_support_enum(meta, Trait::EnumMap)),
+        }
+
+        errors.finish()?;
+
+        let variant_count = variant_idents.len();
+
+        let indexes: Vec<usize> = (0..variant_count).collect();
+
+        let value_type = create_unique_ident(ast, "V");
+        let function_type = create_unique_ident(ast, "F");
+
+        let enum_map_impl = quote! {
+            #[derive(::core::clone::Clone)]
+            #vis struct #map_ident<#value_type> {
+                values: [#value_type; #variant_count],
+            }
+
+            impl<#value_type> #map_ident<#value_type> {
+                #[inline]
+                pub const fn from_array(values: [#value_type; #variant_count]) -> Self {
+                    #map_ident {
+                        values,
+                    }
+                }
+
+                #[inline]
+                pub fn from_fn<#function_type: ::core::ops::FnMut(#ident) -> #value_type>(mut f: #function_type) -> Self {
+                    #map_ident {
+                        values: [#(f(#ident::#variant_idents)),*],
+                    }
+                }
+
+                #[inline]
+                pub fn into_array(self) -> [#value_type; #variant_count] {
+                    self.values
+                }
+
+                #[inline]
+                pub const fn len(&self) -> ::core::primitive::usize {
+                    #variant_count
+                }
+
+                #[inline]
+                pub const fn is_empty(&self) -> ::core::primitive::bool {
+                    #variant_count == 0
+                }
+
+                #[inline]
+                pub fn get(&self, key: #ident) -> &#value_type {
+                    &self.values[Self::key_index(key)]
+                }
+
+                #[inline]
+                pub fn get_mut(&mut self, key: #ident) -> &mut #value_type {
+                    &mut self.values[Self::key_index(key)]
+                }
+
+                #[inline]
+                pub fn insert(&mut self, key: #ident, value: #value_type) -> #value_type {
+                    ::core::mem::replace(self.get_mut(key), value)
+                }
+
+                #[inline]
+                pub fn iter(&self) -> impl ::core::iter::Iterator<Item = (#ident, &#value_type)> {
+                    self.values.iter().enumerate().map(|(index, value)| (Self::key(index), value))
+                }
+
+                #[inline]
+                pub fn iter_mut(&mut self) -> impl ::core::iter::Iterator<Item = (#ident, &mut #value_type)> {
+                    self.values.iter_mut().enumerate().map(|(index, value)| (Self::key(index), value))
+                }
+
+                #[inline]
+                pub fn values(&self) -> ::core::slice::Iter<#value_type> {
+                    self.values.iter()
+                }
+
+                #[inline]
+                pub fn values_mut(&mut self) -> ::core::slice::IterMut<#value_type> {
+                    self.values.iter_mut()
+                }
+
+                #[inline]
+                fn key_index(key: #ident) -> ::core::primitive::usize {
+                    match key {
+                        #(#ident::#variant_idents => #indexes,)*
+                    }
+                }
+
+                #[inline]
+                #[allow(unreachable_patterns)]
+                fn key(index: ::core::primitive::usize) -> #ident {
+                    match index {
+                        #(#indexes => #ident::#variant_idents,)*
+                        _ => ::core::unreachable!(),
+                    }
+                }
+
+                #[inline]
+                fn name(index: ::core::primitive::usize) -> &'static ::core::primitive::str {
+                    const NAMES: [&::core::primitive::str; #variant_count] = [#(#variant_names),*];
+
+                    NAMES[index]
+                }
+            }
+
+            impl<#value_type: ::core::default::Default> ::core::default::Default for #map_ident<#value_type> {
+                #[inline]
+                fn default() -> Self {
+                    Self::from_fn(|_| ::core::default::Default::default())
+                }
+            }
+
+            impl<#value_type: ::core::fmt::Debug> ::core::fmt::Debug for #map_ident<#value_type> {
+                #[inline]
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    struct Name(&'static ::core::primitive::str);
+
+                    impl ::core::fmt::Debug for Name {
+                        #[inline]
+                        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                            f.write_str(self.0)
+                        }
+                    }
+
+                    f.debug_map()
+                        .entries(
+                            self.values.iter().enumerate().map(|(index, value)| (Name(Self::name(index)), value)),
+                        )
+                        .finish()
+                }
+            }
+
+            impl<#value_type> ::core::ops::Index<#ident> for #map_ident<#value_type> {
+                type Output = #value_type;
+
+                #[inline]
+                fn index(&self, key: #ident) -> &#value_type {
+                    self.get(key)
+                }
+            }
+
+            impl<#value_type> ::core::ops::IndexMut<#ident> for #map_ident<#value_type> {
+                #[inline]
+                fn index_mut(&mut self, key: #ident) -> &mut #value_type {
+                    self.get_mut(key)
+                }
+            }
+        };
+
+        tokens.extend(enum_map_impl);
+
+        Ok(())
+    }
+}
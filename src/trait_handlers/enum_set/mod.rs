@@ -0,0 +1,332 @@
+mod models;
+
+use super::{create_unique_ident, create_variant_name_string, TraitHandler};
+
+use crate::error::{self, Errors};
+use crate::proc_macro2::{Literal, TokenStream};
+use crate::syn::{Data, DeriveInput, Fields, Meta};
+use crate::Trait;
+
+use models::TypeAttributeBuilder;
+
+pub struct EnumSetHandler;
+
+impl TraitHandler for EnumSetHandler {
+    fn trait_meta_handler(
+        ast: &DeriveInput,
+        tokens: &mut TokenStream,
+        traits: &[Trait],
+        meta: &Meta,
+    ) -> Result<(), syn::Error> {
+        let mut errors = Errors::new();
+
+        let type_attribute = TypeAttributeBuilder {
+            enable_flag: true,
+            enable_name: true,
+            enable_vis: true,
+        }
+        .from_enum_set_meta(meta)?;
+
+        let ident = &ast.ident;
+
+        let set_ident = type_attribute.name.unwrap_or_else(|| format_ident!("{}Set", ident));
+
+        let vis = type_attribute.vis.as_ref().unwrap_or(&ast.vis);
+
+        let mut variant_idents = Vec::new();
+        let mut variant_names = Vec::new();
+
+        match &ast.data {
+            Data::Enum(data) => {
+                for variant in data.variants.iter() {
+                    if !matches!(variant.fields, Fields::Unit) {
+                        errors
+                            .push(error::trait_only_support_unit_variants(variant, Trait::EnumSet));
+
+                        continue;
+                    }
+
+                    variant_idents.push(&variant.ident);
+                    variant_names.push(create_variant_name_string(variant, traits));
+                }
+            }
+            _ => return Err(error::trait_only_support_enum(meta, Trait::EnumSet)),
+        }
+
+        errors.finish()?;
+
+        let variant_count = variant_idents.len();
+
+        let bits_type = match variant_count {
+            0..=8 => quote!(::core::primitive::u8),
+            9..=16 => quote!(::core::primitive::u16),
+            17..=32 => quote!(::core::primitive::u32),
+            33..=64 => quote!(::core::primitive::u64),
+            65..=128 => quote!(::core::primitive::u128),
+            _ => return Err(error::too_many_variants(meta, Trait::EnumSet, 128)),
+        };
+
+        let indexes: Vec<usize> = (0..variant_count).collect();
+
+        let all_bits = Literal::u128_unsuffixed(if variant_count == 128 {
+            u128::MAX
+        } else {
+            (1 << variant_count) - 1
+        });
+
+        let iter_type = create_unique_ident(ast, "I");
+
+        let enum_set_impl = quote! {
+            #[derive(::core::clone::Clone, ::core::marker::Copy, ::core::cmp::PartialEq, ::core::cmp::Eq, ::core::hash::Hash, ::core::default::Default)]
+            #vis struct #set_ident {
+                bits: #bits_type,
+            }
+
+            impl #set_ident {
+                #[inline]
+                pub const fn new() -> Self {
+                    #set_ident {
+                        bits: 0,
+                    }
+                }
+
+                #[inline]
+                pub const fn all() -> Self {
+                    #set_ident {
+                        bits: #all_bits,
+                    }
+                }
+
+                #[inline]
+                pub const fn bits(&self) -> #bits_type {
+                    self.bits
+                }
+
+                #[inline]
+                pub const fn from_bits_truncate(bits: #bits_type) -> Self {
+                    #set_ident {
+                        bits: bits & Self::all().bits,
+                    }
+                }
+
+                #[inline]
+                pub const fn is_empty(&self) -> ::core::primitive::bool {
+                    self.bits == 0
+                }
+
+                #[inline]
+                pub const fn len(&self) -> ::core::primitive::usize {
+                    self.bits.count_ones() as ::core::primitive::usize
+                }
+
+                #[inline]
+                pub fn clear(&mut self) {
+                    self.bits = 0;
+                }
+
+                #[inline]
+                pub fn contains(&self, value: #ident) -> ::core::primitive::bool {
+                    self.bits & Self::bit(value) != 0
+                }
+
+                #[inline]
+                pub fn insert(&mut self, value: #ident) -> ::core::primitive::bool {
+                    let bit = Self::bit(value);
+
+                    let inserted = self.bits & bit == 0;
+
+                    self.bits |= bit;
+
+                    inserted
+                }
+
+                #[inline]
+                pub fn remove(&mut self, value: #ident) -> ::core::primitive::bool {
+                    let bit = Self::bit(value);
+
+                    let removed = self.bits & bit != 0;
+
+                    self.bits &= !bit;
+
+                    removed
+                }
+
+                #[inline]
+                pub fn iter(&self) -> impl ::core::iter::Iterator<Item = #ident> {
+                    let bits = self.bits;
+
+                    (0..#variant_count).filter_map(move |index| {
+                        if bits & (1 << index) != 0 {
+                            Self::variant(index)
+                        } else {
+                            ::core::option::Option::None
+                        }
+                    })
+                }
+
+                #[inline]
+                fn bit(value: #ident) -> #bits_type {
+                    match value {
+                        #(#ident::#variant_idents => 1 << #indexes,)*
+                    }
+                }
+
+                #[inline]
+                #[allow(unreachable_patterns)]
+                fn variant(index: ::core::primitive::usize) -> ::core::option::Option<#ident> {
+                    match index {
+                        #(#indexes => ::core::option::Option::Some(#ident::#variant_idents),)*
+                        _ => ::core::option::Option::None,
+                    }
+                }
+
+                #[inline]
+                fn name(index: ::core::primitive::usize) -> &'static ::core::primitive::str {
+                    const NAMES: [&::core::primitive::str; #variant_count] = [#(#variant_names),*];
+
+                    NAMES[index]
+                }
+            }
+
+            impl ::core::fmt::Debug for #set_ident {
+                #[inline]
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    struct Name(&'static ::core::primitive::str);
+
+                    impl ::core::fmt::Debug for Name {
+                        #[inline]
+                        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                            f.write_str(self.0)
+                        }
+                    }
+
+                    let bits = self.bits;
+
+                    f.debug_set()
+                        .entries(
+                            (0..#variant_count)
+                                .filter(|index| bits & (1 << *index) != 0)
+                                .map(|index| Name(Self::name(index))),
+                        )
+                        .finish()
+                }
+            }
+
+            impl ::core::convert::From<#ident> for #set_ident {
+                #[inline]
+                fn from(value: #ident) -> Self {
+                    #set_ident {
+                        bits: Self::bit(value),
+                    }
+                }
+            }
+
+            impl ::core::iter::FromIterator<#ident> for #set_ident {
+                #[inline]
+                fn from_iter<#iter_type: ::core::iter::IntoIterator<Item = #ident>>(iter: #iter_type) -> Self {
+                    let mut set = Self::new();
+
+                    ::core::iter::Extend::extend(&mut set, iter);
+
+                    set
+                }
+            }
+
+            impl ::core::iter::Extend<#ident> for #set_ident {
+                #[inline]
+                fn extend<#iter_type: ::core::iter::IntoIterator<Item = #ident>>(&mut self, iter: #iter_type) {
+                    for value in iter {
+                        self.insert(value);
+                    }
+                }
+            }
+
+            impl ::core::ops::BitOr for #set_ident {
+                type Output = Self;
+
+                #[inline]
+                fn bitor(self, rhs: Self) -> Self {
+                    #set_ident {
+                        bits: self.bits | rhs.bits,
+                    }
+                }
+            }
+
+            impl ::core::ops::BitAnd for #set_ident {
+                type Output = Self;
+
+                #[inline]
+                fn bitand(self, rhs: Self) -> Self {
+                    #set_ident {
+                        bits: self.bits & rhs.bits,
+                    }
+                }
+            }
+
+            impl ::core::ops::BitXor for #set_ident {
+                type Output = Self;
+
+                #[inline]
+                fn bitxor(self, rhs: Self) -> Self {
+                    #set_ident {
+                        bits: self.bits ^ rhs.bits,
+                    }
+                }
+            }
+
+            impl ::core::ops::Sub for #set_ident {
+                type Output = Self;
+
+                #[inline]
+                fn sub(self, rhs: Self) -> Self {
+                    #set_ident {
+                        bits: self.bits & !rhs.bits,
+                    }
+                }
+            }
+
+            impl ::core::ops::Not for #set_ident {
+                type Output = Self;
+
+                #[inline]
+                fn not(self) -> Self {
+                    #set_ident {
+                        bits: !self.bits & Self::all().bits,
+                    }
+                }
+            }
+
+            impl ::core::ops::BitOrAssign for #set_ident {
+                #[inline]
+                fn bitor_assign(&mut self, rhs: Self) {
+                    self.bits |= rhs.bits;
+                }
+            }
+
+            impl ::core::ops::BitAndAssign for #set_ident {
+                #[inline]
+                fn bitand_assign(&mut self, rhs: Self) {
+                    self.bits &= rhs.bits;
+                }
+            }
+
+            impl ::core::ops::BitXorAssign for #set_ident {
+                #[inline]
+                fn bitxor_assign(&mut self, rhs: Self) {
+                    self.bits ^= rhs.bits;
+                }
+            }
+
+            impl ::core::ops::SubAssign for #set_ident {
+                #[inline]
+                fn sub_assign(&mut self, rhs: Self) {
+                    self.bits &= !rhs.bits;
+                }
+            }
+        };
+
+        tokens.extend(enum_set_impl);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,3 @@
+mod type_attribute;
+
+pub use type_attribute::*;
@@ -0,0 +1,289 @@
+use super::super::super::{create_ident_from_lit_str, create_visibility_from_lit_str};
+
+use crate::error;
+use crate::quote::ToTokens;
+use crate::syn::{Ident, Lit, Meta, NestedMeta, Visibility};
+
+#[derive(Clone)]
+pub struct TypeAttribute {
+    pub name: Option<Ident>,
+    pub vis: Option<Visibility>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TypeAttributeBuilder {
+    pub enable_flag: bool,
+    pub enable_name: bool,
+    pub enable_vis: bool,
+}
+
+impl TypeAttributeBuilder {
+    #[allow(clippy::cognitive_complexity)]
+    pub fn from_enum_set_meta(&self, meta: &Meta) -> Result<TypeAttribute, syn::Error> {
+        let mut name = None;
+        let mut vis = None;
+
+        let correct_usage_for_enum_set_attribute = {
+            let mut usage = vec![];
+
+            if self.enable_flag {
+                usage.push(stringify!(#[educe(EnumSet)]));
+            }
+
+            usage
+        };
+
+        let correct_usage_for_name = {
+            let usage = vec![
+                stringify!(#[educe(EnumSet(name = "new_name"))]),
+                stringify!(#[educe(EnumSet(name("new_name")))]),
+            ];
+
+            usage
+        };
+
+        let correct_usage_for_vis = {
+            let usage = vec![
+                stringify!(#[educe(EnumSet(vis = "visibility"))]),
+                stringify!(#[educe(EnumSet(vis("visibility")))]),
+            ];
+
+            usage
+        };
+
+        match meta {
+            Meta::List(list) => {
+                for p in list.nested.iter() {
+                    match p {
+                        NestedMeta::Meta(meta) => {
+                            let meta_name = meta.path().into_token_stream().to_string();
+
+                            match meta_name.as_str() {
+                                "name" => {
+                                    if !self.enable_name {
+                                        return Err(error::unknown_parameter(
+                                            meta,
+                                            "EnumSet",
+                                            meta_name.as_str(),
+                                        ));
+                                    }
+
+                                    match meta {
+                                        Meta::List(list) => {
+                                            for p in list.nested.iter() {
+                                                match p {
+                                                    NestedMeta::Lit(lit) => {
+                                                        match lit {
+                                                            Lit::Str(s) => {
+                                                                if name.is_some() {
+                                                                    return Err(
+                                                                        error::reset_parameter(
+                                                                            meta,
+                                                                            meta_name.as_str(),
+                                                                        ),
+                                                                    );
+                                                                }
+
+                                                                let s =
+                                                                    create_ident_from_lit_str(s)?;
+
+                                                                if let Some(s) = s {
+                                                                    name = Some(s);
+                                                                } else {
+                                                                    return Err(
+                                                                        error::empty_parameter(
+                                                                            meta,
+                                                                            meta_name.as_str(),
+                                                                        ),
+                                                                    );
+                                                                }
+                                                            }
+                                                            _ => return Err(
+                                                                error::parameter_incorrect_format(
+                                                                    meta,
+                                                                    meta_name.as_str(),
+                                                                    &correct_usage_for_name,
+                                                                ),
+                                                            ),
+                                                        }
+                                                    }
+                                                    _ => {
+                                                        return Err(
+                                                            error::parameter_incorrect_format(
+                                                                meta,
+                                                                meta_name.as_str(),
+                                                                &correct_usage_for_name,
+                                                            ),
+                                                        )
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        Meta::NameValue(named_value) => {
+                                            let lit = &named_value.lit;
+
+                                            match lit {
+                                                Lit::Str(s) => {
+                                                    if name.is_some() {
+                                                        return Err(error::reset_parameter(
+                                                            meta,
+                                                            meta_name.as_str(),
+                                                        ));
+                                                    }
+
+                                                    let s = create_ident_from_lit_str(s)?;
+
+                                                    if let Some(s) = s {
+                                                        name = Some(s);
+                                                    } else {
+                                                        return Err(error::empty_parameter(
+                                                            meta,
+                                                            meta_name.as_str(),
+                                                        ));
+                                                    }
+                                                }
+                                                _ => {
+                                                    return Err(error::parameter_incorrect_format(
+                                                        meta,
+                                                        meta_name.as_str(),
+                                                        &correct_usage_for_name,
+                                                    ))
+                                                }
+                                            }
+                                        }
+                                        _ => {
+                                            return Err(error::parameter_incorrect_format(
+                                                meta,
+                                                meta_name.as_str(),
+                                                &correct_usage_for_name,
+                                            ))
+                                        }
+                                    }
+                                }
+                                "vis" => {
+                                    if !self.enable_vis {
+                                        return Err(error::unknown_parameter(
+                                            meta,
+                                            "EnumSet",
+                                            meta_name.as_str(),
+                                        ));
+                                    }
+
+                                    match meta {
+                                        Meta::List(list) => {
+                                            for p in list.nested.iter() {
+                                                match p {
+                                                    NestedMeta::Lit(lit) => {
+                                                        match lit {
+                                                            Lit::Str(s) => {
+                                                                if vis.is_some() {
+                                                                    return Err(
+                                                                        error::reset_parameter(
+                                                                            meta,
+                                                                            meta_name.as_str(),
+                                                                        ),
+                                                                    );
+                                                                }
+
+                                                                vis = Some(
+                                                                    create_visibility_from_lit_str(
+                                                                        s,
+                                                                    )?,
+                                                                );
+                                                            }
+                                                            _ => return Err(
+                                                                error::parameter_incorrect_format(
+                                                                    meta,
+                                                                    meta_name.as_str(),
+                                                                    &correct_usage_for_vis,
+                                                                ),
+                                                            ),
+                                                        }
+                                                    }
+                                                    _ => {
+                                                        return Err(
+                                                            error::parameter_incorrect_format(
+                                                                meta,
+                                                                meta_name.as_str(),
+                                                                &correct_usage_for_vis,
+                                                            ),
+                                                        )
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        Meta::NameValue(named_value) => {
+                                            let lit = &named_value.lit;
+
+                                            match lit {
+                                                Lit::Str(s) => {
+                                                    if vis.is_some() {
+                                                        return Err(error::reset_parameter(
+                                                            meta,
+                                                            meta_name.as_str(),
+                                                        ));
+                                                    }
+
+                                                    vis = Some(create_visibility_from_lit_str(s)?);
+                                                }
+                                                _ => {
+                                                    return Err(error::parameter_incorrect_format(
+                                                        meta,
+                                                        meta_name.as_str(),
+                                                        &correct_usage_for_vis,
+                                                    ))
+                                                }
+                                            }
+                                        }
+                                        _ => {
+                                            return Err(error::parameter_incorrect_format(
+                                                meta,
+                                                meta_name.as_str(),
+                                                &correct_usage_for_vis,
+                                            ))
+                                        }
+                                    }
+                                }
+                                _ => {
+                                    return Err(error::unknown_parameter(
+                                        meta,
+                                        "EnumSet",
+                                        meta_name.as_str(),
+                                    ))
+                                }
+                            }
+                        }
+                        _ => {
+                            return Err(error::attribute_incorrect_format(
+                                meta,
+                                "EnumSet",
+                                &correct_usage_for_enum_set_attribute,
+                            ))
+                        }
+                    }
+                }
+            }
+            Meta::NameValue(_) => {
+                return Err(error::attribute_incorrect_format(
+                    meta,
+                    "EnumSet",
+                    &correct_usage_for_enum_set_attribute,
+                ))
+            }
+            Meta::Path(_) => {
+                if !self.enable_flag {
+                    return Err(error::attribute_incorrect_format(
+                        meta,
+                        "EnumSet",
+                        &correct_usage_for_enum_set_attribute,
+                    ));
+                }
+            }
+        }
+
+        Ok(TypeAttribute {
+            name,
+            vis,
+        })
+    }
+}
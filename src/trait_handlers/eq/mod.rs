@@ -1,12 +1,12 @@
 mod models;
 
-use super::TraitHandler;
+use super::{create_field_types, TraitHandler};
 
 use crate::proc_macro2::TokenStream;
-use crate::syn::{DeriveInput, Generics, Meta};
+use crate::syn::{Data, DeriveInput, Generics, Meta};
 use crate::Trait;
 
-use models::TypeAttributeBuilder;
+use models::{FieldAttributeBuilder, TypeAttributeBuilder};
 
 pub struct EqHandler;
 
@@ -14,17 +14,73 @@ impl TraitHandler for EqHandler {
     fn trait_meta_handler(
         ast: &DeriveInput,
         tokens: &mut TokenStream,
-        _traits: &[Trait],
+        traits: &[Trait],
         meta: &Meta,
-    ) {
+    ) -> Result<(), syn::Error> {
         let type_attribute = TypeAttributeBuilder {
             enable_bound: true,
         }
-        .from_eq_meta(meta);
+        .from_eq_meta(meta)?;
 
-        let bound = type_attribute
-            .bound
-            .into_punctuated_where_predicates_by_generic_parameters(&ast.generics.params);
+        let mut bound =
+            type_attribute.bound.into_punctuated_where_predicates_by_generic_parameters(
+                &ast.ident,
+                &ast.generics.params,
+                &create_field_types(&ast.data),
+            );
+
+        match &ast.data {
+            Data::Struct(data) => {
+                for field in data.fields.iter() {
+                    let field_attribute = FieldAttributeBuilder {
+                        enable_bound: true,
+                    }
+                    .from_attributes(&field.attrs, traits)?;
+
+                    bound.extend(field_attribute.bound);
+                }
+            }
+            Data::Enum(data) => {
+                for variant in data.variants.iter() {
+                    let variant_attribute = TypeAttributeBuilder {
+                        enable_bound: true,
+                    }
+                    .from_attributes(&variant.attrs, traits)?;
+
+                    let variant_field_types: Vec<_> =
+                        variant.fields.iter().map(|field| &field.ty).collect();
+
+                    bound.extend(
+                        variant_attribute
+                            .bound
+                            .into_punctuated_where_predicates_by_generic_parameters(
+                                &ast.ident,
+                                &ast.generics.params,
+                                &variant_field_types,
+                            ),
+                    );
+
+                    for field in variant.fields.iter() {
+                        let field_attribute = FieldAttributeBuilder {
+                            enable_bound: true,
+                        }
+                        .from_attributes(&field.attrs, traits)?;
+
+                        bound.extend(field_attribute.bound);
+                    }
+                }
+            }
+            Data::Union(data) => {
+                for field in data.fields.named.iter() {
+                    let field_attribute = FieldAttributeBuilder {
+                        enable_bound: true,
+                    }
+                    .from_attributes(&field.attrs, traits)?;
+
+                    bound.extend(field_attribute.bound);
+                }
+            }
+        }
 
         let ident = &ast.ident;
 
@@ -39,10 +95,12 @@ impl TraitHandler for EqHandler {
         let (impl_generics, ty_generics, where_clause) = generics_cloned.split_for_impl();
 
         let compare_impl = quote! {
-            impl #impl_generics core::cmp::Eq for #ident #ty_generics #where_clause {
+            impl #impl_generics ::core::cmp::Eq for #ident #ty_generics #where_clause {
             }
         };
 
         tokens.extend(compare_impl);
+
+        Ok(())
     }
 }
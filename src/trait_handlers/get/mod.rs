@@ -0,0 +1,166 @@
+mod models;
+
+use super::{is_packed, TraitHandler};
+
+use crate::error::{self, Errors};
+use crate::proc_macro2::TokenStream;
+use crate::syn::{Data, DeriveInput, Fields, GenericArgument, Meta, PathArguments, Type};
+use crate::Trait;
+
+use models::{FieldAttribute, FieldAttributeBuilder, TypeAttributeBuilder};
+
+pub struct GetHandler;
+
+impl TraitHandler for GetHandler {
+    fn trait_meta_handler(
+        ast: &DeriveInput,
+        tokens: &mut TokenStream,
+        traits: &[Trait],
+        meta: &Meta,
+    ) -> Result<(), syn::Error> {
+        let mut errors = Errors::new();
+
+        let type_attribute = TypeAttributeBuilder {
+            enable_flag: true,
+            enable_vis: true,
+            enable_copy: true,
+        }
+        .from_get_meta(meta)?;
+
+        let vis = type_attribute.vis.unwrap_or_else(|| syn::parse2(quote!(pub)).unwrap());
+
+        let ident = &ast.ident;
+
+        let packed = is_packed(ast);
+
+        let mut methods = TokenStream::new();
+
+        match &ast.data {
+            Data::Struct(data) => {
+                match &data.fields {
+                    Fields::Named(fields) => {
+                        let field_attributes: Vec<Option<FieldAttribute>> = fields
+                            .named
+                            .iter()
+                            .map(|field| {
+                                errors.handle(
+                                    FieldAttributeBuilder {
+                                        enable_flag: true,
+                                        enable_name: true,
+                                        enable_ignore: true,
+                                        enable_vis: true,
+                                        enable_copy: true,
+                                        enable_deref: true,
+                                    }
+                                    .from_attributes(&field.attrs, traits),
+                                )
+                            })
+                            .collect();
+
+                        let selected =
+                            field_attributes.iter().flatten().any(|attribute| attribute.flag);
+
+                        for (field, field_attribute) in fields.named.iter().zip(field_attributes) {
+                            let field_attribute = match field_attribute {
+                                Some(field_attribute) => field_attribute,
+                                None => continue,
+                            };
+
+                            if field_attribute.ignore {
+                                continue;
+                            }
+
+                            if selected
+                                && !(field_attribute.flag
+                                    || field_attribute.name.is_some()
+                                    || field_attribute.vis.is_some()
+                                    || field_attribute.copy
+                                    || field_attribute.deref)
+                            {
+                                continue;
+                            }
+
+                            if field_attribute.copy && field_attribute.deref {
+                                errors.push(error::set_copy_with_deref(field));
+                            }
+
+                            if packed && !(field_attribute.copy || type_attribute.copy) {
+                                errors.push(error::reference_to_packed_field(field, Trait::Get));
+                            }
+
+                            let field_ident = field.ident.as_ref().unwrap();
+
+                            let name = field_attribute.name.as_ref().unwrap_or(field_ident);
+
+                            let vis = field_attribute.vis.as_ref().unwrap_or(&vis);
+
+                            let ty = &field.ty;
+
+                            let (return_type, value) = if field_attribute.deref {
+                                match option_inner_type(ty) {
+                                    Some(inner_ty) => {
+                                        (
+                                            quote!(::core::option::Option<&<#inner_ty as ::core::ops::Deref>::Target>),
+                                            quote!(::core::option::Option::as_deref(&self.#field_ident)),
+                                        )
+                                    }
+                                    None => {
+                                        (
+                                            quote!(&<#ty as ::core::ops::Deref>::Target),
+                                            quote!(::core::ops::Deref::deref(&self.#field_ident)),
+                                        )
+                                    }
+                                }
+                            } else if field_attribute.copy || type_attribute.copy {
+                                (quote!(#ty), quote!(self.#field_ident))
+                            } else {
+                                (quote!(&#ty), quote!(&self.#field_ident))
+                            };
+
+                            methods.extend(quote! {
+                                #[inline]
+                                #vis fn #name(&self) -> #return_type {
+                                    #value
+                                }
+                            });
+                        }
+                    }
+                    _ => return Err(error::trait_only_support_named_fields(meta, Trait::Get)),
+                }
+            }
+            _ => return Err(error::trait_only_support_named_fields(meta, Trait::Get)),
+        }
+
+        let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+        let get_impl = quote! {
+            impl #impl_generics #ident #ty_generics #where_clause {
+                #methods
+            }
+        };
+
+        errors.finish()?;
+
+        tokens.extend(get_impl);
+
+        Ok(())
+    }
+}
+
+fn option_inner_type(ty: &Type) -> Option<&Type> {
+    if let Type::Path(ty) = ty {
+        if let Some(segment) = ty.path.segments.last() {
+            if segment.ident == "Option" {
+                if let PathArguments::AngleBracketed(arguments) = &segment.arguments {
+                    if arguments.args.len() == 1 {
+                        if let GenericArgument::Type(ty) = &arguments.args[0] {
+                            return Some(ty);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    None
+}
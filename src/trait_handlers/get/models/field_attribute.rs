@@ -0,0 +1,492 @@
+use super::super::super::{
+    create_ident_from_lit_str, create_visibility_from_lit_str, parse_educe_meta,
+};
+
+use crate::error;
+use crate::quote::ToTokens;
+use crate::syn::{Attribute, Ident, Lit, Meta, NestedMeta, Visibility};
+use crate::Trait;
+
+#[derive(Clone)]
+pub struct FieldAttribute {
+    pub flag: bool,
+    pub name: Option<Ident>,
+    pub ignore: bool,
+    pub vis: Option<Visibility>,
+    pub copy: bool,
+    pub deref: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct FieldAttributeBuilder {
+    pub enable_flag: bool,
+    pub enable_name: bool,
+    pub enable_ignore: bool,
+    pub enable_vis: bool,
+    pub enable_copy: bool,
+    pub enable_deref: bool,
+}
+
+impl FieldAttributeBuilder {
+    #[allow(clippy::cognitive_complexity)]
+    pub fn from_get_meta(&self, meta: &Meta) -> Result<FieldAttribute, syn::Error> {
+        let mut flag = false;
+        let mut name = None;
+        let mut ignore = false;
+        let mut vis = None;
+        let mut copy = false;
+        let mut deref = false;
+
+        let correct_usage_for_get_attribute = {
+            let mut usage = vec![];
+
+            if self.enable_flag {
+                usage.push(stringify!(#[educe(Get)]));
+            }
+
+            if self.enable_ignore {
+                usage.push(stringify!(#[educe(Get(ignore))]));
+            }
+
+            if self.enable_copy {
+                usage.push(stringify!(#[educe(Get(copy))]));
+            }
+
+            if self.enable_deref {
+                usage.push(stringify!(#[educe(Get(deref))]));
+            }
+
+            usage
+        };
+
+        let correct_usage_for_name = {
+            let usage = vec![
+                stringify!(#[educe(Get(name = "new_name"))]),
+                stringify!(#[educe(Get(name("new_name")))]),
+            ];
+
+            usage
+        };
+
+        let correct_usage_for_vis = {
+            let usage = vec![
+                stringify!(#[educe(Get(vis = "visibility"))]),
+                stringify!(#[educe(Get(vis("visibility")))]),
+            ];
+
+            usage
+        };
+
+        let correct_usage_for_ignore = {
+            let usage = vec![stringify!(#[educe(Get(ignore))])];
+
+            usage
+        };
+
+        let correct_usage_for_copy = {
+            let usage = vec![stringify!(#[educe(Get(copy))])];
+
+            usage
+        };
+
+        let correct_usage_for_deref = {
+            let usage = vec![stringify!(#[educe(Get(deref))])];
+
+            usage
+        };
+
+        match meta {
+            Meta::List(list) => {
+                let mut ignore_is_set = false;
+                let mut copy_is_set = false;
+                let mut deref_is_set = false;
+
+                for p in list.nested.iter() {
+                    match p {
+                        NestedMeta::Meta(meta) => {
+                            let meta_name = meta.path().into_token_stream().to_string();
+
+                            match meta_name.as_str() {
+                                "name" => {
+                                    if !self.enable_name {
+                                        return Err(error::unknown_parameter(
+                                            meta,
+                                            "Get",
+                                            meta_name.as_str(),
+                                        ));
+                                    }
+
+                                    match meta {
+                                        Meta::List(list) => {
+                                            for p in list.nested.iter() {
+                                                match p {
+                                                    NestedMeta::Lit(lit) => {
+                                                        match lit {
+                                                            Lit::Str(s) => {
+                                                                if name.is_some() {
+                                                                    return Err(
+                                                                        error::reset_parameter(
+                                                                            meta,
+                                                                            meta_name.as_str(),
+                                                                        ),
+                                                                    );
+                                                                }
+
+                                                                let s =
+                                                                    create_ident_from_lit_str(s)?;
+
+                                                                if let Some(s) = s {
+                                                                    name = Some(s);
+                                                                } else {
+                                                                    return Err(
+                                                                        error::empty_parameter(
+                                                                            meta,
+                                                                            meta_name.as_str(),
+                                                                        ),
+                                                                    );
+                                                                }
+                                                            }
+                                                            _ => return Err(
+                                                                error::parameter_incorrect_format(
+                                                                    meta,
+                                                                    meta_name.as_str(),
+                                                                    &correct_usage_for_name,
+                                                                ),
+                                                            ),
+                                                        }
+                                                    }
+                                                    _ => {
+                                                        return Err(
+                                                            error::parameter_incorrect_format(
+                                                                meta,
+                                                                meta_name.as_str(),
+                                                                &correct_usage_for_name,
+                                                            ),
+                                                        )
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        Meta::NameValue(named_value) => {
+                                            let lit = &named_value.lit;
+
+                                            match lit {
+                                                Lit::Str(s) => {
+                                                    if name.is_some() {
+                                                        return Err(error::reset_parameter(
+                                                            meta,
+                                                            meta_name.as_str(),
+                                                        ));
+                                                    }
+
+                                                    let s = create_ident_from_lit_str(s)?;
+
+                                                    if let Some(s) = s {
+                                                        name = Some(s);
+                                                    } else {
+                                                        return Err(error::empty_parameter(
+                                                            meta,
+                                                            meta_name.as_str(),
+                                                        ));
+                                                    }
+                                                }
+                                                _ => {
+                                                    return Err(error::parameter_incorrect_format(
+                                                        meta,
+                                                        meta_name.as_str(),
+                                                        &correct_usage_for_name,
+                                                    ))
+                                                }
+                                            }
+                                        }
+                                        _ => {
+                                            return Err(error::parameter_incorrect_format(
+                                                meta,
+                                                meta_name.as_str(),
+                                                &correct_usage_for_name,
+                                            ))
+                                        }
+                                    }
+                                }
+                                "vis" => {
+                                    if !self.enable_vis {
+                                        return Err(error::unknown_parameter(
+                                            meta,
+                                            "Get",
+                                            meta_name.as_str(),
+                                        ));
+                                    }
+
+                                    match meta {
+                                        Meta::List(list) => {
+                                            for p in list.nested.iter() {
+                                                match p {
+                                                    NestedMeta::Lit(lit) => {
+                                                        match lit {
+                                                            Lit::Str(s) => {
+                                                                if vis.is_some() {
+                                                                    return Err(
+                                                                        error::reset_parameter(
+                                                                            meta,
+                                                                            meta_name.as_str(),
+                                                                        ),
+                                                                    );
+                                                                }
+
+                                                                vis = Some(
+                                                                    create_visibility_from_lit_str(
+                                                                        s,
+                                                                    )?,
+                                                                );
+                                                            }
+                                                            _ => return Err(
+                                                                error::parameter_incorrect_format(
+                                                                    meta,
+                                                                    meta_name.as_str(),
+                                                                    &correct_usage_for_vis,
+                                                                ),
+                                                            ),
+                                                        }
+                                                    }
+                                                    _ => {
+                                                        return Err(
+                                                            error::parameter_incorrect_format(
+                                                                meta,
+                                                                meta_name.as_str(),
+                                                                &correct_usage_for_vis,
+                                                            ),
+                                                        )
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        Meta::NameValue(named_value) => {
+                                            let lit = &named_value.lit;
+
+                                            match lit {
+                                                Lit::Str(s) => {
+                                                    if vis.is_some() {
+                                                        return Err(error::reset_parameter(
+                                                            meta,
+                                                            meta_name.as_str(),
+                                                        ));
+                                                    }
+
+                                                    vis = Some(create_visibility_from_lit_str(s)?);
+                                                }
+                                                _ => {
+                                                    return Err(error::parameter_incorrect_format(
+                                                        meta,
+                                                        meta_name.as_str(),
+                                                        &correct_usage_for_vis,
+                                                    ))
+                                                }
+                                            }
+                                        }
+                                        _ => {
+                                            return Err(error::parameter_incorrect_format(
+                                                meta,
+                                                meta_name.as_str(),
+                                                &correct_usage_for_vis,
+                                            ))
+                                        }
+                                    }
+                                }
+                                "ignore" => {
+                                    if !self.enable_ignore {
+                                        return Err(error::unknown_parameter(
+                                            meta,
+                                            "Get",
+                                            meta_name.as_str(),
+                                        ));
+                                    }
+
+                                    match meta {
+                                        Meta::Path(_) => {
+                                            if ignore_is_set {
+                                                return Err(error::reset_parameter(
+                                                    meta,
+                                                    meta_name.as_str(),
+                                                ));
+                                            }
+
+                                            ignore_is_set = true;
+
+                                            ignore = true;
+                                        }
+                                        _ => {
+                                            return Err(error::parameter_incorrect_format(
+                                                meta,
+                                                meta_name.as_str(),
+                                                &correct_usage_for_ignore,
+                                            ))
+                                        }
+                                    }
+                                }
+                                "copy" => {
+                                    if !self.enable_copy {
+                                        return Err(error::unknown_parameter(
+                                            meta,
+                                            "Get",
+                                            meta_name.as_str(),
+                                        ));
+                                    }
+
+                                    match meta {
+                                        Meta::Path(_) => {
+                                            if copy_is_set {
+                                                return Err(error::reset_parameter(
+                                                    meta,
+                                                    meta_name.as_str(),
+                                                ));
+                                            }
+
+                                            copy_is_set = true;
+
+                                            copy = true;
+                                        }
+                                        _ => {
+                                            return Err(error::parameter_incorrect_format(
+                                                meta,
+                                                meta_name.as_str(),
+                                                &correct_usage_for_copy,
+                                            ))
+                                        }
+                                    }
+                                }
+                                "deref" => {
+                                    if !self.enable_deref {
+                                        return Err(error::unknown_parameter(
+                                            meta,
+                                            "Get",
+                                            meta_name.as_str(),
+                                        ));
+                                    }
+
+                                    match meta {
+                                        Meta::Path(_) => {
+                                            if deref_is_set {
+                                                return Err(error::reset_parameter(
+                                                    meta,
+                                                    meta_name.as_str(),
+                                                ));
+                                            }
+
+                                            deref_is_set = true;
+
+                                            deref = true;
+                                        }
+                                        _ => {
+                                            return Err(error::parameter_incorrect_format(
+                                                meta,
+                                                meta_name.as_str(),
+                                                &correct_usage_for_deref,
+                                            ))
+                                        }
+                                    }
+                                }
+                                _ => {
+                                    return Err(error::unknown_parameter(
+                                        meta,
+                                        "Get",
+                                        meta_name.as_str(),
+                                    ))
+                                }
+                            }
+                        }
+                        _ => {
+                            return Err(error::attribute_incorrect_format(
+                                meta,
+                                "Get",
+                                &correct_usage_for_get_attribute,
+                            ))
+                        }
+                    }
+                }
+            }
+            Meta::NameValue(_) => {
+                return Err(error::attribute_incorrect_format(
+                    meta,
+                    "Get",
+                    &correct_usage_for_get_attribute,
+                ))
+            }
+            Meta::Path(_) => {
+                if !self.enable_flag {
+                    return Err(error::attribute_incorrect_format(
+                        meta,
+                        "Get",
+                        &correct_usage_for_get_attribute,
+                    ));
+                }
+
+                flag = true;
+            }
+        }
+
+        if (name.is_some() || vis.is_some() || copy || deref) && ignore {
+            return Err(error::set_ignore_with_others(meta));
+        }
+
+        Ok(FieldAttribute {
+            flag,
+            name,
+            ignore,
+            vis,
+            copy,
+            deref,
+        })
+    }
+
+    #[allow(clippy::wrong_self_convention)]
+    pub fn from_attributes(
+        self,
+        attributes: &[Attribute],
+        traits: &[Trait],
+    ) -> Result<FieldAttribute, syn::Error> {
+        let mut result = None;
+
+        for attribute in attributes.iter() {
+            if let Some(meta_name) = attribute.path.get_ident() {
+                if meta_name == "educe" {
+                    let meta = parse_educe_meta(attribute)?;
+
+                    match meta {
+                        Meta::List(list) => {
+                            for p in list.nested.iter() {
+                                match p {
+                                    NestedMeta::Meta(meta) => {
+                                        let t = Trait::from_path(meta.path())?;
+
+                                        if traits.binary_search(&t).is_err() {
+                                            return Err(error::trait_not_used(meta, t));
+                                        }
+
+                                        if t == Trait::Get {
+                                            if result.is_some() {
+                                                return Err(error::reuse_a_trait(meta, t));
+                                            }
+
+                                            result = Some(self.from_get_meta(&meta)?);
+                                        }
+                                    }
+                                    _ => return Err(error::educe_format_incorrect(attribute)),
+                                }
+                            }
+                        }
+                        _ => return Err(error::educe_format_incorrect(attribute)),
+                    }
+                }
+            }
+        }
+
+        Ok(result.unwrap_or(FieldAttribute {
+            flag: false,
+            name: None,
+            ignore: false,
+            vis: None,
+            copy: false,
+            deref: false,
+        }))
+    }
+}
@@ -1,10 +1,11 @@
-use std::fmt::Write;
-use std::str::FromStr;
-
-use super::super::TraitHandler;
+use super::super::{
+    create_field_binding, create_field_member, create_hygienic_ident, create_unique_ident,
+    TraitHandler,
+};
 use super::models::{FieldAttributeBuilder, TypeAttributeBuilder};
 
-use crate::proc_macro2::TokenStream;
+use crate::error::Errors;
+use crate::proc_macro2::{Literal, TokenStream};
 use crate::syn::{Data, DeriveInput, Fields, Generics, Meta};
 use crate::Trait;
 
@@ -16,39 +17,44 @@ impl TraitHandler for HashEnumHandler {
         tokens: &mut TokenStream,
         traits: &[Trait],
         meta: &Meta,
-    ) {
+    ) -> Result<(), syn::Error> {
+        let mut errors = Errors::new();
+
         let type_attribute = TypeAttributeBuilder {
             enable_flag: true,
             enable_bound: true,
         }
-        .from_hash_meta(meta);
+        .from_hash_meta(meta)?;
 
-        let enum_name = ast.ident.to_string();
+        let ident = &ast.ident;
 
-        let bound = type_attribute
-            .bound
-            .into_punctuated_where_predicates_by_generic_parameters(&ast.generics.params);
+        let state = create_hygienic_ident("state");
 
-        let mut hasher_tokens = TokenStream::new();
+        let mut arms = TokenStream::new();
 
-        let mut match_tokens = String::from("match self {");
+        let mut field_types = Vec::new();
+        let mut extra_bound = Vec::new();
 
         if let Data::Enum(data) = &ast.data {
+            let mut variant_attributes = Vec::new();
+
             let has_non_unit = {
                 let mut non_unit = false;
 
                 for variant in data.variants.iter() {
-                    let _ = TypeAttributeBuilder {
-                        enable_flag: false,
-                        enable_bound: false,
-                    }
-                    .from_attributes(&variant.attrs, traits);
+                    variant_attributes.push(
+                        errors.handle(
+                            TypeAttributeBuilder {
+                                enable_flag: false,
+                                enable_bound: true,
+                            }
+                            .from_attributes(&variant.attrs, traits),
+                        ),
+                    );
 
                     match &variant.fields {
                         Fields::Named(_) | Fields::Unnamed(_) => {
                             non_unit = true;
-
-                            break;
                         }
                         _ => (),
                     }
@@ -58,189 +64,153 @@ impl TraitHandler for HashEnumHandler {
             };
 
             if has_non_unit {
-                for (index, variant) in data.variants.iter().enumerate() {
-                    let variant_ident = variant.ident.to_string();
+                for ((index, variant), variant_attribute) in
+                    data.variants.iter().enumerate().zip(variant_attributes)
+                {
+                    let variant_attribute = match variant_attribute {
+                        Some(variant_attribute) => variant_attribute,
+                        None => continue,
+                    };
+
+                    let variant_ident = &variant.ident;
+
+                    let variant_field_types_start = field_types.len();
+
+                    let variant_index = Literal::usize_unsuffixed(index);
+
+                    let mut pattern_tokens = TokenStream::new();
+                    let mut block_tokens =
+                        quote!(::core::hash::Hash::hash(&#variant_index, #state););
+
+                    for (index, field) in variant.fields.iter().enumerate() {
+                        let field_attribute = match errors.handle(
+                            FieldAttributeBuilder {
+                                enable_ignore: true,
+                                enable_impl: true,
+                                enable_bound: true,
+                            }
+                            .from_attributes(&field.attrs, traits),
+                        ) {
+                            Some(field_attribute) => field_attribute,
+                            None => continue,
+                        };
 
-                    match &variant.fields {
-                        Fields::Unit => {
-                            // TODO Unit
-                            match_tokens.write_fmt(format_args!("{enum_name}::{variant_ident} => {{ core::hash::Hash::hash(&{index}, state); }}", enum_name = enum_name, variant_ident = variant_ident, index = index)).unwrap();
-                        }
-                        Fields::Named(fields) => {
-                            // TODO Struct
-                            let mut pattern_tokens = String::new();
-                            let mut block_tokens = String::new();
-
-                            block_tokens
-                                .write_fmt(format_args!(
-                                    "core::hash::Hash::hash(&{index}, state);",
-                                    index = index
-                                ))
-                                .unwrap();
-
-                            for field in fields.named.iter() {
-                                let field_attribute = FieldAttributeBuilder {
-                                    enable_ignore: true,
-                                    enable_impl: true,
-                                }
-                                .from_attributes(&field.attrs, traits);
-
-                                let field_name = field.ident.as_ref().unwrap().to_string();
-
-                                if field_attribute.ignore {
-                                    pattern_tokens
-                                        .write_fmt(format_args!(
-                                            "{field_name}: _,",
-                                            field_name = field_name
-                                        ))
-                                        .unwrap();
-                                    continue;
-                                }
+                        extra_bound.extend(field_attribute.bound.iter().cloned());
 
-                                let hash_trait = field_attribute.hash_trait;
-                                let hash_method = field_attribute.hash_method;
-
-                                pattern_tokens
-                                    .write_fmt(format_args!(
-                                        "{field_name},",
-                                        field_name = field_name
-                                    ))
-                                    .unwrap();
-
-                                match hash_trait {
-                                    Some(hash_trait) => {
-                                        let hash_method = hash_method.unwrap();
-
-                                        block_tokens
-                                            .write_fmt(format_args!(
-                                                "{hash_trait}::{hash_method}({field_name}, state);",
-                                                hash_trait = hash_trait,
-                                                hash_method = hash_method,
-                                                field_name = field_name
-                                            ))
-                                            .unwrap();
-                                    }
-                                    None => {
-                                        match hash_method {
-                                            Some(hash_method) => {
-                                                block_tokens
-                                                    .write_fmt(format_args!(
-                                                        "{hash_method}({field_name}, state);",
-                                                        hash_method = hash_method,
-                                                        field_name = field_name
-                                                    ))
-                                                    .unwrap();
-                                            }
-                                            None => {
-                                                block_tokens.write_fmt(format_args!("core::hash::Hash::hash({field_name}, state);", field_name = field_name)).unwrap();
-                                            }
-                                        }
-                                    }
-                                }
-                            }
+                        let field_member = create_field_member(index, field);
 
-                            match_tokens.write_fmt(format_args!("{enum_name}::{variant_ident} {{ {pattern_tokens} }} => {{ {block_tokens} }}", enum_name = enum_name, variant_ident = variant_ident, pattern_tokens = pattern_tokens, block_tokens = block_tokens)).unwrap();
-                        }
-                        Fields::Unnamed(fields) => {
-                            // TODO Tuple
-                            let mut pattern_tokens = String::new();
-                            let mut block_tokens = String::new();
-
-                            block_tokens
-                                .write_fmt(format_args!(
-                                    "core::hash::Hash::hash(&{index}, state);",
-                                    index = index
-                                ))
-                                .unwrap();
-
-                            for (index, field) in fields.unnamed.iter().enumerate() {
-                                let field_attribute = FieldAttributeBuilder {
-                                    enable_ignore: true,
-                                    enable_impl: true,
-                                }
-                                .from_attributes(&field.attrs, traits);
+                        if field_attribute.ignore {
+                            pattern_tokens.extend(quote!(#field_member: _,));
 
-                                if field_attribute.ignore {
-                                    pattern_tokens.push_str("_,");
-                                    continue;
-                                }
+                            continue;
+                        }
 
-                                let hash_trait = field_attribute.hash_trait;
-                                let hash_method = field_attribute.hash_method;
+                        let field_binding = create_field_binding(index);
 
-                                let field_name = format!("{}", index);
+                        pattern_tokens.extend(quote!(#field_member: #field_binding,));
 
-                                pattern_tokens
-                                    .write_fmt(format_args!(
-                                        "_{field_name},",
-                                        field_name = field_name
-                                    ))
-                                    .unwrap();
+                        if field_attribute.hash_method.is_none() {
+                            field_types.push(&field.ty);
+                        }
 
-                                match hash_trait {
-                                    Some(hash_trait) => {
-                                        let hash_method = hash_method.unwrap();
+                        let statement = match field_attribute.hash_trait {
+                            Some(hash_trait) => {
+                                let hash_method = field_attribute.hash_method.unwrap();
 
-                                        block_tokens.write_fmt(format_args!("{hash_trait}::{hash_method}(_{field_name}, state);", hash_trait = hash_trait, hash_method = hash_method, field_name = field_name)).unwrap();
+                                quote!(#hash_trait::#hash_method(#field_binding, #state);)
+                            }
+                            None => {
+                                match field_attribute.hash_method {
+                                    Some(hash_method) => {
+                                        quote!(#hash_method(#field_binding, #state);)
                                     }
                                     None => {
-                                        match hash_method {
-                                            Some(hash_method) => {
-                                                block_tokens
-                                                    .write_fmt(format_args!(
-                                                        "{hash_method}(_{field_name}, state);",
-                                                        hash_method = hash_method,
-                                                        field_name = field_name
-                                                    ))
-                                                    .unwrap();
-                                            }
-                                            None => {
-                                                block_tokens.write_fmt(format_args!("core::hash::Hash::hash(_{field_name}, state);", field_name = field_name)).unwrap();
-                                            }
-                                        }
+                                        quote!(::core::hash::Hash::hash(#field_binding, #state);)
                                     }
                                 }
                             }
+                        };
 
-                            match_tokens.write_fmt(format_args!("{enum_name}::{variant_ident}( {pattern_tokens} ) => {{ {block_tokens} }}", enum_name = enum_name, variant_ident = variant_ident, pattern_tokens = pattern_tokens, block_tokens = block_tokens)).unwrap();
-                        }
+                        block_tokens.extend(statement);
                     }
+
+                    extra_bound.extend(
+                        variant_attribute
+                            .bound
+                            .into_punctuated_where_predicates_by_generic_parameters(
+                                &ast.ident,
+                                &ast.generics.params,
+                                &field_types[variant_field_types_start..],
+                            ),
+                    );
+
+                    arms.extend(quote! {
+                        Self::#variant_ident { #pattern_tokens } => { #block_tokens }
+                    });
                 }
             } else {
-                for variant in data.variants.iter() {
-                    let variant_ident = variant.ident.to_string();
+                for (variant, variant_attribute) in data.variants.iter().zip(variant_attributes) {
+                    if let Some(variant_attribute) = variant_attribute {
+                        extra_bound.extend(
+                            variant_attribute
+                                .bound
+                                .into_punctuated_where_predicates_by_generic_parameters(
+                                    &ast.ident,
+                                    &ast.generics.params,
+                                    &[],
+                                ),
+                        );
+                    }
 
-                    match_tokens.write_fmt(format_args!("{enum_name}::{variant_ident} => {{ core::hash::Hash::hash(&({enum_name}::{variant_ident} as isize), state); }}", enum_name = enum_name, variant_ident = variant_ident)).unwrap();
+                    let variant_ident = &variant.ident;
+
+                    arms.extend(quote! {
+                        Self::#variant_ident => {
+                            ::core::hash::Hash::hash(&(Self::#variant_ident as ::core::primitive::isize), #state);
+                        }
+                    });
                 }
             }
         }
 
-        match_tokens.push('}');
+        let hasher_tokens = quote! {
+            match self {
+                #arms
+            }
+        };
 
-        hasher_tokens.extend(TokenStream::from_str(&match_tokens).unwrap());
-
-        let ident = &ast.ident;
+        let bound = type_attribute.bound.into_punctuated_where_predicates_by_generic_parameters(
+            &ast.ident,
+            &ast.generics.params,
+            &field_types,
+        );
 
         let mut generics_cloned: Generics = ast.generics.clone();
 
         let where_clause = generics_cloned.make_where_clause();
 
-        for where_predicate in bound {
+        for where_predicate in bound.into_iter().chain(extra_bound) {
             where_clause.predicates.push(where_predicate);
         }
 
         let (impl_generics, ty_generics, where_clause) = generics_cloned.split_for_impl();
 
+        let hasher = create_unique_ident(ast, "H");
+
         let hash_impl = quote! {
-            impl #impl_generics core::hash::Hash for #ident #ty_generics #where_clause {
+            impl #impl_generics ::core::hash::Hash for #ident #ty_generics #where_clause {
                 #[inline]
                 #[allow(clippy::unneeded_field_pattern)]
-                fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                fn hash<#hasher: ::core::hash::Hasher>(&self, #state: &mut #hasher) {
                     #hasher_tokens
                 }
             }
         };
 
+        errors.finish()?;
+
         tokens.extend(hash_impl);
+
+        Ok(())
     }
 }
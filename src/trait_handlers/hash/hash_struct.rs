@@ -1,8 +1,10 @@
-use std::str::FromStr;
-
-use super::super::TraitHandler;
+use super::super::{
+    create_field_member, create_field_reference, create_hygienic_ident,
+    create_packed_field_where_predicate, create_unique_ident, is_packed, TraitHandler,
+};
 use super::models::{FieldAttributeBuilder, TypeAttributeBuilder};
 
+use crate::error::Errors;
 use crate::proc_macro2::TokenStream;
 use crate::syn::{Data, DeriveInput, Generics, Meta};
 use crate::Trait;
@@ -15,99 +17,107 @@ impl TraitHandler for HashStructHandler {
         tokens: &mut TokenStream,
         traits: &[Trait],
         meta: &Meta,
-    ) {
+    ) -> Result<(), syn::Error> {
+        let mut errors = Errors::new();
+
         let type_attribute = TypeAttributeBuilder {
             enable_flag: true,
             enable_bound: true,
         }
-        .from_hash_meta(meta);
+        .from_hash_meta(meta)?;
+
+        let state = create_hygienic_ident("state");
 
-        let bound = type_attribute
-            .bound
-            .into_punctuated_where_predicates_by_generic_parameters(&ast.generics.params);
+        let packed = is_packed(ast);
 
         let mut hasher_tokens = TokenStream::new();
 
+        let mut field_types = Vec::new();
+        let mut extra_bound = Vec::new();
+
         if let Data::Struct(data) = &ast.data {
             for (index, field) in data.fields.iter().enumerate() {
-                let field_attribute = FieldAttributeBuilder {
-                    enable_ignore: true,
-                    enable_impl: true,
-                }
-                .from_attributes(&field.attrs, traits);
+                let field_attribute = match errors.handle(
+                    FieldAttributeBuilder {
+                        enable_ignore: true,
+                        enable_impl: true,
+                        enable_bound: true,
+                    }
+                    .from_attributes(&field.attrs, traits),
+                ) {
+                    Some(field_attribute) => field_attribute,
+                    None => continue,
+                };
+
+                extra_bound.extend(field_attribute.bound.iter().cloned());
 
                 if field_attribute.ignore {
                     continue;
                 }
 
-                let hash_trait = field_attribute.hash_trait;
-                let hash_method = field_attribute.hash_method;
+                let field_member = create_field_member(index, field);
 
-                let field_name = if let Some(ident) = field.ident.as_ref() {
-                    ident.to_string()
-                } else {
-                    format!("{}", index)
-                };
+                if field_attribute.hash_method.is_none() {
+                    field_types.push(&field.ty);
+                }
 
-                match hash_trait {
-                    Some(hash_trait) => {
-                        let hash_method = hash_method.unwrap();
+                if packed {
+                    extra_bound.push(create_packed_field_where_predicate(&field.ty));
+                }
+
+                let self_field = create_field_reference(quote!(self), &field_member, packed);
 
-                        let statement = format!(
-                            "{hash_trait}::{hash_method}(&self.{field_name}, state);",
-                            hash_trait = hash_trait,
-                            hash_method = hash_method,
-                            field_name = field_name
-                        );
+                let statement = match field_attribute.hash_trait {
+                    Some(hash_trait) => {
+                        let hash_method = field_attribute.hash_method.unwrap();
 
-                        hasher_tokens.extend(TokenStream::from_str(&statement).unwrap());
+                        quote!(#hash_trait::#hash_method(#self_field, #state);)
                     }
                     None => {
-                        match hash_method {
-                            Some(hash_method) => {
-                                let statement = format!(
-                                    "{hash_method}(&self.{field_name}, state);",
-                                    hash_method = hash_method,
-                                    field_name = field_name
-                                );
-
-                                hasher_tokens.extend(TokenStream::from_str(&statement).unwrap());
-                            }
-                            None => {
-                                let statement = format!(
-                                    "core::hash::Hash::hash(&self.{field_name}, state);",
-                                    field_name = field_name
-                                );
-
-                                hasher_tokens.extend(TokenStream::from_str(&statement).unwrap());
-                            }
+                        match field_attribute.hash_method {
+                            Some(hash_method) => quote!(#hash_method(#self_field, #state);),
+                            None => quote!(::core::hash::Hash::hash(#self_field, #state);),
                         }
                     }
-                }
+                };
+
+                hasher_tokens.extend(statement);
             }
         }
 
         let ident = &ast.ident;
 
+        let bound = type_attribute.bound.into_punctuated_where_predicates_by_generic_parameters(
+            &ast.ident,
+            &ast.generics.params,
+            &field_types,
+        );
+
         let mut generics_cloned: Generics = ast.generics.clone();
 
         let where_clause = generics_cloned.make_where_clause();
 
-        for where_predicate in bound {
+        for where_predicate in bound.into_iter().chain(extra_bound) {
             where_clause.predicates.push(where_predicate);
         }
 
         let (impl_generics, ty_generics, where_clause) = generics_cloned.split_for_impl();
 
+        let hasher = create_unique_ident(ast, "H");
+
         let hash_impl = quote! {
-            impl #impl_generics core::hash::Hash for #ident #ty_generics #where_clause {
+            impl #impl_generics ::core::hash::Hash for #ident #ty_generics #where_clause {
                 #[inline]
-                fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                fn hash<#hasher: ::core::hash::Hasher>(&self, #state: &mut #hasher) {
                     #hasher_tokens
                 }
             }
         };
 
+        errors.finish()?;
+
         tokens.extend(hash_impl);
+
+        Ok(())
     }
 }
@@ -1,26 +1,34 @@
-use super::super::super::create_path_string_from_lit_str;
+use super::super::super::{
+    create_path_from_lit_str, create_where_predicates_from_lit_str, parse_educe_meta,
+};
 
-use crate::panic;
+use crate::error;
 use crate::quote::ToTokens;
-use crate::syn::{Attribute, Lit, Meta, NestedMeta};
+use crate::syn::spanned::Spanned;
+use crate::syn::{
+    punctuated::Punctuated, token::Comma, Attribute, Lit, Meta, NestedMeta, Path, WherePredicate,
+};
 use crate::Trait;
 
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct FieldAttribute {
     pub ignore: bool,
-    pub hash_method: Option<String>,
-    pub hash_trait: Option<String>,
+    pub hash_method: Option<Path>,
+    pub hash_trait: Option<Path>,
+    pub bound: Punctuated<WherePredicate, Comma>,
 }
 
 #[derive(Debug, Clone)]
 pub struct FieldAttributeBuilder {
     pub enable_ignore: bool,
     pub enable_impl: bool,
+    pub enable_bound: bool,
 }
 
 impl FieldAttributeBuilder {
     #[allow(clippy::cognitive_complexity)]
-    pub fn from_hash_meta(&self, meta: &Meta) -> FieldAttribute {
+    pub fn from_hash_meta(&self, meta: &Meta) -> Result<FieldAttribute, syn::Error> {
+        let mut bound = Punctuated::new();
         let mut ignore = false;
 
         let mut hash_method = None;
@@ -49,8 +57,21 @@ impl FieldAttributeBuilder {
                 stringify!(#[educe(Hash(trait = "path_to_trait"))]),
                 stringify!(#[educe(Hash(trait = "path_to_trait", method = "path_to_method_in_trait"))]),
                 stringify!(#[educe(Hash(method("path_to_method")))]),
+                stringify!(#[educe(Hash(method(path_to_method)))]),
                 stringify!(#[educe(Hash(trait("path_to_trait")))]),
+                stringify!(#[educe(Hash(trait(path_to_trait)))]),
                 stringify!(#[educe(Hash(trait("path_to_trait"), method("path_to_method_in_trait")))]),
+                stringify!(#[educe(Hash(trait(path_to_trait), method(path_to_method_in_trait)))]),
+            ];
+
+            usage
+        };
+
+        let correct_usage_for_bound = {
+            let usage = vec![
+                stringify!(#[educe(Hash(bound = "where_predicates"))]),
+                stringify!(#[educe(Hash(bound("where_predicates")))]),
+                stringify!(#[educe(Hash(bound(where_predicates)))]),
             ];
 
             usage
@@ -68,13 +89,20 @@ impl FieldAttributeBuilder {
                             match meta_name.as_str() {
                                 "ignore" => {
                                     if !self.enable_ignore {
-                                        panic::unknown_parameter("Hash", meta_name.as_str());
+                                        return Err(error::unknown_parameter(
+                                            meta,
+                                            "Hash",
+                                            meta_name.as_str(),
+                                        ));
                                     }
 
                                     match meta {
                                         Meta::Path(_) => {
                                             if ignore_is_set {
-                                                panic::reset_parameter(meta_name.as_str());
+                                                return Err(error::reset_parameter(
+                                                    meta,
+                                                    meta_name.as_str(),
+                                                ));
                                             }
 
                                             ignore_is_set = true;
@@ -82,16 +110,21 @@ impl FieldAttributeBuilder {
                                             ignore = true;
                                         }
                                         _ => {
-                                            panic::parameter_incorrect_format(
+                                            return Err(error::parameter_incorrect_format(
+                                                meta,
                                                 meta_name.as_str(),
                                                 &correct_usage_for_ignore,
-                                            )
+                                            ))
                                         }
                                     }
                                 }
                                 "method" => {
                                     if !self.enable_impl {
-                                        panic::unknown_parameter("Hash", meta_name.as_str());
+                                        return Err(error::unknown_parameter(
+                                            meta,
+                                            "Hash",
+                                            meta_name.as_str(),
+                                        ));
                                     }
 
                                     match meta {
@@ -102,36 +135,44 @@ impl FieldAttributeBuilder {
                                                         match lit {
                                                             Lit::Str(s) => {
                                                                 if hash_method.is_some() {
-                                                                    panic::reset_parameter(
-                                                                        meta_name.as_str(),
+                                                                    return Err(
+                                                                        error::reset_parameter(
+                                                                            meta,
+                                                                            meta_name.as_str(),
+                                                                        ),
                                                                     );
                                                                 }
 
                                                                 let s =
-                                                                    create_path_string_from_lit_str(
-                                                                        s,
-                                                                    );
+                                                                    create_path_from_lit_str(s)?;
 
                                                                 if let Some(s) = s {
                                                                     hash_method = Some(s);
                                                                 } else {
-                                                                    panic::empty_parameter(
-                                                                        meta_name.as_str(),
+                                                                    return Err(
+                                                                        error::empty_parameter(
+                                                                            meta,
+                                                                            meta_name.as_str(),
+                                                                        ),
                                                                     );
                                                                 }
                                                             }
-                                                            _ => {
-                                                                panic::parameter_incorrect_format(
+                                                            _ => return Err(
+                                                                error::parameter_incorrect_format(
+                                                                    meta,
                                                                     meta_name.as_str(),
                                                                     &correct_usage_for_impl,
-                                                                )
-                                                            }
+                                                                ),
+                                                            ),
                                                         }
                                                     }
                                                     _ => {
-                                                        panic::parameter_incorrect_format(
-                                                            meta_name.as_str(),
-                                                            &correct_usage_for_impl,
+                                                        return Err(
+                                                            error::parameter_incorrect_format(
+                                                                meta,
+                                                                meta_name.as_str(),
+                                                                &correct_usage_for_impl,
+                                                            ),
                                                         )
                                                     }
                                                 }
@@ -143,36 +184,48 @@ impl FieldAttributeBuilder {
                                             match lit {
                                                 Lit::Str(s) => {
                                                     if hash_method.is_some() {
-                                                        panic::reset_parameter(meta_name.as_str());
+                                                        return Err(error::reset_parameter(
+                                                            meta,
+                                                            meta_name.as_str(),
+                                                        ));
                                                     }
 
-                                                    let s = create_path_string_from_lit_str(s);
+                                                    let s = create_path_from_lit_str(s)?;
 
                                                     if let Some(s) = s {
                                                         hash_method = Some(s);
                                                     } else {
-                                                        panic::empty_parameter(meta_name.as_str());
+                                                        return Err(error::empty_parameter(
+                                                            meta,
+                                                            meta_name.as_str(),
+                                                        ));
                                                     }
                                                 }
                                                 _ => {
-                                                    panic::parameter_incorrect_format(
+                                                    return Err(error::parameter_incorrect_format(
+                                                        meta,
                                                         meta_name.as_str(),
                                                         &correct_usage_for_impl,
-                                                    )
+                                                    ))
                                                 }
                                             }
                                         }
                                         _ => {
-                                            panic::parameter_incorrect_format(
+                                            return Err(error::parameter_incorrect_format(
+                                                meta,
                                                 meta_name.as_str(),
                                                 &correct_usage_for_impl,
-                                            )
+                                            ))
                                         }
                                     }
                                 }
                                 "trait" => {
                                     if !self.enable_impl {
-                                        panic::unknown_parameter("Hash", meta_name.as_str());
+                                        return Err(error::unknown_parameter(
+                                            meta,
+                                            "Hash",
+                                            meta_name.as_str(),
+                                        ));
                                     }
 
                                     match meta {
@@ -183,36 +236,44 @@ impl FieldAttributeBuilder {
                                                         match lit {
                                                             Lit::Str(s) => {
                                                                 if hash_trait.is_some() {
-                                                                    panic::reset_parameter(
-                                                                        meta_name.as_str(),
+                                                                    return Err(
+                                                                        error::reset_parameter(
+                                                                            meta,
+                                                                            meta_name.as_str(),
+                                                                        ),
                                                                     );
                                                                 }
 
                                                                 let s =
-                                                                    create_path_string_from_lit_str(
-                                                                        s,
-                                                                    );
+                                                                    create_path_from_lit_str(s)?;
 
                                                                 if let Some(s) = s {
                                                                     hash_trait = Some(s);
                                                                 } else {
-                                                                    panic::empty_parameter(
-                                                                        meta_name.as_str(),
+                                                                    return Err(
+                                                                        error::empty_parameter(
+                                                                            meta,
+                                                                            meta_name.as_str(),
+                                                                        ),
                                                                     );
                                                                 }
                                                             }
-                                                            _ => {
-                                                                panic::parameter_incorrect_format(
+                                                            _ => return Err(
+                                                                error::parameter_incorrect_format(
+                                                                    meta,
                                                                     meta_name.as_str(),
                                                                     &correct_usage_for_impl,
-                                                                )
-                                                            }
+                                                                ),
+                                                            ),
                                                         }
                                                     }
                                                     _ => {
-                                                        panic::parameter_incorrect_format(
-                                                            meta_name.as_str(),
-                                                            &correct_usage_for_impl,
+                                                        return Err(
+                                                            error::parameter_incorrect_format(
+                                                                meta,
+                                                                meta_name.as_str(),
+                                                                &correct_usage_for_impl,
+                                                            ),
                                                         )
                                                     }
                                                 }
@@ -224,65 +285,179 @@ impl FieldAttributeBuilder {
                                             match lit {
                                                 Lit::Str(s) => {
                                                     if hash_trait.is_some() {
-                                                        panic::reset_parameter(meta_name.as_str());
+                                                        return Err(error::reset_parameter(
+                                                            meta,
+                                                            meta_name.as_str(),
+                                                        ));
                                                     }
 
-                                                    let s = create_path_string_from_lit_str(s);
+                                                    let s = create_path_from_lit_str(s)?;
 
                                                     if let Some(s) = s {
                                                         hash_trait = Some(s);
                                                     } else {
-                                                        panic::empty_parameter(meta_name.as_str());
+                                                        return Err(error::empty_parameter(
+                                                            meta,
+                                                            meta_name.as_str(),
+                                                        ));
                                                     }
                                                 }
                                                 _ => {
-                                                    panic::parameter_incorrect_format(
+                                                    return Err(error::parameter_incorrect_format(
+                                                        meta,
                                                         meta_name.as_str(),
                                                         &correct_usage_for_impl,
-                                                    )
+                                                    ))
                                                 }
                                             }
                                         }
                                         _ => {
-                                            panic::parameter_incorrect_format(
+                                            return Err(error::parameter_incorrect_format(
+                                                meta,
                                                 meta_name.as_str(),
                                                 &correct_usage_for_impl,
-                                            )
+                                            ))
+                                        }
+                                    }
+                                }
+                                "bound" => {
+                                    if !self.enable_bound {
+                                        return Err(error::unknown_parameter(
+                                            meta,
+                                            "Hash",
+                                            meta_name.as_str(),
+                                        ));
+                                    }
+
+                                    match meta {
+                                        Meta::List(list) => {
+                                            for p in list.nested.iter() {
+                                                match p {
+                                                    NestedMeta::Lit(Lit::Str(s)) => {
+                                                        if !bound.is_empty() {
+                                                            return Err(error::reset_parameter(
+                                                                meta,
+                                                                meta_name.as_str(),
+                                                            ));
+                                                        }
+
+                                                        bound = match create_where_predicates_from_lit_str(s)? {
+                                                            Some(where_predicates) => where_predicates,
+                                                            None => {
+                                                                return Err(error::empty_parameter(
+                                                                    meta,
+                                                                    meta_name.as_str(),
+                                                                ))
+                                                            }
+                                                        };
+                                                    }
+                                                    _ => {
+                                                        return Err(
+                                                            error::parameter_incorrect_format(
+                                                                meta,
+                                                                meta_name.as_str(),
+                                                                &correct_usage_for_bound,
+                                                            ),
+                                                        )
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        Meta::NameValue(named_value) => {
+                                            let lit = &named_value.lit;
+
+                                            match lit {
+                                                Lit::Str(s) => {
+                                                    if !bound.is_empty() {
+                                                        return Err(error::reset_parameter(
+                                                            meta,
+                                                            meta_name.as_str(),
+                                                        ));
+                                                    }
+
+                                                    bound =
+                                                        match create_where_predicates_from_lit_str(
+                                                            s,
+                                                        )? {
+                                                            Some(where_predicates) => {
+                                                                where_predicates
+                                                            }
+                                                            None => {
+                                                                return Err(error::empty_parameter(
+                                                                    meta,
+                                                                    meta_name.as_str(),
+                                                                ))
+                                                            }
+                                                        };
+                                                }
+                                                _ => {
+                                                    return Err(error::parameter_incorrect_format(
+                                                        meta,
+                                                        meta_name.as_str(),
+                                                        &correct_usage_for_bound,
+                                                    ))
+                                                }
+                                            }
+                                        }
+                                        _ => {
+                                            return Err(error::parameter_incorrect_format(
+                                                meta,
+                                                meta_name.as_str(),
+                                                &correct_usage_for_bound,
+                                            ))
                                         }
                                     }
                                 }
-                                _ => panic::unknown_parameter("Hash", meta_name.as_str()),
+                                _ => {
+                                    return Err(error::unknown_parameter(
+                                        meta,
+                                        "Hash",
+                                        meta_name.as_str(),
+                                    ))
+                                }
                             }
                         }
                         _ => {
-                            panic::attribute_incorrect_format(
+                            return Err(error::attribute_incorrect_format(
+                                meta,
                                 "Hash",
                                 &correct_usage_for_hash_attribute,
-                            )
+                            ))
                         }
                     }
                 }
             }
-            _ => panic::attribute_incorrect_format("Hash", &correct_usage_for_hash_attribute),
+            _ => {
+                return Err(error::attribute_incorrect_format(
+                    meta,
+                    "Hash",
+                    &correct_usage_for_hash_attribute,
+                ))
+            }
         }
 
-        if hash_trait.is_some() && hash_method.is_none() {
-            hash_method = Some("hash".to_string());
+        if let (Some(hash_trait), None) = (&hash_trait, &hash_method) {
+            hash_method = Some(Path::from(format_ident!("hash", span = hash_trait.span())));
         }
 
-        FieldAttribute {
+        Ok(FieldAttribute {
             ignore,
             hash_method,
             hash_trait,
-        }
+            bound,
+        })
     }
 
     #[allow(clippy::wrong_self_convention)]
-    pub fn from_attributes(self, attributes: &[Attribute], traits: &[Trait]) -> FieldAttribute {
+    pub fn from_attributes(
+        self,
+        attributes: &[Attribute],
+        traits: &[Trait],
+    ) -> Result<FieldAttribute, syn::Error> {
         let mut result = None;
 
         for attribute in attributes.iter() {
-            let meta = attribute.parse_meta().unwrap();
+            let meta = parse_educe_meta(attribute)?;
 
             let meta_name = meta.path().into_token_stream().to_string();
 
@@ -292,35 +467,34 @@ impl FieldAttributeBuilder {
                         for p in list.nested.iter() {
                             match p {
                                 NestedMeta::Meta(meta) => {
-                                    let meta_name = meta.path().into_token_stream().to_string();
-
-                                    let t = Trait::from_str(meta_name);
+                                    let t = Trait::from_path(meta.path())?;
 
                                     if traits.binary_search(&t).is_err() {
-                                        panic::trait_not_used(t);
+                                        return Err(error::trait_not_used(meta, t));
                                     }
 
                                     if t == Trait::Hash {
                                         if result.is_some() {
-                                            panic::reuse_a_trait(t);
+                                            return Err(error::reuse_a_trait(meta, t));
                                         }
 
-                                        result = Some(self.from_hash_meta(&meta));
+                                        result = Some(self.from_hash_meta(&meta)?);
                                     }
                                 }
-                                _ => panic::educe_format_incorrect(),
+                                _ => return Err(error::educe_format_incorrect(attribute)),
                             }
                         }
                     }
-                    _ => panic::educe_format_incorrect(),
+                    _ => return Err(error::educe_format_incorrect(attribute)),
                 }
             }
         }
 
-        result.unwrap_or(FieldAttribute {
+        Ok(result.unwrap_or(FieldAttribute {
             ignore: false,
             hash_method: None,
             hash_trait: None,
-        })
+            bound: Punctuated::new(),
+        }))
     }
 }
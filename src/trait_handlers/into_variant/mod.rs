@@ -0,0 +1,191 @@
+mod models;
+
+use super::{
+    create_field_binding, create_field_member, create_hygienic_ident,
+    create_snake_case_string_from_ident, create_variant_name_string, TraitHandler,
+};
+
+use crate::error::{self, Errors};
+use crate::proc_macro2::TokenStream;
+use crate::syn::{ext::IdentExt, Data, DeriveInput, Fields, Meta};
+use crate::Trait;
+
+use models::TypeAttributeBuilder;
+
+pub struct IntoVariantHandler;
+
+impl TraitHandler for IntoVariantHandler {
+    fn trait_meta_handler(
+        ast: &DeriveInput,
+        tokens: &mut TokenStream,
+        traits: &[Trait],
+        meta: &Meta,
+    ) -> Result<(), syn::Error> {
+        let mut errors = Errors::new();
+
+        let type_attribute = TypeAttributeBuilder {
+            enable_flag: true,
+            enable_name: false,
+            enable_ignore: false,
+            enable_vis: true,
+        }
+        .from_into_variant_meta(meta)?;
+
+        let vis = type_attribute.vis.unwrap_or_else(|| syn::parse2(quote!(pub)).unwrap());
+
+        let ident = &ast.ident;
+
+        let msg = create_hygienic_ident("msg");
+
+        let mut methods = TokenStream::new();
+
+        match &ast.data {
+            Data::Enum(data) => {
+                let mut variant_patterns = Vec::new();
+                let mut variant_names = Vec::new();
+
+                for variant in data.variants.iter() {
+                    let variant_ident = &variant.ident;
+
+                    variant_patterns.push(quote!(Self::#variant_ident { .. }));
+                    variant_names.push(create_variant_name_string(variant, traits));
+                }
+
+                for (index, variant) in data.variants.iter().enumerate() {
+                    let variant_attribute = match errors.handle(
+                        TypeAttributeBuilder {
+                            enable_flag: false,
+                            enable_name: true,
+                            enable_ignore: true,
+                            enable_vis: true,
+                        }
+                        .from_attributes(&variant.attrs, traits),
+                    ) {
+                        Some(variant_attribute) => variant_attribute,
+                        None => continue,
+                    };
+
+                    if variant_attribute.ignore {
+                        continue;
+                    }
+
+                    let variant_ident = &variant.ident;
+
+                    let (pattern, bindings, types) = match &variant.fields {
+                        Fields::Unit => continue,
+                        Fields::Named(_) | Fields::Unnamed(_) => {
+                            let mut members = Vec::new();
+                            let mut bindings = Vec::new();
+                            let mut types = Vec::new();
+
+                            for (index, field) in variant.fields.iter().enumerate() {
+                                members.push(create_field_member(index, field));
+                                bindings.push(create_field_binding(index));
+                                types.push(&field.ty);
+                            }
+
+                            (
+                                quote!(Self::#variant_ident { #(#members: #bindings),* }),
+                                bindings,
+                                types,
+                            )
+                        }
+                    };
+
+                    let name = match variant_attribute.name {
+                        Some(name) => name.unraw().to_string(),
+                        None => create_snake_case_string_from_ident(variant_ident),
+                    };
+
+                    let method_name_into = format_ident!("into_{}", name);
+                    let method_name_unwrap = format_ident!("unwrap_{}", name);
+                    let method_name_expect = format_ident!("expect_{}", name);
+
+                    let vis = variant_attribute.vis.as_ref().unwrap_or(&vis);
+
+                    let (return_type, return_value) = if types.len() == 1 {
+                        let ty = types[0];
+                        let binding = &bindings[0];
+
+                        (quote!(#ty), quote!(#binding))
+                    } else {
+                        (quote!((#(#types),*)), quote!((#(#bindings),*)))
+                    };
+
+                    let expected_name = &variant_names[index];
+
+                    let mut unwrap_arms = TokenStream::new();
+                    let mut expect_arms = TokenStream::new();
+
+                    for (other_index, other_pattern) in variant_patterns.iter().enumerate() {
+                        if other_index == index {
+                            continue;
+                        }
+
+                        let unwrap_message = format!(
+                            "called `{}()` on a `{}` value, expected `{}`",
+                            method_name_unwrap, variant_names[other_index], expected_name
+                        );
+
+                        let expect_message = format!(
+                            "expected `{}`, found `{}`",
+                            expected_name, variant_names[other_index]
+                        );
+
+                        unwrap_arms.extend(quote! {
+                            #other_pattern => ::core::panic!("{}", #unwrap_message),
+                        });
+
+                        expect_arms.extend(quote! {
+                            #other_pattern => ::core::panic!("{}: {}", #msg, #expect_message),
+                        });
+                    }
+
+                    methods.extend(quote! {
+                        #[inline]
+                        #[allow(unreachable_patterns)]
+                        #vis fn #method_name_into(self) -> ::core::result::Result<#return_type, Self> {
+                            match self {
+                                #pattern => ::core::result::Result::Ok(#return_value),
+                                _ => ::core::result::Result::Err(self),
+                            }
+                        }
+
+                        #[inline]
+                        #[track_caller]
+                        #vis fn #method_name_unwrap(self) -> #return_type {
+                            match self {
+                                #pattern => #return_value,
+                                #unwrap_arms
+                            }
+                        }
+
+                        #[inline]
+                        #[track_caller]
+                        #vis fn #method_name_expect(self, #msg: &::core::primitive::str) -> #return_type {
+                            match self {
+                                #pattern => #return_value,
+                                #expect_arms
+                            }
+                        }
+                    });
+                }
+            }
+            _ => return Err(error::trait_only_support_enum(meta, Trait::IntoVariant)),
+        }
+
+        let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+        let into_variant_impl = quote! {
+            impl #impl_generics #ident #ty_generics #where_clause {
+                #methods
+            }
+        };
+
+        errors.finish()?;
+
+        tokens.extend(into_variant_impl);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,94 @@
+mod models;
+
+use super::{create_snake_case_string_from_ident, TraitHandler};
+
+use crate::error::{self, Errors};
+use crate::proc_macro2::TokenStream;
+use crate::syn::{Data, DeriveInput, Meta};
+use crate::Trait;
+
+use models::TypeAttributeBuilder;
+
+pub struct IsVariantHandler;
+
+impl TraitHandler for IsVariantHandler {
+    fn trait_meta_handler(
+        ast: &DeriveInput,
+        tokens: &mut TokenStream,
+        traits: &[Trait],
+        meta: &Meta,
+    ) -> Result<(), syn::Error> {
+        let mut errors = Errors::new();
+
+        TypeAttributeBuilder {
+            enable_flag: true,
+            enable_name: false,
+            enable_ignore: false,
+        }
+        .from_is_variant_meta(meta)?;
+
+        let ident = &ast.ident;
+
+        let mut methods = TokenStream::new();
+
+        match &ast.data {
+            Data::Enum(data) => {
+                for variant in data.variants.iter() {
+                    let variant_attribute = match errors.handle(
+                        TypeAttributeBuilder {
+                            enable_flag: false,
+                            enable_name: true,
+                            enable_ignore: true,
+                        }
+                        .from_attributes(&variant.attrs, traits),
+                    ) {
+                        Some(variant_attribute) => variant_attribute,
+                        None => continue,
+                    };
+
+                    if variant_attribute.ignore {
+                        continue;
+                    }
+
+                    let variant_ident = &variant.ident;
+
+                    let method_name = match variant_attribute.name {
+                        Some(name) => format_ident!("is_{}", name),
+                        None => {
+                            format_ident!(
+                                "is_{}",
+                                create_snake_case_string_from_ident(variant_ident)
+                            )
+                        }
+                    };
+
+                    methods.extend(quote! {
+                        #[inline]
+                        #[allow(unreachable_patterns)]
+                        pub fn #method_name(&self) -> ::core::primitive::bool {
+                            match self {
+                                Self::#variant_ident { .. } => true,
+                                _ => false,
+                            }
+                        }
+                    });
+                }
+            }
+            _ => return Err(error::trait_only_support_enum(meta, Trait::IsVariant)),
+        }
+
+        let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+        let is_variant_impl = quote! {
+            impl #impl_generics #ident #ty_generics #where_clause {
+                #methods
+            }
+        };
+
+        errors.finish()?;
+
+        tokens.extend(is_variant_impl);
+
+        Ok(())
+    }
+}
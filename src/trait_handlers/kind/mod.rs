@@ -0,0 +1,90 @@
+mod models;
+
+use super::TraitHandler;
+
+use crate::error;
+use crate::proc_macro2::TokenStream;
+use crate::syn::{Data, DeriveInput, Meta};
+use crate::Trait;
+
+use models::TypeAttributeBuilder;
+
+pub struct KindHandler;
+
+impl TraitHandler for KindHandler {
+    fn trait_meta_handler(
+        ast: &DeriveInput,
+        tokens: &mut TokenStream,
+        _traits: &[Trait],
+        meta: &Meta,
+    ) -> Result<(), syn::Error> {
+        let type_attribute = TypeAttributeBuilder {
+            enable_flag: true,
+            enable_name: true,
+            enable_derive: true,
+            enable_vis: true,
+        }
+        .from_kind_meta(meta)?;
+
+        let ident = &ast.ident;
+
+        let kind_ident = type_attribute.name.unwrap_or_else(|| format_ident!("{}Kind", ident));
+
+        let vis = type_attribute.vis.as_ref().unwrap_or(&ast.vis);
+
+        let mut kind_variants = Vec::new();
+        let mut match_arms = TokenStream::new();
+
+        match &ast.data {
+            Data::Enum(data) => {
+                for variant in data.variants.iter() {
+                    let variant_ident = &variant.ident;
+
+                    kind_variants.push(variant_ident);
+
+                    match_arms.extend(quote! {
+                        Self::#variant_ident { .. } => #kind_ident::#variant_ident,
+                    });
+                }
+            }
+            _ => return Err(error::trait_only_support_enum(meta, Trait::Kind)),
+        }
+
+        let kind_enum = quote! {
+            #vis enum #kind_ident {
+                #(#kind_variants),*
+            }
+        };
+
+        if !type_attribute.derive.is_empty() {
+            let derive = &type_attribute.derive;
+
+            let kind_ast: DeriveInput = syn::parse2(quote! {
+                #[educe(#(#derive),*)]
+                #kind_enum
+            })
+            .unwrap();
+
+            tokens.extend(crate::derive_input_handler(kind_ast));
+        }
+
+        let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+        let kind_impl = quote! {
+            #kind_enum
+
+            impl #impl_generics #ident #ty_generics #where_clause {
+                #[inline]
+                #vis fn kind(&self) -> #kind_ident {
+                    match *self {
+                        #match_arms
+                    }
+                }
+            }
+        };
+
+        tokens.extend(kind_impl);
+
+        Ok(())
+    }
+}
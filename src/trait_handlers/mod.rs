@@ -1,9 +1,15 @@
 #![cfg_attr(not(feature = "default"), allow(dead_code))]
 
+#[cfg(feature = "AsVariant")]
+pub mod as_variant;
+#[cfg(feature = "Builder")]
+pub mod builder;
 #[cfg(feature = "Clone")]
 pub mod clone;
 #[cfg(feature = "Copy")]
 pub mod copy;
+#[cfg(feature = "Cycle")]
+pub mod cycle;
 #[cfg(feature = "Debug")]
 pub mod debug;
 #[cfg(feature = "Default")]
@@ -12,24 +18,50 @@ pub mod default;
 pub mod deref;
 #[cfg(feature = "DerefMut")]
 pub mod deref_mut;
+#[cfg(feature = "EnumMap")]
+pub mod enum_map;
+#[cfg(feature = "EnumSet")]
+pub mod enum_set;
 #[cfg(feature = "Eq")]
 pub mod eq;
+#[cfg(feature = "Get")]
+pub mod get;
 #[cfg(feature = "Hash")]
 pub mod hash;
+#[cfg(feature = "IntoVariant")]
+pub mod into_variant;
+#[cfg(feature = "IsVariant")]
+pub mod is_variant;
+#[cfg(feature = "Kind")]
+pub mod kind;
+#[cfg(feature = "New")]
+pub mod new;
 #[cfg(feature = "Ord")]
 pub mod ord;
 #[cfg(feature = "PartialEq")]
 pub mod partial_eq;
 #[cfg(feature = "PartialOrd")]
 pub mod partial_ord;
+#[cfg(feature = "Set")]
+pub mod set;
+#[cfg(feature = "Variants")]
+pub mod variants;
 
-use std::str::FromStr;
+use std::cell::RefCell;
+use std::collections::HashSet;
 
-use crate::proc_macro2::TokenStream;
+use crate::error::{self, Errors};
+use crate::proc_macro2::{Delimiter, Group, Literal, Span, TokenStream, TokenTree};
 use crate::quote::ToTokens;
 use crate::syn::{
-    self, punctuated::Punctuated, token::Comma, DeriveInput, Expr, GenericParam, LitStr, Meta,
-    Path, WhereClause, WherePredicate,
+    self,
+    ext::IdentExt,
+    parse::{ParseStream, Parser},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    token::Comma,
+    Attribute, Data, DeriveInput, Expr, Field, GenericParam, Generics, Ident, Index, Lit, LitStr,
+    Member, Meta, NestedMeta, Path, Type, TypeParam, Variant, Visibility, WherePredicate,
 };
 use crate::Trait;
 
@@ -39,74 +71,539 @@ pub trait TraitHandler {
         tokens: &mut TokenStream,
         traits: &[Trait],
         meta: &Meta,
-    );
+    ) -> Result<(), syn::Error>;
 }
 
+/// Parameters whose values can also be written as tokens, like `bound(T: Debug)`.
+const TYPED_PARAMETERS: [&str; 5] = ["bound", "crate", "expression", "method", "trait"];
+
+/// The suffix of a string literal standing in for a typed value, followed by its index in
+/// `TYPED_VALUES`.
+const TYPED_VALUE_SUFFIX: &str = "__educe_typed_";
+
+thread_local! {
+    /// The original groups of the typed values met while expanding the current derive.
+    static TYPED_VALUES: RefCell<Vec<Group>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Parses an `educe` attribute. The typed values of `TYPED_PARAMETERS` are turned into string
+/// literals spanning the original tokens, so the models only need to handle the quoted forms. The
+/// original tokens are kept aside, so they are parsed with their own spans.
 #[inline]
-pub fn create_path_from_lit_str(s: &LitStr) -> Option<Path> {
-    let s = s.value();
+pub fn parse_educe_meta(attribute: &Attribute) -> Result<Meta, syn::Error> {
+    let mut attribute = attribute.clone();
 
-    let s = s.trim();
+    attribute.tokens = quote_typed_parameters(attribute.tokens);
 
-    if s.is_empty() {
-        None
-    } else {
-        let tokens = TokenStream::from_str(s).unwrap();
+    attribute.parse_meta()
+}
+
+/// Drops the typed values kept by `parse_educe_meta`, once the derive has been expanded.
+#[inline]
+pub fn clear_typed_values() {
+    TYPED_VALUES.with(|typed_values| typed_values.borrow_mut().clear());
+}
+
+fn quote_typed_parameters(tokens: TokenStream) -> TokenStream {
+    let mut output = TokenStream::new();
+    let mut is_typed_parameter = false;
+
+    for tree in tokens {
+        let tree = match tree {
+            TokenTree::Group(group) => {
+                let stream = group.stream();
+
+                let stream = if is_typed_parameter
+                    && group.delimiter() == Delimiter::Parenthesis
+                    && syn::parse2::<LitStr>(stream.clone()).is_err()
+                {
+                    let index = TYPED_VALUES.with(|typed_values| {
+                        let mut typed_values = typed_values.borrow_mut();
+
+                        typed_values.push(group.clone());
+
+                        typed_values.len() - 1
+                    });
+
+                    let literal = Literal::string(&stream.to_string());
+
+                    let mut literal: Literal =
+                        format!("{}{}{}", literal, TYPED_VALUE_SUFFIX, index).parse().unwrap();
+
+                    literal.set_span(group.span());
+
+                    TokenTree::Literal(literal).into()
+                } else {
+                    quote_typed_parameters(stream)
+                };
 
-        Some(syn::parse2(tokens).unwrap())
+                let mut new_group = Group::new(group.delimiter(), stream);
+
+                new_group.set_span(group.span());
+
+                TokenTree::Group(new_group)
+            }
+            tree => tree,
+        };
+
+        is_typed_parameter = match &tree {
+            TokenTree::Ident(ident) => TYPED_PARAMETERS.iter().any(|parameter| ident == parameter),
+            _ => false,
+        };
+
+        output.extend(Some(tree));
+    }
+
+    output
+}
+
+/// Traits which `#[educe(skip)]` on a field ignores, when they are used.
+const SKIPPED_TRAITS: [&str; 4] = ["PartialEq", "PartialOrd", "Ord", "Hash"];
+
+/// Expands the field shorthands `#[educe(ignore(Trait, ...))]` and `#[educe(skip)]` into
+/// `#[educe(Trait(ignore), ...)]`, so the models of every trait only see the metas of traits.
+pub fn expand_field_shorthands(ast: &mut DeriveInput, traits: &[Trait]) -> Result<(), syn::Error> {
+    let mut errors = Errors::new();
+
+    let fields: Vec<&mut Field> = match &mut ast.data {
+        Data::Struct(data) => data.fields.iter_mut().collect(),
+        Data::Enum(data) => {
+            data.variants.iter_mut().flat_map(|variant| variant.fields.iter_mut()).collect()
+        }
+        Data::Union(data) => data.fields.named.iter_mut().collect(),
+    };
+
+    for field in fields {
+        errors.handle(expand_shorthands_of_field(field, traits));
     }
+
+    errors.finish()
 }
 
 #[inline]
-pub fn create_path_string_from_lit_str(s: &LitStr) -> Option<String> {
-    create_path_from_lit_str(s).map(|path| path.into_token_stream().to_string().replace(" ", ""))
+fn is_field_shorthand(meta: &Meta) -> bool {
+    meta.path().is_ident("ignore") || meta.path().is_ident("skip")
+}
+
+fn expand_shorthands_of_field(field: &mut Field, traits: &[Trait]) -> Result<(), syn::Error> {
+    let mut errors = Errors::new();
+
+    let mut lists = Vec::new();
+    let mut trait_names = Vec::new();
+
+    for (index, attribute) in field.attrs.iter().enumerate() {
+        if !attribute.path.is_ident("educe") {
+            continue;
+        }
+
+        // A malformed attribute is left to the models, which report it.
+        if let Ok(Meta::List(list)) = parse_educe_meta(attribute) {
+            for p in list.nested.iter() {
+                if let NestedMeta::Meta(meta) = p {
+                    if !is_field_shorthand(meta) {
+                        trait_names.push(meta.path().into_token_stream().to_string());
+                    }
+                }
+            }
+
+            lists.push((index, list));
+        }
+    }
+
+    for (index, list) in lists {
+        let has_shorthand = list.nested.iter().any(|p| {
+            match p {
+                NestedMeta::Meta(meta) => is_field_shorthand(meta),
+                NestedMeta::Lit(_) => false,
+            }
+        });
+
+        if !has_shorthand {
+            continue;
+        }
+
+        let mut nested: Punctuated<NestedMeta, Comma> = Punctuated::new();
+
+        for p in list.nested {
+            let meta = match p {
+                NestedMeta::Meta(meta) if is_field_shorthand(&meta) => meta,
+                p => {
+                    nested.push(p);
+
+                    continue;
+                }
+            };
+
+            let paths = match errors.handle(create_shorthand_trait_paths(&meta, traits)) {
+                Some(paths) => paths,
+                None => continue,
+            };
+
+            for path in paths {
+                let t = match errors.handle(Trait::from_path(&path)) {
+                    Some(t) => t,
+                    None => continue,
+                };
+
+                let trait_name = path.to_token_stream().to_string();
+
+                if trait_names.contains(&trait_name) {
+                    errors.push(error::reuse_a_trait(&path, t));
+
+                    continue;
+                }
+
+                trait_names.push(trait_name);
+
+                nested.push(syn::parse2(quote_spanned!(path.span() => #path(ignore))).unwrap());
+            }
+        }
+
+        field.attrs[index].tokens = quote!((#nested));
+    }
+
+    errors.finish()
+}
+
+/// The traits a field shorthand ignores.
+fn create_shorthand_trait_paths(meta: &Meta, traits: &[Trait]) -> Result<Vec<Path>, syn::Error> {
+    if meta.path().is_ident("skip") {
+        if let Meta::Path(path) = meta {
+            let paths = SKIPPED_TRAITS
+                .iter()
+                .map(|name| Path::from(Ident::new(name, path.span())))
+                .filter(|path| {
+                    match Trait::from_path(path) {
+                        Ok(t) => traits.binary_search(&t).is_ok(),
+                        Err(_) => false,
+                    }
+                })
+                .collect();
+
+            return Ok(paths);
+        }
+
+        return Err(error::parameter_incorrect_format(meta, "skip", &[stringify!(
+            #[educe(skip)]
+        )]));
+    }
+
+    let correct_usage_for_ignore = [stringify!(#[educe(ignore(PartialEq, Hash))])];
+
+    match meta {
+        Meta::List(list) => {
+            let mut paths = Vec::with_capacity(list.nested.len());
+
+            for p in list.nested.iter() {
+                match p {
+                    NestedMeta::Meta(Meta::Path(path)) => paths.push(path.clone()),
+                    _ => {
+                        return Err(error::parameter_incorrect_format(
+                            p,
+                            "ignore",
+                            &correct_usage_for_ignore,
+                        ))
+                    }
+                }
+            }
+
+            if paths.is_empty() {
+                return Err(error::empty_parameter(meta, "ignore"));
+            }
+
+            Ok(paths)
+        }
+        _ => Err(error::parameter_incorrect_format(meta, "ignore", &correct_usage_for_ignore)),
+    }
+}
+
+/// Parses the value of a string literal. A typed value is parsed from its original tokens, and
+/// otherwise an error from parsing the content is pointed at the literal.
+fn parse_lit_str<T>(
+    s: &LitStr,
+    parser: fn(ParseStream) -> Result<T, syn::Error>,
+) -> Result<T, syn::Error> {
+    let typed_value = s
+        .suffix()
+        .strip_prefix(TYPED_VALUE_SUFFIX)
+        .and_then(|index| index.parse::<usize>().ok())
+        .and_then(|index| {
+            TYPED_VALUES.with(|typed_values| typed_values.borrow().get(index).cloned())
+        });
+
+    match typed_value {
+        Some(group) => {
+            let parser = |input: ParseStream| {
+                let content;
+
+                syn::parenthesized!(content in input);
+
+                parser(&content)
+            };
+
+            parser.parse2(group.into_token_stream())
+        }
+        None => s.parse_with(parser).map_err(|error| syn::Error::new(s.span(), error)),
+    }
 }
 
 #[inline]
-pub fn create_expr_from_lit_str(s: &LitStr) -> Option<Expr> {
-    let s = s.value();
+pub fn create_path_from_lit_str(s: &LitStr) -> Result<Option<Path>, syn::Error> {
+    if s.value().trim().is_empty() {
+        Ok(None)
+    } else {
+        parse_lit_str(s, syn::parse::Parse::parse).map(Some)
+    }
+}
 
-    let s = s.trim();
+#[inline]
+pub fn create_path_string_from_lit_str(s: &LitStr) -> Result<Option<String>, syn::Error> {
+    create_path_from_lit_str(s)
+        .map(|path| path.map(|path| path.into_token_stream().to_string().replace(" ", "")))
+}
 
-    if s.is_empty() {
-        None
+#[inline]
+pub fn create_ident_from_lit_str(s: &LitStr) -> Result<Option<Ident>, syn::Error> {
+    if s.value().trim().is_empty() {
+        Ok(None)
     } else {
-        let tokens = TokenStream::from_str(s).unwrap();
+        parse_lit_str(s, syn::parse::Parse::parse).map(Some)
+    }
+}
 
-        Some(syn::parse2(tokens).unwrap())
+#[inline]
+pub fn create_visibility_from_lit_str(s: &LitStr) -> Result<Visibility, syn::Error> {
+    if s.value().trim().is_empty() {
+        Ok(Visibility::Inherited)
+    } else {
+        parse_lit_str(s, syn::parse::Parse::parse)
     }
 }
 
 #[inline]
-pub fn create_expr_string_from_lit_str(s: &LitStr) -> Option<String> {
-    create_expr_from_lit_str(s).map(|expr| expr.into_token_stream().to_string().replace(" ", ""))
+pub fn create_expr_from_lit_str(s: &LitStr) -> Result<Option<Expr>, syn::Error> {
+    if s.value().trim().is_empty() {
+        Ok(None)
+    } else {
+        parse_lit_str(s, syn::parse::Parse::parse).map(Some)
+    }
 }
 
 #[inline]
 pub fn create_where_predicates_from_lit_str(
     s: &LitStr,
-) -> Option<Punctuated<WherePredicate, Comma>> {
-    let s = s.value();
+) -> Result<Option<Punctuated<WherePredicate, Comma>>, syn::Error> {
+    if s.value().trim().is_empty() {
+        Ok(None)
+    } else {
+        parse_lit_str(s, Punctuated::<WherePredicate, Comma>::parse_terminated).map(Some)
+    }
+}
+
+#[inline]
+pub fn create_idents_from_lit_str(
+    s: &LitStr,
+) -> Result<Option<Punctuated<Ident, Comma>>, syn::Error> {
+    if s.value().trim().is_empty() {
+        Ok(None)
+    } else {
+        parse_lit_str(s, Punctuated::<Ident, Comma>::parse_terminated).map(Some)
+    }
+}
 
-    let s = s.trim();
+#[inline]
+pub fn create_where_predicates_from_generic_parameters(
+    p: &Punctuated<GenericParam, Comma>,
+    bound_trait: &Path,
+) -> Punctuated<WherePredicate, Comma> {
+    let mut where_predicates = Punctuated::new();
 
-    if s.is_empty() {
-        None
+    for param in p.iter() {
+        if let GenericParam::Type(typ) = param {
+            let ident = &typ.ident;
+
+            where_predicates.push(syn::parse2(quote! { #ident: #bound_trait }).unwrap());
+        }
+    }
+
+    where_predicates
+}
+
+/// The member used to access a field through `self`, like `self.f1` or `self.0`.
+#[inline]
+pub fn create_field_member(index: usize, field: &Field) -> Member {
+    match field.ident.as_ref() {
+        Some(ident) => Member::Named(ident.clone()),
+        None => {
+            Member::Unnamed(Index {
+                index: index as u32,
+                span: field.ty.span(),
+            })
+        }
+    }
+}
+
+/// Whether the type has `#[repr(packed)]` or `#[repr(packed(N))]`, whose fields may be unaligned
+/// and therefore cannot be borrowed.
+pub fn is_packed(ast: &DeriveInput) -> bool {
+    ast.attrs.iter().any(|attribute| {
+        if !attribute.path.is_ident("repr") {
+            return false;
+        }
+
+        match attribute.parse_meta() {
+            Ok(Meta::List(list)) => {
+                list.nested.iter().any(|p| {
+                    match p {
+                        NestedMeta::Meta(meta) => meta.path().is_ident("packed"),
+                        NestedMeta::Lit(_) => false,
+                    }
+                })
+            }
+            _ => false,
+        }
+    })
+}
+
+/// A reference to a field, like `&self.f1`. A field of a packed struct is copied out first, like
+/// `&{ self.f1 }`.
+#[inline]
+pub fn create_field_reference<T: ToTokens>(
+    receiver: T,
+    field_member: &Member,
+    packed: bool,
+) -> TokenStream {
+    if packed {
+        quote!(&{ #receiver.#field_member })
     } else {
-        let s = format!("where {}", s);
+        quote!(&#receiver.#field_member)
+    }
+}
 
-        let tokens = TokenStream::from_str(&s).unwrap();
+/// Requires a used field of a packed struct to be `Copy`. The predicate carries the span of the
+/// field type, so a field which cannot be copied is reported where it is declared.
+#[inline]
+pub fn create_packed_field_where_predicate(ty: &Type) -> WherePredicate {
+    syn::parse2(quote_spanned!(ty.span() => #ty: ::core::marker::Copy)).unwrap()
+}
 
-        let where_clause: WhereClause = syn::parse2(tokens).unwrap();
+/// The types of all fields of a struct, of all variants of an enum, or of a union.
+pub fn create_field_types(data: &Data) -> Vec<&Type> {
+    match data {
+        Data::Struct(data) => data.fields.iter().map(|field| &field.ty).collect(),
+        Data::Enum(data) => {
+            data.variants
+                .iter()
+                .flat_map(|variant| variant.fields.iter().map(|field| &field.ty))
+                .collect()
+        }
+        Data::Union(data) => data.fields.named.iter().map(|field| &field.ty).collect(),
+    }
+}
 
-        Some(where_clause.predicates)
+/// Creates an identifier for a local variable or a parameter of a generated method. Its
+/// `mixed_site` span keeps it apart from user code, so neither a field named `other` nor a method
+/// path like `state` can be shadowed by it.
+#[inline]
+pub fn create_hygienic_ident(name: &str) -> Ident {
+    Ident::new(name, Span::mixed_site())
+}
+
+/// Creates an identifier starting with `name` which appears nowhere in the derive input, for the
+/// generic parameters of generated methods, which would otherwise shadow user types.
+pub fn create_unique_ident(ast: &DeriveInput, name: &str) -> Ident {
+    fn collect_words(tokens: TokenStream, words: &mut HashSet<String>) {
+        for token in tokens {
+            match token {
+                TokenTree::Ident(ident) => {
+                    words.insert(ident.unraw().to_string());
+                }
+                TokenTree::Literal(literal) => {
+                    // paths in attributes, like `bound = "T: H"`
+                    for word in
+                        literal.to_string().split(|c: char| !(c.is_alphanumeric() || c == '_'))
+                    {
+                        words.insert(word.to_string());
+                    }
+                }
+                TokenTree::Group(group) => collect_words(group.stream(), words),
+                TokenTree::Punct(_) => (),
+            }
+        }
     }
+
+    let mut words = HashSet::new();
+
+    collect_words(ast.to_token_stream(), &mut words);
+
+    let mut name = String::from(name);
+
+    while words.contains(&name) {
+        name.push('_');
+    }
+
+    Ident::new(&name, Span::call_site())
 }
 
+/// The identifier a field is bound to in a pattern, like `_0`.
 #[inline]
-pub fn create_where_predicates_from_generic_parameters(
+pub fn create_field_binding(index: usize) -> Ident {
+    create_hygienic_ident(&format!("_{}", index))
+}
+
+/// The identifier a field of the second value is bound to in a pattern, like `_other_0`.
+#[inline]
+pub fn create_field_binding_2(index: usize) -> Ident {
+    create_hygienic_ident(&format!("_other_{}", index))
+}
+
+/// Pairs each of `rhs` with its counterpart on the right-hand side, like `__RhsT` for `T`, which
+/// appears nowhere in the derive input.
+#[inline]
+pub fn create_rhs_idents(ast: &DeriveInput, rhs: Vec<Ident>) -> Vec<(Ident, Ident)> {
+    rhs.into_iter()
+        .map(|ident| {
+            let rhs_ident = create_unique_ident(ast, &format!("__Rhs{}", ident.unraw()));
+
+            (ident, rhs_ident)
+        })
+        .collect()
+}
+
+#[inline]
+fn find_rhs_ident<'a>(rhs: &'a [(Ident, Ident)], ident: &Ident) -> Option<&'a Ident> {
+    rhs.iter().find(|(lhs_ident, _)| lhs_ident == ident).map(|(_, rhs_ident)| rhs_ident)
+}
+
+/// Creates an `A: Trait<B>` predicate for each of `rhs` which is mentioned by the field types
+/// compared with `Trait`. Fields compared by a custom method or ignored are not in `types`.
+#[inline]
+pub fn create_where_predicates_from_rhs(
+    types: &[&Type],
+    bound_trait: &Path,
+    rhs: &[(Ident, Ident)],
+) -> Punctuated<WherePredicate, Comma> {
+    let mut where_predicates = Punctuated::new();
+
+    for (ident, rhs_ident) in rhs {
+        if !types
+            .iter()
+            .any(|ty| !is_phantom_data(ty) && contains_ident(ty.to_token_stream(), &[ident]))
+        {
+            continue;
+        }
+
+        where_predicates.push(syn::parse2(quote! { #ident: #bound_trait<#rhs_ident> }).unwrap());
+    }
+
+    where_predicates
+}
+
+#[inline]
+pub fn create_where_predicates_from_generic_parameters_with_rhs(
     p: &Punctuated<GenericParam, Comma>,
     bound_trait: &Path,
+    rhs: &[(Ident, Ident)],
 ) -> Punctuated<WherePredicate, Comma> {
     let mut where_predicates = Punctuated::new();
 
@@ -114,9 +611,364 @@ pub fn create_where_predicates_from_generic_parameters(
         if let GenericParam::Type(typ) = param {
             let ident = &typ.ident;
 
-            where_predicates.push(syn::parse2(quote! { #ident: #bound_trait }).unwrap());
+            if let Some(rhs_ident) = find_rhs_ident(rhs, ident) {
+                where_predicates
+                    .push(syn::parse2(quote! { #ident: #bound_trait<#rhs_ident> }).unwrap());
+            } else {
+                where_predicates.push(syn::parse2(quote! { #ident: #bound_trait }).unwrap());
+            }
         }
     }
 
     where_predicates
 }
+
+/// Whether `tokens` contain one of `idents`, like `T` in `Vec<T>` or `T::Item`.
+fn contains_ident(tokens: TokenStream, idents: &[&Ident]) -> bool {
+    tokens.into_iter().any(|token| {
+        match token {
+            TokenTree::Ident(ident) => idents.contains(&&ident),
+            TokenTree::Group(group) => contains_ident(group.stream(), idents),
+            _ => false,
+        }
+    })
+}
+
+/// Replaces each of `rhs` in `tokens` with its counterpart on the right-hand side.
+fn replace_rhs_idents(tokens: TokenStream, rhs: &[(Ident, Ident)]) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|token| {
+            match token {
+                TokenTree::Ident(ident) => {
+                    match find_rhs_ident(rhs, &ident) {
+                        Some(rhs_ident) => TokenTree::Ident(rhs_ident.clone()),
+                        None => TokenTree::Ident(ident),
+                    }
+                }
+                TokenTree::Group(group) => {
+                    let mut new_group =
+                        Group::new(group.delimiter(), replace_rhs_idents(group.stream(), rhs));
+
+                    new_group.set_span(group.span());
+
+                    TokenTree::Group(new_group)
+                }
+                token => token,
+            }
+        })
+        .collect()
+}
+
+#[inline]
+fn is_phantom_data(ty: &Type) -> bool {
+    match ty {
+        Type::Path(ty) => {
+            ty.path.segments.last().map(|segment| segment.ident == "PhantomData").unwrap_or(false)
+        }
+        _ => false,
+    }
+}
+
+/// Creates a `FieldType: Trait` predicate for each field type which mentions a generic type
+/// parameter. `PhantomData` implements the traits whatever its parameter is, so it is skipped. A
+/// field type which mentions the type `ident` itself, or `Self`, is skipped too, because the
+/// predicate would need the implementation being defined and could never be proven.
+#[inline]
+pub fn create_where_predicates_from_field_types(
+    ident: &Ident,
+    p: &Punctuated<GenericParam, Comma>,
+    types: &[&Type],
+    bound_trait: &Path,
+) -> Punctuated<WherePredicate, Comma> {
+    create_where_predicates_from_field_types_with_rhs(ident, p, types, bound_trait, &[])
+}
+
+pub fn create_where_predicates_from_field_types_with_rhs(
+    ident: &Ident,
+    p: &Punctuated<GenericParam, Comma>,
+    types: &[&Type],
+    bound_trait: &Path,
+    rhs: &[(Ident, Ident)],
+) -> Punctuated<WherePredicate, Comma> {
+    let params: Vec<&Ident> = p
+        .iter()
+        .filter_map(|param| {
+            match param {
+                GenericParam::Type(typ) => Some(&typ.ident),
+                _ => None,
+            }
+        })
+        .collect();
+
+    let rhs_params: Vec<&Ident> = rhs.iter().map(|(ident, _)| ident).collect();
+
+    let self_ident = Ident::new("Self", Span::call_site());
+    let recursive_idents = [ident, &self_ident];
+
+    let mut where_predicates = Punctuated::new();
+    let mut inferred_types = HashSet::new();
+
+    for ty in types.iter().copied() {
+        let tokens = ty.to_token_stream();
+
+        if is_phantom_data(ty)
+            || !contains_ident(tokens.clone(), &params)
+            || contains_ident(tokens.clone(), &recursive_idents)
+        {
+            continue;
+        }
+
+        if !inferred_types.insert(tokens.to_string()) {
+            continue;
+        }
+
+        if contains_ident(tokens.clone(), &rhs_params) {
+            let rhs_ty = replace_rhs_idents(tokens, rhs);
+
+            where_predicates.push(syn::parse2(quote! { #ty: #bound_trait<#rhs_ty> }).unwrap());
+        } else {
+            where_predicates.push(syn::parse2(quote! { #ty: #bound_trait }).unwrap());
+        }
+    }
+
+    where_predicates
+}
+
+pub fn create_rhs_generics(generics: &mut Generics, rhs: &[(Ident, Ident)]) -> TokenStream {
+    if rhs.is_empty() {
+        let (_, ty_generics, _) = generics.split_for_impl();
+
+        return ty_generics.into_token_stream();
+    }
+
+    let mut arguments = Vec::with_capacity(generics.params.len());
+    let mut rhs_params = Vec::with_capacity(rhs.len());
+
+    for param in generics.params.iter() {
+        match param {
+            GenericParam::Type(typ) => {
+                let ident = &typ.ident;
+
+                if let Some(rhs_ident) = find_rhs_ident(rhs, ident) {
+                    let mut rhs_param = TypeParam::from(rhs_ident.clone());
+
+                    rhs_param.bounds = typ.bounds.clone();
+
+                    rhs_params.push(GenericParam::Type(rhs_param));
+
+                    arguments.push(rhs_ident.into_token_stream());
+                } else {
+                    arguments.push(ident.into_token_stream());
+                }
+            }
+            GenericParam::Lifetime(lifetime) => {
+                arguments.push(lifetime.lifetime.to_token_stream());
+            }
+            GenericParam::Const(cst) => {
+                arguments.push(cst.ident.to_token_stream());
+            }
+        }
+    }
+
+    let mut rhs_predicates = Vec::new();
+
+    if let Some(where_clause) = generics.where_clause.as_ref() {
+        for predicate in where_clause.predicates.iter() {
+            if let WherePredicate::Type(predicate_type) = predicate {
+                if let Type::Path(type_path) = &predicate_type.bounded_ty {
+                    if type_path.qself.is_none() {
+                        if let Some(ident) = type_path.path.get_ident() {
+                            if let Some(rhs_ident) = find_rhs_ident(rhs, ident) {
+                                let bounds = &predicate_type.bounds;
+
+                                rhs_predicates
+                                    .push(syn::parse2(quote! { #rhs_ident: #bounds }).unwrap());
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    for param in rhs_params {
+        generics.params.push(param);
+    }
+
+    let where_clause = generics.make_where_clause();
+
+    for predicate in rhs_predicates {
+        where_clause.predicates.push(predicate);
+    }
+
+    quote!(<#(#arguments),*>)
+}
+
+#[inline]
+pub fn create_variant_name_string(variant: &Variant, traits: &[Trait]) -> String {
+    #[cfg(feature = "Debug")]
+    {
+        if traits.binary_search(&Trait::Debug).is_ok() {
+            return debug::create_variant_name_string(variant, traits);
+        }
+    }
+
+    #[cfg(not(feature = "Debug"))]
+    let _ = traits;
+
+    variant.ident.unraw().to_string()
+}
+
+#[inline]
+pub fn create_variant_rank(index: usize, variant: &Variant, traits: &[Trait]) -> Option<isize> {
+    #[cfg(feature = "Ord")]
+    {
+        if traits.binary_search(&Trait::Ord).is_ok() {
+            return Some(ord::create_variant_rank(index, variant, traits));
+        }
+    }
+
+    #[cfg(feature = "PartialOrd")]
+    {
+        if traits.binary_search(&Trait::PartialOrd).is_ok() {
+            return Some(partial_ord::create_variant_rank(index, variant, traits));
+        }
+    }
+
+    let _ = (index, variant, traits);
+
+    None
+}
+
+#[inline]
+pub fn create_field_default_tokens(
+    field: &Field,
+    traits: &[Trait],
+    constant: bool,
+) -> Option<TokenStream> {
+    #[cfg(feature = "Default")]
+    {
+        if traits.binary_search(&Trait::Default).is_ok() {
+            return default::create_field_default_tokens(field, traits, constant);
+        }
+    }
+
+    let _ = (field, traits, constant);
+
+    None
+}
+
+/// The default value of a field set by the `default` parameter of a trait other than `Default`,
+/// like `#[educe(Builder(default = 8))]`, which doesn't need the type to implement `Default`.
+#[derive(Clone)]
+pub enum FieldDefault {
+    Trait,
+    Literal(Lit),
+    Expression(Box<Expr>),
+}
+
+impl FieldDefault {
+    pub fn from_meta(meta: &Meta, correct_usage: &[&str]) -> Result<FieldDefault, syn::Error> {
+        match meta {
+            Meta::Path(_) => Ok(FieldDefault::Trait),
+            Meta::NameValue(named_value) => Ok(FieldDefault::Literal(named_value.lit.clone())),
+            Meta::List(list) => {
+                if list.nested.len() == 1 {
+                    if let NestedMeta::Meta(meta) = &list.nested[0] {
+                        let meta_name = meta.path().into_token_stream().to_string();
+
+                        if meta_name == "expression" || meta_name == "expr" {
+                            let s = match meta {
+                                Meta::NameValue(named_value) => {
+                                    match &named_value.lit {
+                                        Lit::Str(s) => Some(s),
+                                        _ => None,
+                                    }
+                                }
+                                Meta::List(list) if list.nested.len() == 1 => {
+                                    match &list.nested[0] {
+                                        NestedMeta::Lit(Lit::Str(s)) => Some(s),
+                                        _ => None,
+                                    }
+                                }
+                                _ => None,
+                            };
+
+                            if let Some(s) = s {
+                                return match create_expr_from_lit_str(s)? {
+                                    Some(expression) => {
+                                        Ok(FieldDefault::Expression(Box::new(expression)))
+                                    }
+                                    None => Err(error::empty_parameter(meta, meta_name.as_str())),
+                                };
+                            }
+                        }
+                    }
+                }
+
+                Err(error::parameter_incorrect_format(meta, "default", correct_usage))
+            }
+        }
+    }
+
+    /// A string literal is converted with `Into::into`, except in a `const fn`.
+    pub fn into_tokens(self, constant: bool) -> TokenStream {
+        match self {
+            FieldDefault::Trait => quote!(::core::default::Default::default()),
+            FieldDefault::Literal(Lit::Str(s)) if !constant => {
+                quote!(::core::convert::Into::into(#s))
+            }
+            FieldDefault::Literal(lit) => quote!(#lit),
+            FieldDefault::Expression(expression) => quote!(#expression),
+        }
+    }
+}
+
+/// Creates an identifier from `s`, using a raw identifier for a keyword. `None` is returned for the
+/// keywords which cannot be raw identifiers, like `crate` and `self`.
+#[inline]
+pub fn create_ident_from_snake_case_string(s: &str) -> Option<Ident> {
+    match syn::parse_str(s) {
+        Ok(ident) => Some(ident),
+        Err(_) => {
+            match s {
+                "crate" | "self" | "super" | "Self" | "_" => None,
+                _ => Some(Ident::new_raw(s, Span::call_site())),
+            }
+        }
+    }
+}
+
+pub fn create_snake_case_string_from_ident(ident: &Ident) -> String {
+    let s = ident.unraw().to_string();
+
+    let chars: Vec<char> = s.chars().collect();
+
+    let mut snake_case = String::with_capacity(s.len() + 4);
+
+    for (index, c) in chars.iter().copied().enumerate() {
+        if c.is_uppercase() {
+            if index > 0 {
+                let prev = chars[index - 1];
+
+                let next_is_lowercase =
+                    chars.get(index + 1).map(|c| c.is_lowercase()).unwrap_or(false);
+
+                if prev != '_'
+                    && (prev.is_lowercase()
+                        || prev.is_numeric()
+                        || (prev.is_uppercase() && next_is_lowercase))
+                {
+                    snake_case.push('_');
+                }
+            }
+
+            snake_case.extend(c.to_lowercase());
+        } else {
+            snake_case.push(c);
+        }
+    }
+
+    snake_case
+}
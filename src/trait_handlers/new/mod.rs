@@ -0,0 +1,218 @@
+mod models;
+
+use super::{
+    create_field_default_tokens, create_ident_from_snake_case_string,
+    create_snake_case_string_from_ident, TraitHandler,
+};
+
+use crate::error::{self, Errors};
+use crate::proc_macro2::TokenStream;
+use crate::syn::{Data, DeriveInput, Fields, Meta};
+use crate::Trait;
+
+use models::{FieldAttributeBuilder, TypeAttributeBuilder};
+
+pub struct NewHandler;
+
+impl TraitHandler for NewHandler {
+    fn trait_meta_handler(
+        ast: &DeriveInput,
+        tokens: &mut TokenStream,
+        traits: &[Trait],
+        meta: &Meta,
+    ) -> Result<(), syn::Error> {
+        let mut errors = Errors::new();
+
+        let is_enum = matches!(ast.data, Data::Enum(_));
+
+        let type_attribute = TypeAttributeBuilder {
+            enable_flag: true,
+            enable_name: !is_enum,
+            enable_vis: true,
+            enable_const: true,
+            enable_into: true,
+        }
+        .from_new_meta(meta)?;
+
+        let ident = &ast.ident;
+
+        let vis = type_attribute.vis.unwrap_or_else(|| syn::parse2(quote!(pub)).unwrap());
+
+        let methods = match &ast.data {
+            Data::Struct(data) => {
+                let name = type_attribute.name.unwrap_or_else(|| format_ident!("new"));
+
+                let constness = create_constness(type_attribute.constant);
+
+                let (parameters, fields) = create_parameters_and_fields(
+                    &data.fields,
+                    traits,
+                    type_attribute.constant,
+                    type_attribute.into,
+                    &mut errors,
+                );
+
+                quote! {
+                    #[inline]
+                    #vis #constness fn #name(#parameters) -> Self {
+                        #ident #fields
+                    }
+                }
+            }
+            Data::Enum(data) => {
+                let mut methods = TokenStream::new();
+
+                for variant in data.variants.iter() {
+                    let variant_attribute = match errors.handle(
+                        TypeAttributeBuilder {
+                            enable_flag: false,
+                            enable_name: true,
+                            enable_vis: true,
+                            enable_const: true,
+                            enable_into: true,
+                        }
+                        .from_attributes(&variant.attrs, traits),
+                    ) {
+                        Some(variant_attribute) => variant_attribute,
+                        None => continue,
+                    };
+
+                    let variant_ident = &variant.ident;
+
+                    let name = match variant_attribute.name {
+                        Some(name) => name,
+                        None => {
+                            let s = create_snake_case_string_from_ident(variant_ident);
+
+                            match create_ident_from_snake_case_string(&s) {
+                                Some(name) => name,
+                                None => {
+                                    errors.push(error::reserved_method_name(
+                                        variant_ident,
+                                        &s,
+                                        Trait::New,
+                                    ));
+
+                                    continue;
+                                }
+                            }
+                        }
+                    };
+
+                    let vis = variant_attribute.vis.as_ref().unwrap_or(&vis);
+
+                    let constant = type_attribute.constant || variant_attribute.constant;
+
+                    let constness = create_constness(constant);
+
+                    let (parameters, fields) = create_parameters_and_fields(
+                        &variant.fields,
+                        traits,
+                        constant,
+                        type_attribute.into || variant_attribute.into,
+                        &mut errors,
+                    );
+
+                    methods.extend(quote! {
+                        #[inline]
+                        #vis #constness fn #name(#parameters) -> Self {
+                            Self::#variant_ident #fields
+                        }
+                    });
+                }
+
+                methods
+            }
+            Data::Union(_) => return Err(error::trait_not_support_union(meta, Trait::New)),
+        };
+
+        let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+        let new_impl = quote! {
+            impl #impl_generics #ident #ty_generics #where_clause {
+                #methods
+            }
+        };
+
+        errors.finish()?;
+
+        tokens.extend(new_impl);
+
+        Ok(())
+    }
+}
+
+fn create_constness(constant: bool) -> TokenStream {
+    if constant {
+        quote!(const)
+    } else {
+        TokenStream::new()
+    }
+}
+
+fn create_parameters_and_fields(
+    fields: &Fields,
+    traits: &[Trait],
+    constant: bool,
+    into: bool,
+    errors: &mut Errors,
+) -> (TokenStream, TokenStream) {
+    let mut parameters = TokenStream::new();
+    let mut values = Vec::new();
+
+    for (index, field) in fields.iter().enumerate() {
+        let field_attribute = match errors.handle(
+            FieldAttributeBuilder {
+                enable_into: true,
+                enable_default: true,
+            }
+            .from_attributes(&field.attrs, traits),
+        ) {
+            Some(field_attribute) => field_attribute,
+            None => continue,
+        };
+
+        let into = into || field_attribute.into;
+
+        if constant && into {
+            errors.push(error::set_const_with_into(field));
+        }
+
+        let default = match field_attribute.default {
+            Some(default) => Some(default.into_tokens(constant)),
+            None => create_field_default_tokens(field, traits, constant),
+        };
+
+        match default {
+            Some(value) => values.push(value),
+            None => {
+                let parameter = match field.ident.as_ref() {
+                    Some(ident) => ident.clone(),
+                    None => format_ident!("_{}", index),
+                };
+
+                let ty = &field.ty;
+
+                if into {
+                    parameters.extend(quote!(#parameter: impl ::core::convert::Into<#ty>,));
+                    values.push(quote!(::core::convert::Into::into(#parameter)));
+                } else {
+                    parameters.extend(quote!(#parameter: #ty,));
+                    values.push(quote!(#parameter));
+                }
+            }
+        }
+    }
+
+    let fields = match fields {
+        Fields::Named(fields) => {
+            let field_idents = fields.named.iter().map(|field| field.ident.as_ref().unwrap());
+
+            quote!({ #(#field_idents: #values),* })
+        }
+        Fields::Unnamed(_) => quote!(( #(#values),* )),
+        Fields::Unit => TokenStream::new(),
+    };
+
+    (parameters, fields)
+}
@@ -1,16 +1,22 @@
-use super::super::super::create_path_string_from_lit_str;
+use super::super::super::{
+    create_path_from_lit_str, create_where_predicates_from_lit_str, parse_educe_meta,
+};
 
-use crate::panic;
+use crate::error;
 use crate::quote::ToTokens;
-use crate::syn::{Attribute, Lit, Meta, NestedMeta};
+use crate::syn::spanned::Spanned;
+use crate::syn::{
+    punctuated::Punctuated, token::Comma, Attribute, Lit, Meta, NestedMeta, Path, WherePredicate,
+};
 use crate::Trait;
 
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct FieldAttribute {
     pub ignore: bool,
-    pub compare_method: Option<String>,
-    pub compare_trait: Option<String>,
+    pub compare_method: Option<Path>,
+    pub compare_trait: Option<Path>,
     pub rank: isize,
+    pub bound: Punctuated<WherePredicate, Comma>,
 }
 
 #[derive(Debug, Clone)]
@@ -19,11 +25,13 @@ pub struct FieldAttributeBuilder {
     pub enable_impl: bool,
     pub rank: isize,
     pub enable_rank: bool,
+    pub enable_bound: bool,
 }
 
 impl FieldAttributeBuilder {
     #[allow(clippy::cognitive_complexity)]
-    pub fn from_ord_meta(&self, meta: &Meta) -> FieldAttribute {
+    pub fn from_ord_meta(&self, meta: &Meta) -> Result<FieldAttribute, syn::Error> {
+        let mut bound = Punctuated::new();
         let mut ignore = false;
 
         let mut compare_method = None;
@@ -54,8 +62,11 @@ impl FieldAttributeBuilder {
                 stringify!(#[educe(Ord(trait = "path_to_trait"))]),
                 stringify!(#[educe(Ord(trait = "path_to_trait", method = "path_to_method_in_trait"))]),
                 stringify!(#[educe(Ord(method("path_to_method")))]),
+                stringify!(#[educe(Ord(method(path_to_method)))]),
                 stringify!(#[educe(Ord(trait("path_to_trait")))]),
+                stringify!(#[educe(Ord(trait(path_to_trait)))]),
                 stringify!(#[educe(Ord(trait("path_to_trait"), method("path_to_method_in_trait")))]),
+                stringify!(#[educe(Ord(trait(path_to_trait), method(path_to_method_in_trait)))]),
             ];
 
             usage
@@ -72,6 +83,16 @@ impl FieldAttributeBuilder {
 
         let mut rank_is_set = false;
 
+        let correct_usage_for_bound = {
+            let usage = vec![
+                stringify!(#[educe(Ord(bound = "where_predicates"))]),
+                stringify!(#[educe(Ord(bound("where_predicates")))]),
+                stringify!(#[educe(Ord(bound(where_predicates)))]),
+            ];
+
+            usage
+        };
+
         match meta {
             Meta::List(list) => {
                 let mut ignore_is_set = false;
@@ -84,13 +105,20 @@ impl FieldAttributeBuilder {
                             match meta_name.as_str() {
                                 "ignore" => {
                                     if !self.enable_ignore {
-                                        panic::unknown_parameter("Ord", meta_name.as_str());
+                                        return Err(error::unknown_parameter(
+                                            meta,
+                                            "Ord",
+                                            meta_name.as_str(),
+                                        ));
                                     }
 
                                     match meta {
                                         Meta::Path(_) => {
                                             if ignore_is_set {
-                                                panic::reset_parameter(meta_name.as_str());
+                                                return Err(error::reset_parameter(
+                                                    meta,
+                                                    meta_name.as_str(),
+                                                ));
                                             }
 
                                             ignore_is_set = true;
@@ -98,16 +126,21 @@ impl FieldAttributeBuilder {
                                             ignore = true;
                                         }
                                         _ => {
-                                            panic::parameter_incorrect_format(
+                                            return Err(error::parameter_incorrect_format(
+                                                meta,
                                                 meta_name.as_str(),
                                                 &correct_usage_for_ignore,
-                                            )
+                                            ))
                                         }
                                     }
                                 }
                                 "method" => {
                                     if !self.enable_impl {
-                                        panic::unknown_parameter("Ord", meta_name.as_str());
+                                        return Err(error::unknown_parameter(
+                                            meta,
+                                            "Ord",
+                                            meta_name.as_str(),
+                                        ));
                                     }
 
                                     match meta {
@@ -118,36 +151,44 @@ impl FieldAttributeBuilder {
                                                         match lit {
                                                             Lit::Str(s) => {
                                                                 if compare_method.is_some() {
-                                                                    panic::reset_parameter(
-                                                                        meta_name.as_str(),
+                                                                    return Err(
+                                                                        error::reset_parameter(
+                                                                            meta,
+                                                                            meta_name.as_str(),
+                                                                        ),
                                                                     );
                                                                 }
 
                                                                 let s =
-                                                                    create_path_string_from_lit_str(
-                                                                        s,
-                                                                    );
+                                                                    create_path_from_lit_str(s)?;
 
                                                                 if let Some(s) = s {
                                                                     compare_method = Some(s);
                                                                 } else {
-                                                                    panic::empty_parameter(
-                                                                        meta_name.as_str(),
+                                                                    return Err(
+                                                                        error::empty_parameter(
+                                                                            meta,
+                                                                            meta_name.as_str(),
+                                                                        ),
                                                                     );
                                                                 }
                                                             }
-                                                            _ => {
-                                                                panic::parameter_incorrect_format(
+                                                            _ => return Err(
+                                                                error::parameter_incorrect_format(
+                                                                    meta,
                                                                     meta_name.as_str(),
                                                                     &correct_usage_for_impl,
-                                                                )
-                                                            }
+                                                                ),
+                                                            ),
                                                         }
                                                     }
                                                     _ => {
-                                                        panic::parameter_incorrect_format(
-                                                            meta_name.as_str(),
-                                                            &correct_usage_for_impl,
+                                                        return Err(
+                                                            error::parameter_incorrect_format(
+                                                                meta,
+                                                                meta_name.as_str(),
+                                                                &correct_usage_for_impl,
+                                                            ),
                                                         )
                                                     }
                                                 }
@@ -159,36 +200,48 @@ impl FieldAttributeBuilder {
                                             match lit {
                                                 Lit::Str(s) => {
                                                     if compare_method.is_some() {
-                                                        panic::reset_parameter(meta_name.as_str());
+                                                        return Err(error::reset_parameter(
+                                                            meta,
+                                                            meta_name.as_str(),
+                                                        ));
                                                     }
 
-                                                    let s = create_path_string_from_lit_str(s);
+                                                    let s = create_path_from_lit_str(s)?;
 
                                                     if let Some(s) = s {
                                                         compare_method = Some(s);
                                                     } else {
-                                                        panic::empty_parameter(meta_name.as_str());
+                                                        return Err(error::empty_parameter(
+                                                            meta,
+                                                            meta_name.as_str(),
+                                                        ));
                                                     }
                                                 }
                                                 _ => {
-                                                    panic::parameter_incorrect_format(
+                                                    return Err(error::parameter_incorrect_format(
+                                                        meta,
                                                         meta_name.as_str(),
                                                         &correct_usage_for_impl,
-                                                    )
+                                                    ))
                                                 }
                                             }
                                         }
                                         _ => {
-                                            panic::parameter_incorrect_format(
+                                            return Err(error::parameter_incorrect_format(
+                                                meta,
                                                 meta_name.as_str(),
                                                 &correct_usage_for_impl,
-                                            )
+                                            ))
                                         }
                                     }
                                 }
                                 "trait" => {
                                     if !self.enable_impl {
-                                        panic::unknown_parameter("Ord", meta_name.as_str());
+                                        return Err(error::unknown_parameter(
+                                            meta,
+                                            "Ord",
+                                            meta_name.as_str(),
+                                        ));
                                     }
 
                                     match meta {
@@ -199,36 +252,44 @@ impl FieldAttributeBuilder {
                                                         match lit {
                                                             Lit::Str(s) => {
                                                                 if compare_trait.is_some() {
-                                                                    panic::reset_parameter(
-                                                                        meta_name.as_str(),
+                                                                    return Err(
+                                                                        error::reset_parameter(
+                                                                            meta,
+                                                                            meta_name.as_str(),
+                                                                        ),
                                                                     );
                                                                 }
 
                                                                 let s =
-                                                                    create_path_string_from_lit_str(
-                                                                        s,
-                                                                    );
+                                                                    create_path_from_lit_str(s)?;
 
                                                                 if let Some(s) = s {
                                                                     compare_trait = Some(s);
                                                                 } else {
-                                                                    panic::empty_parameter(
-                                                                        meta_name.as_str(),
+                                                                    return Err(
+                                                                        error::empty_parameter(
+                                                                            meta,
+                                                                            meta_name.as_str(),
+                                                                        ),
                                                                     );
                                                                 }
                                                             }
-                                                            _ => {
-                                                                panic::parameter_incorrect_format(
+                                                            _ => return Err(
+                                                                error::parameter_incorrect_format(
+                                                                    meta,
                                                                     meta_name.as_str(),
                                                                     &correct_usage_for_impl,
-                                                                )
-                                                            }
+                                                                ),
+                                                            ),
                                                         }
                                                     }
                                                     _ => {
-                                                        panic::parameter_incorrect_format(
-                                                            meta_name.as_str(),
-                                                            &correct_usage_for_impl,
+                                                        return Err(
+                                                            error::parameter_incorrect_format(
+                                                                meta,
+                                                                meta_name.as_str(),
+                                                                &correct_usage_for_impl,
+                                                            ),
                                                         )
                                                     }
                                                 }
@@ -240,36 +301,48 @@ impl FieldAttributeBuilder {
                                             match lit {
                                                 Lit::Str(s) => {
                                                     if compare_trait.is_some() {
-                                                        panic::reset_parameter(meta_name.as_str());
+                                                        return Err(error::reset_parameter(
+                                                            meta,
+                                                            meta_name.as_str(),
+                                                        ));
                                                     }
 
-                                                    let s = create_path_string_from_lit_str(s);
+                                                    let s = create_path_from_lit_str(s)?;
 
                                                     if let Some(s) = s {
                                                         compare_trait = Some(s);
                                                     } else {
-                                                        panic::empty_parameter(meta_name.as_str());
+                                                        return Err(error::empty_parameter(
+                                                            meta,
+                                                            meta_name.as_str(),
+                                                        ));
                                                     }
                                                 }
                                                 _ => {
-                                                    panic::parameter_incorrect_format(
+                                                    return Err(error::parameter_incorrect_format(
+                                                        meta,
                                                         meta_name.as_str(),
                                                         &correct_usage_for_impl,
-                                                    )
+                                                    ))
                                                 }
                                             }
                                         }
                                         _ => {
-                                            panic::parameter_incorrect_format(
+                                            return Err(error::parameter_incorrect_format(
+                                                meta,
                                                 meta_name.as_str(),
                                                 &correct_usage_for_impl,
-                                            )
+                                            ))
                                         }
                                     }
                                 }
                                 "rank" => {
                                     if !self.enable_rank {
-                                        panic::unknown_parameter("Ord", meta_name.as_str());
+                                        return Err(error::unknown_parameter(
+                                            meta,
+                                            "Ord",
+                                            meta_name.as_str(),
+                                        ));
                                     }
 
                                     match meta {
@@ -280,27 +353,34 @@ impl FieldAttributeBuilder {
                                                         match lit {
                                                             Lit::Int(i) => {
                                                                 if rank_is_set {
-                                                                    panic::reset_parameter(
-                                                                        meta_name.as_str(),
+                                                                    return Err(
+                                                                        error::reset_parameter(
+                                                                            meta,
+                                                                            meta_name.as_str(),
+                                                                        ),
                                                                     );
                                                                 }
 
                                                                 rank_is_set = true;
 
-                                                                rank = i.base10_parse().unwrap();
+                                                                rank = i.base10_parse()?;
                                                             }
-                                                            _ => {
-                                                                panic::parameter_incorrect_format(
+                                                            _ => return Err(
+                                                                error::parameter_incorrect_format(
+                                                                    meta,
                                                                     meta_name.as_str(),
                                                                     &correct_usage_for_rank,
-                                                                )
-                                                            }
+                                                                ),
+                                                            ),
                                                         }
                                                     }
                                                     _ => {
-                                                        panic::parameter_incorrect_format(
-                                                            meta_name.as_str(),
-                                                            &correct_usage_for_rank,
+                                                        return Err(
+                                                            error::parameter_incorrect_format(
+                                                                meta,
+                                                                meta_name.as_str(),
+                                                                &correct_usage_for_rank,
+                                                            ),
                                                         )
                                                     }
                                                 }
@@ -312,66 +392,177 @@ impl FieldAttributeBuilder {
                                             match lit {
                                                 Lit::Int(i) => {
                                                     if rank_is_set {
-                                                        panic::reset_parameter(meta_name.as_str());
+                                                        return Err(error::reset_parameter(
+                                                            meta,
+                                                            meta_name.as_str(),
+                                                        ));
                                                     }
 
                                                     rank_is_set = true;
 
-                                                    rank = i.base10_parse().unwrap();
+                                                    rank = i.base10_parse()?;
                                                 }
                                                 _ => {
-                                                    panic::parameter_incorrect_format(
+                                                    return Err(error::parameter_incorrect_format(
+                                                        meta,
                                                         meta_name.as_str(),
                                                         &correct_usage_for_rank,
-                                                    )
+                                                    ))
                                                 }
                                             }
                                         }
                                         _ => {
-                                            panic::parameter_incorrect_format(
+                                            return Err(error::parameter_incorrect_format(
+                                                meta,
                                                 meta_name.as_str(),
                                                 &correct_usage_for_rank,
-                                            )
+                                            ))
                                         }
                                     }
                                 }
-                                _ => panic::unknown_parameter("Ord", meta_name.as_str()),
+                                "bound" => {
+                                    if !self.enable_bound {
+                                        return Err(error::unknown_parameter(
+                                            meta,
+                                            "Ord",
+                                            meta_name.as_str(),
+                                        ));
+                                    }
+
+                                    match meta {
+                                        Meta::List(list) => {
+                                            for p in list.nested.iter() {
+                                                match p {
+                                                    NestedMeta::Lit(Lit::Str(s)) => {
+                                                        if !bound.is_empty() {
+                                                            return Err(error::reset_parameter(
+                                                                meta,
+                                                                meta_name.as_str(),
+                                                            ));
+                                                        }
+
+                                                        bound = match create_where_predicates_from_lit_str(s)? {
+                                                            Some(where_predicates) => where_predicates,
+                                                            None => {
+                                                                return Err(error::empty_parameter(
+                                                                    meta,
+                                                                    meta_name.as_str(),
+                                                                ))
+                                                            }
+                                                        };
+                                                    }
+                                                    _ => {
+                                                        return Err(
+                                                            error::parameter_incorrect_format(
+                                                                meta,
+                                                                meta_name.as_str(),
+                                                                &correct_usage_for_bound,
+                                                            ),
+                                                        )
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        Meta::NameValue(named_value) => {
+                                            let lit = &named_value.lit;
+
+                                            match lit {
+                                                Lit::Str(s) => {
+                                                    if !bound.is_empty() {
+                                                        return Err(error::reset_parameter(
+                                                            meta,
+                                                            meta_name.as_str(),
+                                                        ));
+                                                    }
+
+                                                    bound =
+                                                        match create_where_predicates_from_lit_str(
+                                                            s,
+                                                        )? {
+                                                            Some(where_predicates) => {
+                                                                where_predicates
+                                                            }
+                                                            None => {
+                                                                return Err(error::empty_parameter(
+                                                                    meta,
+                                                                    meta_name.as_str(),
+                                                                ))
+                                                            }
+                                                        };
+                                                }
+                                                _ => {
+                                                    return Err(error::parameter_incorrect_format(
+                                                        meta,
+                                                        meta_name.as_str(),
+                                                        &correct_usage_for_bound,
+                                                    ))
+                                                }
+                                            }
+                                        }
+                                        _ => {
+                                            return Err(error::parameter_incorrect_format(
+                                                meta,
+                                                meta_name.as_str(),
+                                                &correct_usage_for_bound,
+                                            ))
+                                        }
+                                    }
+                                }
+                                _ => {
+                                    return Err(error::unknown_parameter(
+                                        meta,
+                                        "Ord",
+                                        meta_name.as_str(),
+                                    ))
+                                }
                             }
                         }
                         _ => {
-                            panic::attribute_incorrect_format(
+                            return Err(error::attribute_incorrect_format(
+                                meta,
                                 "Ord",
                                 &correct_usage_for_ord_attribute,
-                            )
+                            ))
                         }
                     }
                 }
             }
-            _ => panic::attribute_incorrect_format("Ord", &correct_usage_for_ord_attribute),
+            _ => {
+                return Err(error::attribute_incorrect_format(
+                    meta,
+                    "Ord",
+                    &correct_usage_for_ord_attribute,
+                ))
+            }
         }
 
-        if compare_trait.is_some() && compare_method.is_none() {
-            compare_method = Some("cmp".to_string());
+        if let (Some(compare_trait), None) = (&compare_trait, &compare_method) {
+            compare_method = Some(Path::from(format_ident!("cmp", span = compare_trait.span())));
         }
 
         if ignore && rank_is_set {
-            panic::ignore_ranked_field();
+            return Err(error::ignore_ranked_field(meta));
         }
 
-        FieldAttribute {
+        Ok(FieldAttribute {
             ignore,
             compare_method,
             compare_trait,
             rank,
-        }
+            bound,
+        })
     }
 
     #[allow(clippy::wrong_self_convention)]
-    pub fn from_attributes(self, attributes: &[Attribute], traits: &[Trait]) -> FieldAttribute {
+    pub fn from_attributes(
+        self,
+        attributes: &[Attribute],
+        traits: &[Trait],
+    ) -> Result<FieldAttribute, syn::Error> {
         let mut result = None;
 
         for attribute in attributes.iter() {
-            let meta = attribute.parse_meta().unwrap();
+            let meta = parse_educe_meta(attribute)?;
 
             let meta_name = meta.path().into_token_stream().to_string();
 
@@ -381,36 +572,35 @@ impl FieldAttributeBuilder {
                         for p in list.nested.iter() {
                             match p {
                                 NestedMeta::Meta(meta) => {
-                                    let meta_name = meta.path().into_token_stream().to_string();
-
-                                    let t = Trait::from_str(meta_name);
+                                    let t = Trait::from_path(meta.path())?;
 
                                     if traits.binary_search(&t).is_err() {
-                                        panic::trait_not_used(t);
+                                        return Err(error::trait_not_used(meta, t));
                                     }
 
                                     if t == Trait::Ord {
                                         if result.is_some() {
-                                            panic::reuse_a_trait(t);
+                                            return Err(error::reuse_a_trait(meta, t));
                                         }
 
-                                        result = Some(self.from_ord_meta(&meta));
+                                        result = Some(self.from_ord_meta(&meta)?);
                                     }
                                 }
-                                _ => panic::educe_format_incorrect(),
+                                _ => return Err(error::educe_format_incorrect(attribute)),
                             }
                         }
                     }
-                    _ => panic::educe_format_incorrect(),
+                    _ => return Err(error::educe_format_incorrect(attribute)),
                 }
             }
         }
 
-        result.unwrap_or(FieldAttribute {
+        Ok(result.unwrap_or(FieldAttribute {
             ignore: false,
             compare_method: None,
             compare_trait: None,
             rank: self.rank,
-        })
+            bound: Punctuated::new(),
+        }))
     }
 }
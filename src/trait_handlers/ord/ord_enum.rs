@@ -1,12 +1,13 @@
 use std::collections::BTreeMap;
-use std::fmt::Write;
-use std::str::FromStr;
 
-use super::super::TraitHandler;
+use super::super::{
+    create_field_binding, create_field_binding_2, create_field_member, create_hygienic_ident,
+    TraitHandler,
+};
 use super::models::{FieldAttributeBuilder, TypeAttributeBuilder};
 
-use crate::panic;
-use crate::proc_macro2::TokenStream;
+use crate::error::{self, Errors};
+use crate::proc_macro2::{Literal, TokenStream};
 use crate::syn::{Data, DeriveInput, Fields, Generics, Meta};
 use crate::Trait;
 
@@ -18,332 +19,274 @@ impl TraitHandler for OrdEnumHandler {
         tokens: &mut TokenStream,
         traits: &[Trait],
         meta: &Meta,
-    ) {
+    ) -> Result<(), syn::Error> {
+        let mut errors = Errors::new();
+
         let type_attribute = TypeAttributeBuilder {
             enable_flag: true,
             enable_bound: true,
             rank: 0,
             enable_rank: false,
         }
-        .from_ord_meta(meta);
+        .from_ord_meta(meta)?;
 
-        let enum_name = ast.ident.to_string();
+        let ident = &ast.ident;
 
-        let bound = type_attribute
-            .bound
-            .into_punctuated_where_predicates_by_generic_parameters(&ast.generics.params);
+        let other = create_hygienic_ident("other");
+        let other_value = create_hygienic_ident("other_value");
+        let variant_to_integer = create_hygienic_ident("variant_to_integer");
+        let unit_to_integer = create_hygienic_ident("unit_to_integer");
 
         let mut comparer_tokens = TokenStream::new();
 
-        let mut match_tokens = String::from("match self {");
+        let mut arms = TokenStream::new();
 
         let mut has_non_unit_or_custom_value = false;
 
+        let mut field_types = Vec::new();
+        let mut extra_bound = Vec::new();
+
         if let Data::Enum(data) = &ast.data {
             let mut variant_values = Vec::new();
-            let mut variant_idents = Vec::new();
             let mut variants = Vec::new();
 
-            let mut variant_to_integer =
-                String::from("let variant_to_integer = |other: &Self| match other {");
-            let mut unit_to_integer =
-                String::from("let unit_to_integer = |other: &Self| match other {");
+            let mut variant_to_integer_arms = TokenStream::new();
+            let mut unit_to_integer_arms = TokenStream::new();
 
             for (index, variant) in data.variants.iter().enumerate() {
-                let variant_attribute = TypeAttributeBuilder {
-                    enable_flag: false,
-                    enable_bound: false,
-                    rank: isize::min_value() + index as isize,
-                    enable_rank: true,
-                }
-                .from_attributes(&variant.attrs, traits);
+                let variant_attribute = match errors.handle(
+                    TypeAttributeBuilder {
+                        enable_flag: false,
+                        enable_bound: true,
+                        rank: isize::min_value() + index as isize,
+                        enable_rank: true,
+                    }
+                    .from_attributes(&variant.attrs, traits),
+                ) {
+                    Some(variant_attribute) => variant_attribute,
+                    None => continue,
+                };
 
                 let value = variant_attribute.rank;
 
                 if variant_values.contains(&value) {
-                    panic::reuse_a_value(value);
+                    errors.push(error::reuse_a_value(variant, value));
                 }
 
                 if value >= 0 {
                     has_non_unit_or_custom_value = true;
                 }
 
-                let variant_ident = variant.ident.to_string();
-
-                match &variant.fields {
-                    Fields::Unit => {
-                        // TODO Unit
-                        unit_to_integer.write_fmt(format_args!("{enum_name}::{variant_ident} => {enum_name}::{variant_ident} as isize,", enum_name = enum_name, variant_ident = variant_ident)).unwrap();
-                        variant_to_integer
-                            .write_fmt(format_args!(
-                                "{enum_name}::{variant_ident} => {value},",
-                                enum_name = enum_name,
-                                variant_ident = variant_ident,
-                                value = value
-                            ))
-                            .unwrap();
-                    }
-                    Fields::Named(_) => {
-                        // TODO Struct
-                        has_non_unit_or_custom_value = true;
-
-                        variant_to_integer
-                            .write_fmt(format_args!(
-                                "{enum_name}::{variant_ident} {{ .. }} => {value},",
-                                enum_name = enum_name,
-                                variant_ident = variant_ident,
-                                value = value
-                            ))
-                            .unwrap();
-                    }
-                    Fields::Unnamed(fields) => {
-                        // TODO Tuple
-                        has_non_unit_or_custom_value = true;
+                let variant_ident = &variant.ident;
 
-                        let mut pattern_tokens = String::new();
+                let value_literal = Literal::isize_suffixed(value);
 
-                        for _ in fields.unnamed.iter() {
-                            pattern_tokens.push_str("_,");
-                        }
-
-                        variant_to_integer
-                            .write_fmt(format_args!(
-                                "{enum_name}::{variant_ident}( {pattern_tokens} ) => {value},",
-                                enum_name = enum_name,
-                                variant_ident = variant_ident,
-                                pattern_tokens = pattern_tokens,
-                                value = value
-                            ))
-                            .unwrap();
-                    }
+                if let Fields::Unit = &variant.fields {
+                    unit_to_integer_arms
+                        .extend(quote!(Self::#variant_ident => Self::#variant_ident as ::core::primitive::isize,));
+                } else {
+                    has_non_unit_or_custom_value = true;
                 }
 
+                variant_to_integer_arms
+                    .extend(quote!(Self::#variant_ident { .. } => #value_literal,));
+
                 variant_values.push(value);
-                variant_idents.push(variant_ident);
-                variants.push(variant);
+                variants.push((variant, variant_attribute.bound));
             }
 
             if has_non_unit_or_custom_value {
-                variant_to_integer.push_str("};");
+                comparer_tokens.extend(quote! {
+                    let #variant_to_integer = |#other: &Self| match #other {
+                        #variant_to_integer_arms
+                    };
+                });
+
+                for ((variant, variant_bound), variant_value) in
+                    variants.into_iter().zip(variant_values)
+                {
+                    let variant_ident = &variant.ident;
+
+                    let variant_field_types_start = field_types.len();
+
+                    let variant_value = Literal::isize_suffixed(variant_value);
+
+                    let mut pattern_tokens = TokenStream::new();
+                    let mut pattern_2_tokens = TokenStream::new();
+                    let mut block_tokens = TokenStream::new();
+
+                    let mut field_attributes = BTreeMap::new();
+                    let mut field_bindings = BTreeMap::new();
+
+                    for (index, field) in variant.fields.iter().enumerate() {
+                        let field_attribute = match errors.handle(
+                            FieldAttributeBuilder {
+                                enable_ignore: true,
+                                enable_impl: true,
+                                rank: isize::min_value() + index as isize,
+                                enable_rank: true,
+                                enable_bound: true,
+                            }
+                            .from_attributes(&field.attrs, traits),
+                        ) {
+                            Some(field_attribute) => field_attribute,
+                            None => continue,
+                        };
 
-                comparer_tokens.extend(TokenStream::from_str(&variant_to_integer).unwrap());
+                        extra_bound.extend(field_attribute.bound.iter().cloned());
 
-                for (index, variant) in variants.into_iter().enumerate() {
-                    let variant_value = variant_values[index];
-                    let variant_ident = &variant_idents[index];
+                        let field_member = create_field_member(index, field);
 
-                    match &variant.fields {
-                        Fields::Unit => {
-                            // TODO Unit
-                            match_tokens.write_fmt(format_args!("{enum_name}::{variant_ident} => {{ let other_value = variant_to_integer(other); return core::cmp::Ord::cmp(&{variant_value}, &other_value); }}", enum_name = enum_name, variant_ident = variant_ident, variant_value = variant_value)).unwrap();
+                        if field_attribute.ignore {
+                            pattern_tokens.extend(quote!(#field_member: _,));
+                            pattern_2_tokens.extend(quote!(#field_member: _,));
+
+                            continue;
                         }
-                        Fields::Named(fields) => {
-                            // TODO Struct
-                            let mut pattern_tokens = String::new();
-                            let mut pattern_2_tokens = String::new();
-                            let mut block_tokens = String::new();
-
-                            let mut field_attributes = BTreeMap::new();
-                            let mut field_names = BTreeMap::new();
-
-                            for (index, field) in fields.named.iter().enumerate() {
-                                let field_attribute = FieldAttributeBuilder {
-                                    enable_ignore: true,
-                                    enable_impl: true,
-                                    rank: isize::min_value() + index as isize,
-                                    enable_rank: true,
-                                }
-                                .from_attributes(&field.attrs, traits);
-
-                                let field_name = field.ident.as_ref().unwrap().to_string();
-
-                                if field_attribute.ignore {
-                                    pattern_tokens
-                                        .write_fmt(format_args!(
-                                            "{field_name}: _,",
-                                            field_name = field_name
-                                        ))
-                                        .unwrap();
-                                    pattern_2_tokens
-                                        .write_fmt(format_args!(
-                                            "{field_name}: _,",
-                                            field_name = field_name
-                                        ))
-                                        .unwrap();
-                                    continue;
-                                }
 
-                                let rank = field_attribute.rank;
+                        let rank = field_attribute.rank;
 
-                                if field_attributes.contains_key(&rank) {
-                                    panic::reuse_a_rank(rank);
-                                }
+                        if field_attributes.contains_key(&rank) {
+                            errors.push(error::reuse_a_rank(field, rank));
+                        }
 
-                                pattern_tokens
-                                    .write_fmt(format_args!(
-                                        "{field_name},",
-                                        field_name = field_name
-                                    ))
-                                    .unwrap();
-                                pattern_2_tokens
-                                    .write_fmt(format_args!(
-                                        "{field_name}: ___{field_name},",
-                                        field_name = field_name
-                                    ))
-                                    .unwrap();
-
-                                field_attributes.insert(rank, field_attribute);
-                                field_names.insert(rank, field_name);
-                            }
+                        let field_binding = create_field_binding(index);
+                        let field_binding_2 = create_field_binding_2(index);
 
-                            for (index, field_attribute) in field_attributes {
-                                let field_name = field_names.get(&index).unwrap();
+                        pattern_tokens.extend(quote!(#field_member: #field_binding,));
+                        pattern_2_tokens.extend(quote!(#field_member: #field_binding_2,));
 
-                                let compare_trait = field_attribute.compare_trait;
-                                let compare_method = field_attribute.compare_method;
+                        if field_attribute.compare_method.is_none() {
+                            field_types.push(&field.ty);
+                        }
+
+                        field_attributes.insert(rank, field_attribute);
+                        field_bindings.insert(rank, (field_binding, field_binding_2));
+                    }
 
-                                match compare_trait {
-                                    Some(compare_trait) => {
-                                        let compare_method = compare_method.unwrap();
+                    extra_bound.extend(
+                        variant_bound.into_punctuated_where_predicates_by_generic_parameters(
+                            &ast.ident,
+                            &ast.generics.params,
+                            &field_types[variant_field_types_start..],
+                        ),
+                    );
 
-                                        block_tokens.write_fmt(format_args!("match {compare_trait}::{compare_method}({field_name}, ___{field_name}) {{ core::cmp::Ordering::Equal => (), core::cmp::Ordering::Greater => {{ return core::cmp::Ordering::Greater; }}, core::cmp::Ordering::Less => {{ return core::cmp::Ordering::Less; }} }}", compare_trait = compare_trait, compare_method = compare_method, field_name = field_name)).unwrap();
+                    for (rank, field_attribute) in field_attributes {
+                        let (field_binding, field_binding_2) = field_bindings.get(&rank).unwrap();
+
+                        let compare = match field_attribute.compare_trait {
+                            Some(compare_trait) => {
+                                let compare_method = field_attribute.compare_method.unwrap();
+
+                                quote!(#compare_trait::#compare_method(#field_binding, #field_binding_2))
+                            }
+                            None => {
+                                match field_attribute.compare_method {
+                                    Some(compare_method) => {
+                                        quote!(#compare_method(#field_binding, #field_binding_2))
                                     }
                                     None => {
-                                        match compare_method {
-                                            Some(compare_method) => {
-                                                block_tokens.write_fmt(format_args!("match {compare_method}({field_name}, ___{field_name}) {{ core::cmp::Ordering::Equal => (), core::cmp::Ordering::Greater => {{ return core::cmp::Ordering::Greater; }}, core::cmp::Ordering::Less => {{ return core::cmp::Ordering::Less; }} }}", compare_method = compare_method, field_name = field_name)).unwrap();
-                                            }
-                                            None => {
-                                                block_tokens.write_fmt(format_args!("match core::cmp::Ord::cmp({field_name}, ___{field_name}) {{ core::cmp::Ordering::Equal => (), core::cmp::Ordering::Greater => {{ return core::cmp::Ordering::Greater; }}, core::cmp::Ordering::Less => {{ return core::cmp::Ordering::Less; }} }}", field_name = field_name)).unwrap();
-                                            }
-                                        }
+                                        quote!(::core::cmp::Ord::cmp(#field_binding, #field_binding_2))
                                     }
                                 }
                             }
+                        };
 
-                            match_tokens.write_fmt(format_args!("{enum_name}::{variant_ident}{{ {pattern_tokens} }} => {{ if let {enum_name}::{variant_ident} {{ {pattern_2_tokens} }} = other {{ {block_tokens} }} else {{ let other_value = variant_to_integer(other); return core::cmp::Ord::cmp(&{variant_value}, &other_value); }} }}", enum_name = enum_name, variant_ident = variant_ident, pattern_tokens = pattern_tokens, pattern_2_tokens = pattern_2_tokens, block_tokens = block_tokens, variant_value = variant_value)).unwrap();
-                        }
-                        Fields::Unnamed(fields) => {
-                            // TODO Tuple
-                            let mut pattern_tokens = String::new();
-                            let mut pattern_2_tokens = String::new();
-                            let mut block_tokens = String::new();
-
-                            let mut field_attributes = BTreeMap::new();
-                            let mut field_names = BTreeMap::new();
-
-                            for (index, field) in fields.unnamed.iter().enumerate() {
-                                let field_attribute = FieldAttributeBuilder {
-                                    enable_ignore: true,
-                                    enable_impl: true,
-                                    rank: isize::min_value() + index as isize,
-                                    enable_rank: true,
+                        block_tokens.extend(quote! {
+                            match #compare {
+                                ::core::cmp::Ordering::Equal => (),
+                                ::core::cmp::Ordering::Greater => {
+                                    return ::core::cmp::Ordering::Greater;
                                 }
-                                .from_attributes(&field.attrs, traits);
-
-                                let field_name = format!("{}", index);
-
-                                if field_attribute.ignore {
-                                    pattern_tokens.push_str("_,");
-                                    pattern_2_tokens.push_str("_,");
-                                    continue;
+                                ::core::cmp::Ordering::Less => {
+                                    return ::core::cmp::Ordering::Less;
                                 }
-
-                                let rank = field_attribute.rank;
-
-                                if field_attributes.contains_key(&rank) {
-                                    panic::reuse_a_rank(rank);
-                                }
-
-                                pattern_tokens
-                                    .write_fmt(format_args!(
-                                        "_{field_name},",
-                                        field_name = field_name
-                                    ))
-                                    .unwrap();
-                                pattern_2_tokens
-                                    .write_fmt(format_args!(
-                                        "__{field_name},",
-                                        field_name = field_name
-                                    ))
-                                    .unwrap();
-
-                                field_attributes.insert(rank, field_attribute);
-                                field_names.insert(rank, field_name);
                             }
+                        });
+                    }
 
-                            for (index, field_attribute) in field_attributes {
-                                let field_name = field_names.get(&index).unwrap();
-
-                                let compare_trait = field_attribute.compare_trait;
-                                let compare_method = field_attribute.compare_method;
-
-                                match compare_trait {
-                                    Some(compare_trait) => {
-                                        let compare_method = compare_method.unwrap();
+                    arms.extend(quote! {
+                        Self::#variant_ident { #pattern_tokens } => {
+                            if let Self::#variant_ident { #pattern_2_tokens } = #other {
+                                #block_tokens
+                            } else {
+                                let #other_value = #variant_to_integer(#other);
 
-                                        block_tokens.write_fmt(format_args!("match {compare_trait}::{compare_method}(_{field_name}, __{field_name}) {{ core::cmp::Ordering::Equal => (), core::cmp::Ordering::Greater => {{ return core::cmp::Ordering::Greater; }}, core::cmp::Ordering::Less => {{ return core::cmp::Ordering::Less; }} }}", compare_trait = compare_trait, compare_method = compare_method, field_name = field_name)).unwrap();
-                                    }
-                                    None => {
-                                        match compare_method {
-                                            Some(compare_method) => {
-                                                block_tokens.write_fmt(format_args!("match {compare_method}(_{field_name}, __{field_name}) {{ core::cmp::Ordering::Equal => (), core::cmp::Ordering::Greater => {{ return core::cmp::Ordering::Greater; }}, core::cmp::Ordering::Less => {{ return core::cmp::Ordering::Less; }} }}", compare_method = compare_method, field_name = field_name)).unwrap();
-                                            }
-                                            None => {
-                                                block_tokens.write_fmt(format_args!("match core::cmp::Ord::cmp(_{field_name}, __{field_name}) {{ core::cmp::Ordering::Equal => (), core::cmp::Ordering::Greater => {{ return core::cmp::Ordering::Greater; }}, core::cmp::Ordering::Less => {{ return core::cmp::Ordering::Less; }} }}", field_name = field_name)).unwrap();
-                                            }
-                                        }
-                                    }
-                                }
+                                return ::core::cmp::Ord::cmp(&#variant_value, &#other_value);
                             }
-
-                            match_tokens.write_fmt(format_args!("{enum_name}::{variant_ident}( {pattern_tokens} ) => {{ if let {enum_name}::{variant_ident} ( {pattern_2_tokens} ) = other {{ {block_tokens} }} else {{ let other_value = variant_to_integer(other); return core::cmp::Ord::cmp(&{variant_value}, &other_value); }} }}", enum_name = enum_name, variant_ident = variant_ident, pattern_tokens = pattern_tokens, pattern_2_tokens = pattern_2_tokens, block_tokens = block_tokens, variant_value = variant_value)).unwrap();
                         }
-                    }
+                    });
                 }
             } else {
-                unit_to_integer.push_str("};");
-
-                comparer_tokens.extend(TokenStream::from_str(&unit_to_integer).unwrap());
-
-                for (index, _) in variants.into_iter().enumerate() {
-                    let variant_ident = &variant_idents[index];
-
-                    match_tokens.write_fmt(format_args!("{enum_name}::{variant_ident} => {{ let other_value = unit_to_integer(other); return core::cmp::Ord::cmp(&({enum_name}::{variant_ident} as isize), &other_value); }}", enum_name = enum_name, variant_ident = variant_ident)).unwrap();
+                comparer_tokens.extend(quote! {
+                    let #unit_to_integer = |#other: &Self| match #other {
+                        #unit_to_integer_arms
+                    };
+                });
+
+                for (variant, variant_bound) in variants {
+                    extra_bound.extend(
+                        variant_bound.into_punctuated_where_predicates_by_generic_parameters(
+                            &ast.ident,
+                            &ast.generics.params,
+                            &[],
+                        ),
+                    );
+
+                    let variant_ident = &variant.ident;
+
+                    arms.extend(quote! {
+                        Self::#variant_ident => {
+                            let #other_value = #unit_to_integer(#other);
+
+                            return ::core::cmp::Ord::cmp(&(Self::#variant_ident as ::core::primitive::isize), &#other_value);
+                        }
+                    });
                 }
             }
         }
 
-        match_tokens.push('}');
-
-        comparer_tokens.extend(TokenStream::from_str(&match_tokens).unwrap());
+        comparer_tokens.extend(quote! {
+            match self {
+                #arms
+            }
+        });
 
         if has_non_unit_or_custom_value {
-            comparer_tokens.extend(quote!(core::cmp::Ordering::Equal));
+            comparer_tokens.extend(quote!(::core::cmp::Ordering::Equal));
         }
 
-        let ident = &ast.ident;
+        let bound = type_attribute.bound.into_punctuated_where_predicates_by_generic_parameters(
+            &ast.ident,
+            &ast.generics.params,
+            &field_types,
+        );
 
         let mut generics_cloned: Generics = ast.generics.clone();
 
         let where_clause = generics_cloned.make_where_clause();
 
-        for where_predicate in bound {
+        for where_predicate in bound.into_iter().chain(extra_bound) {
             where_clause.predicates.push(where_predicate);
         }
 
         let (impl_generics, ty_generics, where_clause) = generics_cloned.split_for_impl();
 
         let compare_impl = quote! {
-            impl #impl_generics core::cmp::Ord for #ident #ty_generics #where_clause {
+            impl #impl_generics ::core::cmp::Ord for #ident #ty_generics #where_clause {
                 #[inline]
                 #[allow(unreachable_code, clippy::unneeded_field_pattern)]
-                fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                fn cmp(&self, #other: &Self) -> ::core::cmp::Ordering {
                     #comparer_tokens
                 }
             }
         };
 
+        errors.finish()?;
+
         tokens.extend(compare_impl);
+
+        Ok(())
     }
 }
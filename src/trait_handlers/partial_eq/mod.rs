@@ -5,7 +5,7 @@ mod partial_eq_struct;
 
 use super::TraitHandler;
 
-use crate::panic;
+use crate::error;
 use crate::proc_macro2::TokenStream;
 use crate::syn::{Data, DeriveInput, Meta};
 use crate::Trait;
@@ -21,15 +21,17 @@ impl TraitHandler for PartialEqHandler {
         tokens: &mut TokenStream,
         traits: &[Trait],
         meta: &Meta,
-    ) {
+    ) -> Result<(), syn::Error> {
         match ast.data {
             Data::Struct(_) => {
-                PartialEqStructHandler::trait_meta_handler(ast, tokens, traits, meta);
+                PartialEqStructHandler::trait_meta_handler(ast, tokens, traits, meta)?;
             }
             Data::Enum(_) => {
-                PartialEqEnumHandler::trait_meta_handler(ast, tokens, traits, meta);
+                PartialEqEnumHandler::trait_meta_handler(ast, tokens, traits, meta)?;
             }
-            Data::Union(_) => panic::trait_not_support_union(Trait::PartialEq),
+            Data::Union(_) => return Err(error::trait_not_support_union(meta, Trait::PartialEq)),
         }
+
+        Ok(())
     }
 }
@@ -1,12 +1,14 @@
 use super::super::super::{
-    create_where_predicates_from_generic_parameters, create_where_predicates_from_lit_str,
+    create_idents_from_lit_str, create_where_predicates_from_field_types_with_rhs,
+    create_where_predicates_from_generic_parameters_with_rhs, create_where_predicates_from_lit_str,
+    create_where_predicates_from_rhs, parse_educe_meta,
 };
 
-use crate::panic;
+use crate::error;
 use crate::quote::ToTokens;
 use crate::syn::{
-    punctuated::Punctuated, token::Comma, Attribute, GenericParam, Lit, Meta, NestedMeta,
-    WherePredicate,
+    punctuated::Punctuated, token::Comma, Attribute, GenericParam, Ident, Lit, Meta, NestedMeta,
+    Type, WherePredicate,
 };
 use crate::Trait;
 
@@ -14,20 +16,40 @@ use crate::Trait;
 pub enum TypeAttributeBound {
     None,
     Auto,
+    Infer,
     Custom(Punctuated<WherePredicate, Comma>),
 }
 
 impl TypeAttributeBound {
     pub fn into_punctuated_where_predicates_by_generic_parameters(
         self,
+        ident: &Ident,
         params: &Punctuated<GenericParam, Comma>,
+        field_types: &[&Type],
+        rhs: &[(Ident, Ident)],
     ) -> Punctuated<WherePredicate, Comma> {
         match self {
-            TypeAttributeBound::None => Punctuated::new(),
+            TypeAttributeBound::None => {
+                create_where_predicates_from_rhs(
+                    field_types,
+                    &syn::parse2(quote!(::core::cmp::PartialEq)).unwrap(),
+                    rhs,
+                )
+            }
             TypeAttributeBound::Auto => {
-                create_where_predicates_from_generic_parameters(
+                create_where_predicates_from_generic_parameters_with_rhs(
                     params,
-                    &syn::parse2(quote!(core::cmp::PartialEq)).unwrap(),
+                    &syn::parse2(quote!(::core::cmp::PartialEq)).unwrap(),
+                    rhs,
+                )
+            }
+            TypeAttributeBound::Infer => {
+                create_where_predicates_from_field_types_with_rhs(
+                    ident,
+                    params,
+                    field_types,
+                    &syn::parse2(quote!(::core::cmp::PartialEq)).unwrap(),
+                    rhs,
                 )
             }
             TypeAttributeBound::Custom(where_predicates) => where_predicates,
@@ -35,22 +57,62 @@ impl TypeAttributeBound {
     }
 }
 
+#[derive(Clone)]
+pub enum TypeAttributeRhs {
+    None,
+    All,
+    Custom(Punctuated<Ident, Comma>),
+}
+
+impl TypeAttributeRhs {
+    pub fn into_idents_by_generic_parameters(
+        self,
+        params: &Punctuated<GenericParam, Comma>,
+    ) -> Result<Vec<Ident>, syn::Error> {
+        let type_params = params.iter().filter_map(|param| {
+            match param {
+                GenericParam::Type(typ) => Some(&typ.ident),
+                _ => None,
+            }
+        });
+
+        match self {
+            TypeAttributeRhs::None => Ok(Vec::new()),
+            TypeAttributeRhs::All => Ok(type_params.cloned().collect()),
+            TypeAttributeRhs::Custom(idents) => {
+                let type_params: Vec<&Ident> = type_params.collect();
+
+                for ident in idents.iter() {
+                    if !type_params.contains(&ident) {
+                        return Err(error::not_a_type_parameter(ident, &ident.to_string()));
+                    }
+                }
+
+                Ok(idents.into_iter().collect())
+            }
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct TypeAttribute {
     pub flag: bool,
     pub bound: TypeAttributeBound,
+    pub rhs: TypeAttributeRhs,
 }
 
 #[derive(Debug, Clone)]
 pub struct TypeAttributeBuilder {
     pub enable_flag: bool,
     pub enable_bound: bool,
+    pub enable_rhs: bool,
 }
 
 impl TypeAttributeBuilder {
-    pub fn from_partial_eq_meta(&self, meta: &Meta) -> TypeAttribute {
+    pub fn from_partial_eq_meta(&self, meta: &Meta) -> Result<TypeAttribute, syn::Error> {
         let mut flag = false;
         let mut bound = TypeAttributeBound::None;
+        let mut rhs = TypeAttributeRhs::None;
 
         let correct_usage_for_partial_eq_attribute = {
             let mut usage = vec![];
@@ -65,8 +127,20 @@ impl TypeAttributeBuilder {
         let correct_usage_for_bound = {
             let usage = vec![
                 stringify!(#[educe(PartialEq(bound))]),
+                stringify!(#[educe(PartialEq(bound(*)))]),
                 stringify!(#[educe(PartialEq(bound = "where_predicates"))]),
                 stringify!(#[educe(PartialEq(bound("where_predicates")))]),
+                stringify!(#[educe(PartialEq(bound(where_predicates)))]),
+            ];
+
+            usage
+        };
+
+        let correct_usage_for_rhs = {
+            let usage = vec![
+                stringify!(#[educe(PartialEq(rhs))]),
+                stringify!(#[educe(PartialEq(rhs = "generic_parameters"))]),
+                stringify!(#[educe(PartialEq(rhs("generic_parameters")))]),
             ];
 
             usage
@@ -75,6 +149,7 @@ impl TypeAttributeBuilder {
         match meta {
             Meta::List(list) => {
                 let mut bound_is_set = false;
+                let mut rhs_is_set = false;
 
                 for p in list.nested.iter() {
                     match p {
@@ -84,7 +159,11 @@ impl TypeAttributeBuilder {
                             match meta_name.as_str() {
                                 "bound" => {
                                     if !self.enable_bound {
-                                        panic::unknown_parameter("PartialEq", meta_name.as_str());
+                                        return Err(error::unknown_parameter(
+                                            meta,
+                                            "PartialEq",
+                                            meta_name.as_str(),
+                                        ));
                                     }
 
                                     match meta {
@@ -95,40 +174,54 @@ impl TypeAttributeBuilder {
                                                         match lit {
                                                             Lit::Str(s) => {
                                                                 if bound_is_set {
-                                                                    panic::reset_parameter(
-                                                                        meta_name.as_str(),
+                                                                    return Err(
+                                                                        error::reset_parameter(
+                                                                            meta,
+                                                                            meta_name.as_str(),
+                                                                        ),
                                                                     );
                                                                 }
 
                                                                 bound_is_set = true;
 
-                                                                let where_predicates = create_where_predicates_from_lit_str(s);
+                                                                bound = if s.value().trim() == "*" {
+                                                                    TypeAttributeBound::Infer
+                                                                } else {
+                                                                    let where_predicates = create_where_predicates_from_lit_str(s)?;
 
-                                                                bound = match where_predicates {
+                                                                    match where_predicates {
                                                                     Some(where_predicates) => {
                                                                         TypeAttributeBound::Custom(
                                                                             where_predicates,
                                                                         )
                                                                     }
                                                                     None => {
-                                                                        panic::empty_parameter(
-                                                                            meta_name.as_str(),
+                                                                        return Err(
+                                                                            error::empty_parameter(
+                                                                                meta,
+                                                                                meta_name.as_str(),
+                                                                            ),
                                                                         )
                                                                     }
+                                                                }
                                                                 };
                                                             }
-                                                            _ => {
-                                                                panic::parameter_incorrect_format(
+                                                            _ => return Err(
+                                                                error::parameter_incorrect_format(
+                                                                    meta,
                                                                     meta_name.as_str(),
                                                                     &correct_usage_for_bound,
-                                                                )
-                                                            }
+                                                                ),
+                                                            ),
                                                         }
                                                     }
                                                     _ => {
-                                                        panic::parameter_incorrect_format(
-                                                            meta_name.as_str(),
-                                                            &correct_usage_for_bound,
+                                                        return Err(
+                                                            error::parameter_incorrect_format(
+                                                                meta,
+                                                                meta_name.as_str(),
+                                                                &correct_usage_for_bound,
+                                                            ),
                                                         )
                                                     }
                                                 }
@@ -140,38 +233,52 @@ impl TypeAttributeBuilder {
                                             match lit {
                                                 Lit::Str(s) => {
                                                     if bound_is_set {
-                                                        panic::reset_parameter(meta_name.as_str());
+                                                        return Err(error::reset_parameter(
+                                                            meta,
+                                                            meta_name.as_str(),
+                                                        ));
                                                     }
 
                                                     bound_is_set = true;
 
-                                                    let where_predicates =
-                                                        create_where_predicates_from_lit_str(s);
-
-                                                    bound = match where_predicates {
-                                                        Some(where_predicates) => {
-                                                            TypeAttributeBound::Custom(
-                                                                where_predicates,
-                                                            )
-                                                        }
-                                                        None => {
-                                                            panic::empty_parameter(
-                                                                meta_name.as_str(),
-                                                            )
+                                                    bound = if s.value().trim() == "*" {
+                                                        TypeAttributeBound::Infer
+                                                    } else {
+                                                        let where_predicates =
+                                                            create_where_predicates_from_lit_str(
+                                                                s,
+                                                            )?;
+
+                                                        match where_predicates {
+                                                            Some(where_predicates) => {
+                                                                TypeAttributeBound::Custom(
+                                                                    where_predicates,
+                                                                )
+                                                            }
+                                                            None => {
+                                                                return Err(error::empty_parameter(
+                                                                    meta,
+                                                                    meta_name.as_str(),
+                                                                ))
+                                                            }
                                                         }
                                                     };
                                                 }
                                                 _ => {
-                                                    panic::parameter_incorrect_format(
+                                                    return Err(error::parameter_incorrect_format(
+                                                        meta,
                                                         meta_name.as_str(),
                                                         &correct_usage_for_bound,
-                                                    )
+                                                    ))
                                                 }
                                             }
                                         }
                                         Meta::Path(_) => {
                                             if bound_is_set {
-                                                panic::reset_parameter(meta_name.as_str());
+                                                return Err(error::reset_parameter(
+                                                    meta,
+                                                    meta_name.as_str(),
+                                                ));
                                             }
 
                                             bound_is_set = true;
@@ -180,85 +287,216 @@ impl TypeAttributeBuilder {
                                         }
                                     }
                                 }
-                                _ => panic::unknown_parameter("PartialEq", meta_name.as_str()),
+                                "rhs" => {
+                                    if !self.enable_rhs {
+                                        return Err(error::unknown_parameter(
+                                            meta,
+                                            "PartialEq",
+                                            meta_name.as_str(),
+                                        ));
+                                    }
+
+                                    match meta {
+                                        Meta::List(list) => {
+                                            for p in list.nested.iter() {
+                                                match p {
+                                                    NestedMeta::Lit(lit) => {
+                                                        match lit {
+                                                            Lit::Str(s) => {
+                                                                if rhs_is_set {
+                                                                    return Err(
+                                                                        error::reset_parameter(
+                                                                            meta,
+                                                                            meta_name.as_str(),
+                                                                        ),
+                                                                    );
+                                                                }
+
+                                                                rhs_is_set = true;
+
+                                                                let idents =
+                                                                    create_idents_from_lit_str(s)?;
+
+                                                                rhs = match idents {
+                                                                    Some(idents) => {
+                                                                        TypeAttributeRhs::Custom(
+                                                                            idents,
+                                                                        )
+                                                                    }
+                                                                    None => {
+                                                                        return Err(
+                                                                            error::empty_parameter(
+                                                                                meta,
+                                                                                meta_name.as_str(),
+                                                                            ),
+                                                                        )
+                                                                    }
+                                                                };
+                                                            }
+                                                            _ => return Err(
+                                                                error::parameter_incorrect_format(
+                                                                    meta,
+                                                                    meta_name.as_str(),
+                                                                    &correct_usage_for_rhs,
+                                                                ),
+                                                            ),
+                                                        }
+                                                    }
+                                                    _ => {
+                                                        return Err(
+                                                            error::parameter_incorrect_format(
+                                                                meta,
+                                                                meta_name.as_str(),
+                                                                &correct_usage_for_rhs,
+                                                            ),
+                                                        )
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        Meta::NameValue(named_value) => {
+                                            let lit = &named_value.lit;
+
+                                            match lit {
+                                                Lit::Str(s) => {
+                                                    if rhs_is_set {
+                                                        return Err(error::reset_parameter(
+                                                            meta,
+                                                            meta_name.as_str(),
+                                                        ));
+                                                    }
+
+                                                    rhs_is_set = true;
+
+                                                    let idents = create_idents_from_lit_str(s)?;
+
+                                                    rhs = match idents {
+                                                        Some(idents) => {
+                                                            TypeAttributeRhs::Custom(idents)
+                                                        }
+                                                        None => {
+                                                            return Err(error::empty_parameter(
+                                                                meta,
+                                                                meta_name.as_str(),
+                                                            ))
+                                                        }
+                                                    };
+                                                }
+                                                _ => {
+                                                    return Err(error::parameter_incorrect_format(
+                                                        meta,
+                                                        meta_name.as_str(),
+                                                        &correct_usage_for_rhs,
+                                                    ))
+                                                }
+                                            }
+                                        }
+                                        Meta::Path(_) => {
+                                            if rhs_is_set {
+                                                return Err(error::reset_parameter(
+                                                    meta,
+                                                    meta_name.as_str(),
+                                                ));
+                                            }
+
+                                            rhs_is_set = true;
+
+                                            rhs = TypeAttributeRhs::All;
+                                        }
+                                    }
+                                }
+                                _ => {
+                                    return Err(error::unknown_parameter(
+                                        meta,
+                                        "PartialEq",
+                                        meta_name.as_str(),
+                                    ))
+                                }
                             }
                         }
                         _ => {
-                            panic::attribute_incorrect_format(
+                            return Err(error::attribute_incorrect_format(
+                                meta,
                                 "PartialEq",
                                 &correct_usage_for_partial_eq_attribute,
-                            )
+                            ))
                         }
                     }
                 }
             }
             Meta::NameValue(_) => {
-                panic::attribute_incorrect_format(
+                return Err(error::attribute_incorrect_format(
+                    meta,
                     "PartialEq",
                     &correct_usage_for_partial_eq_attribute,
-                )
+                ))
             }
             Meta::Path(_) => {
                 if !self.enable_flag {
-                    panic::attribute_incorrect_format(
+                    return Err(error::attribute_incorrect_format(
+                        meta,
                         "PartialEq",
                         &correct_usage_for_partial_eq_attribute,
-                    );
+                    ));
                 }
 
                 flag = true;
             }
         }
 
-        TypeAttribute {
+        Ok(TypeAttribute {
             flag,
             bound,
-        }
+            rhs,
+        })
     }
 
     #[allow(clippy::wrong_self_convention)]
-    pub fn from_attributes(self, attributes: &[Attribute], traits: &[Trait]) -> TypeAttribute {
+    pub fn from_attributes(
+        self,
+        attributes: &[Attribute],
+        traits: &[Trait],
+    ) -> Result<TypeAttribute, syn::Error> {
         let mut result = None;
 
         for attribute in attributes.iter() {
             if let Some(meta_name) = attribute.path.get_ident() {
                 if meta_name == "educe" {
-                    let meta = attribute.parse_meta().unwrap();
+                    let meta = parse_educe_meta(attribute)?;
 
                     match meta {
                         Meta::List(list) => {
                             for p in list.nested.iter() {
                                 match p {
                                     NestedMeta::Meta(meta) => {
-                                        let meta_name = meta.path().into_token_stream().to_string();
-
-                                        let t = Trait::from_str(meta_name);
+                                        let t = Trait::from_path(meta.path())?;
 
                                         if traits.binary_search(&t).is_err() {
-                                            panic::trait_not_used(t);
+                                            return Err(error::trait_not_used(meta, t));
                                         }
 
                                         if t == Trait::PartialEq {
                                             if result.is_some() {
-                                                panic::reuse_a_trait(t);
+                                                return Err(error::reuse_a_trait(meta, t));
                                             }
 
-                                            result = Some(self.from_partial_eq_meta(&meta));
+                                            result = Some(self.from_partial_eq_meta(&meta)?);
                                         }
                                     }
-                                    _ => panic::educe_format_incorrect(),
+                                    _ => return Err(error::educe_format_incorrect(attribute)),
                                 }
                             }
                         }
-                        _ => panic::educe_format_incorrect(),
+                        _ => return Err(error::educe_format_incorrect(attribute)),
                     }
                 }
             }
         }
 
-        result.unwrap_or(TypeAttribute {
+        Ok(result.unwrap_or(TypeAttribute {
             flag: false,
             bound: TypeAttributeBound::None,
-        })
+            rhs: TypeAttributeRhs::None,
+        }))
     }
 }
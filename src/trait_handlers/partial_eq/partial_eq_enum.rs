@@ -1,11 +1,12 @@
-use std::fmt::Write;
-use std::str::FromStr;
-
-use super::super::TraitHandler;
+use super::super::{
+    create_field_binding, create_field_binding_2, create_field_member, create_hygienic_ident,
+    create_rhs_generics, create_rhs_idents, TraitHandler,
+};
 use super::models::{FieldAttributeBuilder, TypeAttributeBuilder};
 
+use crate::error::Errors;
 use crate::proc_macro2::TokenStream;
-use crate::syn::{Data, DeriveInput, Fields, Generics, Meta};
+use crate::syn::{Data, DeriveInput, Generics, Meta};
 use crate::Trait;
 
 pub struct PartialEqEnumHandler;
@@ -16,200 +17,182 @@ impl TraitHandler for PartialEqEnumHandler {
         tokens: &mut TokenStream,
         traits: &[Trait],
         meta: &Meta,
-    ) {
+    ) -> Result<(), syn::Error> {
+        let mut errors = Errors::new();
+
         let type_attribute = TypeAttributeBuilder {
             enable_flag: true,
             enable_bound: true,
+            enable_rhs: true,
         }
-        .from_partial_eq_meta(meta);
-
-        let enum_name = ast.ident.to_string();
-
-        let bound = type_attribute
-            .bound
-            .into_punctuated_where_predicates_by_generic_parameters(&ast.generics.params);
+        .from_partial_eq_meta(meta)?;
 
-        let mut comparer_tokens = TokenStream::new();
+        let rhs = create_rhs_idents(
+            ast,
+            type_attribute.rhs.into_idents_by_generic_parameters(&ast.generics.params)?,
+        );
 
-        let mut match_tokens = String::from("match self {");
-
-        if let Data::Enum(data) = &ast.data {
-            for variant in data.variants.iter() {
-                let _ = TypeAttributeBuilder {
-                    enable_flag: false,
-                    enable_bound: false,
-                }
-                .from_attributes(&variant.attrs, traits);
+        let ident = &ast.ident;
 
-                let variant_ident = variant.ident.to_string();
+        let mut generics_cloned: Generics = ast.generics.clone();
 
-                match &variant.fields {
-                    Fields::Unit => {
-                        // TODO Unit
-                        match_tokens.write_fmt(format_args!("{enum_name}::{variant_ident} => {{ if let {enum_name}::{variant_ident} = other {{ }} else {{ return false; }} }}", enum_name = enum_name, variant_ident = variant_ident)).unwrap();
-                    }
-                    Fields::Named(fields) => {
-                        // TODO Struct
-                        let mut pattern_tokens = String::new();
-                        let mut pattern_2_tokens = String::new();
-                        let mut block_tokens = String::new();
-
-                        let mut field_attributes = Vec::new();
-                        let mut field_names = Vec::new();
-
-                        for field in fields.named.iter() {
-                            let field_attribute = FieldAttributeBuilder {
-                                enable_ignore: true,
-                                enable_impl: true,
-                            }
-                            .from_attributes(&field.attrs, traits);
-
-                            let field_name = field.ident.as_ref().unwrap().to_string();
-
-                            if field_attribute.ignore {
-                                pattern_tokens
-                                    .write_fmt(format_args!(
-                                        "{field_name}: _,",
-                                        field_name = field_name
-                                    ))
-                                    .unwrap();
-                                pattern_2_tokens
-                                    .write_fmt(format_args!(
-                                        "{field_name}: _,",
-                                        field_name = field_name
-                                    ))
-                                    .unwrap();
-                                continue;
-                            }
+        let rhs_ty_generics = create_rhs_generics(&mut generics_cloned, &rhs);
 
-                            pattern_tokens
-                                .write_fmt(format_args!("{field_name},", field_name = field_name))
-                                .unwrap();
-                            pattern_2_tokens
-                                .write_fmt(format_args!(
-                                    "{field_name}: ___{field_name},",
-                                    field_name = field_name
-                                ))
-                                .unwrap();
-
-                            field_attributes.push(field_attribute);
-                            field_names.push(field_name);
-                        }
+        let (compare_trait, rhs_ty, rhs_path) = if rhs.is_empty() {
+            (quote!(::core::cmp::PartialEq), quote!(Self), quote!(Self))
+        } else {
+            (
+                quote!(::core::cmp::PartialEq<#ident #rhs_ty_generics>),
+                quote!(#ident #rhs_ty_generics),
+                quote!(#ident::#rhs_ty_generics),
+            )
+        };
 
-                        for (index, field_attribute) in field_attributes.into_iter().enumerate() {
-                            let field_name = &field_names[index];
+        let other = create_hygienic_ident("other");
 
-                            let compare_trait = field_attribute.compare_trait;
-                            let compare_method = field_attribute.compare_method;
+        let mut arms = TokenStream::new();
 
-                            match compare_trait {
-                                Some(compare_trait) => {
-                                    let compare_method = compare_method.unwrap();
+        let mut field_types = Vec::new();
+        let mut extra_bound = Vec::new();
 
-                                    block_tokens.write_fmt(format_args!("if !{compare_trait}::{compare_method}({field_name}, ___{field_name}) {{ return false; }}", compare_trait = compare_trait, compare_method = compare_method, field_name = field_name)).unwrap();
-                                }
-                                None => {
-                                    match compare_method {
-                                        Some(compare_method) => {
-                                            block_tokens.write_fmt(format_args!("if !{compare_method}({field_name}, ___{field_name}) {{ return false; }}", compare_method = compare_method, field_name = field_name)).unwrap();
-                                        }
-                                        None => {
-                                            block_tokens.write_fmt(format_args!("if core::cmp::PartialEq::ne({field_name}, ___{field_name}) {{ return false; }}", field_name = field_name)).unwrap();
-                                        }
-                                    }
-                                }
-                            }
+        if let Data::Enum(data) = &ast.data {
+            for variant in data.variants.iter() {
+                let variant_attribute = match errors.handle(
+                    TypeAttributeBuilder {
+                        enable_flag: false,
+                        enable_bound: true,
+                        enable_rhs: false,
+                    }
+                    .from_attributes(&variant.attrs, traits),
+                ) {
+                    Some(variant_attribute) => variant_attribute,
+                    None => continue,
+                };
+
+                let variant_ident = &variant.ident;
+
+                let variant_field_types_start = field_types.len();
+
+                let mut pattern_tokens = TokenStream::new();
+                let mut pattern_2_tokens = TokenStream::new();
+                let mut block_tokens = TokenStream::new();
+
+                for (index, field) in variant.fields.iter().enumerate() {
+                    let field_attribute = match errors.handle(
+                        FieldAttributeBuilder {
+                            enable_ignore: true,
+                            enable_impl: true,
+                            enable_bound: true,
                         }
+                        .from_attributes(&field.attrs, traits),
+                    ) {
+                        Some(field_attribute) => field_attribute,
+                        None => continue,
+                    };
 
-                        match_tokens.write_fmt(format_args!("{enum_name}::{variant_ident}{{ {pattern_tokens} }} => {{ if let {enum_name}::{variant_ident} {{ {pattern_2_tokens} }} = other {{ {block_tokens} }} }}", enum_name = enum_name, variant_ident = variant_ident, pattern_tokens = pattern_tokens, pattern_2_tokens = pattern_2_tokens, block_tokens = block_tokens)).unwrap();
-                    }
-                    Fields::Unnamed(fields) => {
-                        // TODO Tuple
-                        let mut pattern_tokens = String::new();
-                        let mut pattern_2_tokens = String::new();
-                        let mut block_tokens = String::new();
-
-                        let mut field_attributes = Vec::new();
-                        let mut field_names = Vec::new();
-
-                        for (index, field) in fields.unnamed.iter().enumerate() {
-                            let field_attribute = FieldAttributeBuilder {
-                                enable_ignore: true,
-                                enable_impl: true,
-                            }
-                            .from_attributes(&field.attrs, traits);
+                    extra_bound.extend(field_attribute.bound.iter().cloned());
 
-                            let field_name = format!("{}", index);
+                    let field_member = create_field_member(index, field);
 
-                            if field_attribute.ignore {
-                                pattern_tokens.push_str("_,");
-                                pattern_2_tokens.push_str("_,");
-                                continue;
-                            }
+                    if field_attribute.ignore {
+                        pattern_tokens.extend(quote!(#field_member: _,));
+                        pattern_2_tokens.extend(quote!(#field_member: _,));
 
-                            pattern_tokens
-                                .write_fmt(format_args!("_{field_name},", field_name = field_name))
-                                .unwrap();
-                            pattern_2_tokens
-                                .write_fmt(format_args!("__{field_name},", field_name = field_name))
-                                .unwrap();
+                        continue;
+                    }
 
-                            field_attributes.push(field_attribute);
-                            field_names.push(field_name);
-                        }
+                    let field_binding = create_field_binding(index);
+                    let field_binding_2 = create_field_binding_2(index);
 
-                        for (index, field_attribute) in field_attributes.into_iter().enumerate() {
-                            let field_name = &field_names[index];
+                    pattern_tokens.extend(quote!(#field_member: #field_binding,));
+                    pattern_2_tokens.extend(quote!(#field_member: #field_binding_2,));
 
-                            let compare_trait = field_attribute.compare_trait;
-                            let compare_method = field_attribute.compare_method;
+                    if field_attribute.compare_method.is_none() {
+                        field_types.push(&field.ty);
+                    }
 
-                            match compare_trait {
-                                Some(compare_trait) => {
-                                    let compare_method = compare_method.unwrap();
+                    let statement = match field_attribute.compare_trait {
+                        Some(compare_trait) => {
+                            let compare_method = field_attribute.compare_method.unwrap();
 
-                                    block_tokens.write_fmt(format_args!("if !{compare_trait}::{compare_method}(_{field_name}, __{field_name}) {{ return false; }}", compare_trait = compare_trait, compare_method = compare_method, field_name = field_name)).unwrap();
+                            quote! {
+                                if !#compare_trait::#compare_method(#field_binding, #field_binding_2) {
+                                    return false;
                                 }
-                                None => {
-                                    match compare_method {
-                                        Some(compare_method) => {
-                                            block_tokens.write_fmt(format_args!("if !{compare_method}(_{field_name}, __{field_name}) {{ return false; }}", compare_method = compare_method, field_name = field_name)).unwrap();
+                            }
+                        }
+                        None => {
+                            match field_attribute.compare_method {
+                                Some(compare_method) => {
+                                    quote! {
+                                        if !#compare_method(#field_binding, #field_binding_2) {
+                                            return false;
                                         }
-                                        None => {
-                                            block_tokens.write_fmt(format_args!("if core::cmp::PartialEq::ne(_{field_name}, __{field_name}) {{ return false; }}", field_name = field_name)).unwrap();
+                                    }
+                                }
+                                None => {
+                                    quote! {
+                                        if ::core::cmp::PartialEq::ne(#field_binding, #field_binding_2) {
+                                            return false;
                                         }
                                     }
                                 }
                             }
                         }
+                    };
 
-                        match_tokens.write_fmt(format_args!("{enum_name}::{variant_ident}( {pattern_tokens} ) => {{ if let {enum_name}::{variant_ident} ( {pattern_2_tokens} ) = other {{ {block_tokens} }} }}", enum_name = enum_name, variant_ident = variant_ident, pattern_tokens = pattern_tokens, pattern_2_tokens = pattern_2_tokens, block_tokens = block_tokens)).unwrap();
-                    }
+                    block_tokens.extend(statement);
                 }
+
+                extra_bound.extend(
+                    variant_attribute.bound.into_punctuated_where_predicates_by_generic_parameters(
+                        &ast.ident,
+                        &ast.generics.params,
+                        &field_types[variant_field_types_start..],
+                        &rhs,
+                    ),
+                );
+
+                arms.extend(quote! {
+                    Self::#variant_ident { #pattern_tokens } => {
+                        if let #rhs_path::#variant_ident { #pattern_2_tokens } = #other {
+                            #block_tokens
+                        } else {
+                            return false;
+                        }
+                    }
+                });
             }
         }
 
-        match_tokens.push('}');
-
-        comparer_tokens.extend(TokenStream::from_str(&match_tokens).unwrap());
-
-        let ident = &ast.ident;
+        let comparer_tokens = quote! {
+            match self {
+                #arms
+            }
+        };
 
-        let mut generics_cloned: Generics = ast.generics.clone();
+        let bound = type_attribute.bound.into_punctuated_where_predicates_by_generic_parameters(
+            &ast.ident,
+            &ast.generics.params,
+            &field_types,
+            &rhs,
+        );
 
         let where_clause = generics_cloned.make_where_clause();
 
-        for where_predicate in bound {
+        for where_predicate in bound.into_iter().chain(extra_bound) {
             where_clause.predicates.push(where_predicate);
         }
 
-        let (impl_generics, ty_generics, where_clause) = generics_cloned.split_for_impl();
+        let (impl_generics, _, where_clause) = generics_cloned.split_for_impl();
+
+        let (_, ty_generics, _) = ast.generics.split_for_impl();
 
         let compare_impl = quote! {
-            impl #impl_generics core::cmp::PartialEq for #ident #ty_generics #where_clause {
+            impl #impl_generics #compare_trait for #ident #ty_generics #where_clause {
                 #[inline]
                 #[allow(clippy::unneeded_field_pattern)]
-                fn eq(&self, other: &Self) -> bool {
+                fn eq(&self, #other: &#rhs_ty) -> ::core::primitive::bool {
                     #comparer_tokens
 
                     true
@@ -217,6 +200,10 @@ impl TraitHandler for PartialEqEnumHandler {
             }
         };
 
+        errors.finish()?;
+
         tokens.extend(compare_impl);
+
+        Ok(())
     }
 }
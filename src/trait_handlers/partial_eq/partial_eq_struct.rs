@@ -1,8 +1,11 @@
-use std::str::FromStr;
-
-use super::super::TraitHandler;
+use super::super::{
+    create_field_member, create_field_reference, create_hygienic_ident,
+    create_packed_field_where_predicate, create_rhs_generics, create_rhs_idents, is_packed,
+    TraitHandler,
+};
 use super::models::{FieldAttributeBuilder, TypeAttributeBuilder};
 
+use crate::error::Errors;
 use crate::proc_macro2::TokenStream;
 use crate::syn::{Data, DeriveInput, Generics, Meta};
 use crate::Trait;
@@ -15,82 +18,133 @@ impl TraitHandler for PartialEqStructHandler {
         tokens: &mut TokenStream,
         traits: &[Trait],
         meta: &Meta,
-    ) {
+    ) -> Result<(), syn::Error> {
+        let mut errors = Errors::new();
+
         let type_attribute = TypeAttributeBuilder {
             enable_flag: true,
             enable_bound: true,
+            enable_rhs: true,
         }
-        .from_partial_eq_meta(meta);
+        .from_partial_eq_meta(meta)?;
+
+        let rhs = create_rhs_idents(
+            ast,
+            type_attribute.rhs.into_idents_by_generic_parameters(&ast.generics.params)?,
+        );
+
+        let other = create_hygienic_ident("other");
 
-        let bound = type_attribute
-            .bound
-            .into_punctuated_where_predicates_by_generic_parameters(&ast.generics.params);
+        let packed = is_packed(ast);
 
         let mut comparer_tokens = TokenStream::new();
 
+        let mut field_types = Vec::new();
+        let mut extra_bound = Vec::new();
+
         if let Data::Struct(data) = &ast.data {
             for (index, field) in data.fields.iter().enumerate() {
-                let field_attribute = FieldAttributeBuilder {
-                    enable_ignore: true,
-                    enable_impl: true,
-                }
-                .from_attributes(&field.attrs, traits);
+                let field_attribute = match errors.handle(
+                    FieldAttributeBuilder {
+                        enable_ignore: true,
+                        enable_impl: true,
+                        enable_bound: true,
+                    }
+                    .from_attributes(&field.attrs, traits),
+                ) {
+                    Some(field_attribute) => field_attribute,
+                    None => continue,
+                };
+
+                extra_bound.extend(field_attribute.bound.iter().cloned());
 
                 if field_attribute.ignore {
                     continue;
                 }
 
-                let compare_trait = field_attribute.compare_trait;
-                let compare_method = field_attribute.compare_method;
+                let field_member = create_field_member(index, field);
 
-                let field_name = if let Some(ident) = field.ident.as_ref() {
-                    ident.to_string()
-                } else {
-                    format!("{}", index)
-                };
+                if field_attribute.compare_method.is_none() {
+                    field_types.push(&field.ty);
+                }
 
-                match compare_trait {
-                    Some(compare_trait) => {
-                        let compare_method = compare_method.unwrap();
+                if packed {
+                    extra_bound.push(create_packed_field_where_predicate(&field.ty));
+                }
+
+                let self_field = create_field_reference(quote!(self), &field_member, packed);
+                let other_field = create_field_reference(&other, &field_member, packed);
 
-                        let statement = format!("if !{compare_trait}::{compare_method}(&self.{field_name}, &other.{field_name}) {{ return false }}", compare_trait = compare_trait, compare_method = compare_method, field_name = field_name);
+                let statement = match field_attribute.compare_trait {
+                    Some(compare_trait) => {
+                        let compare_method = field_attribute.compare_method.unwrap();
 
-                        comparer_tokens.extend(TokenStream::from_str(&statement).unwrap());
+                        quote! {
+                            if !#compare_trait::#compare_method(#self_field, #other_field) {
+                                return false;
+                            }
+                        }
                     }
                     None => {
-                        match compare_method {
+                        match field_attribute.compare_method {
                             Some(compare_method) => {
-                                let statement = format!("if !{compare_method}(&self.{field_name}, &other.{field_name}) {{ return false; }}", compare_method = compare_method, field_name = field_name);
-
-                                comparer_tokens.extend(TokenStream::from_str(&statement).unwrap());
+                                quote! {
+                                    if !#compare_method(#self_field, #other_field) {
+                                        return false;
+                                    }
+                                }
                             }
                             None => {
-                                let statement = format!("if core::cmp::PartialEq::ne(&self.{field_name}, &other.{field_name}) {{ return false; }}", field_name = field_name);
-
-                                comparer_tokens.extend(TokenStream::from_str(&statement).unwrap());
+                                quote! {
+                                    if ::core::cmp::PartialEq::ne(#self_field, #other_field) {
+                                        return false;
+                                    }
+                                }
                             }
                         }
                     }
-                }
+                };
+
+                comparer_tokens.extend(statement);
             }
         }
 
         let ident = &ast.ident;
 
+        let bound = type_attribute.bound.into_punctuated_where_predicates_by_generic_parameters(
+            &ast.ident,
+            &ast.generics.params,
+            &field_types,
+            &rhs,
+        );
+
         let mut generics_cloned: Generics = ast.generics.clone();
 
+        let rhs_ty_generics = create_rhs_generics(&mut generics_cloned, &rhs);
+
         let where_clause = generics_cloned.make_where_clause();
 
-        for where_predicate in bound {
+        for where_predicate in bound.into_iter().chain(extra_bound) {
             where_clause.predicates.push(where_predicate);
         }
 
-        let (impl_generics, ty_generics, where_clause) = generics_cloned.split_for_impl();
+        let (impl_generics, _, where_clause) = generics_cloned.split_for_impl();
+
+        let (_, ty_generics, _) = ast.generics.split_for_impl();
+
+        let (compare_trait, rhs_ty) = if rhs.is_empty() {
+            (quote!(::core::cmp::PartialEq), quote!(Self))
+        } else {
+            (
+                quote!(::core::cmp::PartialEq<#ident #rhs_ty_generics>),
+                quote!(#ident #rhs_ty_generics),
+            )
+        };
 
         let compare_impl = quote! {
-            impl #impl_generics core::cmp::PartialEq for #ident #ty_generics #where_clause {
+            impl #impl_generics #compare_trait for #ident #ty_generics #where_clause {
                 #[inline]
-                fn eq(&self, other: &Self) -> bool {
+                fn eq(&self, #other: &#rhs_ty) -> ::core::primitive::bool {
                     #comparer_tokens
 
                     true
@@ -98,6 +152,10 @@ impl TraitHandler for PartialEqStructHandler {
             }
         };
 
+        errors.finish()?;
+
         tokens.extend(compare_impl);
+
+        Ok(())
     }
 }
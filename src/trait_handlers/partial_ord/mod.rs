@@ -5,11 +5,12 @@ mod partial_ord_struct;
 
 use super::TraitHandler;
 
-use crate::panic;
+use crate::error;
 use crate::proc_macro2::TokenStream;
-use crate::syn::{Data, DeriveInput, Meta};
+use crate::syn::{Data, DeriveInput, Meta, Variant};
 use crate::Trait;
 
+use models::TypeAttributeBuilder;
 use partial_ord_enum::PartialOrdEnumHandler;
 use partial_ord_struct::PartialOrdStructHandler;
 
@@ -21,15 +22,33 @@ impl TraitHandler for PartialOrdHandler {
         tokens: &mut TokenStream,
         traits: &[Trait],
         meta: &Meta,
-    ) {
+    ) -> Result<(), syn::Error> {
         match ast.data {
             Data::Struct(_) => {
-                PartialOrdStructHandler::trait_meta_handler(ast, tokens, traits, meta);
+                PartialOrdStructHandler::trait_meta_handler(ast, tokens, traits, meta)?;
             }
             Data::Enum(_) => {
-                PartialOrdEnumHandler::trait_meta_handler(ast, tokens, traits, meta);
+                PartialOrdEnumHandler::trait_meta_handler(ast, tokens, traits, meta)?;
             }
-            Data::Union(_) => panic::trait_not_support_union(Trait::PartialOrd),
+            Data::Union(_) => return Err(error::trait_not_support_union(meta, Trait::PartialOrd)),
         }
+
+        Ok(())
+    }
+}
+
+pub fn create_variant_rank(index: usize, variant: &Variant, traits: &[Trait]) -> isize {
+    let default_rank = isize::min_value() + index as isize;
+
+    // An invalid attribute is reported by the `PartialOrd` handler itself.
+    TypeAttributeBuilder {
+        enable_flag: false,
+        enable_bound: false,
+        enable_rhs: false,
+        rank: default_rank,
+        enable_rank: true,
     }
+    .from_attributes(&variant.attrs, traits)
+    .map(|type_attribute| type_attribute.rank)
+    .unwrap_or(default_rank)
 }
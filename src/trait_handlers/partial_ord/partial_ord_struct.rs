@@ -1,10 +1,13 @@
 use std::collections::BTreeMap;
-use std::str::FromStr;
 
-use super::super::TraitHandler;
+use super::super::{
+    create_field_member, create_field_reference, create_hygienic_ident,
+    create_packed_field_where_predicate, create_rhs_generics, create_rhs_idents, is_packed,
+    TraitHandler,
+};
 use super::models::{FieldAttributeBuilder, TypeAttributeBuilder};
 
-use crate::panic;
+use crate::error::{self, Errors};
 use crate::proc_macro2::TokenStream;
 use crate::syn::{Data, DeriveInput, Generics, Meta};
 use crate::Trait;
@@ -17,33 +20,52 @@ impl TraitHandler for PartialOrdStructHandler {
         tokens: &mut TokenStream,
         traits: &[Trait],
         meta: &Meta,
-    ) {
+    ) -> Result<(), syn::Error> {
+        let mut errors = Errors::new();
+
         let type_attribute = TypeAttributeBuilder {
             enable_flag: true,
             enable_bound: true,
+            enable_rhs: true,
             rank: 0,
             enable_rank: false,
         }
-        .from_partial_ord_meta(meta);
+        .from_partial_ord_meta(meta)?;
+
+        let rhs = create_rhs_idents(
+            ast,
+            type_attribute.rhs.into_idents_by_generic_parameters(&ast.generics.params)?,
+        );
 
-        let bound = type_attribute
-            .bound
-            .into_punctuated_where_predicates_by_generic_parameters(&ast.generics.params);
+        let other = create_hygienic_ident("other");
+
+        let packed = is_packed(ast);
 
         let mut comparer_tokens = TokenStream::new();
 
+        let mut field_types = Vec::new();
+        let mut extra_bound = Vec::new();
+
         if let Data::Struct(data) = &ast.data {
             let mut field_attributes = BTreeMap::new();
-            let mut field_names = BTreeMap::new();
+            let mut field_members = BTreeMap::new();
 
             for (index, field) in data.fields.iter().enumerate() {
-                let field_attribute = FieldAttributeBuilder {
-                    enable_ignore: true,
-                    enable_impl: true,
-                    rank: isize::min_value() + index as isize,
-                    enable_rank: true,
-                }
-                .from_attributes(&field.attrs, traits);
+                let field_attribute = match errors.handle(
+                    FieldAttributeBuilder {
+                        enable_ignore: true,
+                        enable_impl: true,
+                        rank: isize::min_value() + index as isize,
+                        enable_rank: true,
+                        enable_bound: true,
+                    }
+                    .from_attributes(&field.attrs, traits),
+                ) {
+                    Some(field_attribute) => field_attribute,
+                    None => continue,
+                };
+
+                extra_bound.extend(field_attribute.bound.iter().cloned());
 
                 if field_attribute.ignore {
                     continue;
@@ -52,74 +74,109 @@ impl TraitHandler for PartialOrdStructHandler {
                 let rank = field_attribute.rank;
 
                 if field_attributes.contains_key(&rank) {
-                    panic::reuse_a_rank(rank);
+                    errors.push(error::reuse_a_rank(field, rank));
                 }
 
-                let field_name = if let Some(ident) = field.ident.as_ref() {
-                    ident.to_string()
-                } else {
-                    format!("{}", index)
-                };
+                if field_attribute.compare_method.is_none() {
+                    field_types.push(&field.ty);
+                }
+
+                if packed {
+                    extra_bound.push(create_packed_field_where_predicate(&field.ty));
+                }
 
                 field_attributes.insert(rank, field_attribute);
-                field_names.insert(rank, field_name);
+                field_members.insert(rank, create_field_member(index, field));
             }
 
-            for (index, field_attribute) in field_attributes {
-                let field_name = field_names.get(&index).unwrap();
+            for (rank, field_attribute) in field_attributes {
+                let field_member = field_members.get(&rank).unwrap();
 
-                let compare_trait = field_attribute.compare_trait;
-                let compare_method = field_attribute.compare_method;
+                let self_field = create_field_reference(quote!(self), field_member, packed);
+                let other_field = create_field_reference(&other, field_member, packed);
 
-                match compare_trait {
+                let compare = match field_attribute.compare_trait {
                     Some(compare_trait) => {
-                        let compare_method = compare_method.unwrap();
+                        let compare_method = field_attribute.compare_method.unwrap();
 
-                        let statement = format!("match {compare_trait}::{compare_method}(&self.{field_name}, &other.{field_name}) {{ Some(core::cmp::Ordering::Equal) => (), Some(core::cmp::Ordering::Greater) => {{ return Some(core::cmp::Ordering::Greater); }}, Some(core::cmp::Ordering::Less) => {{ return Some(core::cmp::Ordering::Less); }}, None => {{ return None; }} }}", compare_trait = compare_trait, compare_method = compare_method, field_name = field_name);
-
-                        comparer_tokens.extend(TokenStream::from_str(&statement).unwrap());
+                        quote!(#compare_trait::#compare_method(#self_field, #other_field))
                     }
                     None => {
-                        match compare_method {
+                        match field_attribute.compare_method {
                             Some(compare_method) => {
-                                let statement = format!("match {compare_method}(&self.{field_name}, &other.{field_name}) {{ Some(core::cmp::Ordering::Equal) => (), Some(core::cmp::Ordering::Greater) => {{ return Some(core::cmp::Ordering::Greater); }}, Some(core::cmp::Ordering::Less) => {{ return Some(core::cmp::Ordering::Less); }}, None => {{ return None; }} }}", compare_method = compare_method, field_name = field_name);
-
-                                comparer_tokens.extend(TokenStream::from_str(&statement).unwrap());
+                                quote!(#compare_method(#self_field, #other_field))
                             }
                             None => {
-                                let statement = format!("match core::cmp::PartialOrd::partial_cmp(&self.{field_name}, &other.{field_name}) {{ Some(core::cmp::Ordering::Equal) => (), Some(core::cmp::Ordering::Greater) => {{ return Some(core::cmp::Ordering::Greater); }}, Some(core::cmp::Ordering::Less) => {{ return Some(core::cmp::Ordering::Less); }}, None => {{ return None; }} }}", field_name = field_name);
-
-                                comparer_tokens.extend(TokenStream::from_str(&statement).unwrap());
+                                quote!(::core::cmp::PartialOrd::partial_cmp(#self_field, #other_field))
                             }
                         }
                     }
-                }
+                };
+
+                comparer_tokens.extend(quote! {
+                    match #compare {
+                        ::core::option::Option::Some(::core::cmp::Ordering::Equal) => (),
+                        ::core::option::Option::Some(::core::cmp::Ordering::Greater) => {
+                            return ::core::option::Option::Some(::core::cmp::Ordering::Greater);
+                        }
+                        ::core::option::Option::Some(::core::cmp::Ordering::Less) => {
+                            return ::core::option::Option::Some(::core::cmp::Ordering::Less);
+                        }
+                        ::core::option::Option::None => {
+                            return ::core::option::Option::None;
+                        }
+                    }
+                });
             }
         }
 
         let ident = &ast.ident;
 
+        let bound = type_attribute.bound.into_punctuated_where_predicates_by_generic_parameters(
+            &ast.ident,
+            &ast.generics.params,
+            &field_types,
+            &rhs,
+        );
+
         let mut generics_cloned: Generics = ast.generics.clone();
 
+        let rhs_ty_generics = create_rhs_generics(&mut generics_cloned, &rhs);
+
         let where_clause = generics_cloned.make_where_clause();
 
-        for where_predicate in bound {
+        for where_predicate in bound.into_iter().chain(extra_bound) {
             where_clause.predicates.push(where_predicate);
         }
 
-        let (impl_generics, ty_generics, where_clause) = generics_cloned.split_for_impl();
+        let (impl_generics, _, where_clause) = generics_cloned.split_for_impl();
+
+        let (_, ty_generics, _) = ast.generics.split_for_impl();
+
+        let (compare_trait, rhs_ty) = if rhs.is_empty() {
+            (quote!(::core::cmp::PartialOrd), quote!(Self))
+        } else {
+            (
+                quote!(::core::cmp::PartialOrd<#ident #rhs_ty_generics>),
+                quote!(#ident #rhs_ty_generics),
+            )
+        };
 
         let compare_impl = quote! {
-            impl #impl_generics core::cmp::PartialOrd for #ident #ty_generics #where_clause {
+            impl #impl_generics #compare_trait for #ident #ty_generics #where_clause {
                 #[inline]
-                fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                fn partial_cmp(&self, #other: &#rhs_ty) -> ::core::option::Option<::core::cmp::Ordering> {
                     #comparer_tokens
 
-                    Some(core::cmp::Ordering::Equal)
+                    ::core::option::Option::Some(::core::cmp::Ordering::Equal)
                 }
             }
         };
 
+        errors.finish()?;
+
         tokens.extend(compare_impl);
+
+        Ok(())
     }
 }
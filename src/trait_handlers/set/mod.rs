@@ -0,0 +1,155 @@
+mod models;
+
+use super::{create_hygienic_ident, TraitHandler};
+
+use crate::error::{self, Errors};
+use crate::proc_macro2::TokenStream;
+use crate::syn::{ext::IdentExt, Data, DeriveInput, Fields, Meta};
+use crate::Trait;
+
+use models::{FieldAttribute, FieldAttributeBuilder, TypeAttributeBuilder};
+
+pub struct SetHandler;
+
+impl TraitHandler for SetHandler {
+    fn trait_meta_handler(
+        ast: &DeriveInput,
+        tokens: &mut TokenStream,
+        traits: &[Trait],
+        meta: &Meta,
+    ) -> Result<(), syn::Error> {
+        let mut errors = Errors::new();
+
+        let type_attribute = TypeAttributeBuilder {
+            enable_flag: true,
+            enable_vis: true,
+            enable_into: true,
+            enable_chain: true,
+        }
+        .from_set_meta(meta)?;
+
+        let vis = type_attribute.vis.unwrap_or_else(|| syn::parse2(quote!(pub)).unwrap());
+
+        let ident = &ast.ident;
+
+        let mut methods = TokenStream::new();
+
+        match &ast.data {
+            Data::Struct(data) => {
+                match &data.fields {
+                    Fields::Named(fields) => {
+                        let field_attributes: Vec<Option<FieldAttribute>> = fields
+                            .named
+                            .iter()
+                            .map(|field| {
+                                errors.handle(
+                                    FieldAttributeBuilder {
+                                        enable_flag: true,
+                                        enable_name: true,
+                                        enable_ignore: true,
+                                        enable_vis: true,
+                                        enable_into: true,
+                                        enable_chain: true,
+                                    }
+                                    .from_attributes(&field.attrs, traits),
+                                )
+                            })
+                            .collect();
+
+                        let selected =
+                            field_attributes.iter().flatten().any(|attribute| attribute.flag);
+
+                        for (field, field_attribute) in fields.named.iter().zip(field_attributes) {
+                            let field_attribute = match field_attribute {
+                                Some(field_attribute) => field_attribute,
+                                None => continue,
+                            };
+
+                            if field_attribute.ignore {
+                                continue;
+                            }
+
+                            if selected
+                                && !(field_attribute.flag
+                                    || field_attribute.name.is_some()
+                                    || field_attribute.vis.is_some()
+                                    || field_attribute.into
+                                    || field_attribute.chain)
+                            {
+                                continue;
+                            }
+
+                            let field_ident = field.ident.as_ref().unwrap();
+
+                            let name = field_attribute.name.as_ref().unwrap_or(field_ident).unraw();
+
+                            let set_name = format_ident!("set_{}", name);
+                            let with_name = format_ident!("with_{}", name);
+
+                            let vis = field_attribute.vis.as_ref().unwrap_or(&vis);
+
+                            let ty = &field.ty;
+
+                            let parameter = create_hygienic_ident("value");
+
+                            let (parameter_type, value) =
+                                if field_attribute.into || type_attribute.into {
+                                    (
+                                        quote!(impl ::core::convert::Into<#ty>),
+                                        quote!(::core::convert::Into::into(#parameter)),
+                                    )
+                                } else {
+                                    (quote!(#ty), quote!(#parameter))
+                                };
+
+                            let set_method = if field_attribute.chain || type_attribute.chain {
+                                quote! {
+                                    #[inline]
+                                    #vis fn #set_name(&mut self, #parameter: #parameter_type) -> &mut Self {
+                                        self.#field_ident = #value;
+
+                                        self
+                                    }
+                                }
+                            } else {
+                                quote! {
+                                    #[inline]
+                                    #vis fn #set_name(&mut self, #parameter: #parameter_type) {
+                                        self.#field_ident = #value;
+                                    }
+                                }
+                            };
+
+                            methods.extend(set_method);
+
+                            methods.extend(quote! {
+                                #[inline]
+                                #vis fn #with_name(mut self, #parameter: #parameter_type) -> Self {
+                                    self.#field_ident = #value;
+
+                                    self
+                                }
+                            });
+                        }
+                    }
+                    _ => return Err(error::trait_only_support_named_fields(meta, Trait::Set)),
+                }
+            }
+            _ => return Err(error::trait_only_support_named_fields(meta, Trait::Set)),
+        }
+
+        let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+        let set_impl = quote! {
+            impl #impl_generics #ident #ty_generics #where_clause {
+                #methods
+            }
+        };
+
+        errors.finish()?;
+
+        tokens.extend(set_impl);
+
+        Ok(())
+    }
+}
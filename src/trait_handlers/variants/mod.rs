@@ -0,0 +1,90 @@
+mod models;
+
+use super::{create_variant_name_string, TraitHandler};
+
+use crate::error;
+use crate::proc_macro2::TokenStream;
+use crate::syn::{Data, DeriveInput, Fields, Meta};
+use crate::Trait;
+
+use models::TypeAttributeBuilder;
+
+pub struct VariantsHandler;
+
+impl TraitHandler for VariantsHandler {
+    fn trait_meta_handler(
+        ast: &DeriveInput,
+        tokens: &mut TokenStream,
+        traits: &[Trait],
+        meta: &Meta,
+    ) -> Result<(), syn::Error> {
+        TypeAttributeBuilder {
+            enable_flag: true,
+        }
+        .from_variants_meta(meta)?;
+
+        let ident = &ast.ident;
+
+        let mut variant_names = Vec::new();
+        let mut variant_patterns = Vec::new();
+        let mut unit_variants = Vec::new();
+        let mut all_unit = true;
+
+        match &ast.data {
+            Data::Enum(data) => {
+                for variant in data.variants.iter() {
+                    let variant_ident = &variant.ident;
+
+                    variant_names.push(create_variant_name_string(variant, traits));
+                    variant_patterns.push(quote!(Self::#variant_ident { .. }));
+
+                    if let Fields::Unit = &variant.fields {
+                        unit_variants.push(quote!(Self::#variant_ident));
+                    } else {
+                        all_unit = false;
+                    }
+                }
+            }
+            _ => return Err(error::trait_only_support_enum(meta, Trait::Variants)),
+        }
+
+        let variant_count = variant_names.len();
+        let variant_indexes = 0..variant_count;
+
+        let all_tokens = if all_unit {
+            quote! {
+                pub const ALL: [Self; #variant_count] = [#(#unit_variants),*];
+            }
+        } else {
+            TokenStream::new()
+        };
+
+        let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+        let variants_impl = quote! {
+            impl #impl_generics #ident #ty_generics #where_clause {
+                pub const VARIANTS: [&'static ::core::primitive::str; #variant_count] = [#(#variant_names),*];
+
+                pub const VARIANT_COUNT: ::core::primitive::usize = #variant_count;
+
+                #all_tokens
+
+                #[inline]
+                pub fn variant_name(&self) -> &'static ::core::primitive::str {
+                    Self::VARIANTS[self.variant_index()]
+                }
+
+                #[inline]
+                pub fn variant_index(&self) -> ::core::primitive::usize {
+                    match *self {
+                        #(#variant_patterns => #variant_indexes,)*
+                    }
+                }
+            }
+        };
+
+        tokens.extend(variants_impl);
+
+        Ok(())
+    }
+}
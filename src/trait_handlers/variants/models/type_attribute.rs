@@ -0,0 +1,49 @@
+use crate::error;
+use crate::syn::Meta;
+
+#[derive(Debug, Clone)]
+pub struct TypeAttributeBuilder {
+    pub enable_flag: bool,
+}
+
+impl TypeAttributeBuilder {
+    pub fn from_variants_meta(&self, meta: &Meta) -> Result<(), syn::Error> {
+        let correct_usage_for_variants_attribute = {
+            let mut usage = vec![];
+
+            if self.enable_flag {
+                usage.push(stringify!(#[educe(Variants)]));
+            }
+
+            usage
+        };
+
+        match meta {
+            Meta::List(_) => {
+                return Err(error::attribute_incorrect_format(
+                    meta,
+                    "Variants",
+                    &correct_usage_for_variants_attribute,
+                ))
+            }
+            Meta::NameValue(_) => {
+                return Err(error::attribute_incorrect_format(
+                    meta,
+                    "Variants",
+                    &correct_usage_for_variants_attribute,
+                ))
+            }
+            Meta::Path(_) => {
+                if !self.enable_flag {
+                    return Err(error::attribute_incorrect_format(
+                        meta,
+                        "Variants",
+                        &correct_usage_for_variants_attribute,
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,199 @@
+#![cfg(feature = "AsVariant")]
+#![no_std]
+
+#[macro_use]
+extern crate educe;
+
+#[test]
+#[allow(dead_code)]
+fn basic() {
+    #[derive(Educe)]
+    #[educe(AsVariant)]
+    enum Enum {
+        Unit,
+        Struct {
+            f1: u8,
+        },
+        Tuple(u8),
+    }
+
+    let mut s = Enum::Struct {
+        f1: 1,
+    };
+
+    assert_eq!(Some(&1), s.as_struct());
+    assert_eq!(None, s.as_tuple());
+
+    *s.as_struct_mut().unwrap() = 2;
+
+    assert_eq!(Some(&2), s.as_struct());
+
+    let mut t = Enum::Tuple(1);
+
+    assert_eq!(Some(&1), t.as_tuple());
+    assert_eq!(None, t.as_struct());
+
+    *t.as_tuple_mut().unwrap() = 2;
+
+    assert_eq!(Some(&2), t.as_tuple());
+    assert_eq!(None, Enum::Unit.as_tuple());
+}
+
+#[test]
+#[allow(dead_code)]
+fn multiple_fields() {
+    #[derive(Educe)]
+    #[educe(AsVariant)]
+    enum Enum {
+        Unit,
+        Struct {
+            f1: u8,
+            f2: i32,
+        },
+        Tuple(u8, i32),
+    }
+
+    let mut s = Enum::Struct {
+        f1: 1,
+        f2: 2,
+    };
+
+    assert_eq!(Some((&1, &2)), s.as_struct());
+
+    *s.as_struct_mut().unwrap().1 = 3;
+
+    assert_eq!(Some((&1, &3)), s.as_struct());
+
+    let mut t = Enum::Tuple(1, 2);
+
+    assert_eq!(Some((&1, &2)), t.as_tuple());
+
+    *t.as_tuple_mut().unwrap().0 = 3;
+
+    assert_eq!(Some((&3, &2)), t.as_tuple());
+    assert_eq!(None, Enum::Unit.as_struct());
+}
+
+#[test]
+fn single() {
+    #[derive(Educe)]
+    #[educe(AsVariant)]
+    enum Enum {
+        Tuple(u8),
+    }
+
+    assert_eq!(Some(&1), Enum::Tuple(1).as_tuple());
+}
+
+#[test]
+#[allow(dead_code)]
+fn name() {
+    #[derive(Educe)]
+    #[educe(AsVariant)]
+    enum Enum {
+        #[educe(AsVariant(name = "named"))]
+        Struct {
+            f1: u8,
+        },
+        #[educe(AsVariant(name("value")))]
+        Tuple(u8),
+    }
+
+    assert_eq!(
+        Some(&1),
+        Enum::Struct {
+            f1: 1
+        }
+        .as_named()
+    );
+
+    let mut t = Enum::Tuple(1);
+
+    *t.as_value_mut().unwrap() = 2;
+
+    assert_eq!(Some(&2), t.as_value());
+}
+
+#[test]
+#[allow(dead_code)]
+fn raw_name() {
+    #[derive(Educe)]
+    #[educe(AsVariant)]
+    enum Enum {
+        #[educe(AsVariant(name = "r#type"))]
+        Tuple(u8),
+        Unit,
+    }
+
+    let mut t = Enum::Tuple(1);
+
+    *t.as_type_mut().unwrap() = 2;
+
+    assert_eq!(Some(&2), t.as_type());
+}
+
+#[test]
+#[allow(dead_code)]
+fn ignore() {
+    #[derive(Educe)]
+    #[educe(AsVariant)]
+    enum Enum {
+        #[educe(AsVariant(ignore))]
+        Struct {
+            f1: u8,
+        },
+        Tuple(u8),
+    }
+
+    impl Enum {
+        fn as_struct(&self) -> u8 {
+            0
+        }
+    }
+
+    assert_eq!(
+        0,
+        Enum::Struct {
+            f1: 1
+        }
+        .as_struct()
+    );
+    assert_eq!(Some(&1), Enum::Tuple(1).as_tuple());
+}
+
+mod vis {
+    #[derive(Educe)]
+    #[educe(AsVariant(vis = "pub(crate)"))]
+    pub enum Enum {
+        Tuple(u8),
+        #[educe(AsVariant(vis = ""))]
+        Private(u8),
+    }
+
+    impl Enum {
+        pub fn private(&self) -> Option<&u8> {
+            self.as_private()
+        }
+    }
+}
+
+#[test]
+fn vis() {
+    assert_eq!(Some(&1), vis::Enum::Tuple(1).as_tuple());
+    assert_eq!(Some(&1), vis::Enum::Private(1).private());
+}
+
+#[test]
+#[allow(dead_code)]
+fn generic() {
+    #[derive(Educe)]
+    #[educe(AsVariant)]
+    enum Enum<'a, T, const N: usize> {
+        Unit,
+        Borrowed(&'a T),
+        Array([T; N]),
+    }
+
+    assert_eq!(Some(&&1), Enum::<u8, 1>::Borrowed(&1).as_borrowed());
+    assert_eq!(Some(&[1u8, 2]), Enum::Array([1u8, 2]).as_array());
+}
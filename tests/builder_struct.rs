@@ -0,0 +1,187 @@
+#![cfg(feature = "Builder")]
+#![no_std]
+
+#[macro_use]
+extern crate educe;
+
+extern crate alloc;
+
+use alloc::string::String;
+
+#[test]
+fn basic() {
+    #[derive(Educe)]
+    #[educe(Builder)]
+    struct Struct {
+        f1: u8,
+        f2: String,
+    }
+
+    let s = Struct::builder().f2(String::from("Hi")).f1(1).build().unwrap();
+
+    assert_eq!(1, s.f1);
+    assert_eq!("Hi", s.f2);
+
+    let error = Struct::builder().f1(1).build().err().unwrap();
+
+    assert_eq!("f2", error.field_name());
+}
+
+#[cfg(feature = "Default")]
+#[test]
+fn default() {
+    #[derive(Educe)]
+    #[educe(Default, Builder)]
+    struct Struct {
+        f1: u8,
+        #[educe(Default = "Hello")]
+        f2: String,
+        #[educe(Default(expression = "2 + 3"))]
+        f3: u8,
+    }
+
+    let s = Struct::builder().f1(1).build().unwrap();
+
+    assert_eq!(1, s.f1);
+    assert_eq!("Hello", s.f2);
+    assert_eq!(5, s.f3);
+
+    let s = Struct::builder().f1(1).f3(7).build().unwrap();
+
+    assert_eq!(7, s.f3);
+}
+
+#[test]
+fn default_parameter() {
+    struct Address(u32);
+
+    #[derive(Educe)]
+    #[educe(Builder)]
+    struct Config {
+        addr: Address,
+        #[educe(Builder(default = 8))]
+        workers: u8,
+        #[educe(Builder(default = "localhost"))]
+        host: String,
+        #[educe(Builder(default))]
+        retries: u8,
+        #[educe(Builder(default(expression = "2 + 3")))]
+        timeout: u8,
+    }
+
+    let config = Config::builder().addr(Address(1)).build().unwrap();
+
+    assert_eq!(1, config.addr.0);
+    assert_eq!(8, config.workers);
+    assert_eq!("localhost", config.host);
+    assert_eq!(0, config.retries);
+    assert_eq!(5, config.timeout);
+
+    let config = Config::builder().addr(Address(1)).workers(2).build().unwrap();
+
+    assert_eq!(2, config.workers);
+
+    assert_eq!("addr", Config::builder().build().err().unwrap().field_name());
+}
+
+#[test]
+fn name_vis_into() {
+    mod inner {
+        #[derive(Educe)]
+        #[educe(Builder(name = "Config", vis = "pub(super)", into))]
+        pub(super) struct Struct {
+            pub(super) f1: u64,
+            pub(super) f2: alloc::string::String,
+        }
+    }
+
+    let builder: inner::Config = inner::Struct::builder();
+
+    let s = builder.f1(1u8).f2("Hi").build().unwrap();
+
+    assert_eq!(1, s.f1);
+    assert_eq!("Hi", s.f2);
+}
+
+#[test]
+fn field_into() {
+    #[derive(Educe)]
+    #[educe(Builder)]
+    struct Struct {
+        f1: u8,
+        #[educe(Builder(into))]
+        f2: String,
+    }
+
+    let s = Struct::builder().f1(1).f2("Hi").build().unwrap();
+
+    assert_eq!(1, s.f1);
+    assert_eq!("Hi", s.f2);
+}
+
+#[cfg(feature = "Default")]
+#[test]
+fn typestate() {
+    #[derive(Educe)]
+    #[educe(Default, Builder(typestate))]
+    struct Struct {
+        f1: u8,
+        #[educe(Default = 2)]
+        f2: u8,
+        #[educe(Builder(into))]
+        f3: String,
+    }
+
+    let s = Struct::builder().f3("Hi").f1(1).build();
+
+    assert_eq!(1, s.f1);
+    assert_eq!(2, s.f2);
+    assert_eq!("Hi", s.f3);
+
+    let s = Struct::builder().f2(3).f1(1).f3("Hi").f1(4).build();
+
+    assert_eq!(4, s.f1);
+    assert_eq!(3, s.f2);
+}
+
+#[test]
+fn generic() {
+    #[derive(Educe)]
+    #[educe(Builder)]
+    struct Struct<'a, T: Copy, const N: usize> {
+        f1: &'a T,
+        f2: [T; N],
+    }
+
+    #[derive(Educe)]
+    #[educe(Builder(typestate))]
+    struct Typestate<'a, T: Copy, const N: usize> {
+        f1: &'a T,
+        f2: [T; N],
+    }
+
+    let s = Struct::builder().f1(&1).f2([2, 3]).build().unwrap();
+
+    assert_eq!(1, *s.f1);
+    assert_eq!([2, 3], s.f2);
+
+    let s = Typestate::builder().f2([2, 3]).f1(&1).build();
+
+    assert_eq!(1, *s.f1);
+    assert_eq!([2, 3], s.f2);
+}
+
+#[test]
+fn default_generic() {
+    #[derive(Educe)]
+    #[educe(Builder(typestate))]
+    struct Struct<T = u8, const N: usize = 2> {
+        f1: T,
+        f2: [T; N],
+    }
+
+    let s: Struct = Struct::builder().f1(1).f2([2, 3]).build();
+
+    assert_eq!(1, s.f1);
+    assert_eq!([2, 3], s.f2);
+}
@@ -396,3 +396,62 @@ fn bound_3() {
         panic!();
     }
 }
+
+#[test]
+fn clone_from() {
+    #[derive(Educe)]
+    #[educe(Clone)]
+    enum Enum {
+        Struct {
+            f1: u8,
+        },
+        Tuple(u8),
+    }
+
+    let mut s = Enum::Struct {
+        f1: 1,
+    };
+
+    s.clone_from(&Enum::Tuple(2));
+
+    if let Enum::Tuple(f1) = s {
+        assert_eq!(2, f1);
+    } else {
+        panic!();
+    }
+
+    let mut t = Enum::Tuple(1);
+
+    t.clone_from(&Enum::Struct {
+        f1: 2,
+    });
+
+    if let Enum::Struct {
+        f1,
+    } = t
+    {
+        assert_eq!(2, f1);
+    } else {
+        panic!();
+    }
+}
+
+#[test]
+fn raw_identifier() {
+    #[derive(Educe)]
+    #[educe(Clone)]
+    enum Enum {
+        Struct {
+            r#type: u8,
+        },
+    }
+
+    let Enum::Struct {
+        r#type,
+    } = Enum::Struct {
+        r#type: 1,
+    }
+    .clone();
+
+    assert_eq!(1, r#type);
+}
@@ -290,3 +290,76 @@ fn bound_3() {
     assert_eq!(1, s.f1);
     assert_eq!(1, t.0);
 }
+
+#[test]
+fn bound_infer() {
+    use core::marker::PhantomData;
+
+    struct NotClone;
+
+    fn clone<T>(_: &Option<T>) -> Option<T> {
+        None
+    }
+
+    #[derive(Educe)]
+    #[educe(Clone(bound(*)))]
+    struct Struct<T, U, V> {
+        f1: Option<T>,
+        #[educe(Clone(method(clone)))]
+        f2: Option<U>,
+        f3: PhantomData<V>,
+    }
+
+    let s = Struct {
+        f1: Some(1),
+        f2: Some(NotClone),
+        f3: PhantomData::<NotClone>,
+    }
+    .clone();
+
+    assert_eq!(Some(1), s.f1);
+    assert!(s.f2.is_none());
+}
+
+#[test]
+fn bound_field() {
+    trait Container {
+        type Item;
+    }
+
+    struct Bytes;
+
+    impl Container for Bytes {
+        type Item = u8;
+    }
+
+    #[derive(Educe)]
+    #[educe(Clone)]
+    struct Struct<T: Container> {
+        #[educe(Clone(bound = "T::Item: core::clone::Clone"))]
+        f1: T::Item,
+    }
+
+    let s = Struct::<Bytes> {
+        f1: 1,
+    }
+    .clone();
+
+    assert_eq!(1, s.f1);
+}
+
+#[test]
+fn raw_identifier() {
+    #[derive(Educe)]
+    #[educe(Clone)]
+    struct Struct {
+        r#type: u8,
+    }
+
+    let s = Struct {
+        r#type: 1,
+    }
+    .clone();
+
+    assert_eq!(1, s.r#type);
+}
@@ -0,0 +1,18 @@
+#![cfg(all(
+    feature = "Debug",
+    feature = "PartialEq",
+    feature = "PartialOrd",
+    feature = "Ord",
+    feature = "Hash",
+    feature = "Deref",
+    feature = "Get",
+    feature = "Cycle",
+    feature = "New"
+))]
+
+#[test]
+fn compile_fail() {
+    let t = trybuild::TestCases::new();
+
+    t.compile_fail("tests/ui/*.rs");
+}
@@ -104,3 +104,57 @@ fn bound_3() {
     assert_eq!(1, s.f1);
     assert_eq!(1, t.0);
 }
+
+#[test]
+fn bound_infer() {
+    use core::marker::PhantomData;
+
+    struct NotCopy;
+
+    #[derive(Educe)]
+    #[educe(Copy(bound(*)), Clone(bound(*)))]
+    struct Struct<T, K> {
+        f1: Option<T>,
+        f2: PhantomData<K>,
+    }
+
+    let s = Struct {
+        f1: Some(1),
+        f2: PhantomData::<NotCopy>,
+    };
+
+    let t = s;
+
+    assert_eq!(s.f1, t.f1);
+}
+
+#[test]
+fn bound_field() {
+    trait Container {
+        type Item;
+    }
+
+    struct Bytes;
+
+    impl Container for Bytes {
+        type Item = u8;
+    }
+
+    #[derive(Educe)]
+    #[educe(Copy, Clone)]
+    struct Struct<T: Container> {
+        #[educe(
+            Copy(bound = "T::Item: core::marker::Copy"),
+            Clone(bound = "T::Item: core::marker::Copy")
+        )]
+        f1: T::Item,
+    }
+
+    let s = Struct::<Bytes> {
+        f1: 1,
+    };
+
+    let t = s;
+
+    assert_eq!(s.f1, t.f1);
+}
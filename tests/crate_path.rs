@@ -0,0 +1,52 @@
+#![cfg(feature = "Debug")]
+#![no_std]
+
+#[macro_use]
+extern crate alloc;
+
+extern crate educe;
+
+mod prelude {
+    pub use educe::Educe;
+}
+
+#[test]
+fn crate_path() {
+    #[derive(prelude::Educe)]
+    #[educe(crate = "prelude", Debug)]
+    struct Struct {
+        f1: u8,
+    }
+
+    #[derive(prelude::Educe)]
+    #[educe(crate(crate::prelude))]
+    #[educe(Debug)]
+    enum Enum {
+        Unit,
+    }
+
+    assert_eq!(
+        "Struct { f1: 1 }",
+        format!("{:?}", Struct {
+            f1: 1
+        })
+    );
+
+    assert_eq!("Unit", format!("{:?}", Enum::Unit));
+}
+
+#[test]
+fn crate_path_not_applied() {
+    #[derive(prelude::Educe)]
+    #[educe(crate = "no_such_module", Debug)]
+    struct Struct {
+        f1: u8,
+    }
+
+    assert_eq!(
+        "Struct { f1: 1 }",
+        format!("{:?}", Struct {
+            f1: 1
+        })
+    );
+}
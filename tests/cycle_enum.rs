@@ -0,0 +1,117 @@
+#![cfg(feature = "Cycle")]
+#![no_std]
+
+#[macro_use]
+extern crate educe;
+
+#[test]
+fn basic() {
+    #[derive(Debug, PartialEq, Educe)]
+    #[educe(Cycle)]
+    enum Enum {
+        One,
+        Two,
+        Three,
+    }
+
+    assert_eq!(Some(Enum::Two), Enum::One.next());
+    assert_eq!(Some(Enum::Three), Enum::Two.next());
+    assert_eq!(None, Enum::Three.next());
+
+    assert_eq!(None, Enum::One.prev());
+    assert_eq!(Some(Enum::One), Enum::Two.prev());
+    assert_eq!(Some(Enum::Two), Enum::Three.prev());
+
+    assert_eq!(Enum::Two, Enum::One.wrapping_next());
+    assert_eq!(Enum::One, Enum::Three.wrapping_next());
+
+    assert_eq!(Enum::Three, Enum::One.wrapping_prev());
+    assert_eq!(Enum::Two, Enum::Three.wrapping_prev());
+}
+
+#[test]
+fn single() {
+    #[derive(Debug, PartialEq, Educe)]
+    #[educe(Cycle)]
+    enum Enum {
+        One,
+    }
+
+    assert_eq!(None, Enum::One.next());
+    assert_eq!(None, Enum::One.prev());
+    assert_eq!(Enum::One, Enum::One.wrapping_next());
+    assert_eq!(Enum::One, Enum::One.wrapping_prev());
+}
+
+#[test]
+#[allow(dead_code)]
+fn empty() {
+    #[derive(Educe)]
+    #[educe(Cycle)]
+    enum Enum {}
+}
+
+#[cfg(feature = "Ord")]
+#[test]
+fn rank_ord() {
+    #[derive(Debug, PartialEq, Eq, Educe)]
+    #[educe(PartialOrd, Ord, Cycle(rank))]
+    enum Enum {
+        #[educe(Ord(rank = 2))]
+        One,
+        #[educe(Ord(rank = 0))]
+        Two,
+        #[educe(Ord(rank = 1))]
+        Three,
+    }
+
+    assert_eq!(Some(Enum::Three), Enum::Two.next());
+    assert_eq!(Some(Enum::One), Enum::Three.next());
+    assert_eq!(None, Enum::One.next());
+
+    assert_eq!(None, Enum::Two.prev());
+    assert_eq!(Enum::One, Enum::Two.wrapping_prev());
+    assert_eq!(Enum::Two, Enum::One.wrapping_next());
+}
+
+#[cfg(feature = "PartialOrd")]
+#[test]
+fn rank_partial_ord() {
+    #[derive(Debug, PartialEq, Educe)]
+    #[educe(PartialOrd, Cycle(rank))]
+    enum Enum {
+        #[educe(PartialOrd(rank = 1))]
+        One,
+        #[educe(PartialOrd(rank = 0))]
+        Two,
+    }
+
+    assert_eq!(Some(Enum::One), Enum::Two.next());
+    assert_eq!(None, Enum::One.next());
+    assert_eq!(Some(Enum::Two), Enum::One.prev());
+}
+
+#[cfg(feature = "Ord")]
+#[test]
+fn rank_discriminant() {
+    #[derive(Debug, PartialEq, Eq, Educe)]
+    #[educe(PartialOrd, Ord, Cycle(rank))]
+    enum Enum {
+        A = 5,
+        B = 1,
+        C = 3,
+        D = -2,
+        E,
+    }
+
+    assert!(Enum::D < Enum::E && Enum::E < Enum::B && Enum::C < Enum::A);
+
+    assert_eq!(Some(Enum::E), Enum::D.next());
+    assert_eq!(Some(Enum::B), Enum::E.next());
+    assert_eq!(Some(Enum::C), Enum::B.next());
+    assert_eq!(Some(Enum::A), Enum::C.next());
+    assert_eq!(None, Enum::A.next());
+
+    assert_eq!(None, Enum::D.prev());
+    assert_eq!(Enum::A, Enum::D.wrapping_prev());
+}
@@ -1007,3 +1007,101 @@ fn bound_3() {
     );
     assert_eq!("Tuple(1)", format!("{:?}", Enum::Tuple(1)));
 }
+
+#[test]
+#[allow(dead_code)]
+fn bound_infer() {
+    use core::marker::PhantomData;
+
+    struct NotDebug;
+
+    fn format<T>(_: &T, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_str("Hi")
+    }
+
+    #[derive(Educe)]
+    #[educe(Debug(bound(*)))]
+    enum Enum<T, U, V, W> {
+        Struct {
+            f1: Option<T>,
+            #[educe(Debug(ignore))]
+            f2: U,
+        },
+        Tuple(#[educe(Debug(method(format)))] V, PhantomData<W>),
+    }
+
+    assert_eq!(
+        "Struct { f1: Some(1) }",
+        format!("{:?}", Enum::<_, _, NotDebug, NotDebug>::Struct {
+            f1: Some(1),
+            f2: NotDebug,
+        })
+    );
+
+    assert!(format!("{:?}", Enum::<u8, NotDebug, _, _>::Tuple(NotDebug, PhantomData::<NotDebug>))
+        .starts_with("Tuple(Hi, PhantomData"));
+}
+
+#[test]
+fn bound_field() {
+    struct NotDebug;
+
+    trait Container {
+        type Item;
+    }
+
+    struct Bytes;
+
+    impl Container for Bytes {
+        type Item = u8;
+    }
+
+    #[derive(Educe)]
+    #[educe(Debug)]
+    enum Enum<T: Container, U, V> {
+        Struct {
+            #[educe(Debug(bound = "T::Item: core::fmt::Debug"))]
+            f1: T::Item,
+        },
+        #[educe(Debug(bound(*)))]
+        Tuple(U, #[educe(Debug(ignore))] V),
+    }
+
+    assert_eq!(
+        "Struct { f1: 1 }",
+        format!("{:?}", Enum::<Bytes, u8, NotDebug>::Struct {
+            f1: 1
+        })
+    );
+
+    assert_eq!("Tuple(2)", format!("{:?}", Enum::<Bytes, _, _>::Tuple(2, NotDebug)));
+}
+
+#[test]
+fn raw_identifier() {
+    #[derive(Educe)]
+    #[educe(Debug)]
+    enum Enum {
+        Struct {
+            r#type: u8,
+        },
+    }
+
+    assert_eq!(
+        "Struct { type: 1 }",
+        format!("{:?}", Enum::Struct {
+            r#type: 1
+        })
+    );
+
+    #[derive(Educe)]
+    #[educe(Debug)]
+    #[allow(non_camel_case_types)]
+    enum Raw {
+        r#type(u8),
+        r#Unit,
+    }
+
+    assert_eq!("type(1)", format!("{:?}", Raw::r#type(1)));
+    assert_eq!("Unit", format!("{:?}", Raw::Unit));
+}
@@ -684,6 +684,39 @@ fn format_with_trait_4() {
     assert_eq!("Tuple(Hi)", format!("{:?}", Tuple(1)));
 }
 
+#[test]
+fn format_with_trait_5() {
+    use core::fmt::{self, Formatter};
+
+    trait A {
+        fn format(&self, f: &mut Formatter) -> fmt::Result {
+            f.write_str("Hi")
+        }
+    }
+
+    impl A for u8 {}
+
+    #[derive(Educe)]
+    #[educe(Debug)]
+    struct Struct<T: A> {
+        #[educe(Debug(trait(A), method(format)))]
+        f1: T,
+    }
+
+    assert_eq!(
+        "Struct { f1: Hi }",
+        format!("{:?}", Struct {
+            f1: 1
+        })
+    );
+
+    #[derive(Educe)]
+    #[educe(Debug)]
+    struct Tuple<T: A>(#[educe(Debug(trait(A), method(format)))] T);
+
+    assert_eq!("Tuple(Hi)", format!("{:?}", Tuple(1)));
+}
+
 #[test]
 fn bound_1() {
     #[derive(Educe)]
@@ -749,3 +782,159 @@ fn bound_3() {
 
     assert_eq!("Tuple(1)", format!("{:?}", Tuple(1)));
 }
+
+#[test]
+fn bound_4() {
+    #[derive(Educe)]
+    #[educe(Debug(bound(T: core::fmt::Debug)))]
+    struct Struct<T> {
+        f1: T,
+    }
+
+    assert_eq!(
+        "Struct { f1: 1 }",
+        format!("{:?}", Struct {
+            f1: 1
+        })
+    );
+
+    #[derive(Educe)]
+    #[educe(Debug(bound(T: core::fmt::Debug, K: core::fmt::Debug)))]
+    struct Tuple<T, K>(T, K);
+
+    assert_eq!("Tuple(1, 2)", format!("{:?}", Tuple(1, 2)));
+}
+
+#[test]
+#[allow(dead_code)]
+fn bound_infer() {
+    use core::marker::PhantomData;
+
+    struct NotDebug;
+
+    fn format<T>(_: &T, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_str("Hi")
+    }
+
+    #[derive(Educe)]
+    #[educe(Debug(bound(*)))]
+    struct Struct<T, U, V, W> {
+        f1: Option<T>,
+        #[educe(Debug(ignore))]
+        f2: U,
+        #[educe(Debug(method(format)))]
+        f3: V,
+        f4: PhantomData<W>,
+    }
+
+    assert!(format!("{:?}", Struct {
+        f1: Some(1),
+        f2: NotDebug,
+        f3: NotDebug,
+        f4: PhantomData::<NotDebug>,
+    })
+    .starts_with("Struct { f1: Some(1), f3: Hi, f4: PhantomData"));
+
+    #[derive(Educe)]
+    #[educe(Debug(bound = "*"))]
+    struct Tuple<'a, T: ?Sized>(&'a T);
+
+    assert_eq!("Tuple(\"Hi\")", format!("{:?}", Tuple("Hi")));
+}
+
+#[test]
+fn bound_infer_recursive() {
+    use alloc::boxed::Box;
+
+    #[derive(Educe)]
+    #[educe(Debug(bound(*)))]
+    struct List<T> {
+        v: T,
+        next: Option<Box<List<T>>>,
+    }
+
+    #[derive(Educe)]
+    #[educe(Debug(bound(*)))]
+    struct Tree<T> {
+        v: T,
+        children: alloc::vec::Vec<Self>,
+    }
+
+    assert_eq!(
+        "List { v: 1, next: Some(List { v: 2, next: None }) }",
+        format!("{:?}", List {
+            v: 1,
+            next: Some(Box::new(List {
+                v: 2,
+                next: None
+            })),
+        })
+    );
+
+    assert_eq!(
+        "Tree { v: 1, children: [] }",
+        format!("{:?}", Tree {
+            v: 1,
+            children: alloc::vec::Vec::new()
+        })
+    );
+}
+
+#[test]
+fn bound_field() {
+    trait Container {
+        type Item;
+    }
+
+    struct Bytes;
+
+    impl Container for Bytes {
+        type Item = u8;
+    }
+
+    #[derive(Educe)]
+    #[educe(Debug)]
+    struct Struct<T: Container> {
+        #[educe(Debug(bound = "T::Item: core::fmt::Debug"))]
+        f1: T::Item,
+    }
+
+    assert_eq!(
+        "Struct { f1: 1 }",
+        format!("{:?}", Struct::<Bytes> {
+            f1: 1
+        })
+    );
+
+    #[derive(Educe)]
+    #[educe(Debug(bound(*)))]
+    struct Tuple<T: Container, U>(
+        Option<U>,
+        #[educe(Debug(bound(T::Item: core::fmt::Debug)))] T::Item,
+    );
+
+    assert_eq!("Tuple(Some(1), 2)", format!("{:?}", Tuple::<Bytes, _>(Some(1), 2)));
+}
+
+#[test]
+fn raw_identifier() {
+    #[derive(Educe)]
+    #[educe(Debug)]
+    struct Struct {
+        r#type: u8,
+    }
+
+    assert_eq!(
+        "Struct { type: 1 }",
+        format!("{:?}", Struct {
+            r#type: 1
+        })
+    );
+
+    #[derive(Educe)]
+    #[educe(Debug)]
+    #[allow(non_camel_case_types)]
+    struct r#type(u8);
+
+    assert_eq!("type(1)", format!("{:?}", r#type(1)));
+}
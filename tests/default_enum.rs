@@ -528,3 +528,39 @@ fn new() {
         false
     });
 }
+
+#[test]
+#[allow(irrefutable_let_patterns, dead_code)]
+fn constant() {
+    #[derive(Educe)]
+    #[educe(Default(const, new))]
+    enum Enum {
+        Unit,
+        #[educe(Default)]
+        Struct {
+            #[educe(Default = 1)]
+            f1: u8,
+        },
+        Tuple(u8),
+    }
+
+    static ENUM: Enum = Enum::new();
+
+    assert!(if let Enum::Struct {
+        f1: 1,
+    } = ENUM
+    {
+        true
+    } else {
+        false
+    });
+
+    assert!(if let Enum::Struct {
+        f1: 1,
+    } = Enum::DEFAULT
+    {
+        true
+    } else {
+        false
+    });
+}
@@ -93,6 +93,33 @@ fn type_default_2() {
     assert_eq!(1, Tuple::default().0);
 }
 
+#[test]
+#[allow(irrefutable_let_patterns)]
+fn type_default_3() {
+    #[derive(Educe)]
+    #[educe(Default(expression(Unit)))]
+    struct Unit;
+
+    #[derive(Educe)]
+    #[educe(Default(expression(Struct { f1: 1 })))]
+    struct Struct {
+        f1: u8,
+    }
+
+    #[derive(Educe)]
+    #[educe(Default(expression(Tuple(1))))]
+    struct Tuple(u8);
+
+    assert!(if let Unit = Unit::default() {
+        true
+    } else {
+        false
+    });
+
+    assert_eq!(1, Struct::default().f1);
+    assert_eq!(1, Tuple::default().0);
+}
+
 #[test]
 fn field_default_1() {
     #[derive(Educe)]
@@ -305,6 +332,34 @@ fn field_default_4() {
     assert_eq!('M', t.6);
 }
 
+#[test]
+fn field_default_5() {
+    #[derive(Educe)]
+    #[educe(Default)]
+    struct Struct {
+        #[educe(Default(expression(0 + 1)))]
+        f1: u8,
+        #[educe(Default(expression(String::from("Hello"))))]
+        f2: String,
+    }
+
+    #[derive(Educe)]
+    #[educe(Default)]
+    struct Tuple(
+        #[educe(Default(expression(0 + 1)))] u8,
+        #[educe(Default(expression(String::from("Hello"))))] String,
+    );
+
+    let s = Struct::default();
+    let t = Tuple::default();
+
+    assert_eq!(1, s.f1);
+    assert_eq!("Hello", s.f2);
+
+    assert_eq!(1, t.0);
+    assert_eq!("Hello", t.1);
+}
+
 #[test]
 fn bound_1() {
     #[derive(Educe)]
@@ -353,6 +408,69 @@ fn bound_3() {
     assert_eq!(0, Tuple::default().0);
 }
 
+#[test]
+fn bound_4() {
+    #[derive(Educe)]
+    #[educe(Default(bound(T: core::default::Default)))]
+    struct Struct<T> {
+        f1: T,
+    }
+
+    #[derive(Educe)]
+    #[educe(Default(bound(T: core::default::Default)))]
+    struct Tuple<T>(T);
+
+    assert_eq!(0, Struct::default().f1);
+    assert_eq!(0, Tuple::default().0);
+}
+
+#[test]
+fn bound_infer() {
+    use core::marker::PhantomData;
+
+    struct NotDefault;
+
+    #[derive(Educe)]
+    #[educe(Default(bound(*)))]
+    struct Struct<T, U, V> {
+        f1: Option<T>,
+        #[educe(Default(expression = "PhantomData"))]
+        f2: PhantomData<U>,
+        f3: PhantomData<V>,
+        #[educe(Default = 1)]
+        f4: u8,
+    }
+
+    let s: Struct<NotDefault, NotDefault, NotDefault> = Struct::default();
+
+    assert!(s.f1.is_none());
+    assert_eq!(1, s.f4);
+}
+
+#[test]
+fn bound_field() {
+    trait Container {
+        type Item;
+    }
+
+    struct Bytes;
+
+    impl Container for Bytes {
+        type Item = u8;
+    }
+
+    #[derive(Educe)]
+    #[educe(Default)]
+    struct Struct<T: Container> {
+        #[educe(Default(bound = "T::Item: core::default::Default"))]
+        f1: T::Item,
+    }
+
+    let s: Struct<Bytes> = Struct::default();
+
+    assert_eq!(0, s.f1);
+}
+
 #[test]
 #[allow(irrefutable_let_patterns)]
 fn new() {
@@ -379,3 +497,43 @@ fn new() {
     assert_eq!(0, Struct::new().f1);
     assert_eq!(0, Tuple::new().0);
 }
+
+#[test]
+fn constant() {
+    #[derive(Educe)]
+    #[educe(Default(const, new))]
+    struct Struct {
+        #[educe(Default = 1)]
+        f1: u8,
+        #[educe(Default = "Hi")]
+        f2: &'static str,
+        #[educe(Default(expression = "u16::MAX"))]
+        f3: u16,
+    }
+
+    #[derive(Educe)]
+    #[educe(Default(const))]
+    struct Tuple(#[educe(Default = 2)] u8);
+
+    static STRUCT: Struct = Struct::new();
+    const TUPLE: Tuple = Tuple::DEFAULT;
+
+    assert_eq!(1, STRUCT.f1);
+    assert_eq!("Hi", STRUCT.f2);
+    assert_eq!(u16::MAX, STRUCT.f3);
+    assert_eq!(1, Struct::DEFAULT.f1);
+    assert_eq!(2, TUPLE.0);
+    assert_eq!(2, Tuple::default().0);
+}
+
+#[test]
+fn raw_identifier() {
+    #[derive(Educe)]
+    #[educe(Default)]
+    struct Struct {
+        #[educe(Default = 1)]
+        r#type: u8,
+    }
+
+    assert_eq!(1, Struct::default().r#type);
+}
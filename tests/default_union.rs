@@ -504,3 +504,20 @@ fn new() {
 
     assert_approx_eq!(0.0, unsafe { Union2::new().f2 });
 }
+
+#[test]
+#[allow(dead_code)]
+fn constant() {
+    #[derive(Educe)]
+    #[educe(Default(const, new))]
+    union Union {
+        #[educe(Default = 1)]
+        f1: u8,
+        f2: f64,
+    }
+
+    static UNION: Union = Union::new();
+
+    assert_eq!(1, unsafe { UNION.f1 });
+    assert_eq!(1, unsafe { Union::DEFAULT.f1 });
+}
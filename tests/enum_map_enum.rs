@@ -0,0 +1,125 @@
+#![cfg(feature = "EnumMap")]
+#![no_std]
+
+#[macro_use]
+extern crate educe;
+
+extern crate alloc;
+
+use alloc::format;
+use alloc::vec::Vec;
+
+#[test]
+fn basic() {
+    #[derive(Debug, PartialEq, Educe)]
+    #[educe(EnumMap)]
+    enum Enum {
+        One,
+        Two,
+        Three,
+    }
+
+    let mut map = EnumMap::<u8>::default();
+
+    assert_eq!(3, map.len());
+    assert_eq!([0, 0, 0], map.clone().into_array());
+
+    map[Enum::Two] += 2;
+    *map.get_mut(Enum::Three) = 3;
+
+    assert_eq!(0, map.insert(Enum::One, 1));
+    assert_eq!(1, map[Enum::One]);
+    assert_eq!(2, *map.get(Enum::Two));
+
+    assert_eq!(
+        [(Enum::One, &1), (Enum::Two, &2), (Enum::Three, &3)],
+        map.iter().collect::<Vec<_>>()[..]
+    );
+
+    for (_, value) in map.iter_mut() {
+        *value *= 10;
+    }
+
+    assert_eq!([10, 20, 30], map.values().copied().collect::<Vec<_>>()[..]);
+}
+
+#[test]
+fn from_fn() {
+    #[derive(Debug, PartialEq, Educe)]
+    #[educe(EnumMap)]
+    enum Enum {
+        One,
+        Two,
+    }
+
+    let map = EnumMap::from_fn(|key| key == Enum::Two);
+
+    assert!(!map[Enum::One]);
+    assert!(map[Enum::Two]);
+
+    let map = EnumMap::from_array(["one", "two"]);
+
+    assert_eq!("two", map[Enum::Two]);
+}
+
+#[test]
+#[allow(dead_code)]
+fn debug() {
+    #[derive(Educe)]
+    #[educe(EnumMap)]
+    enum Enum {
+        One,
+        Two,
+    }
+
+    let map = EnumMap::from_array([1, 2]);
+
+    assert_eq!("{One: 1, Two: 2}", format!("{:?}", map));
+}
+
+#[test]
+#[allow(non_camel_case_types)]
+fn raw_ident() {
+    #[derive(Educe)]
+    #[educe(EnumMap)]
+    enum Enum {
+        r#type,
+        r#loop,
+    }
+
+    let map = EnumMap::from_array([1, 2]);
+
+    assert_eq!("{type: 1, loop: 2}", format!("{:?}", map));
+}
+
+#[test]
+fn name_vis() {
+    mod inner {
+        #[derive(Educe)]
+        #[educe(EnumMap(name = "Counters", vis = "pub(super)"))]
+        pub(super) enum Counter {
+            Hit,
+            Miss,
+        }
+    }
+
+    let mut counters = inner::Counters::<u32>::default();
+
+    counters[inner::Counter::Miss] += 1;
+
+    assert_eq!(0, counters[inner::Counter::Hit]);
+    assert_eq!(1, counters[inner::Counter::Miss]);
+}
+
+#[test]
+#[allow(dead_code)]
+fn empty() {
+    #[derive(Educe)]
+    #[educe(EnumMap)]
+    enum Enum {}
+
+    let map = EnumMap::<u8>::default();
+
+    assert!(map.is_empty());
+    assert_eq!("{}", format!("{:?}", map));
+}
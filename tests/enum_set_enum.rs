@@ -0,0 +1,165 @@
+#![cfg(feature = "EnumSet")]
+#![no_std]
+
+#[macro_use]
+extern crate educe;
+
+extern crate alloc;
+
+use alloc::{format, vec};
+use alloc::vec::Vec;
+
+#[test]
+fn basic() {
+    #[derive(Debug, PartialEq, Educe)]
+    #[educe(EnumSet)]
+    enum Enum {
+        One,
+        Two,
+        Three,
+    }
+
+    let mut set = EnumSet::new();
+
+    assert!(set.is_empty());
+
+    assert!(set.insert(Enum::Three));
+    assert!(set.insert(Enum::One));
+    assert!(!set.insert(Enum::One));
+
+    assert_eq!(2, set.len());
+    assert_eq!(0b101, set.bits());
+    assert!(set.contains(Enum::One));
+    assert!(!set.contains(Enum::Two));
+
+    assert_eq!([Enum::One, Enum::Three], set.iter().collect::<Vec<_>>()[..]);
+
+    assert!(set.remove(Enum::One));
+    assert!(!set.remove(Enum::One));
+    assert_eq!(0b100, set.bits());
+
+    set.clear();
+
+    assert!(set.is_empty());
+    assert_eq!(EnumSet::default(), set);
+}
+
+#[test]
+fn operators() {
+    #[derive(Debug, PartialEq, Educe)]
+    #[educe(EnumSet)]
+    enum Enum {
+        One,
+        Two,
+        Three,
+    }
+
+    let a: EnumSet = vec![Enum::One, Enum::Two].into_iter().collect();
+    let b = EnumSet::from(Enum::Two) | EnumSet::from(Enum::Three);
+
+    assert_eq!(0b111, (a | b).bits());
+    assert_eq!(0b010, (a & b).bits());
+    assert_eq!(0b101, (a ^ b).bits());
+    assert_eq!(0b001, (a - b).bits());
+    assert_eq!(0b100, (!a).bits());
+    assert_eq!(EnumSet::all(), a | !a);
+    assert_eq!(0b011, EnumSet::from_bits_truncate(0b1111_1011).bits() & a.bits());
+
+    let mut c = a;
+
+    c |= b;
+    assert_eq!(EnumSet::all(), c);
+
+    c &= b;
+    assert_eq!(b, c);
+
+    c ^= a;
+    assert_eq!(0b101, c.bits());
+
+    c -= a;
+    assert_eq!(0b100, c.bits());
+
+    c.extend([Enum::One]);
+    assert_eq!(0b101, c.bits());
+}
+
+#[test]
+#[allow(dead_code)]
+fn bits_type() {
+    #[derive(Educe)]
+    #[educe(EnumSet)]
+    enum Small {
+        V0,
+        V1,
+        V2,
+        V3,
+        V4,
+        V5,
+        V6,
+        V7,
+    }
+
+    #[derive(Educe)]
+    #[educe(EnumSet)]
+    enum Large {
+        V0,
+        V1,
+        V2,
+        V3,
+        V4,
+        V5,
+        V6,
+        V7,
+        V8,
+    }
+
+    let _: u8 = SmallSet::all().bits();
+    let _: u16 = LargeSet::all().bits();
+
+    assert_eq!(u8::MAX, SmallSet::all().bits());
+    assert_eq!(0x1FF, LargeSet::all().bits());
+}
+
+#[test]
+fn name_vis() {
+    mod inner {
+        #[derive(Educe)]
+        #[educe(EnumSet(name = "Flags", vis = "pub(super)"))]
+        pub(super) enum Flag {
+            Read,
+            Write,
+        }
+    }
+
+    let flags: inner::Flags = vec![inner::Flag::Write].into_iter().collect();
+
+    assert!(flags.contains(inner::Flag::Write));
+    assert!(!flags.contains(inner::Flag::Read));
+}
+
+#[test]
+#[allow(dead_code)]
+fn debug() {
+    #[derive(Educe)]
+    #[educe(EnumSet)]
+    enum Enum {
+        One,
+        Two,
+        Three,
+    }
+
+    let set: EnumSet = vec![Enum::Three, Enum::One].into_iter().collect();
+
+    assert_eq!("{One, Three}", format!("{:?}", set));
+}
+
+#[test]
+#[allow(dead_code)]
+fn empty() {
+    #[derive(Educe)]
+    #[educe(EnumSet)]
+    enum Enum {}
+
+    assert_eq!(0, EnumSet::all().bits());
+    assert_eq!("{}", format!("{:?}", EnumSet::all()));
+}
@@ -138,3 +138,53 @@ fn bound_3() {
     assert!(Tuple(1) == Tuple(1));
     assert!(Tuple(1) != Tuple(2));
 }
+
+#[test]
+fn bound_infer() {
+    use core::marker::PhantomData;
+
+    struct NotEq;
+
+    #[derive(Educe)]
+    #[educe(PartialEq(bound(*)), Eq(bound(*)))]
+    struct Struct<T, K> {
+        f1: Option<T>,
+        f2: PhantomData<K>,
+    }
+
+    fn assert_eq<T: Eq>(_: &T) {}
+
+    assert_eq(&Struct {
+        f1: Some(1),
+        f2: PhantomData::<NotEq>,
+    });
+}
+
+#[test]
+fn bound_field() {
+    trait Container {
+        type Item;
+    }
+
+    struct Bytes;
+
+    impl Container for Bytes {
+        type Item = u8;
+    }
+
+    #[derive(Educe)]
+    #[educe(PartialEq, Eq)]
+    struct Struct<T: Container> {
+        #[educe(
+            PartialEq(bound = "T::Item: core::cmp::PartialEq"),
+            Eq(bound = "T::Item: core::cmp::Eq")
+        )]
+        f1: T::Item,
+    }
+
+    fn assert_eq<T: Eq>(_: &T) {}
+
+    assert_eq(&Struct::<Bytes> {
+        f1: 1,
+    });
+}
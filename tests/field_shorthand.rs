@@ -0,0 +1,172 @@
+#![allow(dead_code)]
+#![no_std]
+
+#[macro_use]
+extern crate alloc;
+
+#[macro_use]
+extern crate educe;
+
+#[cfg(feature = "Hash")]
+fn hash<T: core::hash::Hash>(value: &T) -> u64 {
+    use core::hash::Hasher;
+
+    struct Recorder(u64);
+
+    impl Hasher for Recorder {
+        fn finish(&self) -> u64 {
+            self.0
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            for byte in bytes {
+                self.0 = self.0 * 31 + *byte as u64;
+            }
+        }
+    }
+
+    let mut hasher = Recorder(0);
+
+    value.hash(&mut hasher);
+
+    hasher.finish()
+}
+
+#[cfg(all(
+    feature = "Debug",
+    feature = "PartialEq",
+    feature = "Eq",
+    feature = "PartialOrd",
+    feature = "Ord",
+    feature = "Hash"
+))]
+#[test]
+fn skip() {
+    use core::cmp::Ordering;
+
+    #[derive(Educe)]
+    #[educe(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    struct Struct {
+        f1: u8,
+        #[educe(skip)]
+        cache: u8,
+    }
+
+    #[derive(Educe)]
+    #[educe(PartialEq, Eq, PartialOrd, Ord, Hash)]
+    enum Enum {
+        Struct {
+            f1: u8,
+            #[educe(skip)]
+            cache: u8,
+        },
+        Tuple(#[educe(skip)] u8, u8),
+    }
+
+    let a = Struct {
+        f1: 1,
+        cache: 2,
+    };
+    let b = Struct {
+        f1: 1,
+        cache: 3,
+    };
+
+    assert_eq!("Struct { f1: 1, cache: 2 }", format!("{:?}", a));
+    assert!(a == b);
+    assert_eq!(Ordering::Equal, a.cmp(&b));
+    assert_eq!(Some(Ordering::Equal), a.partial_cmp(&b));
+    assert_eq!(hash(&a), hash(&b));
+
+    let a = Enum::Struct {
+        f1: 1,
+        cache: 2,
+    };
+    let b = Enum::Struct {
+        f1: 1,
+        cache: 3,
+    };
+
+    assert!(a == b);
+    assert_eq!(Ordering::Equal, a.cmp(&b));
+    assert_eq!(hash(&a), hash(&b));
+
+    assert!(Enum::Tuple(1, 2) == Enum::Tuple(3, 2));
+    assert!(Enum::Tuple(1, 2) < Enum::Tuple(1, 3));
+    assert_eq!(hash(&Enum::Tuple(1, 2)), hash(&Enum::Tuple(3, 2)));
+}
+
+#[cfg(all(feature = "Debug", feature = "Hash"))]
+#[test]
+fn skip_unused_traits() {
+    #[derive(Educe)]
+    #[educe(Debug, Hash)]
+    struct Struct {
+        f1: u8,
+        #[educe(skip)]
+        cache: u8,
+    }
+
+    assert_eq!(
+        "Struct { f1: 1, cache: 2 }",
+        format!("{:?}", Struct {
+            f1: 1,
+            cache: 2,
+        })
+    );
+
+    assert_eq!(
+        hash(&Struct {
+            f1: 1,
+            cache: 2,
+        }),
+        hash(&Struct {
+            f1: 1,
+            cache: 3,
+        })
+    );
+}
+
+#[cfg(all(feature = "Debug", feature = "PartialEq", feature = "Hash"))]
+#[test]
+fn ignore() {
+    #[derive(Educe)]
+    #[educe(Debug, PartialEq, Hash)]
+    struct Struct {
+        f1: u8,
+        #[educe(ignore(Debug, PartialEq))]
+        f2: u8,
+        #[educe(ignore(Hash), Debug(name = "f"))]
+        f3: u8,
+    }
+
+    let a = Struct {
+        f1: 1,
+        f2: 2,
+        f3: 3,
+    };
+    let b = Struct {
+        f1: 1,
+        f2: 3,
+        f3: 4,
+    };
+
+    assert_eq!("Struct { f1: 1, f: 3 }", format!("{:?}", a));
+    assert!(a != b);
+    assert!(
+        a == Struct {
+            f1: 1,
+            f2: 4,
+            f3: 3,
+        }
+    );
+    assert_ne!(hash(&a), hash(&b));
+    assert_eq!(
+        hash(&a),
+        hash(&Struct {
+            f1: 1,
+            f2: 2,
+            f3: 4,
+        })
+    );
+}
@@ -0,0 +1,189 @@
+#![allow(clippy::clone_on_copy)]
+#![no_std]
+
+#[macro_use]
+extern crate alloc;
+
+#[macro_use]
+extern crate educe;
+
+#[cfg(all(
+    feature = "Debug",
+    feature = "PartialEq",
+    feature = "Eq",
+    feature = "PartialOrd",
+    feature = "Ord",
+    feature = "Hash",
+    feature = "Clone",
+    feature = "Copy"
+))]
+#[test]
+fn lifetime() {
+    #[derive(Educe)]
+    #[educe(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+    enum Enum<'a, 'b: 'a> {
+        Struct {
+            f1: &'a str,
+        },
+        Tuple(&'b u8),
+        Unit,
+    }
+
+    let s = alloc::string::String::from("Hi");
+
+    let a = Enum::Struct {
+        f1: &s,
+    };
+    let b = a.clone();
+
+    assert_eq!("Struct { f1: \"Hi\" }", format!("{:?}", b));
+    assert!(a == b);
+    assert!(a < Enum::Tuple(&1));
+    assert!(Enum::Tuple(&1) < Enum::Unit);
+}
+
+#[cfg(all(
+    feature = "Debug",
+    feature = "PartialEq",
+    feature = "Eq",
+    feature = "PartialOrd",
+    feature = "Ord",
+    feature = "Hash",
+    feature = "Clone",
+    feature = "Copy",
+    feature = "Default"
+))]
+#[test]
+fn const_generics() {
+    #[derive(Educe)]
+    #[educe(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default)]
+    enum Enum<const N: usize> {
+        Struct {
+            f1: [u8; N],
+        },
+        Tuple([u16; N]),
+        #[educe(Default)]
+        Unit,
+    }
+
+    let a = Enum::<2>::Struct {
+        f1: [1, 2],
+    };
+    let b = a.clone();
+
+    assert_eq!("Struct { f1: [1, 2] }", format!("{:?}", b));
+    assert!(a == b);
+    assert!(a < Enum::Tuple([0, 0]));
+    assert!(Enum::<2>::default() == Enum::Unit);
+}
+
+#[cfg(all(
+    feature = "Debug",
+    feature = "PartialEq",
+    feature = "Eq",
+    feature = "PartialOrd",
+    feature = "Ord",
+    feature = "Hash",
+    feature = "Clone",
+    feature = "Copy"
+))]
+#[test]
+fn unsized_parameter() {
+    #[derive(Educe)]
+    #[educe(
+        Debug(bound),
+        PartialEq(bound),
+        Eq(bound),
+        PartialOrd(bound),
+        Ord(bound),
+        Hash(bound),
+        Clone,
+        Copy
+    )]
+    enum Enum<'a, T: ?Sized> {
+        Struct {
+            f1: &'a T,
+        },
+        Tuple(&'a T, u8),
+    }
+
+    let a: Enum<str> = Enum::Struct {
+        f1: "Hi",
+    };
+    let b = a.clone();
+
+    assert_eq!("Struct { f1: \"Hi\" }", format!("{:?}", b));
+    assert!(a == b);
+    assert!(Enum::Tuple("a", 2) > Enum::Tuple("a", 1));
+}
+
+#[cfg(all(
+    feature = "Debug",
+    feature = "PartialEq",
+    feature = "PartialOrd",
+    feature = "Clone",
+    feature = "Default",
+    feature = "IsVariant",
+    feature = "AsVariant",
+    feature = "IntoVariant",
+    feature = "Variants",
+    feature = "Kind",
+    feature = "New"
+))]
+#[test]
+fn all_together() {
+    #[derive(Educe)]
+    #[educe(
+        Debug(bound),
+        PartialEq(bound),
+        PartialOrd(bound),
+        Clone,
+        Default,
+        IsVariant,
+        AsVariant,
+        IntoVariant,
+        Variants,
+        Kind,
+        New
+    )]
+    enum Enum<'a, T: ?Sized, const N: usize> {
+        Struct {
+            f1: &'a T,
+            f2: [u8; N],
+        },
+        #[educe(Default)]
+        Unit,
+    }
+
+    let a: Enum<str, 1> = Enum::r#struct("Hi", [1]);
+
+    assert!(a.is_struct());
+    assert_eq!(Some((&"Hi", &[1])), a.as_struct());
+    assert!(matches!(a.kind(), EnumKind::Struct));
+    assert_eq!(["Struct", "Unit"], Enum::<str, 1>::VARIANTS);
+    assert!(Enum::<str, 1>::default() == Enum::unit());
+    assert!(a.clone() < Enum::Unit);
+    assert_eq!(("Hi", [1]), a.into_struct().unwrap_or_else(|_| unreachable!()));
+}
+
+#[cfg(all(feature = "Debug", feature = "PartialEq", feature = "Clone", feature = "Cycle"))]
+#[test]
+fn macro_rules() {
+    macro_rules! create_enum {
+        ($name:ident, $variant:ident) => {
+            #[derive(Educe)]
+            #[educe(Debug, PartialEq, Clone, Cycle)]
+            enum $name {
+                $variant,
+                Other,
+            }
+        };
+    }
+
+    create_enum!(Enum, First);
+
+    type Alias = Enum;
+
+    assert_eq!("First", format!("{:?}", Alias::First.clone()));
+    assert!(Alias::First.wrapping_next() == Enum::Other);
+}
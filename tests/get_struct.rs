@@ -0,0 +1,183 @@
+#![cfg(feature = "Get")]
+#![no_std]
+
+#[macro_use]
+extern crate educe;
+
+extern crate alloc;
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+#[test]
+fn basic() {
+    #[derive(Educe)]
+    #[educe(Get)]
+    struct Struct {
+        f1: u8,
+        f2: String,
+    }
+
+    let s = Struct {
+        f1: 1,
+        f2: String::from("Hi"),
+    };
+
+    let f1: &u8 = s.f1();
+    let f2: &String = s.f2();
+
+    assert_eq!(1, *f1);
+    assert_eq!("Hi", f2);
+}
+
+#[test]
+fn copy() {
+    #[derive(Educe)]
+    #[educe(Get(copy))]
+    struct Struct {
+        f1: u8,
+        f2: bool,
+    }
+
+    #[derive(Educe)]
+    #[educe(Get)]
+    struct Field {
+        #[educe(Get(copy))]
+        f1: u8,
+        f2: String,
+    }
+
+    let s = Struct {
+        f1: 1,
+        f2: true,
+    };
+
+    let f1: u8 = s.f1();
+
+    assert_eq!(1, f1);
+    assert!(s.f2());
+
+    let s = Field {
+        f1: 1,
+        f2: String::from("Hi"),
+    };
+
+    let f1: u8 = s.f1();
+
+    assert_eq!(1, f1);
+    assert_eq!("Hi", s.f2());
+}
+
+#[test]
+fn deref() {
+    #[derive(Educe)]
+    #[educe(Get)]
+    struct Struct {
+        #[educe(Get(deref))]
+        f1: String,
+        #[educe(Get(deref))]
+        f2: Vec<u8>,
+        #[educe(Get(deref))]
+        f3: Box<u8>,
+        #[educe(Get(deref))]
+        f4: Option<String>,
+    }
+
+    let s = Struct {
+        f1: String::from("Hi"),
+        f2: vec![1, 2],
+        f3: Box::new(3),
+        f4: Some(String::from("Hello")),
+    };
+
+    let f1: &str = s.f1();
+    let f2: &[u8] = s.f2();
+    let f3: &u8 = s.f3();
+    let f4: Option<&str> = s.f4();
+
+    assert_eq!("Hi", f1);
+    assert_eq!([1, 2], f2);
+    assert_eq!(3, *f3);
+    assert_eq!(Some("Hello"), f4);
+}
+
+#[test]
+fn name_vis_ignore() {
+    mod inner {
+        #[derive(Educe)]
+        #[educe(Get(vis = "pub(super)"))]
+        pub(super) struct Struct {
+            #[educe(Get(name = "first"))]
+            f1: u8,
+            #[educe(Get(ignore))]
+            f2: u8,
+        }
+
+        impl Struct {
+            pub(super) fn f2(&self) -> u8 {
+                self.f2 + 1
+            }
+
+            pub(super) fn create() -> Self {
+                Struct {
+                    f1: 1,
+                    f2: 2,
+                }
+            }
+        }
+    }
+
+    let s = inner::Struct::create();
+
+    assert_eq!(1, *s.first());
+    assert_eq!(3, s.f2());
+}
+
+#[test]
+fn fields() {
+    #[derive(Educe)]
+    #[educe(Get)]
+    struct Struct {
+        #[educe(Get)]
+        f1: u8,
+        f2: u8,
+        #[educe(Get(copy))]
+        f3: u8,
+    }
+
+    impl Struct {
+        fn f2(&self) -> u8 {
+            self.f2 + 1
+        }
+    }
+
+    let s = Struct {
+        f1: 1,
+        f2: 2,
+        f3: 3,
+    };
+
+    assert_eq!(1, *s.f1());
+    assert_eq!(3, s.f2());
+    assert_eq!(3, s.f3());
+}
+
+#[test]
+fn generic() {
+    #[derive(Educe)]
+    #[educe(Get)]
+    struct Struct<'a, T, const N: usize> {
+        f1: &'a T,
+        f2: [T; N],
+    }
+
+    let s = Struct {
+        f1: &1,
+        f2: [2, 3],
+    };
+
+    assert_eq!(1, **s.f1());
+    assert_eq!([2, 3], *s.f2());
+}
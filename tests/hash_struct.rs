@@ -653,3 +653,93 @@ fn bound_3() {
 
     assert_eq!(struct_hash, tuple_hash);
 }
+
+#[test]
+#[allow(dead_code)]
+fn bound_infer() {
+    use core::marker::PhantomData;
+
+    struct NotHash;
+
+    fn hash<T, H: Hasher>(_: &T, state: &mut H) {
+        1u8.hash(state)
+    }
+
+    #[derive(Educe)]
+    #[educe(Hash(bound(*)))]
+    struct Struct<T, U, V, W> {
+        f1: Option<T>,
+        #[educe(Hash(ignore))]
+        f2: U,
+        #[educe(Hash(method(hash)))]
+        f3: V,
+        f4: PhantomData<W>,
+    }
+
+    let hash_1 = {
+        let mut hasher = DefaultHasher::new();
+
+        Struct {
+            f1: Some(1),
+            f2: NotHash,
+            f3: NotHash,
+            f4: PhantomData::<NotHash>,
+        }
+        .hash(&mut hasher);
+
+        hasher.finish()
+    };
+
+    let hash_2 = {
+        let mut hasher = DefaultHasher::new();
+
+        Some(1).hash(&mut hasher);
+        1u8.hash(&mut hasher);
+        PhantomData::<NotHash>.hash(&mut hasher);
+
+        hasher.finish()
+    };
+
+    assert_eq!(hash_1, hash_2);
+}
+
+#[test]
+fn bound_field() {
+    trait Container {
+        type Item;
+    }
+
+    struct Bytes;
+
+    impl Container for Bytes {
+        type Item = u8;
+    }
+
+    #[derive(Educe)]
+    #[educe(Hash)]
+    struct Struct<T: Container> {
+        #[educe(Hash(bound = "T::Item: core::hash::Hash"))]
+        f1: T::Item,
+    }
+
+    let hash_1 = {
+        let mut hasher = DefaultHasher::new();
+
+        Struct::<Bytes> {
+            f1: 1,
+        }
+        .hash(&mut hasher);
+
+        hasher.finish()
+    };
+
+    let hash_2 = {
+        let mut hasher = DefaultHasher::new();
+
+        1u8.hash(&mut hasher);
+
+        hasher.finish()
+    };
+
+    assert_eq!(hash_1, hash_2);
+}
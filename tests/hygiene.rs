@@ -0,0 +1,384 @@
+#![allow(dead_code, non_camel_case_types, non_snake_case, clippy::disallowed_names)]
+#![no_std]
+
+#[macro_use]
+extern crate educe;
+
+extern crate alloc;
+
+// Declares items which shadow the names generated code could refer to without a full path.
+macro_rules! shadow {
+    () => {
+        mod core {}
+
+        struct Option;
+        struct Result;
+        struct Some;
+        struct None;
+        struct Ok;
+        struct Err;
+        struct MyDebug;
+        struct RawString;
+
+        type bool = ();
+        type usize = ();
+        type isize = ();
+        type u8 = ();
+        type str = ();
+    };
+}
+
+#[cfg(feature = "Debug")]
+#[test]
+fn debug() {
+    use alloc::format;
+
+    fn formatter(_: &::core::primitive::u8, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        f.write_str("formatter")
+    }
+
+    shadow!();
+
+    #[derive(Educe)]
+    #[educe(Debug)]
+    struct Struct {
+        formatter: ::core::primitive::u8,
+        builder: ::core::primitive::u8,
+        #[educe(Debug(method = "formatter"))]
+        field: ::core::primitive::u8,
+    }
+
+    #[derive(Educe)]
+    #[educe(Debug(name = false))]
+    struct Map {
+        formatter: ::core::primitive::u8,
+    }
+
+    #[derive(Educe)]
+    #[educe(Debug)]
+    enum Enum {
+        Struct {
+            formatter: ::core::primitive::u8,
+            #[educe(Debug(method = "formatter"))]
+            _0: ::core::primitive::u8,
+        },
+        Tuple(::core::primitive::u8, #[educe(Debug(method = "formatter"))] ::core::primitive::u8),
+    }
+
+    assert_eq!(
+        "Struct { formatter: 1, builder: 2, field: formatter }",
+        format!("{:?}", Struct {
+            formatter: 1,
+            builder: 2,
+            field: 3,
+        })
+    );
+
+    assert_eq!(
+        "{formatter: 1}",
+        format!("{:?}", Map {
+            formatter: 1
+        })
+    );
+
+    assert_eq!(
+        "Struct { formatter: 1, _0: formatter }",
+        format!("{:?}", Enum::Struct {
+            formatter: 1,
+            _0: 2,
+        })
+    );
+
+    assert_eq!("Tuple(1, formatter)", format!("{:?}", Enum::Tuple(1, 2)));
+}
+
+#[cfg(all(feature = "PartialEq", feature = "PartialOrd", feature = "Ord", feature = "Eq"))]
+#[test]
+fn compare() {
+    use ::core::cmp::Ordering;
+
+    fn other(a: &::core::primitive::u8, b: &::core::primitive::u8) -> ::core::primitive::bool {
+        a == b
+    }
+
+    fn other_value(a: &::core::primitive::u8, b: &::core::primitive::u8) -> Ordering {
+        ::core::cmp::Ord::cmp(a, b)
+    }
+
+    shadow!();
+
+    #[derive(Educe)]
+    #[educe(PartialEq, Eq, PartialOrd, Ord)]
+    struct Struct {
+        #[educe(PartialEq(method = "other"), Ord(method = "other_value"))]
+        other: ::core::primitive::u8,
+        other_value: ::core::primitive::u8,
+    }
+
+    #[derive(Educe)]
+    #[educe(PartialEq, Eq, PartialOrd, Ord)]
+    enum Enum {
+        Struct {
+            #[educe(PartialEq(method = "other"), Ord(method = "other_value"))]
+            other: ::core::primitive::u8,
+            _other_0: ::core::primitive::u8,
+        },
+        Tuple(::core::primitive::u8),
+        Unit,
+    }
+
+    #[derive(Educe)]
+    #[educe(PartialEq, Eq, PartialOrd, Ord)]
+    enum variant_to_integer {
+        A,
+        B,
+    }
+
+    #[derive(Educe)]
+    #[educe(PartialEq(bound(*), rhs = "T"), PartialOrd(bound(*), rhs = "T"))]
+    struct Rhs<T, __RhsT> {
+        f1: T,
+        f2: __RhsT,
+    }
+
+    let a = Struct {
+        other: 1,
+        other_value: 2,
+    };
+    let b = Struct {
+        other: 1,
+        other_value: 3,
+    };
+
+    assert!(a != b);
+    assert_eq!(Ordering::Less, ::core::cmp::Ord::cmp(&a, &b));
+
+    let a = Enum::Struct {
+        other: 1,
+        _other_0: 2,
+    };
+    let b = Enum::Struct {
+        other: 1,
+        _other_0: 2,
+    };
+
+    assert!(a == b);
+    assert_eq!(::core::option::Option::Some(Ordering::Equal), a.partial_cmp(&b));
+    assert!(Enum::Tuple(1) < Enum::Unit);
+    assert!(variant_to_integer::A < variant_to_integer::B);
+
+    assert!(
+        Rhs {
+            f1: 1u8,
+            f2: 2u8
+        } == Rhs {
+            f1: 1u8,
+            f2: 2u8
+        }
+    );
+    assert!(
+        Rhs {
+            f1: 1u8,
+            f2: 2u8
+        } < Rhs {
+            f1: 2u8,
+            f2: 2u8
+        }
+    );
+}
+
+#[cfg(feature = "Hash")]
+#[test]
+fn hash() {
+    use ::core::hash::{Hash, Hasher};
+
+    fn state<S: Hasher>(_: &::core::primitive::u8, s: &mut S) {
+        s.write_u8(42);
+    }
+
+    struct Recorder(::core::primitive::u64);
+
+    impl Hasher for Recorder {
+        fn finish(&self) -> ::core::primitive::u64 {
+            self.0
+        }
+
+        fn write(&mut self, bytes: &[::core::primitive::u8]) {
+            for byte in bytes {
+                self.0 = self.0 * 31 + *byte as ::core::primitive::u64;
+            }
+        }
+    }
+
+    shadow!();
+
+    #[derive(Educe)]
+    #[educe(Hash(bound))]
+    struct H<H_> {
+        #[educe(Hash(method = "state"))]
+        state: ::core::primitive::u8,
+        h: H_,
+    }
+
+    #[derive(Educe)]
+    #[educe(Hash)]
+    enum Enum {
+        Struct {
+            #[educe(Hash(method = "state"))]
+            state: ::core::primitive::u8,
+        },
+    }
+
+    let mut hasher = Recorder(0);
+
+    H {
+        state: 1,
+        h: 2u8,
+    }
+    .hash(&mut hasher);
+
+    assert_eq!(42 * 31 + 2, hasher.finish());
+
+    let mut hasher_1 = Recorder(0);
+    let mut hasher_2 = Recorder(0);
+
+    Enum::Struct {
+        state: 1,
+    }
+    .hash(&mut hasher_1);
+
+    Enum::Struct {
+        state: 2,
+    }
+    .hash(&mut hasher_2);
+
+    assert_eq!(hasher_1.finish(), hasher_2.finish());
+}
+
+#[cfg(feature = "Clone")]
+#[test]
+fn clone() {
+    shadow!();
+
+    #[derive(Educe)]
+    #[educe(Clone)]
+    struct Struct {
+        _source: ::core::primitive::u8,
+        done: ::core::primitive::u8,
+    }
+
+    #[derive(Educe)]
+    #[educe(Clone)]
+    enum Enum {
+        Struct {
+            _source: ::core::primitive::u8,
+            done: ::core::primitive::u8,
+        },
+    }
+
+    let mut s = Struct {
+        _source: 1,
+        done: 2,
+    };
+
+    s.clone_from(&Struct {
+        _source: 3,
+        done: 4,
+    });
+
+    assert_eq!((3, 4), (s._source, s.done));
+
+    let mut e = Enum::Struct {
+        _source: 1,
+        done: 2,
+    };
+
+    e.clone_from(&Enum::Struct {
+        _source: 3,
+        done: 4,
+    });
+
+    let Enum::Struct {
+        _source,
+        done,
+    } = e.clone();
+
+    assert_eq!((3, 4), (_source, done));
+}
+
+#[cfg(all(feature = "IsVariant", feature = "AsVariant", feature = "IntoVariant"))]
+#[test]
+fn variant() {
+    shadow!();
+
+    #[derive(Educe)]
+    #[educe(IsVariant, AsVariant, IntoVariant)]
+    enum Enum {
+        Struct {
+            msg: ::core::primitive::u8,
+            _0: ::core::primitive::u8,
+        },
+        Unit,
+    }
+
+    let e = Enum::Struct {
+        msg: 1,
+        _0: 2,
+    };
+
+    assert!(e.is_struct());
+    assert_eq!(::core::option::Option::Some((&1, &2)), e.as_struct());
+    assert_eq!((1, 2), e.expect_struct("msg"));
+    assert!(Enum::Unit.into_struct().is_err());
+}
+
+#[cfg(all(feature = "EnumSet", feature = "EnumMap"))]
+#[test]
+fn companion() {
+    shadow!();
+
+    #[derive(Debug, PartialEq, Educe)]
+    #[educe(EnumSet(name = "I"))]
+    enum V {
+        A,
+        B,
+    }
+
+    #[derive(Debug, PartialEq, Educe)]
+    #[educe(EnumMap(name = "F"))]
+    enum W {
+        A,
+        B,
+    }
+
+    let mut set = I::new();
+
+    set.insert(V::B);
+
+    assert!(set.contains(V::B));
+    assert_eq!([V::B], set.iter().collect::<alloc::vec::Vec<_>>()[..]);
+
+    let map = F::from_fn(|key| key == W::B);
+
+    assert!(!map[W::A]);
+    assert!(map[W::B]);
+}
+
+#[cfg(all(feature = "Builder", feature = "Set"))]
+#[test]
+fn accessor() {
+    shadow!();
+
+    #[derive(Educe)]
+    #[educe(Builder, Set)]
+    struct Struct<__Field0> {
+        value: __Field0,
+        __phantom: ::core::primitive::u8,
+    }
+
+    let mut s = Struct::builder().value(1).__phantom(2).build().unwrap_or_else(|_| unreachable!());
+
+    s.set_value(3);
+
+    assert_eq!((3, 2), (s.value, s.__phantom));
+}
@@ -0,0 +1,160 @@
+#![cfg(feature = "IntoVariant")]
+#![no_std]
+
+#[macro_use]
+extern crate educe;
+
+#[test]
+#[allow(dead_code)]
+fn basic() {
+    #[derive(Educe)]
+    #[educe(IntoVariant)]
+    enum Enum {
+        Unit,
+        Struct {
+            f1: u8,
+        },
+        Tuple(u8),
+    }
+
+    assert_eq!(
+        Ok(1),
+        Enum::Struct {
+            f1: 1
+        }
+        .into_struct()
+        .map_err(|_| ())
+    );
+
+    assert!(matches!(Enum::Unit.into_struct(), Err(Enum::Unit)));
+    assert_eq!(1, Enum::Tuple(1).unwrap_tuple());
+    assert_eq!(1, Enum::Tuple(1).expect_tuple("should be a tuple"));
+}
+
+#[test]
+#[allow(dead_code)]
+fn multiple_fields() {
+    #[derive(Educe)]
+    #[educe(IntoVariant)]
+    enum Enum {
+        Unit,
+        Struct {
+            f1: u8,
+            f2: i32,
+        },
+        Tuple(u8, i32),
+    }
+
+    assert_eq!(
+        (1, 2),
+        Enum::Struct {
+            f1: 1,
+            f2: 2
+        }
+        .unwrap_struct()
+    );
+
+    assert_eq!(Ok((1, 2)), Enum::Tuple(1, 2).into_tuple().map_err(|_| ()));
+}
+
+#[test]
+#[should_panic(expected = "called `unwrap_tuple()` on a `Unit` value, expected `Tuple`")]
+#[allow(dead_code)]
+fn unwrap_panic() {
+    #[derive(Educe)]
+    #[educe(IntoVariant)]
+    enum Enum {
+        Unit,
+        Tuple(u8),
+    }
+
+    Enum::Unit.unwrap_tuple();
+}
+
+#[test]
+#[should_panic(expected = "not a tuple: expected `Tuple`, found `Unit`")]
+#[allow(dead_code)]
+fn expect_panic() {
+    #[derive(Educe)]
+    #[educe(IntoVariant)]
+    enum Enum {
+        Unit,
+        Tuple(u8),
+    }
+
+    Enum::Unit.expect_tuple("not a tuple");
+}
+
+#[cfg(feature = "Debug")]
+#[test]
+#[should_panic(expected = "called `unwrap_tuple()` on a `Nothing` value, expected `Value`")]
+#[allow(dead_code)]
+fn debug_name() {
+    #[derive(Educe)]
+    #[educe(Debug, IntoVariant)]
+    enum Enum {
+        #[educe(Debug(name = "Nothing"))]
+        Unit,
+        #[educe(Debug(name = "Value"))]
+        Tuple(u8),
+    }
+
+    Enum::Unit.unwrap_tuple();
+}
+
+#[test]
+fn single() {
+    #[derive(Educe)]
+    #[educe(IntoVariant)]
+    enum Enum {
+        Tuple(u8),
+    }
+
+    assert_eq!(1, Enum::Tuple(1).unwrap_tuple());
+    assert_eq!(Ok(1), Enum::Tuple(1).into_tuple().map_err(|_| ()));
+}
+
+#[test]
+#[allow(dead_code)]
+fn name() {
+    #[derive(Educe)]
+    #[educe(IntoVariant)]
+    enum Enum {
+        Unit,
+        #[educe(IntoVariant(name = "value"))]
+        Tuple(u8),
+    }
+
+    assert_eq!(1, Enum::Tuple(1).unwrap_value());
+}
+
+#[test]
+#[allow(dead_code)]
+fn raw_name() {
+    #[derive(Educe)]
+    #[educe(IntoVariant)]
+    enum Enum {
+        Unit,
+        #[educe(IntoVariant(name = "r#type"))]
+        Tuple(u8),
+    }
+
+    assert_eq!(Ok(1), Enum::Tuple(1).into_type().map_err(|_| ()));
+    assert_eq!(1, Enum::Tuple(1).unwrap_type());
+    assert_eq!(1, Enum::Tuple(1).expect_type("type"));
+}
+
+#[test]
+#[allow(dead_code)]
+fn generic() {
+    #[derive(Educe)]
+    #[educe(IntoVariant)]
+    enum Enum<'a, T, const N: usize> {
+        Unit,
+        Borrowed(&'a T),
+        Array([T; N]),
+    }
+
+    assert_eq!(&1, Enum::<u8, 1>::Borrowed(&1).unwrap_borrowed());
+    assert_eq!([1u8, 2], Enum::Array([1u8, 2]).unwrap_array());
+}
@@ -0,0 +1,118 @@
+#![cfg(feature = "IsVariant")]
+#![no_std]
+
+#[macro_use]
+extern crate educe;
+
+#[test]
+#[allow(dead_code)]
+fn basic() {
+    #[derive(Educe)]
+    #[educe(IsVariant)]
+    enum Enum {
+        Unit,
+        Struct {
+            f1: u8,
+        },
+        Tuple(u8),
+    }
+
+    assert!(Enum::Unit.is_unit());
+    assert!(!Enum::Unit.is_struct());
+    assert!(!Enum::Unit.is_tuple());
+
+    assert!(Enum::Struct {
+        f1: 1
+    }
+    .is_struct());
+
+    assert!(Enum::Tuple(1).is_tuple());
+    assert!(!Enum::Tuple(1).is_unit());
+}
+
+#[test]
+fn single() {
+    #[derive(Educe)]
+    #[educe(IsVariant)]
+    enum Enum {
+        Unit,
+    }
+
+    assert!(Enum::Unit.is_unit());
+}
+
+#[test]
+#[allow(dead_code)]
+fn snake_case() {
+    #[derive(Educe)]
+    #[educe(IsVariant)]
+    enum Enum {
+        TwoWords,
+        HTTPServer,
+        Ipv4(u8, u8, u8, u8),
+    }
+
+    assert!(Enum::TwoWords.is_two_words());
+    assert!(Enum::HTTPServer.is_http_server());
+    assert!(Enum::Ipv4(127, 0, 0, 1).is_ipv4());
+}
+
+#[test]
+#[allow(dead_code)]
+fn name() {
+    #[derive(Educe)]
+    #[educe(IsVariant)]
+    enum Enum {
+        #[educe(IsVariant(name = "empty"))]
+        Unit,
+        #[educe(IsVariant(name("named")))]
+        Struct {
+            f1: u8,
+        },
+        Tuple(u8),
+    }
+
+    assert!(Enum::Unit.is_empty());
+    assert!(Enum::Struct {
+        f1: 1
+    }
+    .is_named());
+    assert!(Enum::Tuple(1).is_tuple());
+}
+
+#[test]
+#[allow(dead_code)]
+fn ignore() {
+    #[derive(Educe)]
+    #[educe(IsVariant)]
+    enum Enum {
+        #[educe(IsVariant(ignore))]
+        Unit,
+        Tuple(u8),
+    }
+
+    impl Enum {
+        fn is_unit(&self) -> u8 {
+            0
+        }
+    }
+
+    assert_eq!(0, Enum::Unit.is_unit());
+    assert!(Enum::Tuple(1).is_tuple());
+}
+
+#[test]
+#[allow(dead_code)]
+fn generic() {
+    #[derive(Educe)]
+    #[educe(IsVariant)]
+    enum Enum<'a, T, const N: usize> {
+        Unit,
+        Ref(&'a T),
+        Array([T; N]),
+    }
+
+    assert!(Enum::<u8, 1>::Unit.is_unit());
+    assert!(Enum::<u8, 1>::Ref(&1).is_ref());
+    assert!(Enum::Array([1u8, 2]).is_array());
+}
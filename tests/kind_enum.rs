@@ -0,0 +1,108 @@
+#![cfg(feature = "Kind")]
+#![no_std]
+
+#[macro_use]
+extern crate educe;
+
+#[test]
+#[allow(dead_code)]
+fn basic() {
+    #[derive(Educe)]
+    #[educe(Kind)]
+    enum Enum {
+        Unit,
+        Struct {
+            f1: u8,
+        },
+        Tuple(u8),
+    }
+
+    assert!(matches!(Enum::Unit.kind(), EnumKind::Unit));
+    assert!(matches!(
+        Enum::Struct {
+            f1: 1
+        }
+        .kind(),
+        EnumKind::Struct
+    ));
+    assert!(matches!(Enum::Tuple(1).kind(), EnumKind::Tuple));
+}
+
+#[test]
+#[allow(dead_code)]
+fn name() {
+    #[derive(Educe)]
+    #[educe(Kind(name = "MessageKind"))]
+    enum Message {
+        Ping,
+        Text(u8),
+    }
+
+    assert!(matches!(Message::Ping.kind(), MessageKind::Ping));
+    assert!(matches!(Message::Text(1).kind(), MessageKind::Text));
+}
+
+#[cfg(all(
+    feature = "Debug",
+    feature = "Clone",
+    feature = "Copy",
+    feature = "PartialEq",
+    feature = "Eq",
+    feature = "PartialOrd",
+    feature = "Ord",
+    feature = "Hash"
+))]
+#[test]
+#[allow(dead_code)]
+fn derive() {
+    extern crate alloc;
+
+    use alloc::format;
+
+    #[derive(Educe)]
+    #[educe(Kind(derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)))]
+    enum Enum {
+        Unit,
+        Struct {
+            f1: u8,
+        },
+        Tuple(u8),
+    }
+
+    let kind = Enum::Tuple(1).kind();
+    let copied = kind;
+
+    assert_eq!(EnumKind::Tuple, kind);
+    assert_eq!(kind, copied);
+    assert_eq!("Struct", format!("{:?}", Enum::Struct { f1: 1 }.kind()));
+    assert!(Enum::Unit.kind() < Enum::Tuple(1).kind());
+}
+
+#[cfg(feature = "PartialEq")]
+#[test]
+#[allow(dead_code)]
+fn generic() {
+    #[derive(Educe)]
+    #[educe(Kind(derive(PartialEq)))]
+    enum Enum<'a, T, const N: usize> {
+        Borrowed(&'a T),
+        Array([T; N]),
+    }
+
+    assert!(EnumKind::Borrowed == Enum::<u8, 1>::Borrowed(&1).kind());
+    assert!(EnumKind::Array == Enum::Array([1u8; 2]).kind());
+}
+
+#[test]
+#[allow(dead_code)]
+fn vis() {
+    mod inner {
+        #[derive(Educe)]
+        #[educe(Kind(vis = "pub(super)"))]
+        pub(super) enum Enum {
+            Unit,
+        }
+    }
+
+    assert!(matches!(inner::Enum::Unit.kind(), inner::EnumKind::Unit));
+}
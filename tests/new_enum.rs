@@ -0,0 +1,108 @@
+#![cfg(feature = "New")]
+#![no_std]
+
+#[macro_use]
+extern crate educe;
+
+extern crate alloc;
+
+use alloc::string::String;
+
+#[test]
+fn basic() {
+    #[derive(Debug, PartialEq, Educe)]
+    #[educe(New)]
+    enum Shape {
+        Empty,
+        Circle(f64),
+        Rect {
+            w: f64,
+            h: f64,
+        },
+        RoundedRect(f64, f64, f64),
+    }
+
+    assert_eq!(Shape::Empty, Shape::empty());
+    assert_eq!(Shape::Circle(1.0), Shape::circle(1.0));
+    assert_eq!(
+        Shape::Rect {
+            w: 1.0, h: 2.0
+        },
+        Shape::rect(1.0, 2.0)
+    );
+    assert_eq!(Shape::RoundedRect(1.0, 2.0, 0.5), Shape::rounded_rect(1.0, 2.0, 0.5));
+}
+
+#[cfg(feature = "Default")]
+#[test]
+fn default() {
+    #[derive(Debug, PartialEq, Educe)]
+    #[educe(Default, New)]
+    enum Enum {
+        #[educe(Default)]
+        Unit,
+        Struct {
+            f1: u8,
+            #[educe(Default = 2)]
+            f2: u8,
+        },
+        Tuple(#[educe(Default(expression = "1 + 2"))] u8, u8),
+    }
+
+    assert_eq!(
+        Enum::Struct {
+            f1: 1, f2: 2
+        },
+        Enum::r#struct(1)
+    );
+    assert_eq!(Enum::Tuple(3, 4), Enum::tuple(4));
+}
+
+#[test]
+fn name_vis() {
+    mod inner {
+        #[derive(Educe)]
+        #[educe(New(vis = "pub(super)"))]
+        pub(super) enum Enum {
+            #[educe(New(name = "create"))]
+            Unit,
+            #[educe(New(vis = "pub"))]
+            Tuple(u8),
+        }
+    }
+
+    assert!(matches!(inner::Enum::create(), inner::Enum::Unit));
+    assert!(matches!(inner::Enum::tuple(1), inner::Enum::Tuple(1)));
+}
+
+#[test]
+fn constant_into() {
+    #[derive(Debug, PartialEq, Educe)]
+    #[educe(New)]
+    enum Enum {
+        #[educe(New(const))]
+        Unit,
+        #[educe(New(into))]
+        Text(String),
+        Number(#[educe(New(into))] u64),
+    }
+
+    const UNIT: Enum = Enum::unit();
+
+    assert_eq!(Enum::Unit, UNIT);
+    assert_eq!(Enum::Text(String::from("Hi")), Enum::text("Hi"));
+    assert_eq!(Enum::Number(1), Enum::number(1u8));
+}
+
+#[test]
+fn generic() {
+    #[derive(Debug, PartialEq, Educe)]
+    #[educe(New)]
+    enum Enum<'a, T, const N: usize> {
+        Borrowed(&'a T),
+        Array([T; N]),
+    }
+
+    assert_eq!(Enum::<u8, 1>::Borrowed(&1), Enum::borrowed(&1));
+    assert_eq!(Enum::Array([1u8, 2]), Enum::array([1, 2]));
+}
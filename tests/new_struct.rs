@@ -0,0 +1,182 @@
+#![cfg(feature = "New")]
+#![no_std]
+
+#[macro_use]
+extern crate educe;
+
+extern crate alloc;
+
+use alloc::string::String;
+
+#[test]
+fn basic() {
+    #[derive(Educe)]
+    #[educe(New)]
+    struct Unit;
+
+    #[derive(Educe)]
+    #[educe(New)]
+    struct Struct {
+        f1: u8,
+        f2: String,
+    }
+
+    #[derive(Educe)]
+    #[educe(New)]
+    struct Tuple(u8, String);
+
+    let _ = Unit::new();
+
+    let s = Struct::new(1, String::from("Hi"));
+
+    assert_eq!(1, s.f1);
+    assert_eq!("Hi", s.f2);
+
+    let t = Tuple::new(1, String::from("Hi"));
+
+    assert_eq!(1, t.0);
+    assert_eq!("Hi", t.1);
+}
+
+#[cfg(feature = "Default")]
+#[test]
+fn default() {
+    #[derive(Educe)]
+    #[educe(Default, New)]
+    struct Struct {
+        f1: u8,
+        #[educe(Default = "Hello")]
+        f2: String,
+        #[educe(Default(expression = "2 + 3"))]
+        f3: u8,
+    }
+
+    #[derive(Educe)]
+    #[educe(Default, New)]
+    struct Tuple(#[educe(Default = 5)] u8, u16);
+
+    let s = Struct::new(1);
+
+    assert_eq!(1, s.f1);
+    assert_eq!("Hello", s.f2);
+    assert_eq!(5, s.f3);
+
+    let t = Tuple::new(2);
+
+    assert_eq!(5, t.0);
+    assert_eq!(2, t.1);
+}
+
+#[test]
+fn default_parameter() {
+    struct Address(u32);
+
+    #[derive(Educe)]
+    #[educe(New)]
+    struct Struct {
+        addr: Address,
+        #[educe(New(default = "Hello"))]
+        f1: String,
+        #[educe(New(default(expression(2 + 3))))]
+        f2: u8,
+    }
+
+    #[derive(Educe)]
+    #[educe(New(const))]
+    struct Constant(u8, #[educe(New(default = "Hi"))] &'static str);
+
+    let s = Struct::new(Address(1));
+
+    assert_eq!(1, s.addr.0);
+    assert_eq!("Hello", s.f1);
+    assert_eq!(5, s.f2);
+
+    const C: Constant = Constant::new(1);
+
+    assert_eq!(1, C.0);
+    assert_eq!("Hi", C.1);
+}
+
+#[test]
+fn name_vis() {
+    mod inner {
+        #[derive(Educe)]
+        #[educe(New(name = "create", vis = "pub(super)"))]
+        pub(super) struct Struct {
+            pub(super) f1: u8,
+        }
+    }
+
+    assert_eq!(1, inner::Struct::create(1).f1);
+}
+
+#[test]
+fn constant() {
+    #[derive(Educe)]
+    #[educe(New(const))]
+    struct Struct {
+        f1: u8,
+        f2: u16,
+    }
+
+    const S: Struct = Struct::new(1, 2);
+
+    assert_eq!(1, S.f1);
+    assert_eq!(2, S.f2);
+}
+
+#[cfg(feature = "Default")]
+#[test]
+fn constant_default() {
+    #[derive(Educe)]
+    #[educe(Default, New(const))]
+    struct Struct {
+        f1: u8,
+        #[educe(Default = "Hi")]
+        f2: &'static str,
+    }
+
+    const S: Struct = Struct::new(1);
+
+    assert_eq!(1, S.f1);
+    assert_eq!("Hi", S.f2);
+}
+
+#[test]
+fn into() {
+    #[derive(Educe)]
+    #[educe(New(into))]
+    struct Struct {
+        f1: u64,
+        f2: String,
+    }
+
+    #[derive(Educe)]
+    #[educe(New)]
+    struct Tuple(u8, #[educe(New(into))] String);
+
+    let s = Struct::new(1u8, "Hi");
+
+    assert_eq!(1, s.f1);
+    assert_eq!("Hi", s.f2);
+
+    let t = Tuple::new(1, "Hi");
+
+    assert_eq!(1, t.0);
+    assert_eq!("Hi", t.1);
+}
+
+#[test]
+fn generic() {
+    #[derive(Educe)]
+    #[educe(New)]
+    struct Struct<'a, T, const N: usize> {
+        f1: &'a T,
+        f2: [T; N],
+    }
+
+    let s = Struct::new(&1, [2, 3]);
+
+    assert_eq!(1, *s.f1);
+    assert_eq!([2, 3], s.f2);
+}
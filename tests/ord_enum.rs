@@ -859,3 +859,43 @@ fn variant_rank_2() {
 
     assert_eq!(Ordering::Greater, Enum::Two.cmp(&Enum::One));
 }
+
+#[test]
+fn bound_field() {
+    trait Container {
+        type Item;
+    }
+
+    struct Bytes;
+
+    impl Container for Bytes {
+        type Item = u8;
+    }
+
+    #[derive(Educe)]
+    #[educe(PartialEq, Eq, PartialOrd, Ord)]
+    enum Enum<T: Container, U> {
+        Struct {
+            #[educe(
+                PartialEq(bound = "T::Item: core::cmp::PartialEq"),
+                Eq(bound = "T::Item: core::cmp::Eq"),
+                PartialOrd(bound = "T::Item: core::cmp::PartialOrd"),
+                Ord(bound = "T::Item: core::cmp::Ord")
+            )]
+            f1: T::Item,
+        },
+        #[educe(PartialEq(bound(*)), Eq(bound(*)), PartialOrd(bound(*)), Ord(bound(*)))]
+        Tuple(U),
+    }
+
+    assert_eq!(
+        Ordering::Less,
+        Enum::<Bytes, u8>::Struct {
+            f1: 1
+        }
+        .cmp(&Enum::Struct {
+            f1: 2
+        })
+    );
+    assert_eq!(Ordering::Less, Enum::<Bytes, _>::Tuple(1).cmp(&Enum::Tuple(2)));
+}
@@ -668,6 +668,75 @@ fn bound_3() {
     assert_eq!(Ordering::Less, Tuple(1).cmp(&Tuple(2)));
 }
 
+#[test]
+fn bound_infer() {
+    use core::marker::PhantomData;
+
+    struct NotOrd;
+
+    fn cmp<T>(_: &T, _: &T) -> Ordering {
+        Ordering::Equal
+    }
+
+    #[derive(Educe)]
+    #[educe(
+        PartialEq(bound(*)),
+        Eq(bound(*)),
+        PartialOrd(bound(*)),
+        Ord(bound(*))
+    )]
+    struct Struct<T, U> {
+        f1: Option<T>,
+        #[educe(PartialEq(ignore), PartialOrd(ignore), Ord(method(cmp)))]
+        f2: PhantomData<U>,
+    }
+
+    let create = |f1| {
+        Struct {
+            f1,
+            f2: PhantomData::<NotOrd>,
+        }
+    };
+
+    assert_eq!(Ordering::Less, create(Some(1)).cmp(&create(Some(2))));
+    assert_eq!(Ordering::Greater, create(Some(1)).cmp(&create(None)));
+}
+
+#[test]
+fn bound_field() {
+    trait Container {
+        type Item;
+    }
+
+    struct Bytes;
+
+    impl Container for Bytes {
+        type Item = u8;
+    }
+
+    #[derive(Educe)]
+    #[educe(PartialEq, Eq, PartialOrd, Ord)]
+    struct Struct<T: Container> {
+        #[educe(
+            PartialEq(bound = "T::Item: core::cmp::PartialEq"),
+            Eq(bound = "T::Item: core::cmp::Eq"),
+            PartialOrd(bound = "T::Item: core::cmp::PartialOrd"),
+            Ord(bound = "T::Item: core::cmp::Ord")
+        )]
+        f1: T::Item,
+    }
+
+    assert_eq!(
+        Ordering::Less,
+        Struct::<Bytes> {
+            f1: 1
+        }
+        .cmp(&Struct {
+            f1: 2
+        })
+    );
+}
+
 #[test]
 fn rank_1() {
     #[derive(Educe)]
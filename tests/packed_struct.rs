@@ -0,0 +1,193 @@
+#![allow(clippy::clone_on_copy, clippy::repr_packed_without_abi)]
+#![no_std]
+
+#[macro_use]
+extern crate alloc;
+
+#[macro_use]
+extern crate educe;
+
+#[cfg(feature = "Debug")]
+#[test]
+fn debug() {
+    fn fmt(v: &u16, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_fmt(format_args!("{:#06x}", v))
+    }
+
+    #[derive(Educe)]
+    #[educe(Debug)]
+    #[repr(packed)]
+    struct Struct {
+        f1: u8,
+        #[educe(Debug(method = "fmt"))]
+        f2: u16,
+        #[educe(Debug(ignore))]
+        _f3: u32,
+    }
+
+    #[derive(Educe)]
+    #[educe(Debug)]
+    #[repr(C, packed)]
+    struct Tuple(u8, u64);
+
+    assert_eq!(
+        "Struct { f1: 1, f2: 0x0002 }",
+        format!("{:?}", Struct {
+            f1: 1,
+            f2: 2,
+            _f3: 3,
+        })
+    );
+
+    assert_eq!("Tuple(1, 2)", format!("{:?}", Tuple(1, 2)));
+}
+
+#[cfg(all(feature = "PartialEq", feature = "Eq", feature = "PartialOrd", feature = "Ord"))]
+#[test]
+fn compare() {
+    use core::cmp::Ordering;
+
+    #[derive(Educe)]
+    #[educe(PartialEq(bound), Eq(bound), PartialOrd(bound), Ord(bound))]
+    #[repr(packed)]
+    struct Struct<T: Copy> {
+        #[educe(PartialOrd(rank = 1), Ord(rank = 1))]
+        f1: u8,
+        f2: T,
+    }
+
+    let a = Struct {
+        f1: 2,
+        f2: 1u64,
+    };
+    let b = Struct {
+        f1: 1,
+        f2: 2u64,
+    };
+
+    assert!(a != b);
+    assert!(a == a);
+    assert_eq!(Some(Ordering::Less), a.partial_cmp(&b));
+    assert_eq!(Ordering::Less, a.cmp(&b));
+}
+
+#[cfg(feature = "Hash")]
+#[test]
+fn hash() {
+    use core::hash::{Hash, Hasher};
+
+    struct Recorder(u64);
+
+    impl Hasher for Recorder {
+        fn finish(&self) -> u64 {
+            self.0
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            for byte in bytes {
+                self.0 = self.0 * 31 + *byte as u64;
+            }
+        }
+    }
+
+    #[derive(Educe)]
+    #[educe(Hash)]
+    #[repr(packed)]
+    struct Struct {
+        f1: u8,
+        #[educe(Hash(ignore))]
+        _f2: u64,
+        f3: u8,
+    }
+
+    let mut hasher = Recorder(0);
+
+    Struct {
+        f1: 1,
+        _f2: 2,
+        f3: 3,
+    }
+    .hash(&mut hasher);
+
+    assert_eq!(31 + 3, hasher.finish());
+}
+
+#[cfg(feature = "Clone")]
+#[test]
+fn clone() {
+    fn double(v: &u16) -> u16 {
+        v * 2
+    }
+
+    #[derive(Educe)]
+    #[educe(Clone)]
+    #[repr(packed)]
+    struct Struct {
+        f1: u8,
+        #[educe(Clone(method = "double"))]
+        f2: u16,
+    }
+
+    let a = Struct {
+        f1: 1,
+        f2: 2,
+    };
+    let b = a.clone();
+
+    assert_eq!((1, 4), (b.f1, b.f2));
+
+    let mut c = Struct {
+        f1: 0,
+        f2: 0,
+    };
+
+    c.clone_from(&b);
+
+    assert_eq!((1, 8), (c.f1, c.f2));
+}
+
+#[cfg(all(feature = "Clone", feature = "Copy"))]
+#[test]
+fn copy_clone() {
+    #[derive(Educe)]
+    #[educe(Clone, Copy)]
+    #[repr(packed)]
+    struct Struct {
+        f1: u8,
+        f2: u64,
+    }
+
+    let a = Struct {
+        f1: 1,
+        f2: 2,
+    };
+    let mut b = Struct {
+        f1: 0,
+        f2: 0,
+    };
+
+    b.clone_from(&a);
+
+    let c = b.clone();
+
+    assert_eq!((1, 2), (c.f1, c.f2));
+}
+
+#[cfg(feature = "Get")]
+#[test]
+fn get() {
+    #[derive(Educe)]
+    #[educe(Get(copy))]
+    #[repr(packed)]
+    struct Struct {
+        f1: u8,
+        f2: u64,
+    }
+
+    let s = Struct {
+        f1: 1,
+        f2: 2,
+    };
+
+    assert_eq!((1, 2), (s.f1(), s.f2()));
+}
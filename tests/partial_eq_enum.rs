@@ -527,3 +527,101 @@ fn bound_3() {
     assert!(Enum::Tuple(1) == Enum::Tuple(1));
     assert!(Enum::Tuple(1) != Enum::Tuple(2));
 }
+
+#[test]
+fn rhs() {
+    struct Meter(u32);
+    struct Centimeter(u32);
+
+    impl core::cmp::PartialEq<Centimeter> for Meter {
+        fn eq(&self, other: &Centimeter) -> bool {
+            self.0 * 100 == other.0
+        }
+    }
+
+    #[derive(Educe)]
+    #[educe(PartialEq(rhs = "T"))]
+    enum Enum<T> {
+        Unit,
+        Struct {
+            f1: T,
+        },
+        Tuple(T),
+    }
+
+    assert!(Enum::<Meter>::Unit == Enum::<Centimeter>::Unit);
+
+    assert!(
+        Enum::Struct {
+            f1: Meter(1)
+        } == Enum::Struct {
+            f1: Centimeter(100)
+        }
+    );
+
+    assert!(
+        Enum::Struct {
+            f1: Meter(1)
+        } != Enum::Struct {
+            f1: Centimeter(1)
+        }
+    );
+
+    assert!(Enum::Tuple(Meter(1)) == Enum::Tuple(Centimeter(100)));
+    assert!(Enum::Tuple(Meter(1)) != Enum::Tuple(Centimeter(1)));
+}
+
+#[test]
+fn different_variants() {
+    #[derive(Educe)]
+    #[educe(PartialEq)]
+    enum Enum {
+        Struct {
+            f1: u8,
+        },
+        Tuple(u8),
+    }
+
+    assert!(
+        Enum::Struct {
+            f1: 1
+        } != Enum::Tuple(1)
+    );
+    assert!(
+        Enum::Tuple(1)
+            != Enum::Struct {
+                f1: 1
+            }
+    );
+}
+
+#[test]
+fn raw_identifier() {
+    #[derive(Educe)]
+    #[educe(PartialEq)]
+    enum Enum {
+        Struct {
+            r#type: u8,
+            r#match: u8,
+        },
+    }
+
+    assert!(
+        Enum::Struct {
+            r#type: 1,
+            r#match: 2
+        } == Enum::Struct {
+            r#type: 1,
+            r#match: 2
+        }
+    );
+    assert!(
+        Enum::Struct {
+            r#type: 1,
+            r#match: 2
+        } != Enum::Struct {
+            r#type: 1,
+            r#match: 3
+        }
+    );
+}
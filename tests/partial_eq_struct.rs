@@ -530,3 +530,277 @@ fn bound_3() {
     assert!(Tuple(1) == Tuple(1));
     assert!(Tuple(1) != Tuple(2));
 }
+
+#[test]
+fn bound_4() {
+    #[derive(Educe)]
+    #[educe(PartialEq(bound(T: core::cmp::PartialEq)))]
+    struct Struct<T> {
+        f1: T,
+    }
+
+    #[derive(Educe)]
+    #[educe(PartialEq(bound(T: core::cmp::PartialEq)))]
+    struct Tuple<T>(T);
+
+    assert!(
+        Struct {
+            f1: 1
+        } == Struct {
+            f1: 1
+        }
+    );
+
+    assert!(
+        Struct {
+            f1: 1
+        } != Struct {
+            f1: 2
+        }
+    );
+
+    assert!(Tuple(1) == Tuple(1));
+    assert!(Tuple(1) != Tuple(2));
+}
+
+#[test]
+#[allow(dead_code)]
+fn bound_infer() {
+    use core::marker::PhantomData;
+
+    struct NotPartialEq;
+
+    fn eq<T>(_: &T, _: &T) -> bool {
+        true
+    }
+
+    #[derive(Educe)]
+    #[educe(PartialEq(bound(*)))]
+    struct Struct<T, U, V, W> {
+        f1: Option<T>,
+        #[educe(PartialEq(ignore))]
+        f2: U,
+        #[educe(PartialEq(method(eq)))]
+        f3: V,
+        f4: PhantomData<W>,
+    }
+
+    let create = |f1| {
+        Struct {
+            f1,
+            f2: NotPartialEq,
+            f3: NotPartialEq,
+            f4: PhantomData::<NotPartialEq>,
+        }
+    };
+
+    assert!(create(Some(1)) == create(Some(1)));
+    assert!(create(Some(1)) != create(Some(2)));
+
+    struct Meter(u32);
+    struct Centimeter(u32);
+
+    impl core::cmp::PartialEq<Centimeter> for Meter {
+        fn eq(&self, other: &Centimeter) -> bool {
+            self.0 * 100 == other.0
+        }
+    }
+
+    #[derive(Educe)]
+    #[educe(PartialEq(bound(*), rhs = "T"))]
+    struct Tuple<T, K>([T; 2], PhantomData<K>);
+
+    assert!(
+        Tuple([Meter(1), Meter(2)], PhantomData::<NotPartialEq>)
+            == Tuple([Centimeter(100), Centimeter(200)], PhantomData::<NotPartialEq>)
+    );
+}
+
+#[test]
+fn bound_infer_recursive() {
+    #[derive(Educe)]
+    #[educe(PartialEq(bound(*)))]
+    struct List<'a, T> {
+        v: T,
+        next: Option<&'a List<'a, T>>,
+    }
+
+    let tail = List {
+        v: 2,
+        next: None,
+    };
+
+    assert!(
+        List {
+            v: 1,
+            next: Some(&tail)
+        } == List {
+            v: 1,
+            next: Some(&tail)
+        }
+    );
+    assert!(
+        List {
+            v: 1,
+            next: Some(&tail)
+        } != List {
+            v: 1,
+            next: None
+        }
+    );
+}
+
+#[test]
+fn bound_field() {
+    trait Container {
+        type Item;
+    }
+
+    struct Bytes;
+
+    impl Container for Bytes {
+        type Item = u8;
+    }
+
+    #[derive(Educe)]
+    #[educe(PartialEq)]
+    struct Struct<T: Container> {
+        #[educe(PartialEq(bound = "T::Item: core::cmp::PartialEq"))]
+        f1: T::Item,
+    }
+
+    assert!(
+        Struct::<Bytes> {
+            f1: 1
+        } == Struct {
+            f1: 1
+        }
+    );
+    assert!(
+        Struct::<Bytes> {
+            f1: 1
+        } != Struct {
+            f1: 2
+        }
+    );
+}
+
+#[test]
+fn rhs_1() {
+    struct Meter(u32);
+    struct Centimeter(u32);
+
+    impl core::cmp::PartialEq<Centimeter> for Meter {
+        fn eq(&self, other: &Centimeter) -> bool {
+            self.0 * 100 == other.0
+        }
+    }
+
+    #[derive(Educe)]
+    #[educe(PartialEq(rhs = "T"))]
+    struct Struct<T> {
+        f1: [T; 2],
+    }
+
+    #[derive(Educe)]
+    #[educe(PartialEq(rhs("T")))]
+    struct Tuple<T>([T; 2]);
+
+    assert!(
+        Struct {
+            f1: [Meter(1), Meter(2)]
+        } == Struct {
+            f1: [Centimeter(100), Centimeter(200)]
+        }
+    );
+
+    assert!(
+        Struct {
+            f1: [Meter(1), Meter(2)]
+        } != Struct {
+            f1: [Centimeter(1), Centimeter(2)]
+        }
+    );
+
+    assert!(Tuple([Meter(1), Meter(2)]) == Tuple([Centimeter(100), Centimeter(200)]));
+    assert!(Tuple([Meter(1), Meter(2)]) != Tuple([Centimeter(1), Centimeter(2)]));
+}
+
+#[test]
+fn rhs_2() {
+    #[derive(Educe)]
+    #[educe(PartialEq(bound, rhs = "T"))]
+    struct Struct<T: Copy, K> {
+        f1: T,
+        f2: K,
+    }
+
+    #[derive(Educe)]
+    #[educe(PartialEq(rhs))]
+    struct Tuple<T, K>(T, K);
+
+    assert!(
+        Struct {
+            f1: 1u8,
+            f2: 2
+        } == Struct {
+            f1: 1u8,
+            f2: 2
+        }
+    );
+
+    assert!(
+        Struct {
+            f1: 1u8,
+            f2: 2
+        } != Struct {
+            f1: 1u8,
+            f2: 3
+        }
+    );
+
+    assert!(Tuple(1u8, 2u8) == Tuple(1u8, 2u8));
+    assert!(Tuple(1u8, 2u8) != Tuple(1u8, 3u8));
+}
+
+#[test]
+fn rhs_3() {
+    fn same_size<A, B>(_: &A, _: &B) -> bool {
+        core::mem::size_of::<A>() == core::mem::size_of::<B>()
+    }
+
+    #[derive(Educe)]
+    #[educe(PartialEq(rhs = "T"))]
+    struct Struct<T> {
+        f1: u8,
+        #[educe(PartialEq(method = "same_size"))]
+        f2: T,
+    }
+
+    #[derive(Educe)]
+    #[educe(PartialEq(rhs = "T"))]
+    struct Tuple<T>(u8, #[educe(PartialEq(ignore))] T);
+
+    assert!(
+        Struct {
+            f1: 1,
+            f2: 1u8
+        } == Struct {
+            f1: 1,
+            f2: 2i8
+        }
+    );
+
+    assert!(
+        Struct {
+            f1: 1,
+            f2: 1u8
+        } != Struct {
+            f1: 1,
+            f2: "1"
+        }
+    );
+
+    assert!(Tuple(1, 1u8) == Tuple(1, "1"));
+    assert!(Tuple(1, 1u8) != Tuple(2, "1"));
+}
@@ -823,3 +823,58 @@ fn variant_rank_2() {
 
     assert!(Enum::Two > Enum::One);
 }
+
+#[test]
+fn rhs() {
+    struct Meter(u32);
+    struct Centimeter(u32);
+
+    impl core::cmp::PartialEq<Centimeter> for Meter {
+        fn eq(&self, other: &Centimeter) -> bool {
+            self.0 * 100 == other.0
+        }
+    }
+
+    impl core::cmp::PartialOrd<Centimeter> for Meter {
+        fn partial_cmp(&self, other: &Centimeter) -> Option<Ordering> {
+            (self.0 * 100).partial_cmp(&other.0)
+        }
+    }
+
+    #[derive(Educe)]
+    #[educe(PartialEq(rhs = "T"), PartialOrd(rhs = "T"))]
+    enum Enum<T> {
+        Unit,
+        Struct {
+            f1: T,
+        },
+        Tuple(T),
+    }
+
+    assert!(
+        Enum::<Meter>::Unit
+            < Enum::Struct {
+                f1: Centimeter(1)
+            }
+    );
+
+    assert!(
+        Enum::Struct {
+            f1: Meter(1)
+        } < Enum::Struct {
+            f1: Centimeter(101)
+        }
+    );
+
+    assert!(
+        Enum::Struct {
+            f1: Meter(2)
+        } > Enum::Struct {
+            f1: Centimeter(101)
+        }
+    );
+
+    assert!(Enum::Tuple(Meter(1)) < Enum::Tuple(Centimeter(101)));
+    assert!(Enum::Tuple(Meter(2)) > Enum::Tuple(Centimeter(101)));
+    assert!(Enum::Tuple(Meter(1)) > Enum::<Centimeter>::Unit);
+}
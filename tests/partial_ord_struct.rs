@@ -648,6 +648,70 @@ fn bound_3() {
     assert!(Tuple(1) < Tuple(2));
 }
 
+#[test]
+#[allow(dead_code)]
+fn bound_infer() {
+    use core::marker::PhantomData;
+
+    struct NotPartialOrd;
+
+    fn partial_cmp<T>(_: &T, _: &T) -> Option<Ordering> {
+        Some(Ordering::Equal)
+    }
+
+    #[derive(Educe)]
+    #[educe(PartialEq(bound(*)), PartialOrd(bound(*)))]
+    struct Struct<T, U, V> {
+        f1: Option<T>,
+        #[educe(PartialEq(ignore), PartialOrd(ignore))]
+        f2: U,
+        #[educe(PartialEq(ignore), PartialOrd(method(partial_cmp)))]
+        f3: V,
+    }
+
+    let create = |f1| {
+        Struct {
+            f1,
+            f2: NotPartialOrd,
+            f3: PhantomData::<NotPartialOrd>,
+        }
+    };
+
+    assert!(create(Some(1)) < create(Some(2)));
+    assert!(create(Some(2)) > create(None));
+}
+
+#[test]
+fn bound_field() {
+    trait Container {
+        type Item;
+    }
+
+    struct Bytes;
+
+    impl Container for Bytes {
+        type Item = u8;
+    }
+
+    #[derive(Educe)]
+    #[educe(PartialEq, PartialOrd)]
+    struct Struct<T: Container> {
+        #[educe(
+            PartialEq(bound = "T::Item: core::cmp::PartialEq"),
+            PartialOrd(bound = "T::Item: core::cmp::PartialOrd")
+        )]
+        f1: T::Item,
+    }
+
+    assert!(
+        Struct::<Bytes> {
+            f1: 1
+        } < Struct {
+            f1: 2
+        }
+    );
+}
+
 #[test]
 fn rank_1() {
     #[derive(Educe)]
@@ -801,3 +865,96 @@ fn rank_4() {
     assert!(Tuple(2, 1) < Tuple(1, 2));
     assert!(Tuple(1, 2) > Tuple(2, 1));
 }
+
+#[test]
+fn rhs() {
+    struct Meter(u32);
+    struct Centimeter(u32);
+
+    impl core::cmp::PartialEq<Centimeter> for Meter {
+        fn eq(&self, other: &Centimeter) -> bool {
+            self.0 * 100 == other.0
+        }
+    }
+
+    impl core::cmp::PartialOrd<Centimeter> for Meter {
+        fn partial_cmp(&self, other: &Centimeter) -> Option<Ordering> {
+            (self.0 * 100).partial_cmp(&other.0)
+        }
+    }
+
+    #[derive(Educe)]
+    #[educe(PartialEq(rhs = "T"), PartialOrd(rhs = "T"))]
+    struct Struct<T> {
+        f1: T,
+    }
+
+    #[derive(Educe)]
+    #[educe(PartialEq(rhs), PartialOrd(rhs))]
+    struct Tuple<T>(T);
+
+    assert!(
+        Struct {
+            f1: Meter(1)
+        } < Struct {
+            f1: Centimeter(101)
+        }
+    );
+
+    assert!(
+        Struct {
+            f1: Meter(2)
+        } > Struct {
+            f1: Centimeter(101)
+        }
+    );
+
+    assert_eq!(
+        Some(Ordering::Equal),
+        Struct {
+            f1: Meter(1)
+        }
+        .partial_cmp(&Struct {
+            f1: Centimeter(100)
+        })
+    );
+
+    assert!(Tuple(Meter(1)) < Tuple(Centimeter(101)));
+    assert!(Tuple(Meter(2)) > Tuple(Centimeter(101)));
+}
+
+#[test]
+fn rhs_method() {
+    fn same_size<A, B>(_: &A, _: &B) -> bool {
+        core::mem::size_of::<A>() == core::mem::size_of::<B>()
+    }
+
+    fn cmp_size<A, B>(_: &A, _: &B) -> Option<Ordering> {
+        core::mem::size_of::<A>().partial_cmp(&core::mem::size_of::<B>())
+    }
+
+    #[derive(Educe)]
+    #[educe(PartialEq(rhs = "T"), PartialOrd(rhs = "T"))]
+    struct Struct<T> {
+        #[educe(PartialEq(method = "same_size"), PartialOrd(method = "cmp_size"))]
+        f1: T,
+    }
+
+    assert!(
+        Struct {
+            f1: 1u8
+        } < Struct {
+            f1: "1"
+        }
+    );
+
+    assert_eq!(
+        Some(Ordering::Equal),
+        Struct {
+            f1: 1u8
+        }
+        .partial_cmp(&Struct {
+            f1: 2i8
+        })
+    );
+}
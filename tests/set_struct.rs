@@ -0,0 +1,156 @@
+#![cfg(feature = "Set")]
+#![no_std]
+
+#[macro_use]
+extern crate educe;
+
+extern crate alloc;
+
+use alloc::string::String;
+
+#[test]
+fn basic() {
+    #[derive(Educe)]
+    #[educe(Set)]
+    struct Struct {
+        f1: u8,
+        f2: String,
+    }
+
+    let mut s = Struct {
+        f1: 1,
+        f2: String::from("Hi"),
+    };
+
+    s.set_f1(2);
+    s.set_f2(String::from("Hello"));
+
+    assert_eq!(2, s.f1);
+    assert_eq!("Hello", s.f2);
+
+    let s = s.with_f1(3).with_f2(String::from("Hey"));
+
+    assert_eq!(3, s.f1);
+    assert_eq!("Hey", s.f2);
+}
+
+#[test]
+fn into_chain() {
+    #[derive(Educe)]
+    #[educe(Set(into, chain))]
+    struct Struct {
+        f1: u64,
+        f2: String,
+    }
+
+    #[derive(Educe)]
+    #[educe(Set)]
+    struct Field {
+        #[educe(Set(chain))]
+        f1: u8,
+        #[educe(Set(into))]
+        f2: String,
+    }
+
+    let mut s = Struct {
+        f1: 1,
+        f2: String::new(),
+    };
+
+    s.set_f1(2u8).set_f2("Hi");
+
+    assert_eq!(2, s.f1);
+    assert_eq!("Hi", s.f2);
+
+    let mut s = Field {
+        f1: 1,
+        f2: String::new(),
+    };
+
+    s.set_f1(2).set_f1(3);
+    s.set_f2("Hi");
+
+    assert_eq!(3, s.f1);
+    assert_eq!("Hey", s.with_f2("Hey").f2);
+}
+
+#[test]
+fn name_vis_ignore() {
+    mod inner {
+        #[derive(Educe)]
+        #[educe(Set(vis = "pub(super)"))]
+        pub(super) struct Struct {
+            #[educe(Set(name = "first"))]
+            pub(super) f1: u8,
+            #[educe(Set(ignore))]
+            pub(super) f2: u8,
+        }
+
+        impl Struct {
+            pub(super) fn set_f2(&mut self, value: u8) {
+                self.f2 = value + 1;
+            }
+        }
+    }
+
+    let mut s = inner::Struct {
+        f1: 1,
+        f2: 2,
+    };
+
+    s.set_first(3);
+    s.set_f2(3);
+
+    assert_eq!(3, s.f1);
+    assert_eq!(4, s.f2);
+    assert_eq!(5, s.with_first(5).f1);
+}
+
+#[test]
+fn fields() {
+    #[derive(Educe)]
+    #[educe(Set)]
+    struct Struct {
+        #[educe(Set)]
+        f1: u8,
+        f2: u8,
+    }
+
+    impl Struct {
+        fn set_f2(&mut self, value: u8) {
+            self.f2 = value + 1;
+        }
+    }
+
+    let mut s = Struct {
+        f1: 1,
+        f2: 2,
+    };
+
+    s.set_f1(3);
+    s.set_f2(3);
+
+    assert_eq!(3, s.f1);
+    assert_eq!(4, s.f2);
+}
+
+#[test]
+fn generic() {
+    #[derive(Educe)]
+    #[educe(Set)]
+    struct Struct<'a, T, const N: usize> {
+        f1: &'a T,
+        f2: [T; N],
+    }
+
+    let mut s = Struct {
+        f1: &1,
+        f2: [2, 3],
+    };
+
+    s.set_f1(&4);
+    s.set_f2([5, 6]);
+
+    assert_eq!(4, *s.f1);
+    assert_eq!([5, 6], s.f2);
+}
@@ -0,0 +1,13 @@
+#[macro_use]
+extern crate educe;
+
+#[derive(Educe)]
+#[educe(Debug, Hash(foo), Clone(bound = "T: ::"), Hash)]
+struct Struct<T> {
+    #[educe(Debug(bar))]
+    f1: T,
+    #[educe(Debug(ignore, ignore))]
+    f2: u8,
+}
+
+fn main() {}
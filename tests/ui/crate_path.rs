@@ -0,0 +1,12 @@
+#[macro_use]
+extern crate educe;
+
+#[derive(Educe)]
+#[educe(crate = 1, Debug)]
+struct Struct;
+
+#[derive(Educe)]
+#[educe(crate = "educe", crate = "educe", Debug)]
+struct Tuple(u8);
+
+fn main() {}
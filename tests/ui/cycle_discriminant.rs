@@ -0,0 +1,13 @@
+#[macro_use]
+extern crate educe;
+
+const ONE: isize = 1;
+
+#[derive(PartialEq, Eq, Educe)]
+#[educe(PartialOrd, Ord, Cycle(rank))]
+enum Enum {
+    A = ONE,
+    B = 0,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+#[macro_use]
+extern crate educe;
+
+#[derive(Educe)]
+#[educe(PartialEq, Hash)]
+struct Struct {
+    #[educe(skip, Hash(ignore))]
+    f1: u8,
+    #[educe(ignore)]
+    f2: u8,
+    #[educe(ignore(Hash = false))]
+    f3: u8,
+    #[educe(skip(PartialEq))]
+    f4: u8,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+#[macro_use]
+extern crate educe;
+
+fn eq(a: &u16, b: &u16) -> bool {
+    a == b
+}
+
+#[derive(Educe)]
+#[educe(PartialEq)]
+struct Struct {
+    #[educe(PartialEq(method = "eq"))]
+    f1: u8,
+}
+
+fn main() {}
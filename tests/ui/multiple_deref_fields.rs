@@ -0,0 +1,25 @@
+#[macro_use]
+extern crate educe;
+
+#[derive(Educe)]
+#[educe(Deref)]
+struct Struct {
+    #[educe(Deref)]
+    f1: u8,
+    #[educe(Deref)]
+    f2: u8,
+}
+
+#[derive(Educe)]
+#[educe(Deref)]
+enum Enum {
+    Unit,
+    Struct {
+        #[educe(Deref)]
+        f1: u8,
+        #[educe(Deref)]
+        f2: u8,
+    },
+}
+
+fn main() {}
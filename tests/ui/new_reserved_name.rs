@@ -0,0 +1,13 @@
+#[macro_use]
+extern crate educe;
+
+#[derive(Educe)]
+#[educe(New)]
+enum Scope {
+    Crate,
+    Super(u8),
+    #[educe(New(name = "in_crate"))]
+    InCrate,
+}
+
+fn main() {}
@@ -0,0 +1,28 @@
+#[macro_use]
+extern crate educe;
+
+#[derive(Educe)]
+#[educe(Debug)]
+#[repr(packed)]
+struct Struct {
+    f1: u8,
+    f2: String,
+}
+
+#[derive(Educe)]
+#[educe(Deref)]
+#[repr(packed)]
+struct Deref {
+    f1: u8,
+}
+
+#[derive(Educe)]
+#[educe(Get)]
+#[repr(packed)]
+struct Get {
+    f1: u8,
+    #[educe(Get(copy))]
+    f2: u8,
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+#[macro_use]
+extern crate educe;
+
+#[derive(PartialEq, Eq, Educe)]
+#[educe(PartialOrd, Ord)]
+struct Struct {
+    #[educe(Ord(rank = 99999999999999999999))]
+    f1: u8,
+}
+
+#[derive(PartialEq, Educe)]
+#[educe(PartialOrd)]
+enum Enum {
+    #[educe(PartialOrd(rank(99999999999999999999)))]
+    A,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+#[macro_use]
+extern crate educe;
+
+#[derive(PartialEq, Eq, Educe)]
+#[educe(PartialOrd, Ord)]
+struct Struct {
+    #[educe(PartialOrd(rank = 1), Ord(rank = 1))]
+    f1: u8,
+    #[educe(PartialOrd(rank = 1), Ord(rank = 1))]
+    f2: u8,
+}
+
+fn main() {}
@@ -0,0 +1,26 @@
+#[macro_use]
+extern crate educe;
+
+use std::fmt::Debug;
+
+#[derive(Educe)]
+#[educe(Debug(bound(T: Debug, K: NoSuchTrait)))]
+struct Bound<T, K> {
+    f1: T,
+    f2: K,
+}
+
+#[derive(Educe)]
+#[educe(Debug)]
+struct Method {
+    #[educe(Debug(method(crate::nope::no_such_fn)))]
+    f1: u8,
+}
+
+#[derive(Educe)]
+#[educe(PartialEq(bound(T: PartialEq, 1)))]
+struct Syntax<T> {
+    f1: T,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+#[macro_use]
+extern crate educe;
+
+#[derive(Educe)]
+#[educe(Foo, Debug)]
+struct Struct;
+
+#[derive(Educe)]
+#[educe("Debug")]
+struct Literal;
+
+fn main() {}
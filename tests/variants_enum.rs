@@ -0,0 +1,119 @@
+#![cfg(feature = "Variants")]
+#![no_std]
+
+#[macro_use]
+extern crate educe;
+
+#[test]
+#[allow(dead_code)]
+fn basic() {
+    #[derive(Educe)]
+    #[educe(Variants)]
+    enum Enum {
+        Unit,
+        Struct {
+            f1: u8,
+        },
+        Tuple(u8),
+    }
+
+    assert_eq!(["Unit", "Struct", "Tuple"], Enum::VARIANTS);
+    assert_eq!(3, Enum::VARIANT_COUNT);
+
+    assert_eq!("Unit", Enum::Unit.variant_name());
+    assert_eq!(0, Enum::Unit.variant_index());
+
+    assert_eq!(
+        "Struct",
+        Enum::Struct {
+            f1: 1
+        }
+        .variant_name()
+    );
+    assert_eq!(
+        1,
+        Enum::Struct {
+            f1: 1
+        }
+        .variant_index()
+    );
+
+    assert_eq!("Tuple", Enum::Tuple(1).variant_name());
+    assert_eq!(2, Enum::Tuple(1).variant_index());
+}
+
+#[test]
+fn all() {
+    #[derive(Debug, PartialEq, Educe)]
+    #[educe(Variants)]
+    enum Enum {
+        One,
+        Two,
+        Three,
+    }
+
+    assert_eq!([Enum::One, Enum::Two, Enum::Three], Enum::ALL);
+
+    for (index, variant) in Enum::ALL.iter().enumerate() {
+        assert_eq!(index, variant.variant_index());
+        assert_eq!(Enum::VARIANTS[index], variant.variant_name());
+    }
+}
+
+#[test]
+fn empty() {
+    #[derive(Educe)]
+    #[educe(Variants)]
+    enum Enum {}
+
+    assert_eq!(0, Enum::VARIANT_COUNT);
+    assert_eq!(0, Enum::ALL.len());
+}
+
+#[cfg(feature = "Debug")]
+#[test]
+#[allow(dead_code)]
+fn debug_name() {
+    #[derive(Educe)]
+    #[educe(Debug, Variants)]
+    enum Enum {
+        #[educe(Debug(name = "Nothing"))]
+        Unit,
+        Tuple(u8),
+    }
+
+    assert_eq!(["Nothing", "Tuple"], Enum::VARIANTS);
+    assert_eq!("Nothing", Enum::Unit.variant_name());
+}
+
+#[test]
+#[allow(dead_code)]
+fn generic() {
+    #[derive(Educe)]
+    #[educe(Variants)]
+    enum Enum<'a, T, const N: usize> {
+        Unit,
+        Borrowed(&'a T),
+        Array([T; N]),
+    }
+
+    assert_eq!(3, Enum::<u8, 1>::VARIANT_COUNT);
+    assert_eq!("Borrowed", Enum::<u8, 1>::Borrowed(&1).variant_name());
+    assert_eq!(2, Enum::Array([1u8, 2]).variant_index());
+}
+
+#[test]
+#[allow(dead_code, non_camel_case_types)]
+fn raw_ident() {
+    #[derive(Educe)]
+    #[educe(Variants)]
+    enum Enum {
+        r#type,
+        r#Struct {
+            f1: u8,
+        },
+    }
+
+    assert_eq!(["type", "Struct"], Enum::VARIANTS);
+    assert_eq!("type", Enum::r#type.variant_name());
+}